@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use shared::domain::{
+    ApiKeyResponse, ApiResponse, CreateApiKeyRequest, CreatedApiKeyResponse, ErrorResponse,
+};
+
+pub type DynApiKeyService = Arc<dyn ApiKeyServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ApiKeyServiceTrait {
+    async fn find_all(&self) -> Result<ApiResponse<Vec<ApiKeyResponse>>, ErrorResponse>;
+    async fn create(
+        &self,
+        req: &CreateApiKeyRequest,
+    ) -> Result<ApiResponse<CreatedApiKeyResponse>, ErrorResponse>;
+    async fn revoke(&self, id: &i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn authenticate(&self, key: &str) -> Result<String, ErrorResponse>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use shared::domain::{
+    ApiResponsePagination, AuditEventResponse, ErrorResponse, FindAllAuditEventRequest,
+};
+
+pub type DynAuditService = Arc<dyn AuditServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait AuditServiceTrait {
+    async fn find_all(
+        &self,
+        req: &FindAllAuditEventRequest,
+    ) -> Result<ApiResponsePagination<Vec<AuditEventResponse>>, ErrorResponse>;
+}
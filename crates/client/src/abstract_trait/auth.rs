@@ -1,7 +1,12 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use shared::domain::{ApiResponse, ErrorResponse, LoginRequest, RegisterRequest, UserResponse};
+use jsonwebtoken::jwk::JwkSet;
+use shared::domain::{
+    ApiResponse, ErrorResponse, LoginRequest, LoginResponse, LogoutRequest, OidcLoginRequest,
+    PasswordResetRequest, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest,
+    ResetPasswordRequest, TokenResponse, TwoFactorLoginRequest, UserResponse, VerifyEmailRequest,
+};
 
 pub type DynAuthService = Arc<dyn AuthServiceTrait + Send + Sync>;
 
@@ -11,7 +16,43 @@ pub trait AuthServiceTrait {
         &self,
         request_data: RegisterRequest,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
-    async fn login(&self, request_data: LoginRequest)
-    -> Result<ApiResponse<String>, ErrorResponse>;
+    async fn login(
+        &self,
+        request_data: LoginRequest,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse>;
+    async fn verify_two_factor_login(
+        &self,
+        request_data: TwoFactorLoginRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse>;
+    async fn login_with_oidc(
+        &self,
+        request_data: OidcLoginRequest,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse>;
+    async fn refresh_token(
+        &self,
+        request_data: RefreshTokenRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse>;
+    async fn logout(
+        &self,
+        access_token: String,
+        request_data: LogoutRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
     async fn get_me(&self, id: i32) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
+    async fn request_password_reset(
+        &self,
+        request_data: PasswordResetRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn reset_password(
+        &self,
+        request_data: ResetPasswordRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn verify_email(
+        &self,
+        request_data: VerifyEmailRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn resend_verification(
+        &self,
+        request_data: ResendVerificationRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn get_jwks(&self) -> Result<JwkSet, ErrorResponse>;
 }
@@ -1,11 +1,21 @@
+mod api_key;
+mod audit;
 mod auth;
 mod category;
 mod comment;
 mod posts;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
 
+pub use self::api_key::{ApiKeyServiceTrait, DynApiKeyService};
+pub use self::audit::{AuditServiceTrait, DynAuditService};
 pub use self::auth::{AuthServiceTrait, DynAuthService};
 pub use self::category::{CategoryServiceTrait, DynCategoryService};
 pub use self::comment::{CommentServiceTrait, DynCommentService};
 pub use self::posts::{DynPostsService, PostsServiceTrait};
+pub use self::session::{DynSessionService, SessionServiceTrait};
+pub use self::tag::{DynTagService, TagServiceTrait};
+pub use self::two_factor::{DynTwoFactorService, TwoFactorServiceTrait};
 pub use self::user::{DynUserService, UserServiceTrait};
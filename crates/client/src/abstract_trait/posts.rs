@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use shared::domain::{
     ApiResponse, ApiResponsePagination, CreatePostRequest, ErrorResponse, FindAllPostRequest,
-    PostRelationResponse, PostResponse, UpdatePostRequest,
+    FindMyPostsRequest, PostRelationResponse, PostResponse, PostSearchResultResponse,
+    SearchPostsRequest, UpdatePostRequest,
 };
 
 pub type DynPostsService = Arc<dyn PostsServiceTrait + Send + Sync>;
@@ -14,7 +15,16 @@ pub trait PostsServiceTrait {
         &self,
         req: &FindAllPostRequest,
     ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse>;
+    async fn find_mine(
+        &self,
+        req: &FindMyPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse>;
+    async fn search(
+        &self,
+        req: &SearchPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostSearchResultResponse>>, ErrorResponse>;
     async fn find_by_id(&self, id: &i32) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    async fn find_by_slug(&self, slug: &str) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
     async fn create(
         &self,
         req: &CreatePostRequest,
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use shared::domain::{ApiResponse, ErrorResponse, SessionResponse};
+
+pub type DynSessionService = Arc<dyn SessionServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait SessionServiceTrait {
+    async fn find_all(&self) -> Result<ApiResponse<Vec<SessionResponse>>, ErrorResponse>;
+    async fn revoke(&self, id: &str) -> Result<ApiResponse<()>, ErrorResponse>;
+}
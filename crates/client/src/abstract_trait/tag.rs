@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use shared::domain::{
+    ApiResponse, ApiResponsePagination, CreateTagRequest, ErrorResponse, FindAllTagRequest,
+    TagCloudRequest, TagCountResponse, TagResponse, UpdateTagRequest,
+};
+
+pub type DynTagService = Arc<dyn TagServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TagServiceTrait {
+    async fn find_all(
+        &self,
+        req: &FindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, ErrorResponse>;
+    async fn find_by_id(&self, id: &i32) -> Result<ApiResponse<TagResponse>, ErrorResponse>;
+    async fn find_cloud(
+        &self,
+        req: &TagCloudRequest,
+    ) -> Result<ApiResponse<Vec<TagCountResponse>>, ErrorResponse>;
+    async fn create(
+        &self,
+        req: &CreateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse>;
+    async fn update(
+        &self,
+        req: &UpdateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse>;
+    async fn delete(&self, id: &i32) -> Result<ApiResponse<()>, ErrorResponse>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use shared::domain::{
+    ApiResponse, ErrorResponse, RecoveryCodesResponse, TwoFactorCodeRequest,
+    TwoFactorEnrollmentResponse,
+};
+
+pub type DynTwoFactorService = Arc<dyn TwoFactorServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TwoFactorServiceTrait {
+    async fn enroll(&self) -> Result<ApiResponse<TwoFactorEnrollmentResponse>, ErrorResponse>;
+    async fn confirm(
+        &self,
+        req: &TwoFactorCodeRequest,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse>;
+    async fn disable(&self, req: &TwoFactorCodeRequest) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn regenerate_recovery_codes(
+        &self,
+        req: &TwoFactorCodeRequest,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse>;
+}
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use shared::domain::{
-    ApiResponse, ApiResponsePagination, CreateUserRequest, ErrorResponse, FindAllUserRequest,
-    UpdateUserRequest, UserResponse,
+    ApiResponse, ApiResponsePagination, ChangePasswordRequest, ConfirmEmailChangeRequest,
+    CreateUserRequest, ErrorResponse, FindAllUserRequest, RequestEmailChangeRequest,
+    UpdateProfileRequest, UpdateUserRequest, UserResponse,
 };
 use std::sync::Arc;
 
@@ -23,4 +24,20 @@ pub trait UserServiceTrait {
         req: &UpdateUserRequest,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
     async fn delete(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn update_profile(
+        &self,
+        req: &UpdateProfileRequest,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
+    async fn change_password(
+        &self,
+        req: &ChangePasswordRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn request_email_change(
+        &self,
+        req: &RequestEmailChangeRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn confirm_email_change(
+        &self,
+        req: &ConfirmEmailChangeRequest,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
 }
@@ -1,9 +1,11 @@
 use crate::{
     abstract_trait::{
-        DynAuthService, DynCategoryService, DynCommentService, DynPostsService, DynUserService,
+        DynApiKeyService, DynAuditService, DynAuthService, DynCategoryService, DynCommentService,
+        DynPostsService, DynSessionService, DynTagService, DynTwoFactorService, DynUserService,
     },
     service::{
-        AuthService, CategoryService, CommentService, GrpcClients, PostsService, UserService,
+        ApiKeyService, AuditService, AuthService, CategoryService, CommentService, GrpcClients,
+        PostsService, SessionService, TagService, TwoFactorService, UserService,
     },
 };
 
@@ -16,21 +18,31 @@ use tokio::sync::Mutex;
 #[derive(Clone)]
 pub struct DependenciesInject {
     pub category_service: DynCategoryService,
+    pub tag_service: DynTagService,
     pub post_service: DynPostsService,
     pub comment_service: DynCommentService,
     pub user_service: DynUserService,
     pub auth_service: DynAuthService,
     pub file_service: DynFileService,
+    pub api_key_service: DynApiKeyService,
+    pub two_factor_service: DynTwoFactorService,
+    pub session_service: DynSessionService,
+    pub audit_service: DynAuditService,
 }
 
 impl std::fmt::Debug for DependenciesInject {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DependenciesInject")
             .field("category_service", &"DynCategoryService")
+            .field("tag_service", &"DynTagService")
             .field("post_service", &"DynPostsService")
             .field("comment_service", &"DynCommentService")
             .field("user_service", &"DynUserService")
             .field("auth_service", &"DynAuthService")
+            .field("api_key_service", &"DynApiKeyService")
+            .field("two_factor_service", &"DynTwoFactorService")
+            .field("session_service", &"DynSessionService")
+            .field("audit_service", &"DynAuditService")
             .finish()
     }
 }
@@ -47,19 +59,34 @@ impl DependenciesInject {
             Arc::new(UserService::new(clients.user, metrics.clone(), registry).await);
         let category_service: DynCategoryService =
             Arc::new(CategoryService::new(clients.category, metrics.clone(), registry).await);
+        let tag_service: DynTagService =
+            Arc::new(TagService::new(clients.tag, metrics.clone(), registry).await);
         let post_service: DynPostsService =
             Arc::new(PostsService::new(clients.post, metrics.clone(), registry).await);
         let comment_service: DynCommentService =
             Arc::new(CommentService::new(clients.comment, metrics.clone(), registry).await);
         let file_service: DynFileService = Arc::new(FileService::default());
+        let api_key_service: DynApiKeyService =
+            Arc::new(ApiKeyService::new(clients.api_key, metrics.clone(), registry).await);
+        let two_factor_service: DynTwoFactorService =
+            Arc::new(TwoFactorService::new(clients.two_factor, metrics.clone(), registry).await);
+        let session_service: DynSessionService =
+            Arc::new(SessionService::new(clients.session, metrics.clone(), registry).await);
+        let audit_service: DynAuditService =
+            Arc::new(AuditService::new(clients.audit, metrics.clone(), registry).await);
 
         Ok(Self {
             category_service,
+            tag_service,
             post_service,
             comment_service,
             user_service,
             auth_service,
             file_service,
+            api_key_service,
+            two_factor_service,
+            session_service,
+            audit_service,
         })
     }
 }
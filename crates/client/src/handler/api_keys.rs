@@ -0,0 +1,131 @@
+use crate::{
+    middleware::{jwt, validate::SimpleValidatedJson},
+    state::AppState,
+};
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use serde_json::json;
+use shared::domain::{ApiKeyResponse, ApiResponse, CreateApiKeyRequest, CreatedApiKeyResponse};
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/api-keys",
+    responses(
+        (status = 200, description = "API keys of the current user", body = ApiResponse<Vec<ApiKeyResponse>>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "api-keys"
+)]
+pub async fn get_api_keys(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.api_key_service.find_all().await {
+        Ok(api_keys) => Ok((StatusCode::OK, Json(json!(api_keys)))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to fetch API keys",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/api-keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created; the key is only shown once", body = ApiResponse<CreatedApiKeyResponse>),
+        (status = 400, description = "Invalid request body"),
+        (status = 403, description = "Requested scope exceeds the caller's permissions")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "api-keys"
+)]
+pub async fn create_api_key(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.api_key_service.create(&body).await {
+        Ok(api_key) => Ok((StatusCode::CREATED, Json(json!(api_key)))),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to create API key",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/api-keys/{id}",
+    responses(
+        (status = 200, description = "API key revoked"),
+        (status = 404, description = "API key not found or already revoked")
+    ),
+    params(
+        ("id" = i32, Path, description = "API key ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "api-keys"
+)]
+pub async fn revoke_api_key(
+    State(data): State<Arc<AppState>>,
+    Path(api_key_id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.api_key_service.revoke(&api_key_id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == Code::NotFound.to_string() => Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to revoke API key",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+pub fn api_key_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/api-keys", get(get_api_keys))
+        .route("/api/api-keys", post(create_api_key))
+        .route("/api/api-keys/{id}", delete(revoke_api_key))
+        .route_layer(middleware::from_fn(jwt::interactive))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .with_state(app_state)
+}
@@ -0,0 +1,80 @@
+use crate::{
+    middleware::{jwt, rbac},
+    state::AppState,
+};
+use axum::{
+    extract::{Json, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use serde_json::json;
+use shared::domain::{ApiResponsePagination, AuditEventResponse, FindAllAuditEventRequest};
+use shared::model::role::Permission;
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/audit-events",
+    params(FindAllAuditEventRequest),
+    responses(
+        (status = 200, description = "Audit events, newest first", body = ApiResponsePagination<Vec<AuditEventResponse>>),
+        (status = 400, description = "Invalid `from` or `to` timestamp"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing audit:read permission")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "audit"
+)]
+pub async fn get_audit_events(
+    State(data): State<Arc<AppState>>,
+    Query(params): Query<FindAllAuditEventRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.audit_service.find_all(&params).await {
+        Ok(events) => Ok((StatusCode::OK, Json(json!(events)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to fetch audit events",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+pub fn audit_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route(
+            "/api/audit-events",
+            get(get_audit_events).route_layer(middleware::from_fn_with_state(
+                Permission::AuditRead,
+                rbac::authorize,
+            )),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .with_state(app_state)
+}
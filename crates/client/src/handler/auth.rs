@@ -6,13 +6,23 @@ use axum::{
     response::IntoResponse,
     routing::{get, post},
 };
+use axum_extra::extract::cookie::CookieJar;
 use serde_json::{Value, json};
-use shared::domain::{ApiResponse, LoginRequest, RegisterRequest, UserResponse};
+use shared::domain::{
+    ApiResponse, LoginRequest, LoginResponse, LogoutRequest, PasswordResetRequest,
+    RefreshTokenRequest, RegisterRequest, ResendVerificationRequest, ResetPasswordRequest,
+    TokenResponse, TwoFactorLoginRequest, UserResponse, VerifyEmailRequest,
+};
 use std::sync::Arc;
+use tonic::Code;
 use utoipa_axum::router::OpenApiRouter;
 
 use crate::{
-    middleware::{jwt, validate::SimpleValidatedJson},
+    middleware::{
+        auth_cookie,
+        jwt::{self, AccessToken},
+        validate::SimpleValidatedJson,
+    },
     state::AppState,
 };
 
@@ -52,21 +62,233 @@ pub async fn register_user_handler(
     path = "/api/auth/login",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = ApiResponse<String>),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "Tokens, or a two-factor challenge when 2FA is enabled", body = ApiResponse<LoginResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Email address not verified"),
+        (status = 429, description = "Too many failed login attempts")
     ),
     tag = "auth"
 )]
 pub async fn login_user_handler(
     State(data): State<Arc<AppState>>,
+    jar: CookieJar,
     SimpleValidatedJson(body): SimpleValidatedJson<LoginRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
     match data.di_container.auth_service.login(body).await {
-        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Ok(response) => Ok((
+            StatusCode::OK,
+            auth_cookie::issue_login(jar, &data.auth_cookie, &response.data),
+            Json(json!(response)),
+        )),
+        Err(e) if e.status == Code::ResourceExhausted.to_string() => {
+            Err((StatusCode::TOO_MANY_REQUESTS, Json(json!(e))))
+        }
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
         Err(e) => Err((StatusCode::UNAUTHORIZED, Json(json!(e)))),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/login/2fa",
+    request_body = TwoFactorLoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = ApiResponse<TokenResponse>),
+        (status = 401, description = "Invalid code or invalid, expired or used challenge"),
+        (status = 429, description = "Too many failed login attempts")
+    ),
+    tag = "auth"
+)]
+pub async fn verify_two_factor_login_handler(
+    State(data): State<Arc<AppState>>,
+    jar: CookieJar,
+    SimpleValidatedJson(body): SimpleValidatedJson<TwoFactorLoginRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data
+        .di_container
+        .auth_service
+        .verify_two_factor_login(body)
+        .await
+    {
+        Ok(response) => Ok((
+            StatusCode::OK,
+            auth_cookie::issue(jar, &data.auth_cookie, &response.data),
+            Json(json!(response)),
+        )),
+        Err(e) if e.status == Code::ResourceExhausted.to_string() => {
+            Err((StatusCode::TOO_MANY_REQUESTS, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::UNAUTHORIZED, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/refresh",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Token refreshed", body = ApiResponse<TokenResponse>),
+        (status = 401, description = "Invalid, expired or reused refresh token")
+    ),
+    tag = "auth"
+)]
+pub async fn refresh_token_handler(
+    State(data): State<Arc<AppState>>,
+    jar: CookieJar,
+    SimpleValidatedJson(body): SimpleValidatedJson<RefreshTokenRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data.di_container.auth_service.refresh_token(body).await {
+        Ok(response) => Ok((
+            StatusCode::OK,
+            auth_cookie::issue(jar, &data.auth_cookie, &response.data),
+            Json(json!(response)),
+        )),
+        Err(e) => Err((StatusCode::UNAUTHORIZED, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    request_body = LogoutRequest,
+    responses(
+        (status = 200, description = "Logged out successfully; clears the auth cookies"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Cookie credential without a matching X-CSRF-Token header")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn logout_handler(
+    State(data): State<Arc<AppState>>,
+    Extension(AccessToken(token)): Extension<AccessToken>,
+    jar: CookieJar,
+    body: Option<Json<LogoutRequest>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+
+    match data.di_container.auth_service.logout(token, body).await {
+        Ok(response) => Ok((
+            StatusCode::OK,
+            auth_cookie::clear(jar, &data.auth_cookie),
+            Json(json!(response)),
+        )),
+        Err(e) => Err((StatusCode::UNAUTHORIZED, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/password-reset/request",
+    request_body = PasswordResetRequest,
+    responses(
+        (status = 200, description = "Reset link sent if the account exists"),
+        (status = 400, description = "Invalid request body")
+    ),
+    tag = "auth"
+)]
+pub async fn request_password_reset_handler(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<PasswordResetRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data
+        .di_container
+        .auth_service
+        .request_password_reset(body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/password-reset/confirm",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset successfully"),
+        (status = 400, description = "Invalid, expired or already used reset token")
+    ),
+    tag = "auth"
+)]
+pub async fn reset_password_handler(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ResetPasswordRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data.di_container.auth_service.reset_password(body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email address verified"),
+        (status = 400, description = "Invalid, expired or already used verification token")
+    ),
+    tag = "auth"
+)]
+pub async fn verify_email_handler(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data.di_container.auth_service.verify_email(body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email/resend",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Verification link sent if the account is unverified"),
+        (status = 400, description = "Invalid request body")
+    ),
+    tag = "auth"
+)]
+pub async fn resend_verification_handler(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ResendVerificationRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    match data
+        .di_container
+        .auth_service
+        .resend_verification(body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    responses(
+        (status = 200, description = "Public keys used to verify access tokens")
+    ),
+    tag = "auth"
+)]
+pub async fn jwks_handler(State(data): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(data.jwt_config.jwks())
+}
+
 #[utoipa::path(
     get,
     path = "/api/users/me",
@@ -102,8 +324,30 @@ pub async fn get_me_handler(
 pub fn auth_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     OpenApiRouter::new()
         .route("/api/healthchecker", get(health_checker_handler))
+        .route("/.well-known/jwks.json", get(jwks_handler))
         .route("/api/auth/register", post(register_user_handler))
         .route("/api/auth/login", post(login_user_handler))
+        .route("/api/auth/login/2fa", post(verify_two_factor_login_handler))
+        .route("/api/auth/refresh", post(refresh_token_handler))
+        .route(
+            "/api/auth/password-reset/request",
+            post(request_password_reset_handler),
+        )
+        .route(
+            "/api/auth/password-reset/confirm",
+            post(reset_password_handler),
+        )
+        .route("/api/auth/verify-email", post(verify_email_handler))
+        .route(
+            "/api/auth/verify-email/resend",
+            post(resend_verification_handler),
+        )
+        .route(
+            "/api/auth/logout",
+            post(logout_handler)
+                .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+                .with_state(app_state.clone()),
+        )
         .route(
             "/api/users/me",
             get(get_me_handler)
@@ -1,5 +1,5 @@
 use crate::{
-    middleware::{jwt, validate::SimpleValidatedJson},
+    middleware::{jwt, rbac, validate::SimpleValidatedJson},
     state::AppState,
 };
 use axum::{
@@ -15,7 +15,9 @@ use shared::domain::{
     ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest,
     FindAllCategoryRequest, UpdateCategoryRequest,
 };
+use shared::model::role::Permission;
 use std::sync::Arc;
+use tonic::Code;
 use utoipa_axum::router::OpenApiRouter;
 
 #[utoipa::path(
@@ -23,7 +25,8 @@ use utoipa_axum::router::OpenApiRouter;
     path = "/api/categories",
     params(FindAllCategoryRequest),
     responses(
-        (status = 200, description = "List all category successfully", body = ApiResponsePagination<Vec<CategoryResponse>>)
+        (status = 200, description = "List all category successfully", body = ApiResponsePagination<Vec<CategoryResponse>>),
+        (status = 400, description = "Invalid pagination cursor")
     ),
     security(
         ("bearer_auth" = [])
@@ -36,6 +39,9 @@ pub async fn get_categories(
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.category_service.find_all(&params).await {
         Ok(categories) => Ok((StatusCode::OK, Json(json!(categories)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -179,9 +185,27 @@ pub async fn delete_category(
 pub fn category_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
         .route("/api/categories/{id}", get(get_category))
-        .route("/api/categories/create", post(create_category))
-        .route("/api/categories/update/{id}", put(update_category))
-        .route("/api/categories/delete/{id}", delete(delete_category))
+        .route(
+            "/api/categories/create",
+            post(create_category).route_layer(middleware::from_fn_with_state(
+                Permission::CategoriesWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/categories/update/{id}",
+            put(update_category).route_layer(middleware::from_fn_with_state(
+                Permission::CategoriesWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/categories/delete/{id}",
+            delete(delete_category).route_layer(middleware::from_fn_with_state(
+                Permission::CategoriesDelete,
+                rbac::authorize,
+            )),
+        )
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
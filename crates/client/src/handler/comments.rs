@@ -1,5 +1,5 @@
 use crate::{
-    middleware::{jwt, validate::SimpleValidatedJson},
+    middleware::{jwt, rbac, validate::SimpleValidatedJson},
     state::AppState,
 };
 use axum::{
@@ -11,7 +11,9 @@ use axum::{
 };
 use serde_json::json;
 use shared::domain::{ApiResponse, CommentResponse, CreateCommentRequest, UpdateCommentRequest};
+use shared::model::role::Permission;
 use std::sync::Arc;
+use tonic::Code;
 use utoipa_axum::router::OpenApiRouter;
 
 #[utoipa::path(
@@ -64,6 +66,13 @@ pub async fn get_comment(
         .await
     {
         Ok(comment) => Ok((StatusCode::OK, Json(json!(comment)))),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
         Err(e) if e.status == "NOT_FOUND" => Err((
             StatusCode::NOT_FOUND,
             Json(json!({
@@ -88,7 +97,9 @@ pub async fn get_comment(
     request_body = CreateCommentRequest,
     responses(
         (status = 201, description = "Comment created", body = ApiResponse<CommentResponse>),
-        (status = 400, description = "Invalid request body")
+        (status = 400, description = "Invalid request body"),
+        (status = 403, description = "Email address not verified"),
+        (status = 404, description = "Post not found")
     ),
     tag = "comments"
 )]
@@ -98,6 +109,12 @@ pub async fn create_comment(
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.comment_service.create(&body).await {
         Ok(comment) => Ok((StatusCode::CREATED, Json(json!(comment)))),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
+        Err(e) if e.status == Code::NotFound.to_string() => {
+            Err((StatusCode::NOT_FOUND, Json(json!(e))))
+        }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({
@@ -115,6 +132,7 @@ pub async fn create_comment(
     request_body = UpdateCommentRequest,
     responses(
         (status = 200, description = "Comment updated", body = ApiResponse<CommentResponse>),
+        (status = 403, description = "Not the author of this comment"),
         (status = 404, description = "Comment not found")
     ),
     params(
@@ -131,6 +149,13 @@ pub async fn update_comment(
 
     match data.di_container.comment_service.update(&body).await {
         Ok(comment) => Ok((StatusCode::OK, Json(json!(comment)))),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
         Err(e) if e.status == "NOT_FOUND" => Err((
             StatusCode::NOT_FOUND,
             Json(json!({
@@ -154,6 +179,7 @@ pub async fn update_comment(
     path = "/api/comments/{id}",
     responses(
         (status = 200, description = "Comment deleted successfully", body=Value),
+        (status = 403, description = "Not the author of this comment"),
         (status = 500, description = "Failed to delete comment")
     ),
     params(
@@ -173,6 +199,13 @@ pub async fn delete_comment(
                 "message": "Comment deleted successfully"
             })),
         )),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({
@@ -188,9 +221,27 @@ pub fn comment_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
         .route("/api/comments", get(get_comments))
         .route("/api/comments/{id}", get(get_comment))
-        .route("/api/comments", post(create_comment))
-        .route("/api/comments/{id}", put(update_comment))
-        .route("/api/comments/{id}", delete(delete_comment))
+        .route(
+            "/api/comments",
+            post(create_comment).route_layer(middleware::from_fn_with_state(
+                Permission::CommentsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/comments/{id}",
+            put(update_comment).route_layer(middleware::from_fn_with_state(
+                Permission::CommentsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/comments/{id}",
+            delete(delete_comment).route_layer(middleware::from_fn_with_state(
+                Permission::CommentsWrite,
+                rbac::authorize,
+            )),
+        )
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
@@ -1,16 +1,23 @@
+mod api_keys;
+mod audit;
 mod auth;
 mod category;
 mod comments;
+mod oidc;
 mod posts;
+mod sessions;
+mod tags;
+mod two_factor;
 mod user;
 
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 use anyhow::{Context, Result};
 use axum::body::Body;
 use axum::extract::{DefaultBodyLimit, State};
 use axum::http::StatusCode;
 use axum::http::header::CONTENT_TYPE;
+use axum::middleware;
 use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use prometheus_client::encoding::text::encode;
@@ -21,49 +28,91 @@ use utoipa::{Modify, OpenApi};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::state::AppState;
+use crate::{middleware::request_context, state::AppState};
 
+pub use self::api_keys::api_key_routes;
+pub use self::audit::audit_routes;
 pub use self::auth::auth_routes;
 pub use self::category::category_routes;
 pub use self::comments::comment_routes;
+pub use self::oidc::oidc_routes;
 pub use self::posts::post_routes;
+pub use self::sessions::session_routes;
+pub use self::tags::tag_routes;
+pub use self::two_factor::two_factor_routes;
 pub use self::user::user_routes;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         auth::login_user_handler,
+        auth::verify_two_factor_login_handler,
         auth::get_me_handler,
         auth::register_user_handler,
+        auth::refresh_token_handler,
+        auth::logout_handler,
+        auth::request_password_reset_handler,
+        auth::reset_password_handler,
+        auth::verify_email_handler,
+        auth::resend_verification_handler,
+        auth::jwks_handler,
+        oidc::oidc_login_handler,
+        oidc::oidc_callback_handler,
+        sessions::get_sessions,
+        sessions::revoke_session,
+        two_factor::enroll_two_factor,
+        two_factor::confirm_two_factor,
+        two_factor::disable_two_factor,
+        two_factor::regenerate_recovery_codes,
         user::get_users,
         user::get_user,
         user::create_user,
         user::update_user,
         user::delete_user,
+        user::update_me,
+        user::change_my_password,
+        user::request_email_change,
+        user::confirm_email_change,
         category::get_categories,
         category::get_category,
         category::create_category,
         category::update_category,
         category::delete_category,
+        tags::get_tags,
+        tags::get_tag_cloud,
+        tags::get_tag,
+        tags::create_tag,
+        tags::update_tag,
+        tags::delete_tag,
         comments::get_comments,
         comments::get_comment,
         comments::create_comment,
         comments::update_comment,
         comments::delete_comment,
         posts::get_posts,
+        posts::get_my_posts,
+        posts::search_posts,
         posts::get_post,
+        posts::get_post_by_slug,
         posts::get_post_relation,
         posts::create_post,
         posts::update_post,
         posts::delete_post,
+        api_keys::get_api_keys,
+        api_keys::create_api_key,
+        api_keys::revoke_api_key,
+        audit::get_audit_events,
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "auth", description = "Authentication endpoints."),
         (name = "category", description = "Category management endpoints."),
+        (name = "tags", description = "Tag management and tag cloud endpoints."),
         (name = "posts", description = "Post management endpoints."),
         (name = "comments", description = "Comments management endpoints."),
-        (name = "users", description = "User management endpoints.")
+        (name = "users", description = "User management endpoints."),
+        (name = "api-keys", description = "API key management endpoints."),
+        (name = "audit", description = "Audit log endpoints.")
     )
 )]
 struct ApiDoc;
@@ -80,6 +129,15 @@ impl Modify for SecurityAddon {
                 utoipa::openapi::security::HttpAuthScheme::Bearer,
             )),
         );
+        components.add_security_scheme(
+            "api_key_auth",
+            SecurityScheme::ApiKey(utoipa::openapi::security::ApiKey::Header(
+                utoipa::openapi::security::ApiKeyValue::with_description(
+                    "Authorization",
+                    "ApiKey <key>",
+                ),
+            )),
+        );
     }
 }
 
@@ -116,14 +174,21 @@ impl AppRouter {
             .with_state(shared_state.clone());
 
         router = router.merge(auth_routes(shared_state.clone()));
+        router = router.merge(oidc_routes(shared_state.clone()));
+        router = router.merge(session_routes(shared_state.clone()));
         router = router.merge(category_routes(shared_state.clone()));
+        router = router.merge(tag_routes(shared_state.clone()));
         router = router.merge(comment_routes(shared_state.clone()));
         router = router.merge(post_routes(shared_state.clone()));
         router = router.merge(user_routes(shared_state.clone()));
+        router = router.merge(api_key_routes(shared_state.clone()));
+        router = router.merge(two_factor_routes(shared_state.clone()));
+        router = router.merge(audit_routes(shared_state.clone()));
 
         let router = router
             .layer(DefaultBodyLimit::disable())
-            .layer(RequestBodyLimitLayer::new(250 * 1024 * 1024));
+            .layer(RequestBodyLimitLayer::new(250 * 1024 * 1024))
+            .layer(middleware::from_fn(request_context::capture));
 
         let (router, api) = router.split_for_parts();
 
@@ -137,9 +202,12 @@ impl AppRouter {
 
         println!("Server running on http://{}", listener.local_addr()?);
 
-        axum::serve(listener, router)
-            .await
-            .context("Failed to start Axum server")?;
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .context("Failed to start Axum server")?;
 
         Ok(())
     }
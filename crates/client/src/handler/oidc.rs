@@ -0,0 +1,219 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect},
+    routing::get,
+};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use chrono::Utc;
+use serde_json::{Value, json};
+use shared::{
+    domain::{ApiResponse, ErrorResponse, LoginResponse, OidcCallbackRequest, OidcLoginRequest},
+    utils::{generate_random_token, pkce_challenge},
+};
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+use crate::{
+    middleware::auth_cookie,
+    state::{AppState, OidcLogin},
+};
+
+const FLOW_COOKIE: &str = "oidc_flow";
+const FLOW_COOKIE_PATH: &str = "/api/auth/oidc";
+
+/// Per-login secrets kept in an HttpOnly cookie between the redirect to the
+/// identity provider and its callback.
+struct LoginFlow {
+    state: String,
+    nonce: String,
+    code_verifier: String,
+    started_at: i64,
+}
+
+impl LoginFlow {
+    fn start() -> Self {
+        Self {
+            state: generate_random_token(),
+            nonce: generate_random_token(),
+            code_verifier: generate_random_token(),
+            started_at: Utc::now().timestamp(),
+        }
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "{}.{}.{}.{}",
+            self.state, self.nonce, self.code_verifier, self.started_at
+        )
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let mut parts = value.split('.');
+        let flow = Self {
+            state: parts.next()?.to_string(),
+            nonce: parts.next()?.to_string(),
+            code_verifier: parts.next()?.to_string(),
+            started_at: parts.next()?.parse().ok()?,
+        };
+
+        parts.next().is_none().then_some(flow)
+    }
+}
+
+fn fail(status: StatusCode, message: &str) -> (StatusCode, Json<Value>) {
+    (
+        status,
+        Json(json!(ErrorResponse {
+            status: "fail".to_string(),
+            message: message.to_string(),
+        })),
+    )
+}
+
+fn oidc_login(data: &AppState) -> Result<&OidcLogin, (StatusCode, Json<Value>)> {
+    data.oidc.as_ref().ok_or_else(|| {
+        fail(
+            StatusCode::NOT_FOUND,
+            "OpenID Connect login is not configured",
+        )
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/login",
+    responses(
+        (status = 303, description = "Redirect to the identity provider"),
+        (status = 404, description = "OpenID Connect login is not configured"),
+        (status = 502, description = "Identity provider unavailable")
+    ),
+    tag = "auth"
+)]
+pub async fn oidc_login_handler(
+    State(data): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<impl IntoResponse, (StatusCode, Json<Value>)> {
+    let oidc = oidc_login(&data)?;
+    let flow = LoginFlow::start();
+
+    let authorization_url = oidc
+        .provider
+        .authorization_url(
+            &flow.state,
+            &flow.nonce,
+            &pkce_challenge(&flow.code_verifier),
+        )
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, Json(json!(ErrorResponse::from(e)))))?;
+
+    // Lax so the cookie survives the top-level redirect back from the provider.
+    let cookie = Cookie::build((FLOW_COOKIE, flow.encode()))
+        .path(FLOW_COOKIE_PATH)
+        .http_only(true)
+        .secure(oidc.config.redirect_url.starts_with("https://"))
+        .same_site(SameSite::Lax);
+
+    Ok((jar.add(cookie), Redirect::to(&authorization_url)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/callback",
+    params(OidcCallbackRequest),
+    responses(
+        (status = 200, description = "Tokens, or a two-factor challenge when 2FA is enabled", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Missing, expired or mismatched login flow"),
+        (status = 401, description = "Identity provider rejected the login or returned an invalid ID token"),
+        (status = 403, description = "No verified email to link, or the matching account is unverified"),
+        (status = 404, description = "OpenID Connect login is not configured")
+    ),
+    tag = "auth"
+)]
+pub async fn oidc_callback_handler(
+    State(data): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(params): Query<OidcCallbackRequest>,
+) -> impl IntoResponse {
+    let flow = jar
+        .get(FLOW_COOKIE)
+        .and_then(|cookie| LoginFlow::decode(cookie.value()));
+    let jar = jar.remove(Cookie::build(FLOW_COOKIE).path(FLOW_COOKIE_PATH));
+
+    let result = complete_oidc_login(&data, flow, params).await;
+    let jar = match &result {
+        Ok(response) => auth_cookie::issue_login(jar, &data.auth_cookie, &response.data),
+        Err(_) => jar,
+    };
+
+    (
+        jar,
+        result.map(|response| (StatusCode::OK, Json(json!(response)))),
+    )
+}
+
+async fn complete_oidc_login(
+    data: &AppState,
+    flow: Option<LoginFlow>,
+    params: OidcCallbackRequest,
+) -> Result<ApiResponse<LoginResponse>, (StatusCode, Json<Value>)> {
+    let oidc = oidc_login(data)?;
+
+    let flow = flow
+        .filter(|flow| {
+            Utc::now().timestamp() - flow.started_at <= oidc.config.flow_ttl.as_secs() as i64
+        })
+        .ok_or_else(|| fail(StatusCode::BAD_REQUEST, "Login flow is missing or expired"))?;
+
+    if params.state.as_deref() != Some(flow.state.as_str()) {
+        return Err(fail(StatusCode::BAD_REQUEST, "Login state mismatch"));
+    }
+
+    if let Some(error) = params.error {
+        let message = match params.error_description {
+            Some(description) => format!("Identity provider returned {error}: {description}"),
+            None => format!("Identity provider returned {error}"),
+        };
+        return Err(fail(StatusCode::UNAUTHORIZED, &message));
+    }
+
+    let code = params
+        .code
+        .ok_or_else(|| fail(StatusCode::BAD_REQUEST, "Missing authorization code"))?;
+
+    let id_token = oidc
+        .provider
+        .exchange_code(&code, &flow.code_verifier)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(json!(ErrorResponse::from(e))),
+            )
+        })?;
+
+    match data
+        .di_container
+        .auth_service
+        .login_with_oidc(OidcLoginRequest {
+            id_token,
+            nonce: flow.nonce,
+        })
+        .await
+    {
+        Ok(response) => Ok(response),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::UNAUTHORIZED, Json(json!(e)))),
+    }
+}
+
+pub fn oidc_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/auth/oidc/login", get(oidc_login_handler))
+        .route("/api/auth/oidc/callback", get(oidc_callback_handler))
+        .with_state(app_state)
+}
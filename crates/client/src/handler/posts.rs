@@ -1,17 +1,24 @@
-use crate::{middleware::jwt, state::AppState};
+use crate::{
+    middleware::{jwt, rbac},
+    state::AppState,
+};
 use axum::{
+    Extension,
     extract::{Json, Multipart, Path, Query, State},
     http::StatusCode,
     middleware,
-    response::IntoResponse,
+    response::{IntoResponse, Redirect},
     routing::{delete, get, post, put},
 };
 use serde_json::json;
 use shared::domain::{
-    ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest,
-    PostRelationResponse, PostResponse, UpdatePostRequest,
+    ApiResponse, ApiResponsePagination, CreatePostRequest, FindAllPostRequest, FindMyPostsRequest,
+    PostRelationResponse, PostResponse, PostSearchResultResponse, SearchPostsRequest,
+    UpdatePostRequest,
 };
+use shared::{config::Claims, model::role::Permission};
 use std::sync::Arc;
+use tonic::Code;
 use utoipa_axum::router::OpenApiRouter;
 
 #[utoipa::path(
@@ -19,7 +26,9 @@ use utoipa_axum::router::OpenApiRouter;
     path = "/api/posts",
     params(FindAllPostRequest),
     responses(
-        (status = 200, description = "List all posts successfully", body = ApiResponsePagination<Vec<PostResponse>>)
+        (status = 200, description = "List all posts successfully", body = ApiResponsePagination<Vec<PostResponse>>),
+        (status = 400, description = "Invalid cursor, filter or sort"),
+        (status = 403, description = "Listing unpublished posts needs posts:moderate")
     ),
     security(("bearer_auth" = [])),
     tag = "posts"
@@ -30,6 +39,60 @@ pub async fn get_posts(
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.post_service.find_all(&params).await {
         Ok(posts) => Ok((StatusCode::OK, Json(json!(posts)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/search",
+    params(SearchPostsRequest),
+    responses(
+        (status = 200, description = "Published posts matching the query, best first, with highlighted title and snippet", body = ApiResponsePagination<Vec<PostSearchResultResponse>>),
+        (status = 400, description = "Missing or too long query")
+    ),
+    tag = "posts"
+)]
+pub async fn search_posts(
+    State(data): State<Arc<AppState>>,
+    Query(params): Query<SearchPostsRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.post_service.search(&params).await {
+        Ok(results) => Ok((StatusCode::OK, Json(json!(results)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/posts/mine",
+    params(FindMyPostsRequest),
+    responses(
+        (status = 200, description = "The caller's posts, drafts included", body = ApiResponsePagination<Vec<PostResponse>>),
+        (status = 400, description = "Unknown status filter"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "posts"
+)]
+pub async fn get_my_posts(
+    State(data): State<Arc<AppState>>,
+    Query(params): Query<FindMyPostsRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.post_service.find_mine(&params).await {
+        Ok(posts) => Ok((StatusCode::OK, Json(json!(posts)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -70,6 +133,48 @@ pub async fn get_post(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/posts/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Current or former post slug")
+    ),
+    responses(
+        (status = 200, description = "Get post by slug", body = ApiResponse<PostResponse>),
+        (status = 301, description = "Post was renamed; follow Location to its current slug"),
+        (status = 404, description = "Post not found")
+    ),
+    tag = "posts"
+)]
+pub async fn get_post_by_slug(
+    State(data): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.post_service.find_by_slug(&slug).await {
+        Ok(post) if post.data.slug != slug => Ok(Redirect::permanent(&format!(
+            "/api/posts/by-slug/{}",
+            post.data.slug
+        ))
+        .into_response()),
+        Ok(post) => Ok((StatusCode::OK, Json(json!(post))).into_response()),
+        Err(e) if e.status == Code::NotFound.to_string() => Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to fetch post",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/posts/{id}/relation",
@@ -88,6 +193,13 @@ pub async fn get_post_relation(
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.post_service.find_relation(&post_id).await {
         Ok(posts) => Ok((StatusCode::OK, Json(json!(posts)))),
+        Err(e) if e.status == Code::NotFound.to_string() => Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -99,6 +211,7 @@ pub async fn get_post_relation(
     responses(
         (status = 201, description = "Post created successfully", body = ApiResponse<PostResponse>),
         (status = 400, description = "Invalid request body"),
+        (status = 403, description = "Email address not verified"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -108,13 +221,15 @@ pub async fn get_post_relation(
 )]
 pub async fn create_post(
     State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
     mut multipart: Multipart,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     let mut title: Option<String> = None;
     let mut body: Option<String> = None;
     let mut category_id: Option<i32> = None;
-    let mut user_id: Option<i32> = None;
-    let mut user_name: Option<String> = None;
+    let mut status: Option<String> = None;
+    let mut published_at: Option<String> = None;
+    let mut tags: Vec<String> = Vec::new();
     let mut file_data: Option<(String, String, Vec<u8>)> = None;
 
     while let Some(field) = multipart.next_field().await.unwrap() {
@@ -135,24 +250,21 @@ pub async fn create_post(
                         .expect("should be a number for category_id field"),
                 );
             }
-            Some("user_id") => {
-                user_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_id field")
-                        .parse()
-                        .expect("should be a number for user_id field"),
-                );
+            Some("status") => {
+                status = Some(field.text().await.expect("should be text for status field"));
             }
-            Some("user_name") => {
-                user_name = Some(
+            Some("published_at") => {
+                published_at = Some(
                     field
                         .text()
                         .await
-                        .expect("should be text for user_name field"),
+                        .expect("should be text for published_at field"),
                 );
             }
+            Some("tags") => {
+                let value = field.text().await.expect("should be text for tags field");
+                tags.extend(split_tags(&value));
+            }
             Some("file") => {
                 let file_name = field.file_name().map(ToString::to_string);
                 let content_type = field.content_type().map(ToString::to_string);
@@ -200,12 +312,21 @@ pub async fn create_post(
         body: body.unwrap_or_default(),
         file: uploaded_file_name,
         category_id: category_id.unwrap_or(0),
-        user_id: user_id.unwrap_or(0),
-        user_name: user_name.unwrap_or_default(),
+        status: status.unwrap_or_default(),
+        published_at: published_at.unwrap_or_default(),
+        tags,
+        user_id: user_id as i32,
+        user_name: String::new(),
     };
 
     match data.di_container.post_service.create(&post_data).await {
         Ok(post) => Ok((StatusCode::CREATED, Json(json!(post)))),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!({"error": e.message}))))
+        }
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!({"error": e.message}))))
+        }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({"error": e.to_string()})),
@@ -223,6 +344,7 @@ pub async fn create_post(
     responses(
         (status = 200, description = "Post updated successfully", body = ApiResponse<PostResponse>),
         (status = 400, description = "Invalid request body"),
+        (status = 403, description = "Not the author of this post"),
         (status = 404, description = "Post not found")
     ),
     security(
@@ -232,14 +354,16 @@ pub async fn create_post(
 )]
 pub async fn update_post(
     State(data): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Path(post_id): Path<i32>,
     mut multipart: Multipart,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     let mut title: Option<String> = None;
     let mut body: Option<String> = None;
     let mut category_id: Option<i32> = None;
-    let mut user_id: Option<i32> = None;
-    let mut user_name: Option<String> = None;
+    let mut status: Option<String> = None;
+    let mut published_at: Option<String> = None;
+    let mut tags: Option<Vec<String>> = None;
     let mut file_data: Option<(String, String, Vec<u8>)> = None;
 
     let old_post = match data.di_container.post_service.find_by_id(&post_id).await {
@@ -247,12 +371,13 @@ pub async fn update_post(
         Err(e) => return Err((StatusCode::NOT_FOUND, Json(json!(e)))),
     };
 
-    if !old_post.img.is_empty() {
-        let _ = data
-            .di_container
-            .file_service
-            .delete_image("posts", &old_post.img)
-            .await;
+    if i64::from(old_post.user_id) != claims.user_id
+        && !claims.has_permission(Permission::PostsModerate)
+    {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({"error": "You can only modify your own posts"})),
+        ));
     }
 
     while let Some(field) = multipart.next_field().await.unwrap() {
@@ -273,24 +398,22 @@ pub async fn update_post(
                         .expect("should be a number for category_id field"),
                 );
             }
-            Some("user_id") => {
-                user_id = Some(
-                    field
-                        .text()
-                        .await
-                        .expect("should be text for user_id field")
-                        .parse()
-                        .expect("should be a number for user_id field"),
-                );
+            Some("status") => {
+                status = Some(field.text().await.expect("should be text for status field"));
             }
-            Some("user_name") => {
-                user_name = Some(
+            Some("published_at") => {
+                published_at = Some(
                     field
                         .text()
                         .await
-                        .expect("should be text for user_name field"),
+                        .expect("should be text for published_at field"),
                 );
             }
+            Some("tags") => {
+                // Any `tags` field, even an empty one, replaces the post's tags.
+                let value = field.text().await.expect("should be text for tags field");
+                tags.get_or_insert_with(Vec::new).extend(split_tags(&value));
+            }
             Some("file") => {
                 let file_name = field.file_name().map(ToString::to_string);
                 let content_type = field.content_type().map(ToString::to_string);
@@ -339,12 +462,29 @@ pub async fn update_post(
         body: body.unwrap_or_default(),
         file: uploaded_file_name,
         category_id: category_id.unwrap_or(0),
-        user_id: user_id.unwrap_or(0),
-        user_name: user_name.unwrap_or_default(),
+        status: status.unwrap_or_default(),
+        published_at: published_at.unwrap_or_default(),
+        tags,
     };
 
     match data.di_container.post_service.update(&post_data).await {
-        Ok(post) => Ok((StatusCode::OK, Json(json!(post)))),
+        Ok(post) => {
+            if !old_post.img.is_empty() {
+                let _ = data
+                    .di_container
+                    .file_service
+                    .delete_image("posts", &old_post.img)
+                    .await;
+            }
+
+            Ok((StatusCode::OK, Json(json!(post))))
+        }
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!({"error": e.message}))))
+        }
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!({"error": e.message}))))
+        }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({"error": e.to_string()})),
@@ -360,6 +500,7 @@ pub async fn update_post(
     ),
     responses(
         (status = 200, description = "Post deleted successfully"),
+        (status = 403, description = "Not the author of this post"),
         (status = 404, description = "Post not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -380,21 +521,61 @@ pub async fn delete_post(
                 "message": "Post deleted successfully"
             })),
         )),
+        Err(e) if e.status == Code::PermissionDenied.to_string() => {
+            Err((StatusCode::FORBIDDEN, Json(json!(e))))
+        }
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
 
+/// Tags arrive as repeated `tags` fields, comma-separated values, or both.
+fn split_tags(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(ToString::to_string)
+}
+
 pub fn post_routes(app_state: Arc<AppState>) -> OpenApiRouter {
     let protected_routes = OpenApiRouter::new()
-        .route("/api/posts/create", post(create_post))
+        .route(
+            "/api/posts/create",
+            post(create_post).route_layer(middleware::from_fn_with_state(
+                Permission::PostsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route("/api/posts/mine", get(get_my_posts))
         .route("/api/posts/{id}", get(get_post))
-        .route("/api/posts/update/{id}", put(update_post))
-        .route("/api/posts/delete/{id}", delete(delete_post))
+        .route(
+            "/api/posts/update/{id}",
+            put(update_post).route_layer(middleware::from_fn_with_state(
+                Permission::PostsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/posts/delete/{id}",
+            delete(delete_post).route_layer(middleware::from_fn_with_state(
+                Permission::PostsDelete,
+                rbac::authorize,
+            )),
+        )
         .route("/api/posts/{id}/relation", get(get_post_relation))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
-    let public_routes = OpenApiRouter::new().route("/posts", get(get_posts));
+    let public_routes = OpenApiRouter::new()
+        .route(
+            "/posts",
+            get(get_posts).route_layer(middleware::from_fn_with_state(
+                app_state.clone(),
+                jwt::optional_auth,
+            )),
+        )
+        .route("/api/posts/search", get(search_posts))
+        .route("/api/posts/by-slug/{slug}", get(get_post_by_slug));
 
     OpenApiRouter::new()
         .merge(protected_routes)
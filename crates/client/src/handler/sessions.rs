@@ -0,0 +1,93 @@
+use crate::{middleware::jwt, state::AppState};
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use serde_json::json;
+use shared::domain::{ApiResponse, SessionResponse};
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/sessions",
+    responses(
+        (status = 200, description = "Active sessions of the current user", body = ApiResponse<Vec<SessionResponse>>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn get_sessions(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.session_service.find_all().await {
+        Ok(sessions) => Ok((StatusCode::OK, Json(json!(sessions)))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to fetch sessions",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/auth/sessions/{id}",
+    responses(
+        (status = 200, description = "Session revoked; its tokens stop working immediately"),
+        (status = 404, description = "Session not found or already revoked")
+    ),
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn revoke_session(
+    State(data): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.session_service.revoke(&session_id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == Code::NotFound.to_string() => Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "status": "fail",
+                "message": e.message
+            })),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "status": "error",
+                "message": "Failed to revoke session",
+                "error": e.message
+            })),
+        )),
+    }
+}
+
+pub fn session_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/auth/sessions", get(get_sessions))
+        .route("/api/auth/sessions/{id}", delete(revoke_session))
+        .route_layer(middleware::from_fn(jwt::interactive))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .with_state(app_state)
+}
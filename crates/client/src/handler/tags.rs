@@ -0,0 +1,216 @@
+use crate::{
+    middleware::{jwt, rbac, validate::SimpleValidatedJson},
+    state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+use serde_json::json;
+use shared::domain::{
+    ApiResponse, ApiResponsePagination, CreateTagRequest, ErrorResponse, FindAllTagRequest,
+    TagCloudRequest, TagCountResponse, TagResponse, UpdateTagRequest,
+};
+use shared::model::role::Permission;
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+fn error_response(e: ErrorResponse) -> (StatusCode, Json<serde_json::Value>) {
+    let status = match e.status {
+        ref s if *s == Code::NotFound.to_string() => StatusCode::NOT_FOUND,
+        ref s if *s == Code::AlreadyExists.to_string() => StatusCode::CONFLICT,
+        ref s if *s == Code::InvalidArgument.to_string() => StatusCode::BAD_REQUEST,
+        ref s if *s == Code::PermissionDenied.to_string() => StatusCode::FORBIDDEN,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    (status, Json(json!(e)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tags",
+    params(FindAllTagRequest),
+    responses(
+        (status = 200, description = "List all tags successfully", body = ApiResponsePagination<Vec<TagResponse>>)
+    ),
+    tag = "tags"
+)]
+pub async fn get_tags(
+    State(data): State<Arc<AppState>>,
+    Query(params): Query<FindAllTagRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.tag_service.find_all(&params).await {
+        Ok(tags) => Ok((StatusCode::OK, Json(json!(tags)))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tags/cloud",
+    params(TagCloudRequest),
+    responses(
+        (status = 200, description = "Most used tags with their published post counts", body = ApiResponse<Vec<TagCountResponse>>)
+    ),
+    tag = "tags"
+)]
+pub async fn get_tag_cloud(
+    State(data): State<Arc<AppState>>,
+    Query(params): Query<TagCloudRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.tag_service.find_cloud(&params).await {
+        Ok(tags) => Ok((StatusCode::OK, Json(json!(tags)))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tags/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved tag details", body = ApiResponse<TagResponse>),
+        (status = 404, description = "Tag not found", body = serde_json::Value),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn get_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(_user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.tag_service.find_by_id(&id).await {
+        Ok(tag) => Ok((StatusCode::OK, Json(json!(tag)))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags/create",
+    responses(
+        (status = 201, description = "Create tag", body = ApiResponse<TagResponse>),
+        (status = 409, description = "A tag with the same slug already exists")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn create_tag(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<CreateTagRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.tag_service.create(&body).await {
+        Ok(tag) => Ok((StatusCode::CREATED, Json(json!(tag)))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/tags/update/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Update tag", body = ApiResponse<TagResponse>),
+        (status = 404, description = "Tag not found"),
+        (status = 409, description = "A tag with the same slug already exists")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn update_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    SimpleValidatedJson(mut body): SimpleValidatedJson<UpdateTagRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.id = id;
+
+    match data.di_container.tag_service.update(&body).await {
+        Ok(tag) => Ok((StatusCode::OK, Json(json!(tag)))),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tags/delete/{id}",
+    params(
+        ("id" = i32, Path, description = "Tag ID")
+    ),
+    responses(
+        (status = 200, description = "Delete tag", body = Value)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tags"
+)]
+pub async fn delete_tag(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(_user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.tag_service.delete(&id).await {
+        Ok(_) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "status": "success",
+                "message": "Tag deleted successfully"
+            })),
+        )),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+pub fn tag_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/tags/{id}", get(get_tag))
+        .route(
+            "/api/tags/create",
+            post(create_tag).route_layer(middleware::from_fn_with_state(
+                Permission::TagsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/tags/update/{id}",
+            put(update_tag).route_layer(middleware::from_fn_with_state(
+                Permission::TagsWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/tags/delete/{id}",
+            delete(delete_tag).route_layer(middleware::from_fn_with_state(
+                Permission::TagsDelete,
+                rbac::authorize,
+            )),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    let public_routes = OpenApiRouter::new()
+        .route("/api/tags", get(get_tags))
+        .route("/api/tags/cloud", get(get_tag_cloud));
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .merge(public_routes)
+        .with_state(app_state.clone())
+}
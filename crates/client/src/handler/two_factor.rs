@@ -0,0 +1,154 @@
+use crate::{
+    middleware::{jwt, validate::SimpleValidatedJson},
+    state::AppState,
+};
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::post,
+};
+use serde_json::json;
+use shared::domain::{
+    ApiResponse, ErrorResponse, RecoveryCodesResponse, TwoFactorCodeRequest,
+    TwoFactorEnrollmentResponse,
+};
+use std::sync::Arc;
+use tonic::Code;
+use utoipa_axum::router::OpenApiRouter;
+
+fn two_factor_error(e: ErrorResponse) -> (StatusCode, Json<serde_json::Value>) {
+    let status = if e.status == Code::Unauthenticated.to_string() {
+        StatusCode::UNAUTHORIZED
+    } else if e.status == Code::PermissionDenied.to_string() {
+        StatusCode::CONFLICT
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    (
+        status,
+        Json(json!({
+            "status": "fail",
+            "message": e.message
+        })),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/enroll",
+    responses(
+        (status = 200, description = "TOTP secret and provisioning URI", body = ApiResponse<TwoFactorEnrollmentResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn enroll_two_factor(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.two_factor_service.enroll().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(two_factor_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/confirm",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor enabled; recovery codes are only shown once", body = ApiResponse<RecoveryCodesResponse>),
+        (status = 401, description = "Invalid code"),
+        (status = 409, description = "No pending enrollment")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn confirm_two_factor(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.two_factor_service.confirm(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(two_factor_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/disable",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor disabled"),
+        (status = 401, description = "Invalid code"),
+        (status = 409, description = "Two-factor authentication is not enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn disable_two_factor(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.two_factor_service.disable(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(two_factor_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/2fa/recovery-codes",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes; previous codes are invalidated", body = ApiResponse<RecoveryCodesResponse>),
+        (status = 401, description = "Invalid code"),
+        (status = 409, description = "Two-factor authentication is not enabled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+)]
+pub async fn regenerate_recovery_codes(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .two_factor_service
+        .regenerate_recovery_codes(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(two_factor_error(e)),
+    }
+}
+
+pub fn two_factor_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    let protected_routes = OpenApiRouter::new()
+        .route("/api/auth/2fa/enroll", post(enroll_two_factor))
+        .route("/api/auth/2fa/confirm", post(confirm_two_factor))
+        .route("/api/auth/2fa/disable", post(disable_two_factor))
+        .route(
+            "/api/auth/2fa/recovery-codes",
+            post(regenerate_recovery_codes),
+        )
+        .route_layer(middleware::from_fn(jwt::interactive))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
+    OpenApiRouter::new()
+        .merge(protected_routes)
+        .with_state(app_state)
+}
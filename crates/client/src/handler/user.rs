@@ -1,5 +1,5 @@
 use crate::{
-    middleware::{jwt, validate::SimpleValidatedJson},
+    middleware::{jwt, rbac, validate::SimpleValidatedJson},
     state::AppState,
 };
 use axum::{
@@ -7,22 +7,46 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
 };
 use serde_json::json;
 use shared::domain::{
-    ApiResponse, ApiResponsePagination, CreateUserRequest, FindAllUserRequest, UpdateUserRequest,
-    UserResponse,
+    ApiResponse, ApiResponsePagination, ChangePasswordRequest, ConfirmEmailChangeRequest,
+    CreateUserRequest, ErrorResponse, FindAllUserRequest, RequestEmailChangeRequest,
+    UpdateProfileRequest, UpdateUserRequest, UserResponse,
 };
+use shared::model::role::Permission;
 use std::sync::Arc;
+use tonic::Code;
 use utoipa_axum::router::OpenApiRouter;
 
+fn account_error(e: ErrorResponse) -> (StatusCode, Json<serde_json::Value>) {
+    let status = if e.status == Code::InvalidArgument.to_string() {
+        StatusCode::BAD_REQUEST
+    } else if e.status == Code::AlreadyExists.to_string() {
+        StatusCode::CONFLICT
+    } else if e.status == Code::Unauthenticated.to_string() {
+        StatusCode::UNAUTHORIZED
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    (
+        status,
+        Json(json!({
+            "status": "fail",
+            "message": e.message
+        })),
+    )
+}
+
 #[utoipa::path(
     get,
     path = "/api/users",
     params(FindAllUserRequest),
     responses(
-        (status = 200, description = "List all user successfully", body = ApiResponsePagination<Vec<UserResponse>>)
+        (status = 200, description = "List all user successfully", body = ApiResponsePagination<Vec<UserResponse>>),
+        (status = 400, description = "Invalid pagination cursor")
     ),
     security(
         ("bearer_auth" = [])
@@ -35,6 +59,9 @@ pub async fn get_users(
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.user_service.find_all(&params).await {
         Ok(users) => Ok((StatusCode::OK, Json(json!(users)))),
+        Err(e) if e.status == Code::InvalidArgument.to_string() => {
+            Err((StatusCode::BAD_REQUEST, Json(json!(e))))
+        }
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -155,17 +182,163 @@ pub async fn delete_user(
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/users/me",
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = ApiResponse<UserResponse>),
+        (status = 400, description = "Invalid request body"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn update_me(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<UpdateProfileRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.user_service.update_profile(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(account_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed and other sessions signed out"),
+        (status = 400, description = "Invalid request body or wrong current password"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn change_my_password(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.user_service.change_password(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(account_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/email",
+    request_body = RequestEmailChangeRequest,
+    responses(
+        (status = 200, description = "Confirmation link sent to the new address"),
+        (status = 400, description = "Invalid request body or wrong current password"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Email already in use")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn request_email_change(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<RequestEmailChangeRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .user_service
+        .request_email_change(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(account_error(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/email/confirm",
+    request_body = ConfirmEmailChangeRequest,
+    responses(
+        (status = 200, description = "Email address changed", body = ApiResponse<UserResponse>),
+        (status = 400, description = "Invalid, expired or already used confirmation token"),
+        (status = 409, description = "Email already in use")
+    ),
+    tag = "users"
+)]
+pub async fn confirm_email_change(
+    State(data): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ConfirmEmailChangeRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .user_service
+        .confirm_email_change(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err(account_error(e)),
+    }
+}
+
 pub fn user_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    // Self-service routes only need a signed-in user. `GET /api/users/me`
+    // lives with the auth routes and is merged with these.
+    let account_routes = OpenApiRouter::new()
+        .route("/api/users/me", patch(update_me))
+        .route("/api/users/me/password", post(change_my_password))
+        .route("/api/users/me/email", post(request_email_change))
+        .route_layer(middleware::from_fn(jwt::interactive))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone());
+
     let protected_routes = OpenApiRouter::new()
-        .route("/api/users", get(get_users))
-        .route("/api/users/{id}", get(get_user))
-        .route("/api/users/create", post(create_user))
-        .route("/api/users/update/{id}", put(update_user))
-        .route("/api/users/delete/{id}", delete(delete_user))
+        .route(
+            "/api/users",
+            get(get_users).route_layer(middleware::from_fn_with_state(
+                Permission::UsersRead,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/users/{id}",
+            get(get_user).route_layer(middleware::from_fn_with_state(
+                Permission::UsersRead,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/users/create",
+            post(create_user).route_layer(middleware::from_fn_with_state(
+                Permission::UsersWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/users/update/{id}",
+            put(update_user).route_layer(middleware::from_fn_with_state(
+                Permission::UsersWrite,
+                rbac::authorize,
+            )),
+        )
+        .route(
+            "/api/users/delete/{id}",
+            delete(delete_user).route_layer(middleware::from_fn_with_state(
+                Permission::UsersDelete,
+                rbac::authorize,
+            )),
+        )
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone());
 
     OpenApiRouter::new()
+        .route("/api/users/me/email/confirm", post(confirm_email_change))
+        .merge(account_routes)
         .merge(protected_routes)
         .with_state(app_state.clone())
 }
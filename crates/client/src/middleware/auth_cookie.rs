@@ -0,0 +1,80 @@
+use axum::http::{HeaderMap, Method};
+use axum_extra::extract::cookie::{Cookie, CookieJar};
+use shared::{
+    config::AuthCookieConfig,
+    domain::{LoginResponse, TokenResponse},
+    utils::{generate_random_token, hash_token},
+};
+
+pub const TOKEN_COOKIE: &str = "token";
+pub const CSRF_COOKIE: &str = "csrf_token";
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+/// Sets the access token cookie, plus a fresh CSRF token that scripts can read
+/// and echo back in the `X-CSRF-Token` header. Both are session cookies; an
+/// expired access token is rejected by [`super::jwt::auth`] regardless.
+pub fn issue(jar: CookieJar, config: &AuthCookieConfig, tokens: &TokenResponse) -> CookieJar {
+    if !config.enabled {
+        return jar;
+    }
+
+    let mut token = build(config, TOKEN_COOKIE, tokens.access_token.clone());
+    token.set_http_only(true);
+
+    jar.add(token)
+        .add(build(config, CSRF_COOKIE, generate_random_token()))
+}
+
+/// Like [`issue`], for login endpoints that may answer with a two-factor
+/// challenge instead of tokens.
+pub fn issue_login(
+    jar: CookieJar,
+    config: &AuthCookieConfig,
+    response: &LoginResponse,
+) -> CookieJar {
+    match response {
+        LoginResponse::Tokens(tokens) => issue(jar, config, tokens),
+        LoginResponse::TwoFactorRequired(_) => jar,
+    }
+}
+
+pub fn clear(jar: CookieJar, config: &AuthCookieConfig) -> CookieJar {
+    jar.remove(build(config, TOKEN_COOKIE, ""))
+        .remove(build(config, CSRF_COOKIE, ""))
+}
+
+/// Double-submit check for requests authenticated by the token cookie.
+/// Browsers attach cookies to cross-site requests but a foreign page can
+/// neither read the CSRF cookie nor set the header.
+pub fn csrf_valid(jar: &CookieJar, method: &Method, headers: &HeaderMap) -> bool {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return true;
+    }
+
+    let Some(expected) = jar.get(CSRF_COOKIE).map(Cookie::value) else {
+        return false;
+    };
+
+    headers
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok())
+        // Compare digests so the comparison time says nothing about the token.
+        .is_some_and(|actual| !expected.is_empty() && hash_token(actual) == hash_token(expected))
+}
+
+fn build(
+    config: &AuthCookieConfig,
+    name: &'static str,
+    value: impl Into<String>,
+) -> Cookie<'static> {
+    let mut cookie = Cookie::build((name, value.into()))
+        .path("/")
+        .secure(config.secure)
+        .same_site(config.same_site);
+
+    if let Some(domain) = &config.domain {
+        cookie = cookie.domain(domain.clone());
+    }
+
+    cookie.build()
+}
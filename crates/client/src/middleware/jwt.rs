@@ -4,14 +4,30 @@ use axum::{
     extract::State,
     http::{Request, StatusCode, header},
     middleware::Next,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
 use axum_extra::extract::cookie::CookieJar;
 use std::sync::Arc;
 
-use shared::domain::ErrorResponse;
+use shared::{config::Claims, domain::ErrorResponse};
 
-use crate::state::AppState;
+use crate::{
+    middleware::auth_cookie::{self, TOKEN_COOKIE},
+    state::AppState,
+};
+
+#[derive(Clone, Debug)]
+pub struct AccessToken(pub String);
+
+tokio::task_local! {
+    pub static ACCESS_TOKEN: String;
+}
+
+enum Credential {
+    Token(String),
+    ApiKey(String),
+    Cookie(String),
+}
 
 pub async fn auth(
     cookie_jar: CookieJar,
@@ -19,18 +35,55 @@ pub async fn auth(
     mut req: Request<Body>,
     next: Next,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    let token = cookie_jar
-        .get("token")
-        .map(|cookie| cookie.value().to_string())
+    // An explicit Authorization header wins over the cookie: a cross-site
+    // request cannot set it, so it needs no CSRF check.
+    let credential = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|auth_header| auth_header.to_str().ok())
+        .and_then(|auth_value| {
+            if let Some(token) = auth_value.strip_prefix("Bearer ") {
+                Some(Credential::Token(token.to_owned()))
+            } else {
+                auth_value
+                    .strip_prefix("ApiKey ")
+                    .map(|key| Credential::ApiKey(key.to_owned()))
+            }
+        })
         .or_else(|| {
-            req.headers()
-                .get(header::AUTHORIZATION)
-                .and_then(|auth_header| auth_header.to_str().ok())
-                .and_then(|auth_value| auth_value.strip_prefix("Bearer ").map(str::to_owned))
+            cookie_jar
+                .get(TOKEN_COOKIE)
+                .map(|cookie| Credential::Cookie(cookie.value().to_string()))
         });
 
-    let token = match token {
-        Some(token) => token,
+    let token = match credential {
+        Some(Credential::Token(token)) => token,
+        Some(Credential::Cookie(token)) => {
+            if !auth_cookie::csrf_valid(&cookie_jar, req.method(), req.headers()) {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    Json(ErrorResponse {
+                        status: "fail".to_string(),
+                        message: "Missing or invalid CSRF token".to_string(),
+                    }),
+                ));
+            }
+            token
+        }
+        Some(Credential::ApiKey(key)) => {
+            match data.di_container.api_key_service.authenticate(&key).await {
+                Ok(token) => token,
+                Err(_) => {
+                    return Err((
+                        StatusCode::UNAUTHORIZED,
+                        Json(ErrorResponse {
+                            status: "fail".to_string(),
+                            message: "Invalid API key".to_string(),
+                        }),
+                    ));
+                }
+            }
+        }
         None => {
             return Err((
                 StatusCode::UNAUTHORIZED,
@@ -42,8 +95,8 @@ pub async fn auth(
         }
     };
 
-    let user_id = match data.jwt_config.verify_token(&token) {
-        Ok(id) => id,
+    let claims = match data.jwt_config.decode_token(&token) {
+        Ok(claims) => claims,
         Err(_) => {
             return Err((
                 StatusCode::UNAUTHORIZED,
@@ -55,7 +108,78 @@ pub async fn auth(
         }
     };
 
-    req.extensions_mut().insert(user_id);
+    match data.token_revocation.is_revoked(&claims).await {
+        Ok(false) => {}
+        Ok(true) => {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Json(ErrorResponse {
+                    status: "fail".to_string(),
+                    message: "Token has been revoked".to_string(),
+                }),
+            ));
+        }
+        // Without the revocation list a logged-out token would look valid.
+        Err(_) => {
+            return Err((
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorResponse {
+                    status: "error".to_string(),
+                    message: "Unable to verify token".to_string(),
+                }),
+            ));
+        }
+    }
+
+    req.extensions_mut().insert(claims.user_id);
+    req.extensions_mut().insert(claims);
+    req.extensions_mut().insert(AccessToken(token.clone()));
+
+    Ok(ACCESS_TOKEN.scope(token, next.run(req)).await)
+}
+
+/// Like [`auth`], but lets requests without any credentials through
+/// anonymously, for public routes that show more to signed-in users.
+pub async fn optional_auth(
+    cookie_jar: CookieJar,
+    State(data): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let has_credential =
+        req.headers().contains_key(header::AUTHORIZATION) || cookie_jar.get(TOKEN_COOKIE).is_some();
+
+    if !has_credential {
+        return Ok(next.run(req).await);
+    }
+
+    auth(cookie_jar, State(data), req, next)
+        .await
+        .map(IntoResponse::into_response)
+}
+
+/// Layered inside [`auth`] on account self-service routes: tokens minted
+/// from an API key may call the API but not change the account they belong
+/// to, so a leaked key cannot be escalated into a takeover.
+pub async fn interactive(
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let allowed = req
+        .extensions()
+        .get::<Claims>()
+        .is_some_and(Claims::is_interactive);
+
+    if !allowed {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                status: "fail".to_string(),
+                message: "This action requires signing in; API keys cannot manage the account"
+                    .to_string(),
+            }),
+        ));
+    }
 
     Ok(next.run(req).await)
 }
@@ -1,2 +1,5 @@
+pub mod auth_cookie;
 pub mod jwt;
+pub mod rbac;
+pub mod request_context;
 pub mod validate;
@@ -0,0 +1,33 @@
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+};
+
+use shared::{config::Claims, domain::ErrorResponse, model::role::Permission};
+
+pub async fn authorize(
+    State(permission): State<Permission>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let allowed = req
+        .extensions()
+        .get::<Claims>()
+        .is_some_and(|claims| claims.has_permission(permission));
+
+    if !allowed {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                status: "fail".to_string(),
+                message: format!("Missing permission: {permission}"),
+            }),
+        ));
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,49 @@
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderValue, Request, header},
+    middleware::Next,
+    response::Response,
+};
+use shared::domain::ClientInfo;
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    pub static CLIENT_INFO: ClientInfo;
+}
+
+/// Captures where a request came from so the gRPC calls made while serving it
+/// can forward it to the server, and tags the response with its request id.
+pub async fn capture(req: Request<Body>, next: Next) -> Response {
+    let headers = req.headers();
+
+    let request_id = headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty() && value.len() <= 64)
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let client = ClientInfo {
+        ip_address: req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string()),
+        user_agent: headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+        request_id: Some(request_id.clone()),
+    };
+
+    let mut response = CLIENT_INFO.scope(client, next.run(req)).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
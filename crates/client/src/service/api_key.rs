@@ -0,0 +1,344 @@
+use async_trait::async_trait;
+use genproto::api_key::{
+    AuthenticateApiKeyRequest, CreateApiKeyRequest, ListApiKeysRequest, RevokeApiKeyRequest,
+    api_key_service_client::ApiKeyServiceClient,
+};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    domain::{
+        ApiKeyResponse, ApiResponse, CreateApiKeyRequest as DomainCreateApiKeyRequest,
+        CreatedApiKeyResponse, ErrorResponse,
+    },
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tonic::{Request, transport::Channel};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::ApiKeyServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
+
+#[derive(Debug)]
+pub struct ApiKeyService {
+    client: Arc<Mutex<ApiKeyServiceClient<Channel>>>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl ApiKeyService {
+    pub async fn new(
+        client: Arc<Mutex<ApiKeyServiceClient<Channel>>>,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "api_key_handler_request_counter",
+            "Total number of requests to the ApiKeyService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "api_key_handler_request_duration",
+            "Histogram of request durations for the ApiKeyService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self { client, metrics }
+    }
+
+    pub fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("api-key-client-service")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        inject_access_token(request);
+        inject_request_context(request);
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl ApiKeyServiceTrait for ApiKeyService {
+    async fn find_all(&self) -> Result<ApiResponse<Vec<ApiKeyResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "ListApiKeys",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("operation", "find_all"),
+            ],
+        );
+
+        let mut request = Request::new(ListApiKeysRequest {});
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.list_api_keys(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect::<Vec<_>>(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Retrieved {} API keys", response.data.len()),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to list API keys: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn create(
+        &self,
+        req: &DomainCreateApiKeyRequest,
+    ) -> Result<ApiResponse<CreatedApiKeyResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "CreateApiKey",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("operation", "create"),
+                KeyValue::new("api_key.name", req.name.clone()),
+            ],
+        );
+
+        let mut request = Request::new(CreateApiKeyRequest {
+            name: req.name.clone(),
+            scopes: req.scopes.clone(),
+            expires_in_days: req.expires_in_days,
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.create_api_key(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let data: CreatedApiKeyResponse = inner.data.unwrap_or_default().into();
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key {} created", data.api_key.prefix),
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data,
+                })
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to create API key: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn revoke(&self, id: &i32) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "RevokeApiKey",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("operation", "revoke"),
+                KeyValue::new("api_key.id", *id as i64),
+            ],
+        );
+
+        let mut request = Request::new(RevokeApiKeyRequest { id: *id });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.revoke_api_key(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key {id} revoked"),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke API key {id}: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn authenticate(&self, key: &str) -> Result<String, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "AuthenticateApiKey",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("operation", "authenticate"),
+            ],
+        );
+
+        let mut request = Request::new(AuthenticateApiKeyRequest {
+            key: key.to_string(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.authenticate_api_key(request).await {
+            Ok(resp) => {
+                self.complete_tracing_success(&tracing_ctx, method, "API key authenticated")
+                    .await;
+
+                Ok(resp.into_inner().access_token)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key authentication failed: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use genproto::audit::{ListAuditEventsRequest, audit_service_client::AuditServiceClient};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    domain::{ApiResponsePagination, AuditEventResponse, ErrorResponse, FindAllAuditEventRequest},
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tonic::{Request, transport::Channel};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::AuditServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
+
+#[derive(Debug)]
+pub struct AuditService {
+    client: Arc<Mutex<AuditServiceClient<Channel>>>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl AuditService {
+    pub async fn new(
+        client: Arc<Mutex<AuditServiceClient<Channel>>>,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "audit_handler_request_counter",
+            "Total number of requests to the AuditService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "audit_handler_request_duration",
+            "Histogram of request durations for the AuditService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self { client, metrics }
+    }
+
+    pub fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("audit-client-service")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        inject_access_token(request);
+        inject_request_context(request);
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl AuditServiceTrait for AuditService {
+    async fn find_all(
+        &self,
+        req: &FindAllAuditEventRequest,
+    ) -> Result<ApiResponsePagination<Vec<AuditEventResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "ListAuditEvents",
+            vec![
+                KeyValue::new("component", "audit"),
+                KeyValue::new("operation", "find_all"),
+                KeyValue::new("page", req.page.to_string()),
+                KeyValue::new("page_size", req.page_size.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(ListAuditEventsRequest {
+            page: req.page,
+            page_size: req.page_size,
+            actor_id: req.actor_id,
+            action: req.action.clone(),
+            target_type: req.target_type.clone(),
+            target_id: req.target_id.clone(),
+            from: req.from.clone(),
+            to: req.to.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.list_audit_events(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponsePagination {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                    pagination: inner.pagination.unwrap_or_default().into(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Audit events retrieved successfully (page: {}, size: {})",
+                        req.page, req.page_size
+                    ),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to list audit events: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+}
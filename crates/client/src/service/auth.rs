@@ -1,7 +1,11 @@
 use async_trait::async_trait;
 use genproto::auth::{
-    GetMeRequest, LoginRequest, RegisterRequest, auth_service_client::AuthServiceClient,
+    GetJwksRequest, GetMeRequest, LoginRequest, LogoutRequest, OidcLoginRequest,
+    RefreshTokenRequest, RegisterRequest, RequestPasswordResetRequest, ResendVerificationRequest,
+    ResetPasswordRequest, VerifyEmailRequest, VerifyTwoFactorLoginRequest,
+    auth_service_client::AuthServiceClient,
 };
+use jsonwebtoken::jwk::JwkSet;
 use opentelemetry::{
     Context, KeyValue,
     global::{self, BoxedTracer},
@@ -10,8 +14,14 @@ use opentelemetry::{
 use prometheus_client::registry::Registry;
 use shared::{
     domain::{
-        ApiResponse, ErrorResponse, LoginRequest as LoginDomainRequest,
-        RegisterRequest as RegisterDomainRequest, UserResponse,
+        ApiResponse, ErrorResponse, LoginRequest as LoginDomainRequest, LoginResponse,
+        LogoutRequest as LogoutDomainRequest, OidcLoginRequest as OidcLoginDomainRequest,
+        PasswordResetRequest as PasswordResetDomainRequest,
+        RefreshTokenRequest as RefreshTokenDomainRequest, RegisterRequest as RegisterDomainRequest,
+        ResendVerificationRequest as ResendVerificationDomainRequest,
+        ResetPasswordRequest as ResetPasswordDomainRequest, TokenResponse,
+        TwoFactorLoginRequest as TwoFactorLoginDomainRequest, UserResponse,
+        VerifyEmailRequest as VerifyEmailDomainRequest,
     },
     utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
@@ -20,7 +30,10 @@ use tokio::{sync::Mutex, time::Instant};
 use tonic::{Request, transport::Channel};
 use tracing::{error, info};
 
-use crate::abstract_trait::AuthServiceTrait;
+use crate::{
+    abstract_trait::AuthServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
 
 #[derive(Debug)]
 pub struct AuthService {
@@ -56,6 +69,9 @@ impl AuthService {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+
+        inject_access_token(request);
+        inject_request_context(request);
     }
 
     fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
@@ -210,7 +226,7 @@ impl AuthServiceTrait for AuthService {
     async fn login(
         &self,
         request_data: LoginDomainRequest,
-    ) -> Result<ApiResponse<String>, ErrorResponse> {
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse> {
         let method = Method::Post;
         let tracing_ctx = self.start_tracing(
             "LoginUser",
@@ -236,10 +252,14 @@ impl AuthServiceTrait for AuthService {
         match result {
             Ok(resp) => {
                 let inner = resp.into_inner();
+                let data = match inner.challenge {
+                    Some(challenge) => LoginResponse::TwoFactorRequired(challenge.into()),
+                    None => LoginResponse::Tokens(inner.data.into()),
+                };
                 let response = ApiResponse {
                     status: inner.status,
                     message: inner.message,
-                    data: inner.data,
+                    data,
                 };
 
                 self.complete_tracing_success(
@@ -272,6 +292,240 @@ impl AuthServiceTrait for AuthService {
         }
     }
 
+    async fn verify_two_factor_login(
+        &self,
+        request_data: TwoFactorLoginDomainRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "VerifyTwoFactorLogin",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "verify_two_factor_login"),
+            ],
+        );
+
+        let mut request = Request::new(VerifyTwoFactorLoginRequest {
+            challenge_token: request_data.challenge_token,
+            code: request_data.code,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.verify_two_factor_login(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Two-factor login successful")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Two-factor login failed: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn login_with_oidc(
+        &self,
+        request_data: OidcLoginDomainRequest,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "LoginWithOidc",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "login_with_oidc"),
+            ],
+        );
+
+        let mut request = Request::new(OidcLoginRequest {
+            id_token: request_data.id_token,
+            nonce: request_data.nonce,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.login_with_oidc(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let data = match inner.challenge {
+                    Some(challenge) => LoginResponse::TwoFactorRequired(challenge.into()),
+                    None => LoginResponse::Tokens(inner.data.into()),
+                };
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data,
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "OIDC login successful")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("OIDC login failed: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn refresh_token(
+        &self,
+        request_data: RefreshTokenDomainRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "RefreshToken",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "refresh_token"),
+            ],
+        );
+
+        let mut request = Request::new(RefreshTokenRequest {
+            refresh_token: request_data.refresh_token,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.refresh_token(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Token refreshed successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to refresh token: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn logout(
+        &self,
+        access_token: String,
+        request_data: LogoutDomainRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "Logout",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "logout"),
+                KeyValue::new("all_devices", request_data.all_devices),
+            ],
+        );
+
+        let mut request = Request::new(LogoutRequest {
+            access_token,
+            refresh_token: request_data.refresh_token.unwrap_or_default(),
+            all_devices: request_data.all_devices,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.logout(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Logged out successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to logout: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
     async fn get_me(&self, id: i32) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
         let method = Method::Get;
         let tracing_ctx = self.start_tracing(
@@ -329,4 +583,300 @@ impl AuthServiceTrait for AuthService {
             }
         }
     }
+
+    async fn request_password_reset(
+        &self,
+        request_data: PasswordResetDomainRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "RequestPasswordReset",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "request_password_reset"),
+                KeyValue::new("user.email", request_data.email.clone()),
+            ],
+        );
+
+        let mut request = Request::new(RequestPasswordResetRequest {
+            email: request_data.email.clone(),
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.request_password_reset(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Password reset requested for {}", request_data.email),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to request password reset for {}: {}",
+                        request_data.email, error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn reset_password(
+        &self,
+        request_data: ResetPasswordDomainRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ResetPassword",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "reset_password"),
+            ],
+        );
+
+        let mut request = Request::new(ResetPasswordRequest {
+            token: request_data.token,
+            new_password: request_data.new_password,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.reset_password(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Password reset successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to reset password: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn verify_email(
+        &self,
+        request_data: VerifyEmailDomainRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "VerifyEmail",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "verify_email"),
+            ],
+        );
+
+        let mut request = Request::new(VerifyEmailRequest {
+            token: request_data.token,
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.verify_email(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Email verified successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to verify email: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn resend_verification(
+        &self,
+        request_data: ResendVerificationDomainRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ResendVerification",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "resend_verification"),
+                KeyValue::new("user.email", request_data.email.clone()),
+            ],
+        );
+
+        let mut request = Request::new(ResendVerificationRequest {
+            email: request_data.email.clone(),
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.resend_verification(request).await
+        };
+
+        match result {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Verification resent for {}", request_data.email),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to resend verification for {}: {}",
+                        request_data.email, error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn get_jwks(&self) -> Result<JwkSet, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "GetJwks",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "get_jwks"),
+            ],
+        );
+
+        let mut request = Request::new(GetJwksRequest {});
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let result = {
+            let mut client = self.client.lock().await;
+            client.get_jwks(request).await
+        };
+
+        let result = result
+            .map_err(|status| ErrorResponse {
+                status: status.code().to_string(),
+                message: status.message().to_string(),
+            })
+            .and_then(|resp| {
+                serde_json::from_str::<JwkSet>(&resp.into_inner().jwks).map_err(|e| ErrorResponse {
+                    status: "error".to_string(),
+                    message: format!("Invalid JWKS: {e}"),
+                })
+            });
+
+        match result {
+            Ok(jwks) => {
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Fetched {} verification keys", jwks.keys.len()),
+                )
+                .await;
+
+                Ok(jwks)
+            }
+            Err(error_response) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to fetch JWKS: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
 }
@@ -23,7 +23,10 @@ use tokio::{sync::Mutex, time::Instant};
 use tonic::{Request, transport::Channel};
 use tracing::{error, info};
 
-use crate::abstract_trait::CategoryServiceTrait;
+use crate::{
+    abstract_trait::CategoryServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
 
 #[derive(Debug)]
 pub struct CategoryService {
@@ -58,6 +61,9 @@ impl CategoryService {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+
+        inject_access_token(request);
+        inject_request_context(request);
     }
 
     fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
@@ -161,6 +167,7 @@ impl CategoryServiceTrait for CategoryService {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            cursor: req.cursor.clone(),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
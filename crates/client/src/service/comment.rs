@@ -21,7 +21,10 @@ use tokio::{sync::Mutex, time::Instant};
 use tonic::{Request, transport::Channel};
 use tracing::{error, info};
 
-use crate::abstract_trait::CommentServiceTrait;
+use crate::{
+    abstract_trait::CommentServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
 
 #[derive(Debug)]
 pub struct CommentService {
@@ -57,6 +60,9 @@ impl CommentService {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+
+        inject_access_token(request);
+        inject_request_context(request);
     }
 
     fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
@@ -253,13 +259,11 @@ impl CommentServiceTrait for CommentService {
                 KeyValue::new("component", "comment"),
                 KeyValue::new("operation", "create"),
                 KeyValue::new("comment.post_id", req.id_post_comment as i64),
-                KeyValue::new("comment.user", req.user_name_comment.clone()),
             ],
         );
 
         let mut request = Request::new(CreateCommentRequest {
             id_post_comment: req.id_post_comment,
-            user_name_comment: req.user_name_comment.clone(),
             comment: req.comment.clone(),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
@@ -277,8 +281,8 @@ impl CommentServiceTrait for CommentService {
                     &tracing_ctx,
                     method,
                     &format!(
-                        "Comment created successfully for post {} by user {}",
-                        req.id_post_comment, req.user_name_comment
+                        "Comment created successfully for post {}",
+                        req.id_post_comment
                     ),
                 )
                 .await;
@@ -312,7 +316,6 @@ impl CommentServiceTrait for CommentService {
     ) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
         let method = Method::Put;
         let id = req.id_post_comment;
-        let user = req.user_name_comment.clone();
         let comment = req.comment.clone();
 
         let tracing_ctx = self.start_tracing(
@@ -321,13 +324,11 @@ impl CommentServiceTrait for CommentService {
                 KeyValue::new("component", "comment"),
                 KeyValue::new("operation", "update"),
                 KeyValue::new("comment.id", id as i64),
-                KeyValue::new("comment.user", user.clone()),
             ],
         );
 
         let mut request = Request::new(UpdateCommentRequest {
             id_post_comment: id,
-            user_name_comment: user.clone(),
             comment,
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
@@ -344,7 +345,7 @@ impl CommentServiceTrait for CommentService {
                 self.complete_tracing_success(
                     &tracing_ctx,
                     method,
-                    &format!("Comment {id} updated successfully by user {user}",),
+                    &format!("Comment {id} updated successfully"),
                 )
                 .await;
 
@@ -1,24 +1,41 @@
+mod api_key;
+mod audit;
 mod auth;
 mod category;
 mod comment;
 mod posts;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
 
+pub use self::api_key::ApiKeyService;
+pub use self::audit::AuditService;
 pub use self::auth::AuthService;
 pub use self::category::CategoryService;
 pub use self::comment::CommentService;
 pub use self::posts::PostsService;
+pub use self::session::SessionService;
+pub use self::tag::TagService;
+pub use self::two_factor::TwoFactorService;
 pub use self::user::UserService;
 
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tonic::transport::Channel;
+use tonic::{Request, transport::Channel};
+
+use crate::middleware::{jwt::ACCESS_TOKEN, request_context::CLIENT_INFO};
 
 use genproto::{
-    auth::auth_service_client::AuthServiceClient,
+    api_key::api_key_service_client::ApiKeyServiceClient,
+    audit::audit_service_client::AuditServiceClient, auth::auth_service_client::AuthServiceClient,
     category::category_service_client::CategoryServiceClient,
     comment::comment_service_client::CommentServiceClient,
-    post::posts_service_client::PostsServiceClient, user::user_service_client::UserServiceClient,
+    post::posts_service_client::PostsServiceClient,
+    session::session_service_client::SessionServiceClient,
+    tag::tag_service_client::TagServiceClient,
+    two_factor::two_factor_service_client::TwoFactorServiceClient,
+    user::user_service_client::UserServiceClient,
 };
 
 #[derive(Clone)]
@@ -26,8 +43,13 @@ pub struct GrpcClients {
     pub auth: Arc<Mutex<AuthServiceClient<Channel>>>,
     pub user: Arc<Mutex<UserServiceClient<Channel>>>,
     pub category: Arc<Mutex<CategoryServiceClient<Channel>>>,
+    pub tag: Arc<Mutex<TagServiceClient<Channel>>>,
     pub post: Arc<Mutex<PostsServiceClient<Channel>>>,
     pub comment: Arc<Mutex<CommentServiceClient<Channel>>>,
+    pub api_key: Arc<Mutex<ApiKeyServiceClient<Channel>>>,
+    pub two_factor: Arc<Mutex<TwoFactorServiceClient<Channel>>>,
+    pub session: Arc<Mutex<SessionServiceClient<Channel>>>,
+    pub audit: Arc<Mutex<AuditServiceClient<Channel>>>,
 }
 
 impl GrpcClients {
@@ -36,8 +58,43 @@ impl GrpcClients {
             auth: Arc::new(Mutex::new(AuthServiceClient::new(channel.clone()))),
             user: Arc::new(Mutex::new(UserServiceClient::new(channel.clone()))),
             category: Arc::new(Mutex::new(CategoryServiceClient::new(channel.clone()))),
+            tag: Arc::new(Mutex::new(TagServiceClient::new(channel.clone()))),
             post: Arc::new(Mutex::new(PostsServiceClient::new(channel.clone()))),
-            comment: Arc::new(Mutex::new(CommentServiceClient::new(channel))),
+            comment: Arc::new(Mutex::new(CommentServiceClient::new(channel.clone()))),
+            api_key: Arc::new(Mutex::new(ApiKeyServiceClient::new(channel.clone()))),
+            two_factor: Arc::new(Mutex::new(TwoFactorServiceClient::new(channel.clone()))),
+            session: Arc::new(Mutex::new(SessionServiceClient::new(channel.clone()))),
+            audit: Arc::new(Mutex::new(AuditServiceClient::new(channel))),
         }
     }
 }
+
+pub(crate) fn inject_access_token<T>(request: &mut Request<T>) {
+    let Ok(token) = ACCESS_TOKEN.try_with(Clone::clone) else {
+        return;
+    };
+
+    if let Ok(value) = format!("Bearer {token}").parse() {
+        request.metadata_mut().insert("authorization", value);
+    }
+}
+
+pub(crate) fn inject_request_context<T>(request: &mut Request<T>) {
+    let Ok(client) = CLIENT_INFO.try_with(Clone::clone) else {
+        return;
+    };
+
+    let metadata = request.metadata_mut();
+
+    if let Some(value) = client.ip_address.and_then(|ip| ip.parse().ok()) {
+        metadata.insert("x-forwarded-for", value);
+    }
+
+    if let Some(value) = client.user_agent.and_then(|ua| ua.parse().ok()) {
+        metadata.insert("x-client-user-agent", value);
+    }
+
+    if let Some(value) = client.request_id.and_then(|id| id.parse().ok()) {
+        metadata.insert("x-request-id", value);
+    }
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use genproto::post::{
-    CreatePostRequest, FindAllPostRequest, FindPostRequest, UpdatePostRequest,
+    CreatePostRequest, FindAllPostRequest, FindMyPostsRequest, FindPostBySlugRequest,
+    FindPostRequest, SearchPostsRequest, TagNames, UpdatePostRequest,
     posts_service_client::PostsServiceClient,
 };
 use opentelemetry::{
@@ -12,8 +13,10 @@ use prometheus_client::registry::Registry;
 use shared::{
     domain::{
         ApiResponse, ApiResponsePagination, CreatePostRequest as DomainCreatePostRequest,
-        ErrorResponse, FindAllPostRequest as DomainFindAllPostRequest, PostRelationResponse,
-        PostResponse, UpdatePostRequest as DomainUpdatePostRequest,
+        ErrorResponse, FindAllPostRequest as DomainFindAllPostRequest,
+        FindMyPostsRequest as DomainFindMyPostsRequest, PostRelationResponse, PostResponse,
+        PostSearchResultResponse, SearchPostsRequest as DomainSearchPostsRequest,
+        UpdatePostRequest as DomainUpdatePostRequest,
     },
     utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
@@ -22,7 +25,10 @@ use tokio::{sync::Mutex, time::Instant};
 use tonic::{Request, transport::Channel};
 use tracing::{error, info};
 
-use crate::abstract_trait::PostsServiceTrait;
+use crate::{
+    abstract_trait::PostsServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
 
 #[derive(Debug)]
 pub struct PostsService {
@@ -58,6 +64,9 @@ impl PostsService {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+
+        inject_access_token(request);
+        inject_request_context(request);
     }
 
     fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
@@ -161,6 +170,15 @@ impl PostsServiceTrait for PostsService {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            tag: req.tag.clone(),
+            cursor: req.cursor.clone(),
+            category_id: req.category_id,
+            user_id: req.user_id,
+            from: req.from.clone(),
+            to: req.to.clone(),
+            status: req.status.clone(),
+            sort: req.sort.clone(),
+            order: req.order.clone(),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
@@ -197,6 +215,121 @@ impl PostsServiceTrait for PostsService {
         }
     }
 
+    async fn find_mine(
+        &self,
+        req: &DomainFindMyPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "FindMyPosts",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("operation", "find_mine"),
+                KeyValue::new("page", req.page.to_string()),
+                KeyValue::new("page_size", req.page_size.to_string()),
+                KeyValue::new("status", req.status.clone()),
+            ],
+        );
+
+        let mut request = Request::new(FindMyPostsRequest {
+            page: req.page,
+            page_size: req.page_size,
+            search: req.search.clone(),
+            status: req.status.clone(),
+            tag: req.tag.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.find_my_posts(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponsePagination {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                    pagination: inner.pagination.unwrap_or_default().into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Posts retrieved successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve posts: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn search(
+        &self,
+        req: &DomainSearchPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostSearchResultResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "SearchPosts",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("operation", "search"),
+                KeyValue::new("query", req.q.clone()),
+                KeyValue::new("page", req.page.to_string()),
+                KeyValue::new("page_size", req.page_size.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(SearchPostsRequest {
+            query: req.q.clone(),
+            page: req.page,
+            page_size: req.page_size,
+            tag: req.tag.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.search_posts(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponsePagination {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                    pagination: inner.pagination.unwrap_or_default().into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Posts searched successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to search posts: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
     async fn find_relation(
         &self,
         id: &i32,
@@ -298,6 +431,53 @@ impl PostsServiceTrait for PostsService {
         }
     }
 
+    async fn find_by_slug(&self, slug: &str) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "FindPostBySlug",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("operation", "find_by_slug"),
+                KeyValue::new("post.slug", slug.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(FindPostBySlugRequest {
+            slug: slug.to_string(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.find_post_by_slug(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Post retrieved successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve post: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
     async fn create(
         &self,
         req: &DomainCreatePostRequest,
@@ -319,8 +499,9 @@ impl PostsServiceTrait for PostsService {
             body: req.body.clone(),
             file: req.file.clone(),
             category_id: req.category_id,
-            user_id: req.user_id,
-            user_name: req.user_name.clone(),
+            status: req.status.clone(),
+            published_at: req.published_at.clone(),
+            tags: req.tags.clone(),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
@@ -368,7 +549,6 @@ impl PostsServiceTrait for PostsService {
                 KeyValue::new("post.id", req.post_id as i64),
                 KeyValue::new("post.title", req.title.clone()),
                 KeyValue::new("post.category_id", req.category_id.to_string()),
-                KeyValue::new("post.user_id", req.user_id.to_string()),
             ],
         );
 
@@ -378,8 +558,9 @@ impl PostsServiceTrait for PostsService {
             body: req.body.clone(),
             file: req.file.clone(),
             category_id: req.category_id,
-            user_id: req.user_id,
-            user_name: req.user_name.clone(),
+            status: req.status.clone(),
+            published_at: req.published_at.clone(),
+            tags: req.tags.clone().map(|names| TagNames { names }),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use genproto::session::{
+    ListSessionsRequest, RevokeSessionRequest, session_service_client::SessionServiceClient,
+};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    domain::{ApiResponse, ErrorResponse, SessionResponse},
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tonic::{Request, transport::Channel};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::SessionServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
+
+#[derive(Debug)]
+pub struct SessionService {
+    client: Arc<Mutex<SessionServiceClient<Channel>>>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl SessionService {
+    pub async fn new(
+        client: Arc<Mutex<SessionServiceClient<Channel>>>,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "session_handler_request_counter",
+            "Total number of requests to the SessionService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "session_handler_request_duration",
+            "Histogram of request durations for the SessionService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self { client, metrics }
+    }
+
+    pub fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("session-client-service")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        inject_access_token(request);
+        inject_request_context(request);
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl SessionServiceTrait for SessionService {
+    async fn find_all(&self) -> Result<ApiResponse<Vec<SessionResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "ListSessions",
+            vec![
+                KeyValue::new("component", "session"),
+                KeyValue::new("operation", "find_all"),
+            ],
+        );
+
+        let mut request = Request::new(ListSessionsRequest {});
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.list_sessions(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect::<Vec<_>>(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Retrieved {} sessions", response.data.len()),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to list sessions: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn revoke(&self, id: &str) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "RevokeSession",
+            vec![
+                KeyValue::new("component", "session"),
+                KeyValue::new("operation", "revoke"),
+                KeyValue::new("session.id", id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(RevokeSessionRequest { id: id.to_string() });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.revoke_session(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Session {id} revoked"),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke session {id}: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use async_trait::async_trait;
+use genproto::tag::{
+    CreateTagRequest, FindAllTagRequest, FindTagRequest, TagCloudRequest, UpdateTagRequest,
+    tag_service_client::TagServiceClient,
+};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    domain::{
+        ApiResponse, ApiResponsePagination, CreateTagRequest as DomainCreateTagRequest,
+        ErrorResponse, FindAllTagRequest as DomainFindAllTagRequest,
+        TagCloudRequest as DomainTagCloudRequest, TagCountResponse, TagResponse,
+        UpdateTagRequest as DomainUpdateTagRequest,
+    },
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tonic::{Request, transport::Channel};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::TagServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
+
+#[derive(Debug)]
+pub struct TagService {
+    client: Arc<Mutex<TagServiceClient<Channel>>>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl TagService {
+    pub async fn new(
+        client: Arc<Mutex<TagServiceClient<Channel>>>,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "tag_handler_request_counter",
+            "Total number of requests to the TagService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "tag_handler_request_duration",
+            "Histogram of request durations for the TagService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self { client, metrics }
+    }
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("tag-service-client")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        inject_access_token(request);
+        inject_request_context(request);
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl TagServiceTrait for TagService {
+    async fn find_all(
+        &self,
+        req: &DomainFindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "FindAllTags",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "find_all"),
+                KeyValue::new("page", req.page.to_string()),
+                KeyValue::new("page_size", req.page_size.to_string()),
+                KeyValue::new("search", req.search.clone()),
+            ],
+        );
+
+        let mut request = Request::new(FindAllTagRequest {
+            page: req.page,
+            page_size: req.page_size,
+            search: req.search.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.get_tags(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponsePagination {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(|u| u.into()).collect(),
+                    pagination: inner.pagination.unwrap_or_default().into(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Tags retrieved successfully (page: {}, size: {})",
+                        req.page, req.page_size
+                    ),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                let error_response = ErrorResponse {
+                    status: err.code().to_string(),
+                    message: err.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to retrieve tags (page: {}, size: {}): {}",
+                        req.page, req.page_size, error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn find_by_id(&self, id: &i32) -> Result<ApiResponse<TagResponse>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "FindTagById",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "find_by_id"),
+                KeyValue::new("tag.id", *id as i64),
+            ],
+        );
+
+        let mut request = Request::new(FindTagRequest { id: *id });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.get_tag(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag {id} found successfully"),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to find tag {}: {}", id, error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn find_cloud(
+        &self,
+        req: &DomainTagCloudRequest,
+    ) -> Result<ApiResponse<Vec<TagCountResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "FindTagCloud",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "find_cloud"),
+                KeyValue::new("limit", req.limit.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(TagCloudRequest { limit: req.limit });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.get_tag_cloud(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Tag cloud retrieved successfully",
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve tag cloud: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn create(
+        &self,
+        req: &DomainCreateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "CreateTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "create"),
+                KeyValue::new("tag.name", req.name.clone()),
+            ],
+        );
+
+        let mut request = Request::new(CreateTagRequest {
+            name: req.name.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.create_tag(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag '{}' created successfully", req.name),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to create tag '{}': {}",
+                        req.name, error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn update(
+        &self,
+        req: &DomainUpdateTagRequest,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse> {
+        let method = Method::Put;
+        let tag_name = req.name.clone();
+        let tag_id = req.id;
+
+        let tracing_ctx = self.start_tracing(
+            "UpdateTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "update"),
+                KeyValue::new("tag.id", tag_id as i64),
+                KeyValue::new("tag.name", tag_name.to_string()),
+            ],
+        );
+
+        let update_request = UpdateTagRequest {
+            id: tag_id,
+            name: tag_name.to_string(),
+        };
+
+        let mut request = Request::new(update_request);
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.update_tag(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag updated successfully (ID: {tag_id}, Name: {tag_name})",),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to update tag (ID: {tag_id}, Name: {tag_name}): {}",
+                        error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn delete(&self, id: &i32) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "DeleteTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("operation", "delete"),
+                KeyValue::new("tag.id", *id as i64),
+            ],
+        );
+
+        let mut request = Request::new(FindTagRequest { id: *id });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.delete_tag(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag {id} deleted successfully"),
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to delete tag {}: {}", id, error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use async_trait::async_trait;
+use genproto::two_factor::{
+    EnrollTwoFactorRequest, TwoFactorCodeRequest, two_factor_service_client::TwoFactorServiceClient,
+};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    domain::{
+        ApiResponse, ErrorResponse, RecoveryCodesResponse,
+        TwoFactorCodeRequest as DomainTwoFactorCodeRequest, TwoFactorEnrollmentResponse,
+    },
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tonic::{Request, transport::Channel};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::TwoFactorServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
+
+#[derive(Debug)]
+pub struct TwoFactorService {
+    client: Arc<Mutex<TwoFactorServiceClient<Channel>>>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl TwoFactorService {
+    pub async fn new(
+        client: Arc<Mutex<TwoFactorServiceClient<Channel>>>,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "two_factor_handler_request_counter",
+            "Total number of requests to the TwoFactorService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "two_factor_handler_request_duration",
+            "Histogram of request durations for the TwoFactorService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self { client, metrics }
+    }
+
+    pub fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("two-factor-client-service")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        inject_access_token(request);
+        inject_request_context(request);
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl TwoFactorServiceTrait for TwoFactorService {
+    async fn enroll(&self) -> Result<ApiResponse<TwoFactorEnrollmentResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "EnrollTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("operation", "enroll"),
+            ],
+        );
+
+        let mut request = Request::new(EnrollTwoFactorRequest {});
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.enroll_two_factor(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Two-factor enrollment started",
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                })
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to start two-factor enrollment: {}",
+                        error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn confirm(
+        &self,
+        req: &DomainTwoFactorCodeRequest,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ConfirmTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("operation", "confirm"),
+            ],
+        );
+
+        let mut request = Request::new(TwoFactorCodeRequest {
+            code: req.code.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.confirm_two_factor(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+
+                self.complete_tracing_success(&tracing_ctx, method, "Two-factor enabled")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                })
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to confirm two-factor enrollment: {}",
+                        error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn disable(
+        &self,
+        req: &DomainTwoFactorCodeRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "DisableTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("operation", "disable"),
+            ],
+        );
+
+        let mut request = Request::new(TwoFactorCodeRequest {
+            code: req.code.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.disable_two_factor(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+
+                self.complete_tracing_success(&tracing_ctx, method, "Two-factor disabled")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                })
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to disable two-factor authentication: {}",
+                        error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        req: &DomainTwoFactorCodeRequest,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "RegenerateRecoveryCodes",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("operation", "regenerate_recovery_codes"),
+            ],
+        );
+
+        let mut request = Request::new(TwoFactorCodeRequest {
+            code: req.code.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self
+            .client
+            .lock()
+            .await
+            .regenerate_recovery_codes(request)
+            .await
+        {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+
+                self.complete_tracing_success(&tracing_ctx, method, "Recovery codes regenerated")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                })
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!(
+                        "Failed to regenerate recovery codes: {}",
+                        error_response.message
+                    ),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+}
@@ -1,7 +1,11 @@
-use crate::abstract_trait::UserServiceTrait;
+use crate::{
+    abstract_trait::UserServiceTrait,
+    service::{inject_access_token, inject_request_context},
+};
 use async_trait::async_trait;
 use genproto::user::{
-    CreateUserRequest, DeleteUserRequest, FindAllUserRequest, FindUserByIdRequest,
+    ChangePasswordRequest, ConfirmEmailChangeRequest, CreateUserRequest, DeleteUserRequest,
+    FindAllUserRequest, FindUserByIdRequest, RequestEmailChangeRequest, UpdateProfileRequest,
     UpdateUserRequest, user_service_client::UserServiceClient,
 };
 use opentelemetry::{
@@ -12,8 +16,12 @@ use opentelemetry::{
 use prometheus_client::registry::Registry;
 use shared::{
     domain::{
-        ApiResponse, ApiResponsePagination, CreateUserRequest as DomainCreateUserRequest,
-        ErrorResponse, FindAllUserRequest as DomainFindAllUserRequest,
+        ApiResponse, ApiResponsePagination, ChangePasswordRequest as DomainChangePasswordRequest,
+        ConfirmEmailChangeRequest as DomainConfirmEmailChangeRequest,
+        CreateUserRequest as DomainCreateUserRequest, ErrorResponse,
+        FindAllUserRequest as DomainFindAllUserRequest,
+        RequestEmailChangeRequest as DomainRequestEmailChangeRequest,
+        UpdateProfileRequest as DomainUpdateProfileRequest,
         UpdateUserRequest as DomainUpdateUserRequest, UserResponse,
     },
     utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
@@ -57,6 +65,9 @@ impl UserService {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+
+        inject_access_token(request);
+        inject_request_context(request);
     }
 
     fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
@@ -160,6 +171,7 @@ impl UserServiceTrait for UserService {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            cursor: req.cursor.clone(),
         });
 
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
@@ -328,6 +340,9 @@ impl UserServiceTrait for UserService {
         if let Some(password) = &req.password {
             update_request.password = password.clone();
         }
+        if let Some(role) = &req.role {
+            update_request.role = role.clone();
+        }
 
         let mut request = Request::new(update_request);
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
@@ -411,4 +426,211 @@ impl UserServiceTrait for UserService {
             }
         }
     }
+
+    async fn update_profile(
+        &self,
+        req: &DomainUpdateProfileRequest,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        let method = Method::Put;
+        let tracing_ctx = self.start_tracing(
+            "UpdateProfile",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "update_profile"),
+            ],
+        );
+
+        let mut request = Request::new(UpdateProfileRequest {
+            firstname: req.firstname.clone(),
+            lastname: req.lastname.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.update_profile(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Profile updated successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to update profile: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn change_password(
+        &self,
+        req: &DomainChangePasswordRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ChangePassword",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "change_password"),
+            ],
+        );
+
+        let mut request = Request::new(ChangePasswordRequest {
+            current_password: req.current_password.clone(),
+            new_password: req.new_password.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.change_password(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Password changed successfully",
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to change password: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn request_email_change(
+        &self,
+        req: &DomainRequestEmailChangeRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "RequestEmailChange",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "request_email_change"),
+            ],
+        );
+
+        let mut request = Request::new(RequestEmailChangeRequest {
+            new_email: req.new_email.clone(),
+            current_password: req.current_password.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.request_email_change(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: (),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Email change requested")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to request email change: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
+
+    async fn confirm_email_change(
+        &self,
+        req: &DomainConfirmEmailChangeRequest,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ConfirmEmailChange",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "confirm_email_change"),
+            ],
+        );
+
+        let mut request = Request::new(ConfirmEmailChangeRequest {
+            token: req.token.clone(),
+        });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.lock().await.confirm_email_change(request).await {
+            Ok(resp) => {
+                let inner = resp.into_inner();
+                let response = ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into(),
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Email changed successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(status) => {
+                let error_response = ErrorResponse {
+                    status: status.code().to_string(),
+                    message: status.message().to_string(),
+                };
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to confirm email change: {}", error_response.message),
+                )
+                .await;
+
+                Err(error_response)
+            }
+        }
+    }
 }
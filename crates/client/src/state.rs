@@ -1,27 +1,51 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use prometheus_client::registry::Registry;
 use shared::{
-    config::JwtConfig,
+    abstract_trait::DynOidcProvider,
+    cache::{CacheStore, TokenRevocationStore},
+    config::{
+        AuthCookieConfig, GrpcClientTlsConfig, JwtConfig, OidcConfig, RedisClient, RedisConfig,
+    },
+    service::OidcProvider,
     utils::{Metrics, SystemMetrics, run_metrics_collector},
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::sync::Mutex;
 use tonic::transport::Channel;
+use tracing::error;
 
-use crate::{di::DependenciesInject, service::GrpcClients};
+use crate::{abstract_trait::DynAuthService, di::DependenciesInject, service::GrpcClients};
+
+#[derive(Clone)]
+pub struct OidcLogin {
+    pub config: OidcConfig,
+    pub provider: DynOidcProvider,
+}
+
+impl std::fmt::Debug for OidcLogin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcLogin")
+            .field("config", &self.config)
+            .field("provider", &"DynOidcProvider")
+            .finish()
+    }
+}
 
 #[derive(Debug)]
 pub struct AppState {
     pub registry: Arc<Mutex<Registry>>,
     pub jwt_config: JwtConfig,
+    pub token_revocation: Arc<TokenRevocationStore>,
     pub metrics: Arc<Mutex<Metrics>>,
     pub di_container: DependenciesInject,
     pub system_metrics: Arc<SystemMetrics>,
+    pub oidc: Option<OidcLogin>,
+    pub auth_cookie: AuthCookieConfig,
 }
 
 impl AppState {
     pub async fn new(jwt_secret: &str) -> Result<Self> {
-        let jwt_config = JwtConfig::new(jwt_secret);
+        let jwt_config = JwtConfig::from_env(jwt_secret)?;
         let registry = Arc::new(Mutex::new(Registry::default()));
         let metrics = Arc::new(Mutex::new(Metrics::new()));
         let system_metrics = Arc::new(SystemMetrics::new());
@@ -30,10 +54,38 @@ impl AppState {
 
         tokio::spawn(run_metrics_collector(system_metrics.clone()));
 
-        let channel = Channel::from_static("http://blog-server:50051")
+        let redis = RedisClient::new(&RedisConfig::default())
+            .await
+            .context("Failed to connect to Redis")?;
+
+        redis.ping().context("Failed to ping Redis server")?;
+
+        let token_revocation = Arc::new(TokenRevocationStore::new(Arc::new(CacheStore::new(
+            redis.client.clone(),
+        ))));
+
+        let tls = GrpcClientTlsConfig::from_env()
+            .load()
+            .context("Failed to load gRPC TLS configuration")?;
+
+        let server_url = std::env::var("GRPC_SERVER_URL").unwrap_or_else(|_| {
+            let scheme = if tls.is_some() { "https" } else { "http" };
+            format!("{scheme}://blog-server:50051")
+        });
+
+        let mut endpoint = Channel::from_shared(server_url.clone())
+            .with_context(|| format!("Invalid gRPC server URL: {server_url}"))?;
+
+        if let Some(tls) = tls {
+            endpoint = endpoint
+                .tls_config(tls)
+                .context("Failed to apply gRPC TLS configuration")?;
+        }
+
+        let channel = endpoint
             .connect()
             .await
-            .context("gRPC connection to blog-server:50051 failed")?;
+            .with_context(|| format!("gRPC connection to {server_url} failed"))?;
 
         let clients = GrpcClients::init(channel).await;
 
@@ -44,16 +96,86 @@ impl AppState {
                 .context("Failed to initialize dependency injection container")?
         };
 
+        if jwt_config.algorithm.is_asymmetric() {
+            let auth_service = di_container.auth_service.clone();
+
+            load_jwks(&auth_service, &jwt_config)
+                .await
+                .context("Failed to load JWKS from gRPC server")?;
+
+            let refresh_interval = match std::env::var("JWKS_REFRESH_SECS") {
+                Ok(value) => match value.parse::<u64>() {
+                    Ok(secs) if secs > 0 => Duration::from_secs(secs),
+                    Ok(_) => {
+                        return Err(anyhow!(
+                            "Invalid value for JWKS_REFRESH_SECS ('{value}'): must be greater than zero"
+                        ));
+                    }
+                    Err(e) => {
+                        return Err(anyhow!(
+                            "Invalid value for JWKS_REFRESH_SECS ('{value}'): {e}"
+                        ));
+                    }
+                },
+                Err(_) => Duration::from_secs(300),
+            };
+
+            tokio::spawn(refresh_jwks(
+                auth_service,
+                jwt_config.clone(),
+                refresh_interval,
+            ));
+        }
+
+        let oidc = match OidcConfig::from_env().context("Invalid OIDC configuration")? {
+            Some(config) => Some(OidcLogin {
+                provider: Arc::new(
+                    OidcProvider::new(config.clone())
+                        .context("Failed to initialize OIDC provider")?,
+                ),
+                config,
+            }),
+            None => None,
+        };
+
         Ok(Self {
             registry,
             jwt_config,
+            token_revocation,
             metrics,
             di_container,
             system_metrics,
+            oidc,
+            auth_cookie: AuthCookieConfig::from_env()
+                .context("Invalid auth cookie configuration")?,
         })
     }
 }
 
+async fn load_jwks(auth_service: &DynAuthService, jwt_config: &JwtConfig) -> Result<()> {
+    let jwks = auth_service
+        .get_jwks()
+        .await
+        .map_err(|e| anyhow::anyhow!(e.message))?;
+
+    jwt_config.install_keys(None, jwks.keys)?;
+
+    Ok(())
+}
+
+async fn refresh_jwks(auth_service: DynAuthService, jwt_config: JwtConfig, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+
+        if let Err(err) = load_jwks(&auth_service, &jwt_config).await {
+            error!("Failed to refresh JWKS: {err}");
+        }
+    }
+}
+
 trait MetricsRegister {
     fn register_metrics(&mut self, metrics: &SystemMetrics);
 }
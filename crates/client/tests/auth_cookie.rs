@@ -0,0 +1,99 @@
+use axum::http::{HeaderMap, HeaderValue, Method};
+use axum_extra::extract::cookie::{Cookie, CookieJar};
+use seaquery_client::middleware::auth_cookie::{self, CSRF_COOKIE, CSRF_HEADER, TOKEN_COOKIE};
+use shared::{config::AuthCookieConfig, domain::TokenResponse};
+
+fn tokens() -> TokenResponse {
+    TokenResponse {
+        access_token: "access".to_string(),
+        refresh_token: "refresh".to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 900,
+    }
+}
+
+fn cookie_mode() -> AuthCookieConfig {
+    AuthCookieConfig {
+        enabled: true,
+        ..AuthCookieConfig::default()
+    }
+}
+
+fn csrf_header(value: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(CSRF_HEADER, HeaderValue::from_str(value).unwrap());
+    headers
+}
+
+#[test]
+fn cookies_are_only_issued_in_cookie_mode() {
+    let jar = auth_cookie::issue(CookieJar::new(), &AuthCookieConfig::default(), &tokens());
+
+    assert!(jar.get(TOKEN_COOKIE).is_none());
+}
+
+#[test]
+fn token_cookie_is_http_only_and_csrf_cookie_is_readable() {
+    let jar = auth_cookie::issue(CookieJar::new(), &cookie_mode(), &tokens());
+
+    let token = jar.get(TOKEN_COOKIE).unwrap();
+    assert_eq!(token.value(), "access");
+    assert_eq!(token.http_only(), Some(true));
+    assert_eq!(token.secure(), Some(true));
+
+    let csrf = jar.get(CSRF_COOKIE).unwrap();
+    assert!(!csrf.value().is_empty());
+    assert_ne!(csrf.http_only(), Some(true));
+}
+
+#[test]
+fn state_changing_requests_need_the_matching_header() {
+    let jar = CookieJar::new().add(Cookie::new(CSRF_COOKIE, "expected"));
+
+    assert!(auth_cookie::csrf_valid(
+        &jar,
+        &Method::GET,
+        &HeaderMap::new()
+    ));
+    assert!(!auth_cookie::csrf_valid(
+        &jar,
+        &Method::POST,
+        &HeaderMap::new()
+    ));
+    assert!(!auth_cookie::csrf_valid(
+        &jar,
+        &Method::DELETE,
+        &csrf_header("forged")
+    ));
+    assert!(auth_cookie::csrf_valid(
+        &jar,
+        &Method::PUT,
+        &csrf_header("expected")
+    ));
+}
+
+#[test]
+fn header_alone_is_not_enough_without_the_cookie() {
+    assert!(!auth_cookie::csrf_valid(
+        &CookieJar::new(),
+        &Method::POST,
+        &csrf_header("anything"),
+    ));
+}
+
+#[test]
+fn invalid_cookie_settings_fail_at_startup() {
+    let load = |key: &str, value: &str| {
+        // SAFETY: no other test in this binary reads or writes the environment.
+        unsafe { std::env::set_var(key, value) };
+        let config = AuthCookieConfig::from_env();
+        unsafe { std::env::remove_var(key) };
+        config
+    };
+
+    assert!(!AuthCookieConfig::from_env().unwrap().enabled);
+    assert!(load("AUTH_COOKIE_ENABLED", "true").unwrap().enabled);
+    assert!(load("AUTH_COOKIE_ENABLED", "yes").is_err());
+    assert!(load("AUTH_COOKIE_SECURE", "off").is_err());
+    assert!(load("AUTH_COOKIE_SAME_SITE", "relaxed").is_err());
+}
@@ -8,7 +8,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "../../proto/auth.proto",
                 "../../proto/post.proto",
                 "../../proto/category.proto",
+                "../../proto/tag.proto",
                 "../../proto/comment.proto",
+                "../../proto/api_key.proto",
+                "../../proto/two_factor.proto",
+                "../../proto/session.proto",
+                "../../proto/audit.proto",
             ],
             &["../../proto"],
         )?;
@@ -1,5 +1,5 @@
 // This file is @generated by prost-build.
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pagination {
     #[prost(int32, tag = "1")]
     pub page: i32,
@@ -9,6 +9,9 @@ pub struct Pagination {
     pub total_items: i64,
     #[prost(int32, tag = "4")]
     pub total_pages: i32,
+    /// Cursor of the next page when paging by cursor and more rows follow.
+    #[prost(string, optional, tag = "5")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseEmpty {
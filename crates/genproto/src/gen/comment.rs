@@ -11,13 +11,13 @@ pub struct CommentResponse {
     pub user_name_comment: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub comment: ::prost::alloc::string::String,
+    #[prost(int32, tag = "5")]
+    pub user_id: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateCommentRequest {
     #[prost(int32, tag = "1")]
     pub id_post_comment: i32,
-    #[prost(string, tag = "2")]
-    pub user_name_comment: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub comment: ::prost::alloc::string::String,
 }
@@ -25,8 +25,6 @@ pub struct CreateCommentRequest {
 pub struct UpdateCommentRequest {
     #[prost(int32, tag = "1")]
     pub id_post_comment: i32,
-    #[prost(string, tag = "2")]
-    pub user_name_comment: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub comment: ::prost::alloc::string::String,
 }
@@ -7,6 +7,34 @@ pub struct FindAllPostRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub tag: ::prost::alloc::string::String,
+    /// Set (empty for the first page) to page by cursor instead of page number.
+    #[prost(string, optional, tag = "5")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int32, optional, tag = "6")]
+    pub category_id: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "7")]
+    pub user_id: ::core::option::Option<i32>,
+    /// RFC 3339 bounds on the creation time: from inclusive, to exclusive.
+    #[prost(string, optional, tag = "8")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "9")]
+    pub to: ::core::option::Option<::prost::alloc::string::String>,
+    /// Empty for published posts; any other status needs posts:moderate.
+    #[prost(string, tag = "10")]
+    pub status: ::prost::alloc::string::String,
+    /// created_at, title or popularity, and asc or desc.
+    #[prost(string, tag = "11")]
+    pub sort: ::prost::alloc::string::String,
+    #[prost(string, tag = "12")]
+    pub order: ::prost::alloc::string::String,
+}
+/// Wraps a tag list so that leaving it out can mean "unchanged".
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagNames {
+    #[prost(string, repeated, tag = "1")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreatePostRequest {
@@ -18,10 +46,12 @@ pub struct CreatePostRequest {
     pub file: ::prost::alloc::string::String,
     #[prost(int32, tag = "4")]
     pub category_id: i32,
-    #[prost(int32, tag = "5")]
-    pub user_id: i32,
-    #[prost(string, tag = "6")]
-    pub user_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub published_at: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "9")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdatePostRequest {
@@ -35,10 +65,36 @@ pub struct UpdatePostRequest {
     pub file: ::prost::alloc::string::String,
     #[prost(int32, tag = "5")]
     pub category_id: i32,
-    #[prost(int32, tag = "6")]
-    pub user_id: i32,
-    #[prost(string, tag = "7")]
-    pub user_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub published_at: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub tags: ::core::option::Option<TagNames>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindMyPostsRequest {
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub tag: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchPostsRequest {
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub page: i32,
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+    #[prost(string, tag = "4")]
+    pub tag: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct FindPostRequest {
@@ -46,6 +102,11 @@ pub struct FindPostRequest {
     pub post_id: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindPostBySlugRequest {
+    #[prost(string, tag = "1")]
+    pub slug: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PostResponse {
     #[prost(int32, tag = "1")]
     pub id: i32,
@@ -61,6 +122,26 @@ pub struct PostResponse {
     pub user_id: i32,
     #[prost(string, tag = "7")]
     pub user_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "10")]
+    pub published_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "11")]
+    pub tags: ::prost::alloc::vec::Vec<super::tag::TagResponse>,
+}
+/// Highlights are HTML-escaped with matches wrapped in <mark>.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostSearchResult {
+    #[prost(message, optional, tag = "1")]
+    pub post: ::core::option::Option<PostResponse>,
+    #[prost(float, tag = "2")]
+    pub rank: f32,
+    #[prost(string, tag = "3")]
+    pub title_highlight: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub snippet: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PostRelationResponse {
@@ -107,6 +188,17 @@ pub struct ApiResponsePostsPaginated {
     pub pagination: ::core::option::Option<super::api::Pagination>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponsePostSearch {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<PostSearchResult>,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<super::api::Pagination>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseEmpty {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
@@ -228,6 +320,54 @@ pub mod posts_service_client {
                 .insert(GrpcMethod::new("post.PostsService", "FindAllPosts"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_my_posts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindMyPostsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePostsPaginated>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/post.PostsService/FindMyPosts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("post.PostsService", "FindMyPosts"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn search_posts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchPostsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePostSearch>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/post.PostsService/SearchPosts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("post.PostsService", "SearchPosts"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_post(
             &mut self,
             request: impl tonic::IntoRequest<super::FindPostRequest>,
@@ -252,6 +392,30 @@ pub mod posts_service_client {
                 .insert(GrpcMethod::new("post.PostsService", "FindPost"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_post_by_slug(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindPostBySlugRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePost>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/post.PostsService/FindPostBySlug",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("post.PostsService", "FindPostBySlug"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_post_relation(
             &mut self,
             request: impl tonic::IntoRequest<super::FindPostRequest>,
@@ -370,10 +534,28 @@ pub mod posts_service_server {
             tonic::Response<super::ApiResponsePostsPaginated>,
             tonic::Status,
         >;
+        async fn find_my_posts(
+            &self,
+            request: tonic::Request<super::FindMyPostsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePostsPaginated>,
+            tonic::Status,
+        >;
+        async fn search_posts(
+            &self,
+            request: tonic::Request<super::SearchPostsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePostSearch>,
+            tonic::Status,
+        >;
         async fn find_post(
             &self,
             request: tonic::Request<super::FindPostRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponsePost>, tonic::Status>;
+        async fn find_post_by_slug(
+            &self,
+            request: tonic::Request<super::FindPostBySlugRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponsePost>, tonic::Status>;
         async fn find_post_relation(
             &self,
             request: tonic::Request<super::FindPostRequest>,
@@ -518,6 +700,96 @@ pub mod posts_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/post.PostsService/FindMyPosts" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindMyPostsSvc<T: PostsService>(pub Arc<T>);
+                    impl<
+                        T: PostsService,
+                    > tonic::server::UnaryService<super::FindMyPostsRequest>
+                    for FindMyPostsSvc<T> {
+                        type Response = super::ApiResponsePostsPaginated;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindMyPostsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PostsService>::find_my_posts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindMyPostsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/post.PostsService/SearchPosts" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchPostsSvc<T: PostsService>(pub Arc<T>);
+                    impl<
+                        T: PostsService,
+                    > tonic::server::UnaryService<super::SearchPostsRequest>
+                    for SearchPostsSvc<T> {
+                        type Response = super::ApiResponsePostSearch;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchPostsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PostsService>::search_posts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SearchPostsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/post.PostsService/FindPost" => {
                     #[allow(non_camel_case_types)]
                     struct FindPostSvc<T: PostsService>(pub Arc<T>);
@@ -563,6 +835,52 @@ pub mod posts_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/post.PostsService/FindPostBySlug" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindPostBySlugSvc<T: PostsService>(pub Arc<T>);
+                    impl<
+                        T: PostsService,
+                    > tonic::server::UnaryService<super::FindPostBySlugRequest>
+                    for FindPostBySlugSvc<T> {
+                        type Response = super::ApiResponsePost;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindPostBySlugRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PostsService>::find_post_by_slug(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindPostBySlugSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/post.PostsService/FindPostRelation" => {
                     #[allow(non_camel_case_types)]
                     struct FindPostRelationSvc<T: PostsService>(pub Arc<T>);
@@ -18,6 +18,26 @@ pub mod category {
     include!("gen/category.rs");
 }
 
+pub mod tag {
+    include!("gen/tag.rs");
+}
+
+pub mod api_key {
+    include!("gen/api_key.rs");
+}
+
+pub mod two_factor {
+    include!("gen/two_factor.rs");
+}
+
+pub mod session {
+    include!("gen/session.rs");
+}
+
+pub mod audit {
+    include!("gen/audit.rs");
+}
+
 pub mod post {
     include!("gen/post.rs");
 }
@@ -0,0 +1,211 @@
+use shared::{
+    config::Claims,
+    domain::{AuditContext, ClientInfo, ErrorResponse},
+    model::role::Permission,
+    state::AppState,
+    utils::AppError,
+};
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    Request, Status,
+    body::Body,
+    codegen::{BoxFuture, Service, http, http::HeaderMap},
+};
+use tower_layer::Layer;
+
+/// Authenticates every gRPC call before it reaches a service: a bearer token
+/// is checked for signature, expiry and revocation, and its claims are stored
+/// in the request extensions for [`viewer`] and [`authenticate`]. Calls
+/// without a token pass through anonymously; each handler decides whether
+/// that is enough.
+///
+/// This is a tower layer rather than a tonic interceptor because the
+/// revocation lookup is async.
+#[derive(Clone, Debug)]
+pub struct AuthLayer {
+    state: Arc<AppState>,
+}
+
+impl AuthLayer {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthMiddleware {
+            inner,
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AuthMiddleware<S> {
+    inner: S,
+    state: Arc<AppState>,
+}
+
+impl<S, B> Service<http::Request<B>> for AuthMiddleware<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        // `self.inner` is the instance `poll_ready` was called on; keep it and
+        // leave the fresh clone behind for the next request.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let state = self.state.clone();
+
+        Box::pin(async move {
+            match verify_bearer(&state, request.headers()).await {
+                Ok(Some(claims)) => {
+                    request.extensions_mut().insert(claims);
+                }
+                Ok(None) => {}
+                Err(status) => return Ok(status.into_http()),
+            }
+
+            inner.call(request).await
+        })
+    }
+}
+
+async fn verify_bearer(state: &AppState, headers: &HeaderMap) -> Result<Option<Claims>, Status> {
+    let Some(value) = headers.get("authorization") else {
+        return Ok(None);
+    };
+
+    let token = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| Status::unauthenticated("Malformed authorization metadata"))?;
+
+    state
+        .di_container
+        .auth_service
+        .verify_token(token)
+        .await
+        .map(Some)
+        .map_err(|err| match err {
+            // Fail closed: a token that cannot be checked is not accepted.
+            AppError::CacheError(_) => Status::unavailable("Unable to verify token"),
+            err => Status::unauthenticated(ErrorResponse::from(err).message),
+        })
+}
+
+pub fn client_info<T>(state: &AppState, request: &Request<T>) -> ClientInfo {
+    let metadata = request.metadata();
+    let header = |name: &str| {
+        metadata
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+
+    let peer = request.remote_addr().map(|addr| addr.ip());
+    let has_client_certificate = request.peer_certs().is_some_and(|certs| !certs.is_empty());
+
+    // Only a trusted proxy may speak for the client; from anyone else the
+    // header is just a claim.
+    let forwarded_for = state
+        .trusted_proxies
+        .trusts(peer, has_client_certificate)
+        .then(|| header("x-forwarded-for"))
+        .flatten()
+        .and_then(|value| value.split(',').next().map(|ip| ip.trim().to_string()))
+        .filter(|ip| !ip.is_empty());
+
+    ClientInfo {
+        ip_address: forwarded_for.or_else(|| peer.map(|ip| ip.to_string())),
+        // The gateway relays the browser's user agent; the gRPC channel's own
+        // `user-agent` header only names the tonic client.
+        user_agent: header("x-client-user-agent"),
+        request_id: header("x-request-id"),
+    }
+}
+
+pub fn audit_context<T>(state: &AppState, request: &Request<T>, claims: &Claims) -> AuditContext {
+    AuditContext::new(claims, client_info(state, request))
+}
+
+/// The caller's claims on endpoints that also serve anonymous requests.
+pub fn viewer<T>(request: &Request<T>) -> Option<Claims> {
+    request.extensions().get::<Claims>().cloned()
+}
+
+#[allow(clippy::result_large_err)]
+pub fn authenticate<T>(request: &Request<T>) -> Result<Claims, Status> {
+    viewer(request).ok_or_else(|| Status::unauthenticated("Missing bearer token"))
+}
+
+/// Like [`authenticate`], for account self-service endpoints, which take a
+/// real login rather than an API key token.
+#[allow(clippy::result_large_err)]
+pub fn authenticate_interactive<T>(request: &Request<T>) -> Result<Claims, Status> {
+    let claims = authenticate(request)?;
+
+    claims
+        .require_interactive()
+        .map_err(|err| Status::permission_denied(ErrorResponse::from(err).message))?;
+
+    Ok(claims)
+}
+
+#[allow(clippy::result_large_err)]
+pub fn authorize<T>(request: &Request<T>, permission: Permission) -> Result<Claims, Status> {
+    let claims = authenticate(request)?;
+
+    if !claims.has_permission(permission) {
+        return Err(Status::permission_denied(format!(
+            "Missing permission: {permission}"
+        )));
+    }
+
+    Ok(claims)
+}
+
+#[allow(clippy::result_large_err)]
+pub async fn content_author(state: &AppState, claims: &Claims) -> Result<String, Status> {
+    let user = match state
+        .di_container
+        .user_service
+        .find_by_id(claims.user_id as i32)
+        .await
+    {
+        Ok(Some(user)) => user.data,
+        Ok(None) => return Err(Status::unauthenticated("User no longer exists")),
+        Err(err) => return Err(Status::internal(err.message)),
+    };
+
+    if !user.email_verified
+        && state
+            .di_container
+            .auth_service
+            .verification_policy()
+            .blocks_content()
+    {
+        return Err(Status::permission_denied(
+            "Email address must be verified before creating content",
+        ));
+    }
+
+    Ok(format!("{} {}", user.firstname, user.lastname))
+}
@@ -6,20 +6,27 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use genproto::{
-    auth::auth_service_server::AuthServiceServer,
+    api_key::api_key_service_server::ApiKeyServiceServer,
+    audit::audit_service_server::AuditServiceServer, auth::auth_service_server::AuthServiceServer,
     category::category_service_server::CategoryServiceServer,
     comment::comment_service_server::CommentServiceServer,
-    post::posts_service_server::PostsServiceServer, user::user_service_server::UserServiceServer,
+    post::posts_service_server::PostsServiceServer,
+    session::session_service_server::SessionServiceServer,
+    tag::tag_service_server::TagServiceServer,
+    two_factor::two_factor_service_server::TwoFactorServiceServer,
+    user::user_service_server::UserServiceServer,
 };
 use prometheus_client::encoding::text::encode;
 use shared::{
-    config::{Config, ConnectionManager},
+    config::{Config, ConnectionManager, GrpcServerTlsConfig, PublishingConfig},
+    service::run_scheduled_publishing,
     state::AppState,
     utils::{Telemetry, init_logger},
 };
 use std::sync::Arc;
 use tokio::net::TcpListener;
 
+mod guard;
 mod service;
 
 pub async fn metrics_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
@@ -63,23 +70,54 @@ async fn main() -> Result<()> {
 
     let state = Arc::new(AppState::new(db_pool, &config.jwt_secret).await);
 
+    let publishing = PublishingConfig::from_env().context("Invalid publishing configuration")?;
+
+    tokio::spawn(run_scheduled_publishing(
+        state.di_container.post_service.clone(),
+        publishing.check_interval,
+    ));
+
     let service_auth = service::auth::AuthServiceImpl::new(state.clone());
     let service_user = service::user::UserServiceImpl::new(state.clone());
     let service_post = service::posts::PostsServiceImpl::new(state.clone());
     let service_comment = service::comment::CommentServiceImpl::new(state.clone());
     let service_category = service::category::CategoryServiceImpl::new(state.clone());
+    let service_tag = service::tag::TagServiceImpl::new(state.clone());
+    let service_api_key = service::api_key::ApiKeyServiceImpl::new(state.clone());
+    let service_two_factor = service::two_factor::TwoFactorServiceImpl::new(state.clone());
+    let service_session = service::session::SessionServiceImpl::new(state.clone());
+    let service_audit = service::audit::AuditServiceImpl::new(state.clone());
 
     let addr = "0.0.0.0:50051"
         .parse()
         .context("Failed to parse gRPC address")?;
 
+    let mut grpc_builder = tonic::transport::Server::builder();
+
+    if let Some(tls) = GrpcServerTlsConfig::from_env()
+        .load()
+        .context("Failed to load gRPC TLS configuration")?
+    {
+        grpc_builder = grpc_builder
+            .tls_config(tls)
+            .context("Failed to apply gRPC TLS configuration")?;
+    }
+
+    let auth_layer = guard::AuthLayer::new(state.clone());
+
     let grpc_server = tokio::spawn(async move {
-        tonic::transport::Server::builder()
+        grpc_builder
+            .layer(auth_layer)
             .add_service(AuthServiceServer::new(service_auth))
             .add_service(UserServiceServer::new(service_user))
             .add_service(PostsServiceServer::new(service_post))
             .add_service(CommentServiceServer::new(service_comment))
             .add_service(CategoryServiceServer::new(service_category))
+            .add_service(TagServiceServer::new(service_tag))
+            .add_service(ApiKeyServiceServer::new(service_api_key))
+            .add_service(TwoFactorServiceServer::new(service_two_factor))
+            .add_service(SessionServiceServer::new(service_session))
+            .add_service(AuditServiceServer::new(service_audit))
             .serve(addr)
             .await
     });
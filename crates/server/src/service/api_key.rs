@@ -0,0 +1,125 @@
+use genproto::{
+    api::ApiResponseEmpty,
+    api_key::{
+        ApiResponseAuthenticateApiKey, ApiResponseCreatedApiKey, ApiResponsesApiKey,
+        AuthenticateApiKeyRequest, CreateApiKeyRequest, ListApiKeysRequest, RevokeApiKeyRequest,
+        api_key_service_server::ApiKeyService,
+    },
+};
+use shared::{domain::CreateApiKeyRequest as SharedCreateApiKeyRequest, state::AppState};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+use crate::guard::{audit_context, authenticate_interactive};
+
+#[derive(Debug, Clone)]
+pub struct ApiKeyServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl ApiKeyServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl ApiKeyService for ApiKeyServiceImpl {
+    async fn create_api_key(
+        &self,
+        request: Request<CreateApiKeyRequest>,
+    ) -> Result<Response<ApiResponseCreatedApiKey>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let req = request.into_inner();
+
+        let body = SharedCreateApiKeyRequest {
+            name: req.name,
+            scopes: req.scopes,
+            expires_in_days: req.expires_in_days,
+        };
+
+        match self
+            .state
+            .di_container
+            .api_key_service
+            .create_api_key(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseCreatedApiKey {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn list_api_keys(
+        &self,
+        request: Request<ListApiKeysRequest>,
+    ) -> Result<Response<ApiResponsesApiKey>, Status> {
+        let claims = authenticate_interactive(&request)?;
+
+        match self
+            .state
+            .di_container
+            .api_key_service
+            .list_api_keys(&claims)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponsesApiKey {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn revoke_api_key(
+        &self,
+        request: Request<RevokeApiKeyRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let id = request.into_inner().id;
+
+        match self
+            .state
+            .di_container
+            .api_key_service
+            .revoke_api_key(&claims, id, &audit_ctx)
+            .await
+        {
+            Ok(Some(api_response)) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Ok(None) => Err(Status::not_found("API key not found")),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn authenticate_api_key(
+        &self,
+        request: Request<AuthenticateApiKeyRequest>,
+    ) -> Result<Response<ApiResponseAuthenticateApiKey>, Status> {
+        let key = request.into_inner().key;
+
+        match self
+            .state
+            .di_container
+            .api_key_service
+            .authenticate(&key)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseAuthenticateApiKey {
+                status: api_response.status,
+                message: api_response.message,
+                access_token: api_response.data,
+            })),
+            Err(err) => Err(Status::unauthenticated(err.message)),
+        }
+    }
+}
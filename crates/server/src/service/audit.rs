@@ -0,0 +1,58 @@
+use genproto::audit::{
+    ApiResponseAuditEventsPaginated, ListAuditEventsRequest, audit_service_server::AuditService,
+};
+use shared::{domain::FindAllAuditEventRequest, model::role::Permission, state::AppState};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+use crate::guard::authorize;
+
+#[derive(Debug, Clone)]
+pub struct AuditServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl AuditServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl AuditService for AuditServiceImpl {
+    async fn list_audit_events(
+        &self,
+        request: Request<ListAuditEventsRequest>,
+    ) -> Result<Response<ApiResponseAuditEventsPaginated>, Status> {
+        authorize(&request, Permission::AuditRead)?;
+
+        let req = request.into_inner();
+
+        let body = FindAllAuditEventRequest {
+            page: req.page,
+            page_size: req.page_size,
+            actor_id: req.actor_id,
+            action: req.action,
+            target_type: req.target_type,
+            target_id: req.target_id,
+            from: req.from,
+            to: req.to,
+        };
+
+        match self
+            .state
+            .di_container
+            .audit_service
+            .get_audit_events(body)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseAuditEventsPaginated {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+                pagination: Some(api_response.pagination.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -1,16 +1,34 @@
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 
-use genproto::auth::{
-    ApiResponseGetMe, ApiResponseLogin, ApiResponseRegister, GetMeRequest, LoginRequest,
-    RegisterRequest, auth_service_server::AuthService,
+use genproto::{
+    api::ApiResponseEmpty,
+    auth::{
+        ApiResponseGetMe, ApiResponseJwks, ApiResponseLogin, ApiResponseRefreshToken,
+        ApiResponseRegister, GetJwksRequest, GetMeRequest, LoginRequest, LogoutRequest,
+        OidcLoginRequest, RefreshTokenRequest, RegisterRequest, RequestPasswordResetRequest,
+        ResendVerificationRequest, ResetPasswordRequest, VerifyEmailRequest,
+        VerifyTwoFactorLoginRequest, auth_service_server::AuthService,
+    },
 };
 
 use shared::{
-    domain::{LoginRequest as LoginDomainRequest, RegisterRequest as RegisterDomainRequest},
+    domain::{
+        ApiResponse, LoginRequest as LoginDomainRequest, LoginResponse,
+        LogoutRequest as LogoutDomainRequest, OidcLoginRequest as OidcLoginDomainRequest,
+        PasswordResetRequest as PasswordResetDomainRequest,
+        RefreshTokenRequest as RefreshTokenDomainRequest, RegisterRequest as RegisterDomainRequest,
+        ResendVerificationRequest as ResendVerificationDomainRequest,
+        ResetPasswordRequest as ResetPasswordDomainRequest,
+        TwoFactorLoginRequest as TwoFactorLoginDomainRequest,
+        VerifyEmailRequest as VerifyEmailDomainRequest,
+    },
+    model::role::Permission,
     state::AppState,
 };
 
+use crate::guard::{authenticate, client_info};
+
 #[derive(Debug, Clone)]
 pub struct AuthServiceImpl {
     pub state: Arc<AppState>,
@@ -22,12 +40,27 @@ impl AuthServiceImpl {
     }
 }
 
+fn login_reply(api_response: ApiResponse<LoginResponse>) -> ApiResponseLogin {
+    let (data, challenge) = match api_response.data {
+        LoginResponse::Tokens(token) => (Some(token.into()), None),
+        LoginResponse::TwoFactorRequired(challenge) => (None, Some(challenge.into())),
+    };
+
+    ApiResponseLogin {
+        status: api_response.status,
+        message: api_response.message,
+        data,
+        challenge,
+    }
+}
+
 #[tonic::async_trait]
 impl AuthService for AuthServiceImpl {
     async fn login_user(
         &self,
         request: Request<LoginRequest>,
     ) -> Result<Response<ApiResponseLogin>, Status> {
+        let client = client_info(&self.state, &request);
         let req = request.into_inner();
 
         let domain_req = LoginDomainRequest {
@@ -39,25 +72,77 @@ impl AuthService for AuthServiceImpl {
             .state
             .di_container
             .auth_service
-            .login_user(&domain_req)
+            .login_user(&domain_req, &client)
             .await
         {
-            Ok(api_response) => {
-                let reply = ApiResponseLogin {
-                    status: api_response.status,
-                    message: api_response.message,
-                    data: api_response.data,
-                };
-                Ok(Response::new(reply))
+            Ok(api_response) => Ok(Response::new(login_reply(api_response))),
+            Err(err) if err.status == "too_many_requests" || err.status == "forbidden" => {
+                Err(err.into())
             }
             Err(err) => Err(Status::unauthenticated(err.message)),
         }
     }
 
+    async fn verify_two_factor_login(
+        &self,
+        request: Request<VerifyTwoFactorLoginRequest>,
+    ) -> Result<Response<ApiResponseLogin>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = TwoFactorLoginDomainRequest {
+            challenge_token: req.challenge_token,
+            code: req.code,
+        };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .verify_two_factor_login(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseLogin {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+                challenge: None,
+            })),
+            Err(err) if err.status == "too_many_requests" => Err(err.into()),
+            Err(err) => Err(Status::unauthenticated(err.message)),
+        }
+    }
+
+    async fn login_with_oidc(
+        &self,
+        request: Request<OidcLoginRequest>,
+    ) -> Result<Response<ApiResponseLogin>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = OidcLoginDomainRequest {
+            id_token: req.id_token,
+            nonce: req.nonce,
+        };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .login_with_oidc(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(login_reply(api_response))),
+            Err(err) if err.status == "forbidden" => Err(err.into()),
+            Err(err) => Err(Status::unauthenticated(err.message)),
+        }
+    }
+
     async fn register_user(
         &self,
         request: Request<RegisterRequest>,
     ) -> Result<Response<ApiResponseRegister>, Status> {
+        let client = client_info(&self.state, &request);
         let req = request.into_inner();
 
         let domain_req = RegisterDomainRequest {
@@ -71,7 +156,7 @@ impl AuthService for AuthServiceImpl {
             .state
             .di_container
             .auth_service
-            .register_user(&domain_req)
+            .register_user(&domain_req, &client)
             .await
         {
             Ok(api_response) => {
@@ -91,8 +176,15 @@ impl AuthService for AuthServiceImpl {
         &self,
         request: Request<GetMeRequest>,
     ) -> Result<Response<ApiResponseGetMe>, Status> {
+        let claims = authenticate(&request)?;
         let req = request.into_inner();
 
+        if i64::from(req.id) != claims.user_id && !claims.has_permission(Permission::UsersRead) {
+            return Err(Status::permission_denied(
+                "You can only view your own profile",
+            ));
+        }
+
         match self
             .state
             .di_container
@@ -112,4 +204,174 @@ impl AuthService for AuthServiceImpl {
             Err(err) => Err(Status::internal(err.message)),
         }
     }
+
+    async fn refresh_token(
+        &self,
+        request: Request<RefreshTokenRequest>,
+    ) -> Result<Response<ApiResponseRefreshToken>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = RefreshTokenDomainRequest {
+            refresh_token: req.refresh_token,
+        };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .refresh_token(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => {
+                let reply = ApiResponseRefreshToken {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: Some(api_response.data.into()),
+                };
+                Ok(Response::new(reply))
+            }
+            Err(err) => Err(Status::unauthenticated(err.message)),
+        }
+    }
+
+    async fn logout(
+        &self,
+        request: Request<LogoutRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = LogoutDomainRequest {
+            refresh_token: (!req.refresh_token.is_empty()).then_some(req.refresh_token),
+            all_devices: req.all_devices,
+        };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .logout(&req.access_token, &domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) if err.status == "forbidden" => Err(err.into()),
+            Err(err) => Err(Status::unauthenticated(err.message)),
+        }
+    }
+
+    async fn request_password_reset(
+        &self,
+        request: Request<RequestPasswordResetRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = PasswordResetDomainRequest { email: req.email };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .request_password_reset(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(Status::internal(err.message)),
+        }
+    }
+
+    async fn reset_password(
+        &self,
+        request: Request<ResetPasswordRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = ResetPasswordDomainRequest {
+            token: req.token,
+            new_password: req.new_password,
+        };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .reset_password(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(Status::invalid_argument(err.message)),
+        }
+    }
+
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let domain_req = VerifyEmailDomainRequest { token: req.token };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .verify_email(&domain_req, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(Status::invalid_argument(err.message)),
+        }
+    }
+
+    async fn resend_verification(
+        &self,
+        request: Request<ResendVerificationRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let req = request.into_inner();
+
+        let domain_req = ResendVerificationDomainRequest { email: req.email };
+
+        match self
+            .state
+            .di_container
+            .auth_service
+            .resend_verification(&domain_req)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(Status::internal(err.message)),
+        }
+    }
+
+    async fn get_jwks(
+        &self,
+        _request: Request<GetJwksRequest>,
+    ) -> Result<Response<ApiResponseJwks>, Status> {
+        let jwks = serde_json::to_string(&self.state.di_container.auth_service.jwks())
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(ApiResponseJwks {
+            status: "success".to_string(),
+            message: "JWKS retrieved successfully".to_string(),
+            jwks,
+        }))
+    }
 }
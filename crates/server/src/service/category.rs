@@ -11,12 +11,15 @@ use shared::{
         FindAllCategoryRequest as SharedFindAllCategoryRequest,
         UpdateCategoryRequest as SharedUpdateCategoryRequest,
     },
+    model::role::Permission,
     state::AppState,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{error, info};
 
+use crate::guard::{audit_context, authorize};
+
 pub struct CategoryServiceImpl {
     pub state: Arc<AppState>,
 }
@@ -41,6 +44,7 @@ impl CategoryService for CategoryServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            cursor: req.cursor.clone(),
         };
 
         match self
@@ -62,7 +66,7 @@ impl CategoryService for CategoryServiceImpl {
             }
             Err(err) => {
                 error!("Failed to get categories: {}", err.message);
-                Err(Status::internal(err.message))
+                Err(err.into())
             }
         }
     }
@@ -97,6 +101,9 @@ impl CategoryService for CategoryServiceImpl {
         &self,
         request: Request<CreateCategoryRequest>,
     ) -> Result<Response<ApiResponseCategory>, Status> {
+        let claims = authorize(&request, Permission::CategoriesWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let req = request.get_ref();
 
         let body = SharedCreateCategoryRequest {
@@ -107,7 +114,7 @@ impl CategoryService for CategoryServiceImpl {
             .state
             .di_container
             .category_service
-            .create_category(&body)
+            .create_category(&body, &audit_ctx)
             .await
         {
             Ok(category) => Ok(Response::new(ApiResponseCategory {
@@ -123,6 +130,9 @@ impl CategoryService for CategoryServiceImpl {
         &self,
         request: Request<UpdateCategoryRequest>,
     ) -> Result<Response<ApiResponseCategory>, Status> {
+        let claims = authorize(&request, Permission::CategoriesWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let req = request.get_ref();
 
         let body = SharedUpdateCategoryRequest {
@@ -134,7 +144,7 @@ impl CategoryService for CategoryServiceImpl {
             .state
             .di_container
             .category_service
-            .update_category(&body)
+            .update_category(&body, &audit_ctx)
             .await
         {
             Ok(Some(category)) => Ok(Response::new(ApiResponseCategory {
@@ -151,13 +161,16 @@ impl CategoryService for CategoryServiceImpl {
         &self,
         request: Request<FindCategoryRequest>,
     ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authorize(&request, Permission::CategoriesDelete)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let id = request.into_inner().id;
 
         match self
             .state
             .di_container
             .category_service
-            .delete_category(id)
+            .delete_category(id, &audit_ctx)
             .await
         {
             Ok(result) => Ok(Response::new(ApiResponseEmpty {
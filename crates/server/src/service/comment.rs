@@ -11,12 +11,15 @@ use shared::{
         CreateCommentRequest as SharedCreateCommentRequest,
         UpdateCommentRequest as SharedUpdateCommentRequest,
     },
+    model::role::Permission,
     state::AppState,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{error, info};
 
+use crate::guard::{audit_context, authorize, content_author};
+
 pub struct CommentServiceImpl {
     pub state: Arc<AppState>,
 }
@@ -82,11 +85,16 @@ impl CommentService for CommentServiceImpl {
         &self,
         request: Request<ProtoCreateCommentRequest>,
     ) -> Result<Response<ApiResponseComment>, Status> {
+        let claims = authorize(&request, Permission::CommentsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let user_name_comment = content_author(&self.state, &claims).await?;
+
         let req = request.get_ref();
 
         let body = SharedCreateCommentRequest {
             id_post_comment: req.id_post_comment,
-            user_name_comment: req.user_name_comment.clone(),
+            user_id: claims.user_id as i32,
+            user_name_comment,
             comment: req.comment.clone(),
         };
 
@@ -94,7 +102,7 @@ impl CommentService for CommentServiceImpl {
             .state
             .di_container
             .comment_service
-            .create_comment(&body)
+            .create_comment(&body, &claims, &audit_ctx)
             .await
         {
             Ok(comment) => Ok(Response::new(ApiResponseComment {
@@ -102,7 +110,7 @@ impl CommentService for CommentServiceImpl {
                 message: comment.message,
                 data: Some(comment.data.into()),
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -110,11 +118,13 @@ impl CommentService for CommentServiceImpl {
         &self,
         request: Request<ProtoUpdateCommentRequest>,
     ) -> Result<Response<ApiResponseComment>, Status> {
+        let claims = authorize(&request, Permission::CommentsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let req = request.get_ref();
 
         let body = SharedUpdateCommentRequest {
             id_post_comment: req.id_post_comment,
-            user_name_comment: req.user_name_comment.clone(),
             comment: req.comment.clone(),
         };
 
@@ -122,7 +132,7 @@ impl CommentService for CommentServiceImpl {
             .state
             .di_container
             .comment_service
-            .update_comment(&body)
+            .update_comment(&body, &claims, &audit_ctx)
             .await
         {
             Ok(Some(comment)) => Ok(Response::new(ApiResponseComment {
@@ -131,7 +141,7 @@ impl CommentService for CommentServiceImpl {
                 data: Some(comment.data.into()),
             })),
             Ok(None) => Err(Status::not_found("Comment not found")),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -139,20 +149,23 @@ impl CommentService for CommentServiceImpl {
         &self,
         request: Request<FindCommentRequest>,
     ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authorize(&request, Permission::CommentsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let id = request.into_inner().id;
 
         match self
             .state
             .di_container
             .comment_service
-            .delete_comment(id)
+            .delete_comment(id, &claims, &audit_ctx)
             .await
         {
             Ok(result) => Ok(Response::new(ApiResponseEmpty {
                 status: result.status,
                 message: result.message,
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 }
@@ -1,5 +1,10 @@
+pub mod api_key;
+pub mod audit;
 pub mod auth;
 pub mod category;
 pub mod comment;
 pub mod posts;
+pub mod session;
+pub mod tag;
+pub mod two_factor;
 pub mod user;
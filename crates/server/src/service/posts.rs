@@ -1,20 +1,26 @@
 use genproto::api::ApiResponseEmpty;
 use genproto::post::{
-    ApiResponsePost, ApiResponsePostRelation, ApiResponsePostsPaginated, CreatePostRequest,
-    FindAllPostRequest, FindPostRequest, UpdatePostRequest, posts_service_server::PostsService,
+    ApiResponsePost, ApiResponsePostRelation, ApiResponsePostSearch, ApiResponsePostsPaginated,
+    CreatePostRequest, FindAllPostRequest, FindMyPostsRequest, FindPostBySlugRequest,
+    FindPostRequest, SearchPostsRequest, UpdatePostRequest, posts_service_server::PostsService,
 };
 use shared::{
     domain::{
         CreatePostRequest as SharedCreatePostRequest,
         FindAllPostRequest as SharedFindAllPostRequest,
+        FindMyPostsRequest as SharedFindMyPostsRequest,
+        SearchPostsRequest as SharedSearchPostsRequest,
         UpdatePostRequest as SharedUpdatePostRequest,
     },
+    model::role::Permission,
     state::AppState,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{error, info};
 
+use crate::guard::{audit_context, authenticate, authorize, content_author, viewer};
+
 pub struct PostsServiceImpl {
     pub state: Arc<AppState>,
 }
@@ -33,19 +39,29 @@ impl PostsService for PostsServiceImpl {
     ) -> Result<Response<ApiResponsePostsPaginated>, Status> {
         info!("Getting all posts");
 
+        let viewer = viewer(&request);
         let req = request.get_ref();
 
         let myrequest = SharedFindAllPostRequest {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            tag: req.tag.clone(),
+            cursor: req.cursor.clone(),
+            category_id: req.category_id,
+            user_id: req.user_id,
+            from: req.from.clone(),
+            to: req.to.clone(),
+            status: req.status.clone(),
+            sort: req.sort.clone(),
+            order: req.order.clone(),
         };
 
         match self
             .state
             .di_container
             .post_service
-            .get_all_posts(myrequest)
+            .get_all_posts(myrequest, viewer.as_ref())
             .await
         {
             Ok(api_response) => {
@@ -60,18 +76,87 @@ impl PostsService for PostsServiceImpl {
             }
             Err(err) => {
                 error!("Failed to get posts: {}", err.message);
-                Err(Status::internal(err.message))
+                Err(err.into())
             }
         }
     }
 
+    async fn find_my_posts(
+        &self,
+        request: Request<FindMyPostsRequest>,
+    ) -> Result<Response<ApiResponsePostsPaginated>, Status> {
+        let claims = authenticate(&request)?;
+        let req = request.into_inner();
+
+        let myrequest = SharedFindMyPostsRequest {
+            page: req.page,
+            page_size: req.page_size,
+            search: req.search,
+            status: req.status,
+            tag: req.tag,
+        };
+
+        match self
+            .state
+            .di_container
+            .post_service
+            .get_my_posts(myrequest, &claims)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponsePostsPaginated {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+                pagination: Some(api_response.pagination.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn search_posts(
+        &self,
+        request: Request<SearchPostsRequest>,
+    ) -> Result<Response<ApiResponsePostSearch>, Status> {
+        let req = request.into_inner();
+
+        let myrequest = SharedSearchPostsRequest {
+            q: req.query,
+            page: req.page,
+            page_size: req.page_size,
+            tag: req.tag,
+        };
+
+        match self
+            .state
+            .di_container
+            .post_service
+            .search_posts(myrequest)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponsePostSearch {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+                pagination: Some(api_response.pagination.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     async fn find_post(
         &self,
         request: Request<FindPostRequest>,
     ) -> Result<Response<ApiResponsePost>, Status> {
+        let viewer = viewer(&request);
         let post_id = request.into_inner().post_id;
 
-        match self.state.di_container.post_service.get_post(post_id).await {
+        match self
+            .state
+            .di_container
+            .post_service
+            .get_post(post_id, viewer.as_ref())
+            .await
+        {
             Ok(Some(post)) => {
                 let reply = ApiResponsePost {
                     status: "success".into(),
@@ -85,17 +170,42 @@ impl PostsService for PostsServiceImpl {
         }
     }
 
+    async fn find_post_by_slug(
+        &self,
+        request: Request<FindPostBySlugRequest>,
+    ) -> Result<Response<ApiResponsePost>, Status> {
+        let viewer = viewer(&request);
+        let slug = request.into_inner().slug;
+
+        match self
+            .state
+            .di_container
+            .post_service
+            .get_post_by_slug(&slug, viewer.as_ref())
+            .await
+        {
+            Ok(Some(post)) => Ok(Response::new(ApiResponsePost {
+                status: post.status,
+                message: post.message,
+                data: Some(post.data.into()),
+            })),
+            Ok(None) => Err(Status::not_found("Post not found")),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     async fn find_post_relation(
         &self,
         request: Request<FindPostRequest>,
     ) -> Result<Response<ApiResponsePostRelation>, Status> {
+        let viewer = viewer(&request);
         let post_id = request.into_inner().post_id;
 
         match self
             .state
             .di_container
             .post_service
-            .get_post_relation(post_id)
+            .get_post_relation(post_id, viewer.as_ref())
             .await
         {
             Ok(post_relation) => Ok(Response::new(ApiResponsePostRelation {
@@ -103,7 +213,7 @@ impl PostsService for PostsServiceImpl {
                 message: post_relation.message,
                 data: Some(post_relation.data.into()),
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -111,6 +221,10 @@ impl PostsService for PostsServiceImpl {
         &self,
         request: Request<CreatePostRequest>,
     ) -> Result<Response<ApiResponsePost>, Status> {
+        let claims = authorize(&request, Permission::PostsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let user_name = content_author(&self.state, &claims).await?;
+
         let req = request.get_ref();
 
         let body = SharedCreatePostRequest {
@@ -118,15 +232,18 @@ impl PostsService for PostsServiceImpl {
             body: req.body.clone(),
             file: req.file.clone(),
             category_id: req.category_id,
-            user_id: req.user_id,
-            user_name: req.user_name.clone(),
+            status: req.status.clone(),
+            published_at: req.published_at.clone(),
+            tags: req.tags.clone(),
+            user_id: claims.user_id as i32,
+            user_name,
         };
 
         match self
             .state
             .di_container
             .post_service
-            .create_post(&body)
+            .create_post(&body, &audit_ctx)
             .await
         {
             Ok(post) => Ok(Response::new(ApiResponsePost {
@@ -134,7 +251,7 @@ impl PostsService for PostsServiceImpl {
                 message: post.message,
                 data: Some(post.data.into()),
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -142,6 +259,9 @@ impl PostsService for PostsServiceImpl {
         &self,
         request: Request<UpdatePostRequest>,
     ) -> Result<Response<ApiResponsePost>, Status> {
+        let claims = authorize(&request, Permission::PostsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let req = request.get_ref();
 
         let body = SharedUpdatePostRequest {
@@ -150,15 +270,16 @@ impl PostsService for PostsServiceImpl {
             body: req.body.clone(),
             file: req.file.clone(),
             category_id: req.category_id,
-            user_id: req.user_id,
-            user_name: req.user_name.clone(),
+            status: req.status.clone(),
+            published_at: req.published_at.clone(),
+            tags: req.tags.clone().map(|tags| tags.names),
         };
 
         match self
             .state
             .di_container
             .post_service
-            .update_post(&body)
+            .update_post(&body, &claims, &audit_ctx)
             .await
         {
             Ok(post) => Ok(Response::new(ApiResponsePost {
@@ -166,7 +287,7 @@ impl PostsService for PostsServiceImpl {
                 message: post.message,
                 data: Some(post.data.into()),
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -174,20 +295,23 @@ impl PostsService for PostsServiceImpl {
         &self,
         request: Request<FindPostRequest>,
     ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authorize(&request, Permission::PostsDelete)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let post_id = request.into_inner().post_id;
 
         match self
             .state
             .di_container
             .post_service
-            .delete_post(post_id)
+            .delete_post(post_id, &claims, &audit_ctx)
             .await
         {
             Ok(result) => Ok(Response::new(ApiResponseEmpty {
                 status: result.status,
                 message: result.message,
             })),
-            Err(err) => Err(Status::internal(err.message)),
+            Err(err) => Err(err.into()),
         }
     }
 }
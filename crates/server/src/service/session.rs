@@ -0,0 +1,72 @@
+use genproto::{
+    api::ApiResponseEmpty,
+    session::{
+        ApiResponsesSession, ListSessionsRequest, RevokeSessionRequest,
+        session_service_server::SessionService,
+    },
+};
+use shared::state::AppState;
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+use crate::guard::{audit_context, authenticate_interactive};
+
+#[derive(Debug, Clone)]
+pub struct SessionServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl SessionServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl SessionService for SessionServiceImpl {
+    async fn list_sessions(
+        &self,
+        request: Request<ListSessionsRequest>,
+    ) -> Result<Response<ApiResponsesSession>, Status> {
+        let claims = authenticate_interactive(&request)?;
+
+        match self
+            .state
+            .di_container
+            .session_service
+            .list_sessions(&claims)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponsesSession {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn revoke_session(
+        &self,
+        request: Request<RevokeSessionRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let id = request.into_inner().id;
+
+        match self
+            .state
+            .di_container
+            .session_service
+            .revoke_session(&claims, &id, &audit_ctx)
+            .await
+        {
+            Ok(Some(api_response)) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Ok(None) => Err(Status::not_found("Session not found")),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use genproto::api::ApiResponseEmpty;
+use genproto::tag::{
+    ApiResponseTag, ApiResponseTagCloud, ApiResponseTagsPaginated, CreateTagRequest,
+    FindAllTagRequest, FindTagRequest, TagCloudRequest, UpdateTagRequest,
+    tag_service_server::TagService,
+};
+
+use shared::{
+    domain::{
+        CreateTagRequest as SharedCreateTagRequest, FindAllTagRequest as SharedFindAllTagRequest,
+        TagCloudRequest as SharedTagCloudRequest, UpdateTagRequest as SharedUpdateTagRequest,
+    },
+    model::role::Permission,
+    state::AppState,
+};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+use tracing::{error, info};
+
+use crate::guard::{audit_context, authorize};
+
+pub struct TagServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl TagServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl TagService for TagServiceImpl {
+    async fn get_tags(
+        &self,
+        request: Request<FindAllTagRequest>,
+    ) -> Result<Response<ApiResponseTagsPaginated>, Status> {
+        info!("Getting tags");
+
+        let req = request.get_ref();
+
+        let myrequest = SharedFindAllTagRequest {
+            page: req.page,
+            page_size: req.page_size,
+            search: req.search.clone(),
+        };
+
+        match self
+            .state
+            .di_container
+            .tag_service
+            .get_tags(myrequest)
+            .await
+        {
+            Ok(api_response) => {
+                let tags: Vec<_> = api_response.data.into_iter().map(Into::into).collect();
+
+                Ok(Response::new(ApiResponseTagsPaginated {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: tags,
+                    pagination: Some(api_response.pagination.into()),
+                }))
+            }
+            Err(err) => {
+                error!("Failed to get tags: {}", err.message);
+                Err(Status::internal(err.message))
+            }
+        }
+    }
+
+    async fn get_tag(
+        &self,
+        request: Request<FindTagRequest>,
+    ) -> Result<Response<ApiResponseTag>, Status> {
+        let id = request.into_inner().id;
+
+        match self.state.di_container.tag_service.get_tag(id).await {
+            Ok(Some(tag)) => {
+                let reply = ApiResponseTag {
+                    status: "success".into(),
+                    message: "Tag fetched successfully".into(),
+                    data: Some(tag.data.into()),
+                };
+                Ok(Response::new(reply))
+            }
+            Ok(None) => Err(Status::not_found("Tag not found")),
+            Err(err) => Err(Status::internal(err.message)),
+        }
+    }
+
+    async fn get_tag_cloud(
+        &self,
+        request: Request<TagCloudRequest>,
+    ) -> Result<Response<ApiResponseTagCloud>, Status> {
+        let req = request.into_inner();
+
+        // Zero is what an unset proto field arrives as.
+        let limit = if req.limit > 0 { req.limit } else { 50 };
+
+        match self
+            .state
+            .di_container
+            .tag_service
+            .get_tag_cloud(SharedTagCloudRequest { limit })
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseTagCloud {
+                status: api_response.status,
+                message: api_response.message,
+                data: api_response.data.into_iter().map(Into::into).collect(),
+            })),
+            Err(err) => {
+                error!("Failed to get tag cloud: {}", err.message);
+                Err(Status::internal(err.message))
+            }
+        }
+    }
+
+    async fn create_tag(
+        &self,
+        request: Request<CreateTagRequest>,
+    ) -> Result<Response<ApiResponseTag>, Status> {
+        let claims = authorize(&request, Permission::TagsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let req = request.get_ref();
+
+        let body = SharedCreateTagRequest {
+            name: req.name.clone(),
+        };
+
+        match self
+            .state
+            .di_container
+            .tag_service
+            .create_tag(&body, &audit_ctx)
+            .await
+        {
+            Ok(tag) => Ok(Response::new(ApiResponseTag {
+                status: tag.status,
+                message: tag.message,
+                data: Some(tag.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn update_tag(
+        &self,
+        request: Request<UpdateTagRequest>,
+    ) -> Result<Response<ApiResponseTag>, Status> {
+        let claims = authorize(&request, Permission::TagsWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let req = request.get_ref();
+
+        let body = SharedUpdateTagRequest {
+            id: req.id,
+            name: req.name.clone(),
+        };
+
+        match self
+            .state
+            .di_container
+            .tag_service
+            .update_tag(&body, &audit_ctx)
+            .await
+        {
+            Ok(Some(tag)) => Ok(Response::new(ApiResponseTag {
+                status: tag.status,
+                message: tag.message,
+                data: Some(tag.data.into()),
+            })),
+            Ok(None) => Err(Status::not_found("Tag not found")),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn delete_tag(
+        &self,
+        request: Request<FindTagRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authorize(&request, Permission::TagsDelete)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let id = request.into_inner().id;
+
+        match self
+            .state
+            .di_container
+            .tag_service
+            .delete_tag(id, &audit_ctx)
+            .await
+        {
+            Ok(result) => Ok(Response::new(ApiResponseEmpty {
+                status: result.status,
+                message: result.message,
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use genproto::{
+    api::ApiResponseEmpty,
+    two_factor::{
+        ApiResponseRecoveryCodes, ApiResponseTwoFactorEnrollment, EnrollTwoFactorRequest,
+        TwoFactorCodeRequest, two_factor_service_server::TwoFactorService,
+    },
+};
+use shared::{domain::TwoFactorCodeRequest as SharedTwoFactorCodeRequest, state::AppState};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+use crate::guard::{audit_context, authenticate_interactive};
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorServiceImpl {
+    pub state: Arc<AppState>,
+}
+
+impl TwoFactorServiceImpl {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl TwoFactorService for TwoFactorServiceImpl {
+    async fn enroll_two_factor(
+        &self,
+        request: Request<EnrollTwoFactorRequest>,
+    ) -> Result<Response<ApiResponseTwoFactorEnrollment>, Status> {
+        let claims = authenticate_interactive(&request)?;
+
+        match self
+            .state
+            .di_container
+            .two_factor_service
+            .enroll(&claims)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseTwoFactorEnrollment {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn confirm_two_factor(
+        &self,
+        request: Request<TwoFactorCodeRequest>,
+    ) -> Result<Response<ApiResponseRecoveryCodes>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let body = SharedTwoFactorCodeRequest {
+            code: request.into_inner().code,
+        };
+
+        match self
+            .state
+            .di_container
+            .two_factor_service
+            .confirm(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseRecoveryCodes {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn disable_two_factor(
+        &self,
+        request: Request<TwoFactorCodeRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let body = SharedTwoFactorCodeRequest {
+            code: request.into_inner().code,
+        };
+
+        match self
+            .state
+            .di_container
+            .two_factor_service
+            .disable(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        request: Request<TwoFactorCodeRequest>,
+    ) -> Result<Response<ApiResponseRecoveryCodes>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+        let body = SharedTwoFactorCodeRequest {
+            code: request.into_inner().code,
+        };
+
+        match self
+            .state
+            .di_container
+            .two_factor_service
+            .regenerate_recovery_codes(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseRecoveryCodes {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
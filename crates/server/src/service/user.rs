@@ -1,19 +1,28 @@
 use genproto::api::ApiResponseEmpty;
 use genproto::user::{
-    ApiResponseUserResponse, ApiResponsesUserResponse, CreateUserRequest, DeleteUserRequest,
-    FindAllUserRequest, FindUserByIdRequest, UpdateUserRequest, user_service_server::UserService,
+    ApiResponseUserResponse, ApiResponsesUserResponse, ChangePasswordRequest,
+    ConfirmEmailChangeRequest, CreateUserRequest, DeleteUserRequest, FindAllUserRequest,
+    FindUserByIdRequest, RequestEmailChangeRequest, UpdateProfileRequest, UpdateUserRequest,
+    user_service_server::UserService,
 };
 use shared::{
     domain::{
+        ChangePasswordRequest as SharedChangePasswordRequest,
+        ConfirmEmailChangeRequest as SharedConfirmEmailChangeRequest,
         CreateUserRequest as SharedCreateUserRequest,
         FindAllUserRequest as SharedFindAllUserRequest,
+        RequestEmailChangeRequest as SharedRequestEmailChangeRequest,
+        UpdateProfileRequest as SharedUpdateProfileRequest,
         UpdateUserRequest as SharedUpdateUserRequest,
     },
+    model::role::Permission,
     state::AppState,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 
+use crate::guard::{audit_context, authenticate, authenticate_interactive, authorize, client_info};
+
 pub struct UserServiceImpl {
     pub state: Arc<AppState>,
 }
@@ -30,6 +39,9 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<CreateUserRequest>,
     ) -> Result<Response<ApiResponseUserResponse>, Status> {
+        let claims = authorize(&request, Permission::UsersWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let myrequest = SharedCreateUserRequest {
             firstname: request.get_ref().firstname.clone(),
             lastname: request.get_ref().lastname.clone(),
@@ -41,7 +53,7 @@ impl UserService for UserServiceImpl {
             .state
             .di_container
             .user_service
-            .create_user(&myrequest)
+            .create_user(&myrequest, &audit_ctx)
             .await
         {
             Ok(user) => Ok(Response::new(ApiResponseUserResponse {
@@ -57,12 +69,15 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<FindAllUserRequest>,
     ) -> Result<Response<ApiResponsesUserResponse>, Status> {
+        authorize(&request, Permission::UsersRead)?;
+
         let req = request.get_ref();
 
         let myrequest = SharedFindAllUserRequest {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            cursor: req.cursor.clone(),
         };
 
         match self
@@ -85,7 +100,7 @@ impl UserService for UserServiceImpl {
             }
             Err(err) => {
                 tracing::error!("Failed to fetch users: {}", err);
-                Err(Status::internal("Failed to fetch users"))
+                Err(err.into())
             }
         }
     }
@@ -94,8 +109,16 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<FindUserByIdRequest>,
     ) -> Result<Response<ApiResponseUserResponse>, Status> {
+        let claims = authenticate(&request)?;
         let id = request.into_inner().id;
 
+        if claims.user_id != id as i64 && !claims.has_permission(Permission::UsersRead) {
+            return Err(Status::permission_denied(format!(
+                "Missing permission: {}",
+                Permission::UsersRead
+            )));
+        }
+
         match self.state.di_container.user_service.find_by_id(id).await {
             Ok(Some(user)) => {
                 let reply = ApiResponseUserResponse {
@@ -114,6 +137,9 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<UpdateUserRequest>,
     ) -> Result<Response<ApiResponseUserResponse>, Status> {
+        let claims = authorize(&request, Permission::UsersWrite)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let req = request.get_ref();
 
         let body = SharedUpdateUserRequest {
@@ -122,13 +148,14 @@ impl UserService for UserServiceImpl {
             lastname: Some(req.lastname.clone()),
             email: Some(req.email.clone()),
             password: Some(req.password.clone()),
+            role: (!req.role.is_empty()).then(|| req.role.clone()),
         };
 
         match self
             .state
             .di_container
             .user_service
-            .update_user(&body)
+            .update_user(&body, &audit_ctx)
             .await
         {
             Ok(Some(api_response)) => Ok(Response::new(ApiResponseUserResponse {
@@ -145,13 +172,16 @@ impl UserService for UserServiceImpl {
         &self,
         request: Request<DeleteUserRequest>,
     ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authorize(&request, Permission::UsersDelete)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
         let email = request.get_ref().email.clone();
 
         match self
             .state
             .di_container
             .user_service
-            .delete_user(email.as_str())
+            .delete_user(email.as_str(), &audit_ctx)
             .await
         {
             Ok(user) => Ok(Response::new(ApiResponseEmpty {
@@ -161,4 +191,117 @@ impl UserService for UserServiceImpl {
             Err(err) => Err(Status::internal(err.message)),
         }
     }
+
+    async fn update_profile(
+        &self,
+        request: Request<UpdateProfileRequest>,
+    ) -> Result<Response<ApiResponseUserResponse>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let req = request.into_inner();
+
+        let body = SharedUpdateProfileRequest {
+            firstname: req.firstname,
+            lastname: req.lastname,
+        };
+
+        match self
+            .state
+            .di_container
+            .user_service
+            .update_profile(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseUserResponse {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn change_password(
+        &self,
+        request: Request<ChangePasswordRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let req = request.into_inner();
+
+        let body = SharedChangePasswordRequest {
+            current_password: req.current_password,
+            new_password: req.new_password,
+        };
+
+        match self
+            .state
+            .di_container
+            .user_service
+            .change_password(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn request_email_change(
+        &self,
+        request: Request<RequestEmailChangeRequest>,
+    ) -> Result<Response<ApiResponseEmpty>, Status> {
+        let claims = authenticate_interactive(&request)?;
+        let audit_ctx = audit_context(&self.state, &request, &claims);
+
+        let req = request.into_inner();
+
+        let body = SharedRequestEmailChangeRequest {
+            new_email: req.new_email,
+            current_password: req.current_password,
+        };
+
+        match self
+            .state
+            .di_container
+            .user_service
+            .request_email_change(&claims, &body, &audit_ctx)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseEmpty {
+                status: api_response.status,
+                message: api_response.message,
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn confirm_email_change(
+        &self,
+        request: Request<ConfirmEmailChangeRequest>,
+    ) -> Result<Response<ApiResponseUserResponse>, Status> {
+        let client = client_info(&self.state, &request);
+        let req = request.into_inner();
+
+        let body = SharedConfirmEmailChangeRequest { token: req.token };
+
+        match self
+            .state
+            .di_container
+            .user_service
+            .confirm_email_change(&body, &client)
+            .await
+        {
+            Ok(api_response) => Ok(Response::new(ApiResponseUserResponse {
+                status: api_response.status,
+                message: api_response.message,
+                data: Some(api_response.data.into()),
+            })),
+            Err(err) => Err(err.into()),
+        }
+    }
 }
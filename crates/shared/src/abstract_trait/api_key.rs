@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::{
+    config::Claims,
+    domain::{
+        ApiKeyResponse, ApiResponse, AuditContext, CreateApiKeyRequest, CreatedApiKeyResponse,
+        ErrorResponse,
+    },
+    model::api_key::{ApiKey, NewApiKey},
+    utils::AppError,
+};
+
+pub type DynApiKeyRepository = Arc<dyn ApiKeyRepositoryTrait + Send + Sync>;
+pub type DynApiKeyService = Arc<dyn ApiKeyServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ApiKeyRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<ApiKey>, AppError>;
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, AppError>;
+    async fn create(&self, key: &NewApiKey) -> Result<ApiKey, AppError>;
+    async fn revoke(&self, id: i32, user_id: i32) -> Result<bool, AppError>;
+    async fn touch(&self, id: i32, used_at: DateTime<Utc>) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait ApiKeyServiceTrait {
+    async fn create_api_key(
+        &self,
+        actor: &Claims,
+        input: &CreateApiKeyRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<CreatedApiKeyResponse>, ErrorResponse>;
+    async fn list_api_keys(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<Vec<ApiKeyResponse>>, ErrorResponse>;
+    async fn revoke_api_key(
+        &self,
+        actor: &Claims,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+    async fn authenticate(&self, key: &str) -> Result<ApiResponse<String>, ErrorResponse>;
+}
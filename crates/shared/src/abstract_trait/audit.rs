@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        ApiResponsePagination, AuditContext, AuditEventResponse, ErrorResponse,
+        FindAllAuditEventRequest,
+    },
+    model::audit_event::{AuditEntry, AuditEvent, AuditEventFilter, NewAuditEvent},
+    utils::AppError,
+};
+
+pub type DynAuditRepository = Arc<dyn AuditRepositoryTrait + Send + Sync>;
+pub type DynAuditService = Arc<dyn AuditServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait AuditRepositoryTrait {
+    async fn create(&self, event: &NewAuditEvent) -> Result<AuditEvent, AppError>;
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &AuditEventFilter,
+    ) -> Result<(Vec<AuditEvent>, i64), AppError>;
+}
+
+#[async_trait]
+pub trait AuditServiceTrait {
+    /// Appends an event to the audit log. Failures are logged rather than
+    /// returned so that auditing never undoes the operation being audited.
+    async fn record(&self, ctx: &AuditContext, entry: AuditEntry);
+    async fn get_audit_events(
+        &self,
+        req: FindAllAuditEventRequest,
+    ) -> Result<ApiResponsePagination<Vec<AuditEventResponse>>, ErrorResponse>;
+}
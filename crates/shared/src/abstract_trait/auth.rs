@@ -1,9 +1,16 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
 
 use crate::{
-    domain::{ApiResponse, ErrorResponse, LoginRequest, RegisterRequest, UserResponse},
+    config::{Claims, EmailVerificationPolicy},
+    domain::{
+        ApiResponse, ClientInfo, ErrorResponse, LoginRequest, LoginResponse, LogoutRequest,
+        OidcLoginRequest, PasswordResetRequest, RefreshTokenRequest, RegisterRequest,
+        ResendVerificationRequest, ResetPasswordRequest, TokenResponse, TwoFactorLoginRequest,
+        UserResponse, VerifyEmailRequest,
+    },
     utils::AppError,
 };
 
@@ -14,7 +21,54 @@ pub trait AuthServiceTrait {
     async fn register_user(
         &self,
         input: &RegisterRequest,
+        client: &ClientInfo,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
-    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse>;
-    fn verify_token(&self, token: &str) -> Result<i64, AppError>;
+    async fn login_user(
+        &self,
+        input: &LoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse>;
+    async fn verify_two_factor_login(
+        &self,
+        input: &TwoFactorLoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse>;
+    async fn login_with_oidc(
+        &self,
+        input: &OidcLoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse>;
+    async fn refresh_token(
+        &self,
+        input: &RefreshTokenRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse>;
+    async fn logout(
+        &self,
+        access_token: &str,
+        input: &LogoutRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn request_password_reset(
+        &self,
+        input: &PasswordResetRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn reset_password(
+        &self,
+        input: &ResetPasswordRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn verify_email(
+        &self,
+        input: &VerifyEmailRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn resend_verification(
+        &self,
+        input: &ResendVerificationRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn verify_token(&self, token: &str) -> Result<Claims, AppError>;
+    fn verification_policy(&self) -> EmailVerificationPolicy;
+    fn jwks(&self) -> JwkSet;
 }
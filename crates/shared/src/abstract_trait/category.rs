@@ -4,11 +4,11 @@ use async_trait::async_trait;
 
 use crate::{
     domain::{
-        ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest, ErrorResponse,
-        FindAllCategoryRequest, UpdateCategoryRequest,
+        ApiResponse, ApiResponsePagination, AuditContext, CategoryResponse, CreateCategoryRequest,
+        ErrorResponse, FindAllCategoryRequest, UpdateCategoryRequest,
     },
     model::category::Category,
-    utils::AppError,
+    utils::{AppError, PageCursor},
 };
 
 pub type DynCategoryRepository = Arc<dyn CategoryRepositoryTrait + Send + Sync>;
@@ -22,6 +22,12 @@ pub trait CategoryRepositoryTrait {
         page_size: i32,
         search: Option<String>,
     ) -> Result<(Vec<Category>, i64), AppError>;
+    async fn find_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        search: Option<String>,
+    ) -> Result<(Vec<Category>, Option<PageCursor>), AppError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<Category>, AppError>;
     async fn create(&self, input: &CreateCategoryRequest) -> Result<Category, AppError>;
     async fn update(&self, input: &UpdateCategoryRequest) -> Result<Category, AppError>;
@@ -41,10 +47,16 @@ pub trait CategoryServiceTrait {
     async fn create_category(
         &self,
         input: &CreateCategoryRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<CategoryResponse>, ErrorResponse>;
     async fn update_category(
         &self,
         input: &UpdateCategoryRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse>;
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn delete_category(
+        &self,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
 }
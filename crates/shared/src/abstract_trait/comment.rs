@@ -3,8 +3,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::{
+    config::Claims,
     domain::{
-        ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest,
+        ApiResponse, AuditContext, CommentResponse, CreateCommentRequest, ErrorResponse,
+        UpdateCommentRequest,
     },
     model::comment::Comment,
     utils::AppError,
@@ -32,10 +34,19 @@ pub trait CommentServiceTrait {
     async fn create_comment(
         &self,
         input: &CreateCommentRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<CommentResponse>, ErrorResponse>;
     async fn update_comment(
         &self,
         input: &UpdateCommentRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse>;
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn delete_comment(
+        &self,
+        id: i32,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
 }
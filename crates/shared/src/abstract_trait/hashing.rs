@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+pub type DynHashing = Arc<dyn HashingTrait + Send + Sync>;
+
+#[async_trait]
+pub trait HashingTrait {
+    async fn hash_password(&self, password: &str) -> Result<String, AppError>;
+    async fn compare_password(&self, hashed_password: &str, password: &str)
+    -> Result<(), AppError>;
+    fn needs_rehash(&self, hashed_password: &str) -> bool;
+}
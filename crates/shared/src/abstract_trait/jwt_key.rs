@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::{model::jwt_key::JwtKey, utils::AppError};
+
+pub type DynJwtKeyRepository = Arc<dyn JwtKeyRepositoryTrait + Send + Sync>;
+pub type DynJwtKeyService = Arc<dyn JwtKeyServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait JwtKeyRepositoryTrait {
+    async fn find_unretired(&self) -> Result<Vec<JwtKey>, AppError>;
+    async fn create(&self, key: &JwtKey) -> Result<(), AppError>;
+    async fn retire_superseded(
+        &self,
+        current_kid: &str,
+        activated_before: DateTime<Utc>,
+        retires_at: DateTime<Utc>,
+    ) -> Result<u64, AppError>;
+    async fn delete_retired(&self) -> Result<u64, AppError>;
+}
+
+#[async_trait]
+pub trait JwtKeyServiceTrait {
+    async fn rotate(&self) -> Result<(), AppError>;
+}
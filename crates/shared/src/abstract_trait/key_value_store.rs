@@ -0,0 +1,26 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+pub type DynKeyValueStore = Arc<dyn KeyValueStoreTrait + Send + Sync>;
+
+/// The flags and counters the token denylist and login throttling keep in
+/// Redis. Unlike the best-effort helpers on `CacheStore`, every failure is
+/// returned so that each caller decides whether to fail open or closed.
+#[async_trait]
+pub trait KeyValueStoreTrait {
+    async fn set_flag(&self, key: &str, expiration: Duration) -> Result<(), AppError>;
+    /// Sets a flag only if it is not already present, reporting whether this
+    /// call created it.
+    async fn set_if_absent(&self, key: &str, expiration: Duration) -> Result<bool, AppError>;
+    async fn exists(&self, key: &str) -> Result<bool, AppError>;
+    async fn get_counter(&self, key: &str) -> Result<i64, AppError>;
+    async fn increment(&self, key: &str) -> Result<i64, AppError>;
+    /// Increments a counter, starting its expiry window on the first hit.
+    async fn increment_with_expiry(&self, key: &str, expiration: Duration)
+    -> Result<i64, AppError>;
+    async fn time_to_live(&self, key: &str) -> Result<Option<Duration>, AppError>;
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+pub type DynMailer = Arc<dyn MailerTrait + Send + Sync>;
+
+#[derive(Debug, Clone)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait MailerTrait {
+    async fn send(&self, message: &MailMessage) -> Result<(), AppError>;
+}
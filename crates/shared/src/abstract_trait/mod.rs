@@ -1,9 +1,31 @@
+mod api_key;
+mod audit;
 mod auth;
 mod category;
 mod comment;
 mod file;
+mod hashing;
+mod jwt_key;
+mod key_value_store;
+mod mailer;
+mod oidc;
 mod post;
+mod refresh_token;
+mod role;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
+mod user_identity;
+mod user_token;
+
+pub use self::api_key::{
+    ApiKeyRepositoryTrait, ApiKeyServiceTrait, DynApiKeyRepository, DynApiKeyService,
+};
+
+pub use self::audit::{
+    AuditRepositoryTrait, AuditServiceTrait, DynAuditRepository, DynAuditService,
+};
 
 pub use self::category::{
     CategoryRepositoryTrait, CategoryServiceTrait, DynCategoryRepository, DynCategoryService,
@@ -13,6 +35,8 @@ pub use self::post::{
     DynPostsRepository, DynPostsService, PostsRepositoryTrait, PostsServiceTrait,
 };
 
+pub use self::tag::{DynTagRepository, DynTagService, TagRepositoryTrait, TagServiceTrait};
+
 pub use self::comment::{
     CommentRepositoryTrait, CommentServiceTrait, DynCommentRepository, DynCommentService,
 };
@@ -21,4 +45,32 @@ pub use self::user::{DynUserRepository, DynUserService, UserRepositoryTrait, Use
 
 pub use self::auth::{AuthServiceTrait, DynAuthService};
 
+pub use self::refresh_token::{DynRefreshTokenRepository, RefreshTokenRepositoryTrait};
+
+pub use self::role::{DynRoleRepository, RoleRepositoryTrait};
+
+pub use self::session::{
+    DynSessionRepository, DynSessionService, SessionRepositoryTrait, SessionServiceTrait,
+};
+
 pub use self::file::{DynFileService, FileServiceTrait};
+
+pub use self::hashing::{DynHashing, HashingTrait};
+
+pub use self::jwt_key::{
+    DynJwtKeyRepository, DynJwtKeyService, JwtKeyRepositoryTrait, JwtKeyServiceTrait,
+};
+
+pub use self::key_value_store::{DynKeyValueStore, KeyValueStoreTrait};
+
+pub use self::mailer::{DynMailer, MailMessage, MailerTrait};
+
+pub use self::user_token::{DynUserTokenRepository, UserTokenRepositoryTrait};
+
+pub use self::two_factor::{
+    DynTwoFactorRepository, DynTwoFactorService, TwoFactorRepositoryTrait, TwoFactorServiceTrait,
+};
+
+pub use self::user_identity::{DynUserIdentityRepository, UserIdentityRepositoryTrait};
+
+pub use self::oidc::{DynOidcProvider, OidcIdentity, OidcProviderTrait};
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+pub type DynOidcProvider = Arc<dyn OidcProviderTrait + Send + Sync>;
+
+/// Claims taken from a verified ID token.
+#[derive(Debug, Clone)]
+pub struct OidcIdentity {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub name: Option<String>,
+    /// The token's `jti`, or its nonce when the provider sets no `jti`;
+    /// together with the issuer it identifies this one token and is the key
+    /// that replay protection records. Many providers omit `jti`, so the
+    /// nonce fallback is required: an empty id would make every later login
+    /// from the same issuer look like a replay.
+    pub token_id: String,
+    /// Unix timestamp after which the provider no longer accepts the token.
+    pub expires_at: i64,
+}
+
+#[async_trait]
+pub trait OidcProviderTrait {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, AppError>;
+    async fn exchange_code(&self, code: &str, code_verifier: &str) -> Result<String, AppError>;
+    async fn verify_id_token(&self, id_token: &str, nonce: &str) -> Result<OidcIdentity, AppError>;
+}
@@ -3,12 +3,14 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::{
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreatePostRequest, ErrorResponse, FindAllPostRequest,
-        PostRelationResponse, PostResponse, UpdatePostRequest,
+        ApiResponse, ApiResponsePagination, AuditContext, CreatePostRequest, ErrorResponse,
+        FindAllPostRequest, FindMyPostsRequest, PostRelationResponse, PostResponse,
+        PostSearchResultResponse, SearchPostsRequest, UpdatePostRequest,
     },
-    model::posts::Post,
-    utils::AppError,
+    model::posts::{Post, PostFilter, PostPublication, PostSearchHit},
+    utils::{AppError, PageCursor},
 };
 
 pub type DynPostsRepository = Arc<dyn PostsRepositoryTrait + Send + Sync>;
@@ -20,12 +22,36 @@ pub trait PostsRepositoryTrait {
         &self,
         page: i32,
         page_size: i32,
-        search: Option<String>,
+        filter: &PostFilter,
     ) -> Result<(Vec<Post>, i64), AppError>;
+    async fn get_posts_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        filter: &PostFilter,
+    ) -> Result<(Vec<Post>, Option<PageCursor>), AppError>;
+    async fn search_posts(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &PostFilter,
+    ) -> Result<(Vec<PostSearchHit>, i64), AppError>;
     async fn get_post(&self, post_id: i32) -> Result<Option<Post>, AppError>;
+    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<Post>, AppError>;
     async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, AppError>;
-    async fn create_post(&self, input: &CreatePostRequest) -> Result<Post, AppError>;
-    async fn update_post(&self, input: &UpdatePostRequest) -> Result<Post, AppError>;
+    async fn create_post(
+        &self,
+        input: &CreatePostRequest,
+        publication: &PostPublication,
+        tags: &[String],
+    ) -> Result<Post, AppError>;
+    async fn update_post(
+        &self,
+        input: &UpdatePostRequest,
+        publication: &PostPublication,
+        tags: Option<&[String]>,
+    ) -> Result<Post, AppError>;
+    async fn publish_due_posts(&self) -> Result<Vec<Post>, AppError>;
     async fn delete_post(&self, post_id: i32) -> Result<(), AppError>;
 }
 
@@ -34,22 +60,48 @@ pub trait PostsServiceTrait {
     async fn get_all_posts(
         &self,
         req: FindAllPostRequest,
+        viewer: Option<&Claims>,
     ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse>;
+    async fn get_my_posts(
+        &self,
+        req: FindMyPostsRequest,
+        actor: &Claims,
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse>;
+    async fn search_posts(
+        &self,
+        req: SearchPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostSearchResultResponse>>, ErrorResponse>;
     async fn get_post(
         &self,
         post_id: i32,
+        viewer: Option<&Claims>,
+    ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse>;
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        viewer: Option<&Claims>,
     ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse>;
     async fn get_post_relation(
         &self,
         post_id: i32,
+        viewer: Option<&Claims>,
     ) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse>;
     async fn create_post(
         &self,
         input: &CreatePostRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
     async fn update_post(
         &self,
         input: &UpdatePostRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn delete_post(
+        &self,
+        post_id: i32,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn publish_due_posts(&self) -> Result<usize, ErrorResponse>;
 }
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{model::refresh_token::RefreshToken, utils::AppError};
+
+pub type DynRefreshTokenRepository = Arc<dyn RefreshTokenRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait RefreshTokenRepositoryTrait {
+    async fn create(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        family_id: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<RefreshToken, AppError>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AppError>;
+    async fn rotate(
+        &self,
+        current: &RefreshToken,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<RefreshToken>, AppError>;
+    async fn revoke_family(&self, family_id: &str) -> Result<(), AppError>;
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError>;
+}
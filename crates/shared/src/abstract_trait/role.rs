@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+pub type DynRoleRepository = Arc<dyn RoleRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait RoleRepositoryTrait {
+    async fn find_permissions(&self, role: &str) -> Result<Vec<String>, AppError>;
+}
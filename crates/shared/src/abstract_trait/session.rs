@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::{
+    config::Claims,
+    domain::{ApiResponse, AuditContext, ErrorResponse, SessionResponse},
+    model::session::Session,
+    utils::AppError,
+};
+
+pub type DynSessionRepository = Arc<dyn SessionRepositoryTrait + Send + Sync>;
+pub type DynSessionService = Arc<dyn SessionServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait SessionRepositoryTrait {
+    async fn create(
+        &self,
+        id: &str,
+        user_id: i32,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+    ) -> Result<Session, AppError>;
+    async fn find_active_by_user(
+        &self,
+        user_id: i32,
+        seen_after: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AppError>;
+    async fn touch(&self, id: &str) -> Result<bool, AppError>;
+    async fn revoke(&self, id: &str, user_id: i32) -> Result<bool, AppError>;
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError>;
+    /// Revokes every active session but `keep_id`, returning the ids it ended.
+    async fn revoke_others(&self, user_id: i32, keep_id: &str) -> Result<Vec<String>, AppError>;
+}
+
+#[async_trait]
+pub trait SessionServiceTrait {
+    async fn list_sessions(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<Vec<SessionResponse>>, ErrorResponse>;
+    async fn revoke_session(
+        &self,
+        actor: &Claims,
+        id: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+}
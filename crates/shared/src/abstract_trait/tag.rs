@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        ApiResponse, ApiResponsePagination, AuditContext, CreateTagRequest, ErrorResponse,
+        FindAllTagRequest, TagCloudRequest, TagCountResponse, TagResponse, UpdateTagRequest,
+    },
+    model::tag::{PostTag, Tag, TagCount},
+    utils::AppError,
+};
+
+pub type DynTagRepository = Arc<dyn TagRepositoryTrait + Send + Sync>;
+pub type DynTagService = Arc<dyn TagServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TagRepositoryTrait {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+    ) -> Result<(Vec<Tag>, i64), AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<Tag>, AppError>;
+    async fn find_for_posts(&self, post_ids: &[i32]) -> Result<Vec<PostTag>, AppError>;
+    async fn tag_cloud(&self, limit: i32) -> Result<Vec<TagCount>, AppError>;
+    async fn create(&self, input: &CreateTagRequest) -> Result<Tag, AppError>;
+    async fn update(&self, input: &UpdateTagRequest) -> Result<Tag, AppError>;
+    async fn delete(&self, id: i32) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait TagServiceTrait {
+    async fn get_tags(
+        &self,
+        req: FindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, ErrorResponse>;
+    async fn get_tag(&self, id: i32) -> Result<Option<ApiResponse<TagResponse>>, ErrorResponse>;
+    async fn get_tag_cloud(
+        &self,
+        req: TagCloudRequest,
+    ) -> Result<ApiResponse<Vec<TagCountResponse>>, ErrorResponse>;
+    async fn create_tag(
+        &self,
+        input: &CreateTagRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse>;
+    async fn update_tag(
+        &self,
+        input: &UpdateTagRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<TagResponse>>, ErrorResponse>;
+    async fn delete_tag(
+        &self,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+}
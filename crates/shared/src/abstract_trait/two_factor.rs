@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::{
+    config::Claims,
+    domain::{
+        ApiResponse, AuditContext, ErrorResponse, RecoveryCodesResponse, TwoFactorCodeRequest,
+        TwoFactorEnrollmentResponse,
+    },
+    model::two_factor::TotpSecret,
+    utils::AppError,
+};
+
+pub type DynTwoFactorRepository = Arc<dyn TwoFactorRepositoryTrait + Send + Sync>;
+pub type DynTwoFactorService = Arc<dyn TwoFactorServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TwoFactorRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<TotpSecret>, AppError>;
+    async fn save_pending(&self, user_id: i32, secret: &str) -> Result<TotpSecret, AppError>;
+    async fn confirm(&self, user_id: i32, step: i64) -> Result<bool, AppError>;
+    async fn record_step(&self, user_id: i32, step: i64) -> Result<bool, AppError>;
+    async fn delete(&self, user_id: i32) -> Result<(), AppError>;
+    async fn replace_recovery_codes(
+        &self,
+        user_id: i32,
+        code_hashes: &[String],
+    ) -> Result<(), AppError>;
+    async fn consume_recovery_code(&self, user_id: i32, code_hash: &str) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+pub trait TwoFactorServiceTrait {
+    async fn enroll(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<TwoFactorEnrollmentResponse>, ErrorResponse>;
+    async fn confirm(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse>;
+    async fn disable(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn regenerate_recovery_codes(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse>;
+    async fn is_enabled(&self, user_id: i32) -> Result<bool, AppError>;
+    async fn verify_code(&self, user_id: i32, code: &str) -> Result<bool, AppError>;
+}
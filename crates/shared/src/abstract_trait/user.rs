@@ -2,12 +2,14 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::{
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreateUserRequest, ErrorResponse, FindAllUserRequest,
-        UpdateUserRequest, UserResponse,
+        ApiResponse, ApiResponsePagination, AuditContext, ChangePasswordRequest, ClientInfo,
+        ConfirmEmailChangeRequest, CreateUserRequest, ErrorResponse, FindAllUserRequest,
+        RequestEmailChangeRequest, UpdateProfileRequest, UpdateUserRequest, UserResponse,
     },
     model::user::User,
-    utils::AppError,
+    utils::{AppError, PageCursor},
 };
 
 pub type DynUserRepository = Arc<dyn UserRepositoryTrait + Send + Sync>;
@@ -21,11 +23,21 @@ pub trait UserRepositoryTrait {
         page_size: i32,
         search: Option<String>,
     ) -> Result<(Vec<User>, i64), AppError>;
+    async fn find_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        search: Option<String>,
+    ) -> Result<(Vec<User>, Option<PageCursor>), AppError>;
     async fn find_by_email_exists(&self, email: &str) -> Result<bool, AppError>;
     async fn create_user(&self, input: &CreateUserRequest) -> Result<User, AppError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, AppError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<User>, AppError>;
     async fn update_user(&self, input: &UpdateUserRequest) -> Result<User, AppError>;
+    async fn update_password(&self, id: i32, password: &str) -> Result<(), AppError>;
+    async fn mark_email_verified(&self, id: i32) -> Result<(), AppError>;
+    async fn set_pending_email(&self, id: i32, email: &str) -> Result<(), AppError>;
+    async fn confirm_email_change(&self, id: i32) -> Result<Option<User>, AppError>;
     async fn delete_user(&self, email: &str) -> Result<(), AppError>;
 }
 
@@ -38,12 +50,41 @@ pub trait UserServiceTrait {
     async fn create_user(
         &self,
         input: &CreateUserRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
     async fn find_by_id(&self, id: i32)
     -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
     async fn update_user(
         &self,
         input: &UpdateUserRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
-    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn delete_user(
+        &self,
+        email: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn update_profile(
+        &self,
+        actor: &Claims,
+        input: &UpdateProfileRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
+    async fn change_password(
+        &self,
+        actor: &Claims,
+        input: &ChangePasswordRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn request_email_change(
+        &self,
+        actor: &Claims,
+        input: &RequestEmailChangeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn confirm_email_change(
+        &self,
+        input: &ConfirmEmailChangeRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
 }
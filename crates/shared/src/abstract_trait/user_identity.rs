@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{model::user_identity::UserIdentity, utils::AppError};
+
+pub type DynUserIdentityRepository = Arc<dyn UserIdentityRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserIdentityRepositoryTrait {
+    async fn find(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>, AppError>;
+    async fn create(
+        &self,
+        user_id: i32,
+        issuer: &str,
+        subject: &str,
+        email: &str,
+    ) -> Result<UserIdentity, AppError>;
+    async fn record_login(&self, id: i32, email: &str) -> Result<(), AppError>;
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    model::user_token::{TokenPurpose, UserToken},
+    utils::AppError,
+};
+
+pub type DynUserTokenRepository = Arc<dyn UserTokenRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserTokenRepositoryTrait {
+    async fn create(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<UserToken, AppError>;
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> Result<Option<UserToken>, AppError>;
+    async fn invalidate_for_user(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+    ) -> Result<(), AppError>;
+}
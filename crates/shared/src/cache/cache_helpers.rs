@@ -1,8 +1,22 @@
-use redis::{Commands, Connection};
+use async_trait::async_trait;
+use redis::{AsyncConnectionConfig, Commands, Connection, aio::MultiplexedConnection};
 use serde::{Serialize, de::DeserializeOwned};
 use std::{sync::Arc, time::Duration};
 use tracing::{debug, error};
 
+use crate::{abstract_trait::KeyValueStoreTrait, utils::AppError};
+
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+const INCREMENT_WITH_EXPIRY: &str = r#"
+local value = redis.call('INCR', KEYS[1])
+if value == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+return value
+"#;
+
 #[derive(Clone)]
 pub struct CacheStore {
     pub redis: Arc<redis::Client>,
@@ -90,4 +104,130 @@ impl CacheStore {
             }
         }
     }
+
+    /// Deletes every key matching a Redis glob `pattern`.
+    pub fn delete_matching(&self, pattern: &str) {
+        let Some(mut conn) = self.get_conn() else {
+            return;
+        };
+
+        let keys: Vec<String> = match conn.scan_match(pattern) {
+            Ok(iter) => iter.collect(),
+            Err(e) => {
+                error!("Failed to scan keys matching {}: {:?}", pattern, e);
+                return;
+            }
+        };
+
+        if !keys.is_empty() {
+            if let Err(e) = redis::cmd("DEL").arg(&keys).query::<()>(&mut conn) {
+                error!("Failed to delete keys matching {}: {:?}", pattern, e);
+            }
+        }
+    }
+}
+
+impl CacheStore {
+    async fn get_async_conn(&self) -> Result<MultiplexedConnection, AppError> {
+        let config = AsyncConnectionConfig::new()
+            .set_connection_timeout(CONNECTION_TIMEOUT)
+            .set_response_timeout(RESPONSE_TIMEOUT);
+
+        self.redis
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+            .map_err(|e| {
+                error!("Failed to get Redis connection: {:?}", e);
+                AppError::CacheError(e.to_string())
+            })
+    }
+
+    async fn query<T: redis::FromRedisValue>(&self, cmd: &redis::Cmd) -> Result<T, AppError> {
+        let mut conn = self.get_async_conn().await?;
+
+        cmd.query_async(&mut conn)
+            .await
+            .map_err(|e| AppError::CacheError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl KeyValueStoreTrait for CacheStore {
+    async fn set_flag(&self, key: &str, expiration: Duration) -> Result<(), AppError> {
+        self.query(
+            redis::cmd("SET")
+                .arg(key)
+                .arg(1)
+                .arg("EX")
+                .arg(expiration.as_secs().max(1)),
+        )
+        .await
+        .inspect_err(|e| error!("Failed to set cache key {}: {}", key, e))
+    }
+
+    async fn set_if_absent(&self, key: &str, expiration: Duration) -> Result<bool, AppError> {
+        self.query::<Option<String>>(
+            redis::cmd("SET")
+                .arg(key)
+                .arg(1)
+                .arg("NX")
+                .arg("EX")
+                .arg(expiration.as_secs().max(1)),
+        )
+        .await
+        .map(|reply| reply.is_some())
+        .inspect_err(|e| error!("Failed to set cache key {}: {}", key, e))
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, AppError> {
+        self.query(redis::cmd("EXISTS").arg(key))
+            .await
+            .inspect_err(|e| error!("Redis exists error for key {}: {}", key, e))
+    }
+
+    async fn get_counter(&self, key: &str) -> Result<i64, AppError> {
+        self.query::<Option<i64>>(redis::cmd("GET").arg(key))
+            .await
+            .map(Option::unwrap_or_default)
+            .inspect_err(|e| error!("Redis get error for key {}: {}", key, e))
+    }
+
+    async fn increment(&self, key: &str) -> Result<i64, AppError> {
+        self.query(redis::cmd("INCR").arg(key))
+            .await
+            .inspect_err(|e| error!("Failed to increment key {}: {}", key, e))
+    }
+
+    /// Runs as one script so a crash between the two steps cannot leave a
+    /// counter that never expires.
+    async fn increment_with_expiry(
+        &self,
+        key: &str,
+        expiration: Duration,
+    ) -> Result<i64, AppError> {
+        self.query(
+            redis::cmd("EVAL")
+                .arg(INCREMENT_WITH_EXPIRY)
+                .arg(1)
+                .arg(key)
+                .arg(expiration.as_secs().max(1)),
+        )
+        .await
+        .inspect_err(|e| error!("Failed to increment key {}: {}", key, e))
+    }
+
+    async fn time_to_live(&self, key: &str) -> Result<Option<Duration>, AppError> {
+        let secs: i64 = self
+            .query(redis::cmd("TTL").arg(key))
+            .await
+            .inspect_err(|e| error!("Redis ttl error for key {}: {}", key, e))?;
+
+        Ok((secs > 0).then(|| Duration::from_secs(secs as u64)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        self.query(redis::cmd("DEL").arg(key))
+            .await
+            .inspect_err(|e| error!("Failed to delete key {}: {}", key, e))
+    }
 }
@@ -0,0 +1,195 @@
+use prometheus_client::metrics::{counter::Counter, family::Family};
+use prometheus_client::registry::Registry;
+use prometheus_client_derive_encode::{EncodeLabelSet, EncodeLabelValue};
+use std::time::Duration;
+use tracing::warn;
+
+use crate::abstract_trait::DynKeyValueStore;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum LockoutScope {
+    Email,
+    Ip,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct LockoutLabels {
+    pub scope: LockoutScope,
+}
+
+#[derive(Clone, Debug)]
+pub struct LoginAttemptPolicy {
+    pub max_failures_per_email: i64,
+    pub max_failures_per_ip: i64,
+    pub failure_window: Duration,
+    pub base_lockout: Duration,
+    pub max_lockout: Duration,
+}
+
+impl Default for LoginAttemptPolicy {
+    fn default() -> Self {
+        Self {
+            max_failures_per_email: 5,
+            max_failures_per_ip: 20,
+            failure_window: Duration::from_secs(15 * 60),
+            base_lockout: Duration::from_secs(30),
+            max_lockout: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LoginAttemptStore {
+    store: DynKeyValueStore,
+    policy: LoginAttemptPolicy,
+    failures: Family<LockoutLabels, Counter>,
+    lockouts: Family<LockoutLabels, Counter>,
+}
+
+impl std::fmt::Debug for LoginAttemptStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginAttemptStore")
+            .field("store", &"KeyValueStore")
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl LoginAttemptStore {
+    pub fn new(store: DynKeyValueStore, policy: LoginAttemptPolicy) -> Self {
+        Self {
+            store,
+            policy,
+            failures: Family::default(),
+            lockouts: Family::default(),
+        }
+    }
+
+    pub fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "auth_login_failures",
+            "Total number of failed login attempts",
+            self.failures.clone(),
+        );
+        registry.register(
+            "auth_login_lockouts",
+            "Total number of temporary login lockouts",
+            self.lockouts.clone(),
+        );
+    }
+
+    fn failures_key(scope: &LockoutScope, subject: &str) -> String {
+        match scope {
+            LockoutScope::Email => format!("auth:login_failures:email={subject}"),
+            LockoutScope::Ip => format!("auth:login_failures:ip={subject}"),
+        }
+    }
+
+    fn lockout_key(scope: &LockoutScope, subject: &str) -> String {
+        match scope {
+            LockoutScope::Email => format!("auth:lockout:email={subject}"),
+            LockoutScope::Ip => format!("auth:lockout:ip={subject}"),
+        }
+    }
+
+    /// Without this, varying the case or padding of an address would get a
+    /// fresh failure budget for the same account.
+    fn normalize_email(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    fn subjects(email: &str, ip: Option<&str>) -> Vec<(LockoutScope, String)> {
+        let mut subjects = vec![(LockoutScope::Email, Self::normalize_email(email))];
+
+        if let Some(ip) = ip {
+            subjects.push((LockoutScope::Ip, ip.to_string()));
+        }
+
+        subjects
+    }
+
+    /// The longest lockout currently applying to the email or IP. Lockouts
+    /// are a brake on guessing, not an access check, so a cache outage lets
+    /// logins through rather than locking everyone out.
+    pub async fn locked_for(&self, email: &str, ip: Option<&str>) -> Option<Duration> {
+        let mut locked_for = None;
+
+        for (scope, subject) in Self::subjects(email, ip) {
+            let ttl = self
+                .store
+                .time_to_live(&Self::lockout_key(&scope, &subject))
+                .await
+                .ok()
+                .flatten();
+
+            locked_for = locked_for.max(ttl);
+        }
+
+        locked_for
+    }
+
+    pub async fn record_failure(&self, email: &str, ip: Option<&str>) {
+        for (scope, subject) in Self::subjects(email, ip) {
+            self.failures
+                .get_or_create(&LockoutLabels {
+                    scope: scope.clone(),
+                })
+                .inc();
+
+            let Ok(count) = self
+                .store
+                .increment_with_expiry(
+                    &Self::failures_key(&scope, &subject),
+                    self.policy.failure_window,
+                )
+                .await
+            else {
+                continue;
+            };
+
+            let limit = match scope {
+                LockoutScope::Email => self.policy.max_failures_per_email,
+                LockoutScope::Ip => self.policy.max_failures_per_ip,
+            };
+
+            if count < limit {
+                continue;
+            }
+
+            let exponent = (count - limit).min(16) as u32;
+            let lockout = self
+                .policy
+                .base_lockout
+                .saturating_mul(2u32.saturating_pow(exponent))
+                .min(self.policy.max_lockout);
+
+            warn!(
+                "Locking out {:?} {} for {}s after {} failed logins",
+                scope,
+                subject,
+                lockout.as_secs(),
+                count
+            );
+
+            if self
+                .store
+                .set_flag(&Self::lockout_key(&scope, &subject), lockout)
+                .await
+                .is_err()
+            {
+                continue;
+            }
+            self.lockouts.get_or_create(&LockoutLabels { scope }).inc();
+        }
+    }
+
+    pub async fn record_success(&self, email: &str) {
+        let _ = self
+            .store
+            .delete(&Self::failures_key(
+                &LockoutScope::Email,
+                &Self::normalize_email(email),
+            ))
+            .await;
+    }
+}
@@ -1,2 +1,7 @@
 mod cache_helpers;
+mod login_attempts;
+mod token_revocation;
+
 pub use cache_helpers::CacheStore;
+pub use login_attempts::{LoginAttemptPolicy, LoginAttemptStore};
+pub use token_revocation::TokenRevocationStore;
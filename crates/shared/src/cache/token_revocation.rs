@@ -0,0 +1,110 @@
+use chrono::Utc;
+use std::time::Duration;
+use tracing::info;
+
+use crate::{
+    abstract_trait::DynKeyValueStore,
+    config::Claims,
+    utils::{AppError, hash_token},
+};
+
+#[derive(Clone)]
+pub struct TokenRevocationStore {
+    store: DynKeyValueStore,
+}
+
+impl std::fmt::Debug for TokenRevocationStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenRevocationStore")
+            .field("store", &"DynKeyValueStore")
+            .finish()
+    }
+}
+
+impl TokenRevocationStore {
+    pub fn new(store: DynKeyValueStore) -> Self {
+        Self { store }
+    }
+
+    fn denylist_key(jti: &str) -> String {
+        format!("auth:revoked:jti={jti}")
+    }
+
+    fn session_key(session_id: &str) -> String {
+        format!("auth:revoked:sid={session_id}")
+    }
+
+    fn generation_key(user_id: i64) -> String {
+        format!("auth:generation:user={user_id}")
+    }
+
+    pub async fn revoke(&self, claims: &Claims) -> Result<(), AppError> {
+        let remaining = (claims.exp as i64 - Utc::now().timestamp()).max(1) as u64;
+
+        info!("Revoking token {} for user {}", claims.jti, claims.user_id);
+
+        self.store
+            .set_flag(
+                &Self::denylist_key(&claims.jti),
+                Duration::from_secs(remaining),
+            )
+            .await
+    }
+
+    /// Rejects every access token issued for the session. The marker only has
+    /// to outlive the longest-lived access token; refreshing is blocked by the
+    /// session row itself.
+    pub async fn revoke_session(&self, session_id: &str, ttl: Duration) -> Result<(), AppError> {
+        info!("Revoking session {session_id}");
+
+        self.store
+            .set_flag(&Self::session_key(session_id), ttl)
+            .await
+    }
+
+    /// Records that an identity provider's ID token has been exchanged for a
+    /// session, until the token itself expires. Returns `false` when it was
+    /// already used, so a captured ID token cannot be replayed.
+    pub async fn consume_id_token(
+        &self,
+        issuer: &str,
+        token_id: &str,
+        expires_at: i64,
+    ) -> Result<bool, AppError> {
+        let remaining = (expires_at - Utc::now().timestamp()).max(1) as u64;
+        let key = format!(
+            "auth:oidc:consumed={}",
+            hash_token(&format!("{issuer}|{token_id}"))
+        );
+
+        self.store
+            .set_if_absent(&key, Duration::from_secs(remaining))
+            .await
+    }
+
+    pub async fn current_generation(&self, user_id: i64) -> Result<i64, AppError> {
+        self.store.get_counter(&Self::generation_key(user_id)).await
+    }
+
+    pub async fn bump_generation(&self, user_id: i64) -> Result<i64, AppError> {
+        info!("Revoking all tokens for user {user_id}");
+
+        self.store.increment(&Self::generation_key(user_id)).await
+    }
+
+    /// Fails closed: when the store cannot be reached the caller gets an
+    /// error, never a "not revoked".
+    pub async fn is_revoked(&self, claims: &Claims) -> Result<bool, AppError> {
+        if self.store.exists(&Self::denylist_key(&claims.jti)).await? {
+            return Ok(true);
+        }
+
+        if let Some(sid) = claims.sid.as_deref() {
+            if self.store.exists(&Self::session_key(sid)).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(claims.generation < self.current_generation(claims.user_id).await?)
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::{Result, anyhow};
+use axum_extra::extract::cookie::SameSite;
+
+/// Opt-in mode where the gateway hands browsers their access token in an
+/// HttpOnly cookie instead of leaving them to store the JSON response.
+#[derive(Clone, Debug)]
+pub struct AuthCookieConfig {
+    pub enabled: bool,
+    pub secure: bool,
+    pub same_site: SameSite,
+    pub domain: Option<String>,
+}
+
+impl Default for AuthCookieConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            secure: true,
+            same_site: SameSite::Strict,
+            domain: None,
+        }
+    }
+}
+
+impl AuthCookieConfig {
+    /// Unset variables keep their defaults; a value that is set but cannot
+    /// be read is an error, so a typo cannot quietly switch cookies off or
+    /// loosen their `SameSite` policy.
+    pub fn from_env() -> Result<Self> {
+        let default = Self::default();
+
+        Ok(Self {
+            enabled: env_bool("AUTH_COOKIE_ENABLED", default.enabled)?,
+            secure: env_bool("AUTH_COOKIE_SECURE", default.secure)?,
+            same_site: match std::env::var("AUTH_COOKIE_SAME_SITE") {
+                Ok(value) => match value.to_ascii_lowercase().as_str() {
+                    "strict" => SameSite::Strict,
+                    "lax" => SameSite::Lax,
+                    "none" => SameSite::None,
+                    _ => {
+                        return Err(anyhow!(
+                            "Invalid value for AUTH_COOKIE_SAME_SITE ('{value}'): expected strict, lax or none"
+                        ));
+                    }
+                },
+                Err(_) => default.same_site,
+            },
+            domain: std::env::var("AUTH_COOKIE_DOMAIN").ok(),
+        })
+    }
+}
+
+fn env_bool(key: &str, default: bool) -> Result<bool> {
+    match std::env::var(key) {
+        Ok(value) => value
+            .parse()
+            .map_err(|e| anyhow!("Invalid value for {key} ('{value}'): {e}")),
+        Err(_) => Ok(default),
+    }
+}
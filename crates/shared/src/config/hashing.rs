@@ -1,25 +1,207 @@
-use crate::utils::AppError;
-use bcrypt::{BcryptError, hash, verify};
+use crate::{abstract_trait::HashingTrait, utils::AppError};
+use anyhow::{Result, anyhow};
+use argon2::{
+    Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version,
+    password_hash::{SaltString, rand_core::OsRng},
+};
+use async_trait::async_trait;
+use std::{fmt::Display, str::FromStr};
+use tokio::task;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Argon2id,
+    Bcrypt,
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "argon2id" => Ok(Self::Argon2id),
+            "bcrypt" => Ok(Self::Bcrypt),
+            other => Err(AppError::InternalError(format!(
+                "Unknown password hash algorithm: {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HashingConfig {
+    pub algorithm: HashAlgorithm,
+    pub bcrypt_cost: u32,
+    pub argon2_memory_kib: u32,
+    pub argon2_iterations: u32,
+    pub argon2_parallelism: u32,
+}
+
+impl Default for HashingConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: HashAlgorithm::Argon2id,
+            bcrypt_cost: 12,
+            argon2_memory_kib: 19 * 1024,
+            argon2_iterations: 2,
+            argon2_parallelism: 1,
+        }
+    }
+}
+
+impl HashingConfig {
+    pub fn from_env() -> Result<Self> {
+        let default = Self::default();
+
+        let config = Self {
+            algorithm: env_or("PASSWORD_HASH_ALGORITHM", default.algorithm)?,
+            bcrypt_cost: env_or("BCRYPT_COST", default.bcrypt_cost)?,
+            argon2_memory_kib: env_or("ARGON2_MEMORY_KIB", default.argon2_memory_kib)?,
+            argon2_iterations: env_or("ARGON2_ITERATIONS", default.argon2_iterations)?,
+            argon2_parallelism: env_or("ARGON2_PARALLELISM", default.argon2_parallelism)?,
+        };
+
+        if !(4..=31).contains(&config.bcrypt_cost) {
+            return Err(anyhow!(
+                "BCRYPT_COST must be between 4 and 31, got {}",
+                config.bcrypt_cost
+            ));
+        }
+
+        Params::new(
+            config.argon2_memory_kib,
+            config.argon2_iterations,
+            config.argon2_parallelism,
+            None,
+        )
+        .map_err(|e| anyhow!("Invalid Argon2 parameters: {e}"))?;
+
+        Ok(config)
+    }
+}
+
+/// Reads `key`, falling back to `default` only when it is unset. A value
+/// that is set but does not parse is a configuration error.
+fn env_or<T>(key: &str, default: T) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match std::env::var(key) {
+        Ok(value) => value
+            .parse()
+            .map_err(|e| anyhow!("Invalid value for {key} ('{value}'): {e}")),
+        Err(_) => Ok(default),
+    }
+}
 
 #[derive(Clone, Debug)]
-pub struct Hashing;
+pub struct Hashing {
+    config: HashingConfig,
+}
 
 impl Hashing {
-    pub async fn hash_password(&self, password: &str) -> anyhow::Result<String, BcryptError> {
-        hash(password, 4)
+    pub fn new(config: HashingConfig) -> Self {
+        Self { config }
+    }
+
+    fn argon2(config: &HashingConfig) -> Result<Argon2<'static>, AppError> {
+        let params = Params::new(
+            config.argon2_memory_kib,
+            config.argon2_iterations,
+            config.argon2_parallelism,
+            None,
+        )
+        .map_err(|e| AppError::HashingError(e.to_string()))?;
+
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    fn hash_blocking(config: &HashingConfig, password: &str) -> Result<String, AppError> {
+        match config.algorithm {
+            HashAlgorithm::Argon2id => {
+                let salt = SaltString::generate(&mut OsRng);
+
+                Self::argon2(config)?
+                    .hash_password(password.as_bytes(), &salt)
+                    .map(|hash| hash.to_string())
+                    .map_err(|e| AppError::HashingError(e.to_string()))
+            }
+            HashAlgorithm::Bcrypt => bcrypt::hash(password, config.bcrypt_cost)
+                .map_err(|e| AppError::BcryptError(e.to_string())),
+        }
+    }
+
+    fn verify_blocking(hashed_password: &str, password: &str) -> Result<(), AppError> {
+        if hashed_password.starts_with("$argon2") {
+            let parsed = PasswordHash::new(hashed_password)
+                .map_err(|e| AppError::HashingError(e.to_string()))?;
+
+            return Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .map_err(|_| AppError::InvalidCredentials);
+        }
+
+        match bcrypt::verify(password, hashed_password) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(AppError::InvalidCredentials),
+            Err(e) => Err(AppError::BcryptError(e.to_string())),
+        }
     }
 
-    pub async fn compare_password(
+    fn bcrypt_cost(hashed_password: &str) -> Option<u32> {
+        hashed_password.split('$').nth(2)?.parse().ok()
+    }
+}
+
+#[async_trait]
+impl HashingTrait for Hashing {
+    async fn hash_password(&self, password: &str) -> Result<String, AppError> {
+        let config = self.config.clone();
+        let password = password.to_string();
+
+        task::spawn_blocking(move || Self::hash_blocking(&config, &password))
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+    }
+
+    async fn compare_password(
         &self,
         hashed_password: &str,
         password: &str,
-    ) -> anyhow::Result<(), AppError> {
-        match verify(password, hashed_password) {
-            Ok(true) => Ok(()),
-            Ok(false) => Err(AppError::HashingError(BcryptError::from(
-                std::io::Error::other("Passwords do not match."),
-            ))),
-            Err(e) => Err(AppError::BcryptError(e.to_string())),
+    ) -> Result<(), AppError> {
+        let hashed_password = hashed_password.to_string();
+        let password = password.to_string();
+
+        task::spawn_blocking(move || Self::verify_blocking(&hashed_password, &password))
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+    }
+
+    fn needs_rehash(&self, hashed_password: &str) -> bool {
+        match self.config.algorithm {
+            HashAlgorithm::Argon2id => {
+                let Ok(parsed) = PasswordHash::new(hashed_password) else {
+                    return true;
+                };
+
+                if parsed.algorithm != argon2::ARGON2ID_IDENT {
+                    return true;
+                }
+
+                match Params::try_from(&parsed) {
+                    Ok(params) => {
+                        params.m_cost() != self.config.argon2_memory_kib
+                            || params.t_cost() != self.config.argon2_iterations
+                            || params.p_cost() != self.config.argon2_parallelism
+                    }
+                    Err(_) => true,
+                }
+            }
+            HashAlgorithm::Bcrypt => {
+                !hashed_password.starts_with("$2")
+                    || Self::bcrypt_cost(hashed_password) != Some(self.config.bcrypt_cost)
+            }
         }
     }
 }
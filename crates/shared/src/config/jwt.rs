@@ -1,62 +1,349 @@
-use crate::utils::AppError;
+use crate::{model::role::Permission, utils::AppError};
 use anyhow::Result;
 use chrono::{Duration, Utc};
 use jsonwebtoken::{
-    DecodingKey, EncodingKey, Header, Validation, decode, encode, errors::ErrorKind as JwtError,
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    errors::ErrorKind as JwtError,
+    jwk::{Jwk, JwkSet},
 };
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// How the holder of an access token authenticated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    /// A password, two-factor or OIDC login with a session behind it.
+    #[default]
+    Session,
+    /// Exchanged for an API key: scoped, and not tied to any session.
+    ApiKey,
+}
+
+impl TokenKind {
+    fn is_session(&self) -> bool {
+        *self == TokenKind::Session
+    }
+}
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub user_id: i64,
+    pub role: String,
+    pub permissions: Vec<String>,
+    pub jti: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    #[serde(default, rename = "typ", skip_serializing_if = "TokenKind::is_session")]
+    pub kind: TokenKind,
+    pub generation: i64,
     pub exp: usize,
     pub iat: usize,
 }
 
 impl Claims {
-    pub fn new(user_id: i64, exp: usize, iat: usize) -> Self {
-        Claims { user_id, exp, iat }
+    pub fn new(
+        user_id: i64,
+        role: String,
+        permissions: Vec<String>,
+        generation: i64,
+        exp: usize,
+        iat: usize,
+    ) -> Self {
+        Claims {
+            user_id,
+            role,
+            permissions,
+            jti: Uuid::new_v4().to_string(),
+            sid: None,
+            kind: TokenKind::Session,
+            generation,
+            exp,
+            iat,
+        }
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.iter().any(|p| p == permission.as_str())
+    }
+
+    /// Whether the token comes from a login rather than an API key.
+    pub fn is_interactive(&self) -> bool {
+        self.kind == TokenKind::Session
+    }
+
+    /// Account self-service needs a real login: an API key must not be able
+    /// to mint or revoke other credentials.
+    pub fn require_interactive(&self) -> Result<(), AppError> {
+        if self.is_interactive() {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(
+                "This action requires signing in; API keys cannot manage the account".to_string(),
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    #[default]
+    Hs256,
+    Rs256,
+    EdDsa,
+}
+
+impl JwtAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JwtAlgorithm::Hs256 => "HS256",
+            JwtAlgorithm::Rs256 => "RS256",
+            JwtAlgorithm::EdDsa => "EdDSA",
+        }
+    }
+
+    pub fn is_asymmetric(&self) -> bool {
+        !matches!(self, JwtAlgorithm::Hs256)
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        match self {
+            JwtAlgorithm::Hs256 => Algorithm::HS256,
+            JwtAlgorithm::Rs256 => Algorithm::RS256,
+            JwtAlgorithm::EdDsa => Algorithm::EdDSA,
+        }
+    }
+}
+
+impl FromStr for JwtAlgorithm {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "hs256" => Ok(Self::Hs256),
+            "rs256" => Ok(Self::Rs256),
+            "eddsa" => Ok(Self::EdDsa),
+            other => Err(AppError::InternalError(format!(
+                "Unknown JWT algorithm: {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SigningKey {
+    pub kid: String,
+    pub algorithm: JwtAlgorithm,
+    pub key: EncodingKey,
+}
+
+#[derive(Default)]
+struct KeyRing {
+    signing: Option<SigningKey>,
+    verification: HashMap<String, (Algorithm, DecodingKey)>,
+    published: Vec<Jwk>,
+}
+
+impl std::fmt::Debug for KeyRing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyRing")
+            .field("signing", &self.signing.as_ref().map(|key| &key.kid))
+            .field("verification", &self.verification.keys())
+            .finish()
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct JwtConfig {
     pub jwt_secret: String,
+    pub algorithm: JwtAlgorithm,
+    pub access_token_ttl: Duration,
+    pub refresh_token_ttl: Duration,
+    keys: Arc<RwLock<KeyRing>>,
 }
 
 impl JwtConfig {
     pub fn new(jwt_secret: &str) -> Self {
         JwtConfig {
             jwt_secret: jwt_secret.to_string(),
+            algorithm: JwtAlgorithm::Hs256,
+            access_token_ttl: Duration::minutes(60),
+            refresh_token_ttl: Duration::days(30),
+            keys: Arc::new(RwLock::new(KeyRing::default())),
+        }
+    }
+
+    /// An unknown `JWT_ALGORITHM` is an error rather than a fallback to
+    /// HS256, which would quietly sign with the shared secret instead of the
+    /// key pairs the deployment asked for.
+    pub fn from_env(jwt_secret: &str) -> Result<Self, AppError> {
+        let algorithm = match std::env::var("JWT_ALGORITHM") {
+            Ok(value) => value.parse()?,
+            Err(_) => JwtAlgorithm::default(),
+        };
+
+        Ok(JwtConfig {
+            algorithm,
+            ..Self::new(jwt_secret)
+        })
+    }
+
+    pub fn install_keys(
+        &self,
+        signing: Option<SigningKey>,
+        jwks: Vec<Jwk>,
+    ) -> Result<(), AppError> {
+        let mut verification = HashMap::new();
+
+        for jwk in &jwks {
+            let (Some(kid), Some(key_algorithm)) = (&jwk.common.key_id, jwk.common.key_algorithm)
+            else {
+                continue;
+            };
+
+            let algorithm = Algorithm::from_str(&key_algorithm.to_string())
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+            let key =
+                DecodingKey::from_jwk(jwk).map_err(|e| AppError::InternalError(e.to_string()))?;
+
+            verification.insert(kid.clone(), (algorithm, key));
         }
+
+        let mut ring = self
+            .keys
+            .write()
+            .map_err(|_| AppError::InternalError("JWT key ring poisoned".to_string()))?;
+
+        *ring = KeyRing {
+            signing,
+            verification,
+            published: jwks,
+        };
+
+        Ok(())
+    }
+
+    pub fn jwks(&self) -> JwkSet {
+        let keys = self
+            .keys
+            .read()
+            .map(|ring| ring.published.clone())
+            .unwrap_or_default();
+
+        JwkSet { keys }
     }
 
-    pub fn generate_token(&self, user_id: i64) -> Result<String, AppError> {
+    pub fn generate_token(
+        &self,
+        user_id: i64,
+        role: &str,
+        permissions: Vec<String>,
+        session_id: Option<&str>,
+        generation: i64,
+    ) -> Result<String, AppError> {
         let now = Utc::now();
         let iat = now.timestamp() as usize;
-        let exp = (now + Duration::minutes(60)).timestamp() as usize;
+        let exp = (now + self.access_token_ttl).timestamp() as usize;
+
+        let claims = Claims {
+            sid: session_id.map(str::to_string),
+            ..Claims::new(user_id, role.to_string(), permissions, generation, exp, iat)
+        };
+
+        self.sign(&claims)
+    }
+
+    /// An access token for an API key exchange, marked so it is refused on
+    /// account self-service endpoints.
+    pub fn generate_api_key_token(
+        &self,
+        user_id: i64,
+        role: &str,
+        permissions: Vec<String>,
+        generation: i64,
+    ) -> Result<String, AppError> {
+        let now = Utc::now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + self.access_token_ttl).timestamp() as usize;
+
+        let claims = Claims {
+            kind: TokenKind::ApiKey,
+            ..Claims::new(user_id, role.to_string(), permissions, generation, exp, iat)
+        };
+
+        self.sign(&claims)
+    }
+
+    fn sign(&self, claims: &Claims) -> Result<String, AppError> {
+        let (header, key) = if self.algorithm.is_asymmetric() {
+            let signing = self
+                .keys
+                .read()
+                .ok()
+                .and_then(|ring| ring.signing.clone())
+                .ok_or_else(|| AppError::InternalError("No active JWT signing key".to_string()))?;
 
-        let claims = Claims::new(user_id, exp, iat);
+            let mut header = Header::new(signing.algorithm.algorithm());
+            header.kid = Some(signing.kid);
 
-        match encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
-        ) {
+            (header, signing.key)
+        } else {
+            (
+                Header::default(),
+                EncodingKey::from_secret(self.jwt_secret.as_ref()),
+            )
+        };
+
+        match encode(&header, claims, &key) {
             Ok(token) => Ok(token),
             Err(err) => Err(AppError::TokenGenerationError(err)),
         }
     }
 
     pub fn verify_token(&self, token: &str) -> Result<i64, AppError> {
-        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
+        self.decode_token(token).map(|claims| claims.user_id)
+    }
+
+    pub fn decode_token(&self, token: &str) -> Result<Claims, AppError> {
+        let (decoding_key, validation) = if self.algorithm.is_asymmetric() {
+            let kid = decode_header(token)
+                .ok()
+                .and_then(|header| header.kid)
+                .ok_or_else(|| {
+                    debug!("Rejected token without a key id");
+                    AppError::TokenValidationError
+                })?;
+
+            let ring = self
+                .keys
+                .read()
+                .map_err(|_| AppError::InternalError("JWT key ring poisoned".to_string()))?;
+
+            let (algorithm, key) = ring.verification.get(&kid).cloned().ok_or_else(|| {
+                debug!("Rejected token signed with unknown key {kid}");
+                AppError::TokenValidationError
+            })?;
 
-        match decode::<Claims>(token, &decoding_key, &Validation::default()) {
+            (key, Validation::new(algorithm))
+        } else {
+            (
+                DecodingKey::from_secret(self.jwt_secret.as_ref()),
+                Validation::default(),
+            )
+        };
+
+        match decode::<Claims>(token, &decoding_key, &validation) {
             Ok(token_data) => {
                 let current_time = Utc::now().timestamp() as usize;
 
                 if token_data.claims.exp >= current_time {
-                    Ok(token_data.claims.user_id)
+                    Ok(token_data.claims)
                 } else {
                     Err(AppError::TokenExpiredError)
                 }
@@ -65,10 +352,52 @@ impl JwtConfig {
                 if let JwtError::ExpiredSignature = err.kind() {
                     Err(AppError::TokenExpiredError)
                 } else {
-                    eprintln!("Error decoding token: {err}");
+                    warn!("Error decoding token: {err}");
                     Err(AppError::TokenValidationError)
                 }
             }
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct JwtKeyPolicy {
+    pub rotation_interval: Duration,
+    pub publish_delay: Duration,
+    pub check_interval: std::time::Duration,
+}
+
+impl Default for JwtKeyPolicy {
+    fn default() -> Self {
+        Self {
+            rotation_interval: Duration::days(30),
+            publish_delay: Duration::minutes(10),
+            check_interval: std::time::Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+impl JwtKeyPolicy {
+    pub fn from_env() -> Result<Self, AppError> {
+        let default = Self::default();
+
+        Ok(Self {
+            rotation_interval: env_number("JWT_KEY_ROTATION_DAYS")?
+                .map(Duration::days)
+                .unwrap_or(default.rotation_interval),
+            publish_delay: env_number("JWT_KEY_PUBLISH_DELAY_SECS")?
+                .map(Duration::seconds)
+                .unwrap_or(default.publish_delay),
+            ..default
+        })
+    }
+}
+
+fn env_number(key: &str) -> Result<Option<i64>, AppError> {
+    match std::env::var(key) {
+        Ok(value) => value.parse().map(Some).map_err(|e| {
+            AppError::InternalError(format!("Invalid value for {key} ('{value}'): {e}"))
+        }),
+        Err(_) => Ok(None),
+    }
+}
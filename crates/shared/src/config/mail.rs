@@ -0,0 +1,79 @@
+use crate::utils::AppError;
+use std::{str::FromStr, time::Duration};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmailVerificationPolicy {
+    Disabled,
+    #[default]
+    BlockContent,
+    BlockLogin,
+}
+
+impl EmailVerificationPolicy {
+    pub fn blocks_login(&self) -> bool {
+        matches!(self, Self::BlockLogin)
+    }
+
+    pub fn blocks_content(&self) -> bool {
+        matches!(self, Self::BlockContent | Self::BlockLogin)
+    }
+}
+
+impl FromStr for EmailVerificationPolicy {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "disabled" | "none" => Ok(Self::Disabled),
+            "content" => Ok(Self::BlockContent),
+            "login" => Ok(Self::BlockLogin),
+            other => Err(AppError::InternalError(format!(
+                "Unknown email verification policy: {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MailConfig {
+    pub outbox_dir: String,
+    pub from_address: String,
+    pub app_base_url: String,
+    pub password_reset_ttl: Duration,
+    pub email_verification_ttl: Duration,
+    pub verification_policy: EmailVerificationPolicy,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            outbox_dir: "outbox".to_string(),
+            from_address: "no-reply@localhost".to_string(),
+            app_base_url: "http://localhost:5000".to_string(),
+            password_reset_ttl: Duration::from_secs(30 * 60),
+            email_verification_ttl: Duration::from_secs(24 * 60 * 60),
+            verification_policy: EmailVerificationPolicy::default(),
+        }
+    }
+}
+
+impl MailConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let default = Self::default();
+
+        let verification_policy = match std::env::var("EMAIL_VERIFICATION_POLICY") {
+            Ok(value) => value.parse().map_err(|e| {
+                anyhow::anyhow!("Invalid value for EMAIL_VERIFICATION_POLICY ('{value}'): {e}")
+            })?,
+            Err(_) => default.verification_policy,
+        };
+
+        Ok(Self {
+            outbox_dir: std::env::var("MAIL_OUTBOX_DIR").unwrap_or(default.outbox_dir),
+            from_address: std::env::var("MAIL_FROM_ADDRESS").unwrap_or(default.from_address),
+            app_base_url: std::env::var("APP_BASE_URL").unwrap_or(default.app_base_url),
+            verification_policy,
+            ..default
+        })
+    }
+}
@@ -1,11 +1,25 @@
+mod auth_cookie;
 mod database;
 mod hashing;
 mod jwt;
+mod mail;
 mod myconfig;
+mod oidc;
+mod publishing;
 mod redis;
+mod tls;
+mod trusted_proxies;
+mod two_factor;
 
+pub use self::auth_cookie::AuthCookieConfig;
 pub use self::database::{ConnectionManager, ConnectionPool};
-pub use self::hashing::Hashing;
-pub use self::jwt::JwtConfig;
+pub use self::hashing::{HashAlgorithm, Hashing, HashingConfig};
+pub use self::jwt::{Claims, JwtAlgorithm, JwtConfig, JwtKeyPolicy, SigningKey, TokenKind};
+pub use self::mail::{EmailVerificationPolicy, MailConfig};
 pub use self::myconfig::Config;
+pub use self::oidc::OidcConfig;
+pub use self::publishing::PublishingConfig;
 pub use self::redis::{RedisClient, RedisConfig};
+pub use self::tls::{GrpcClientTlsConfig, GrpcServerTlsConfig};
+pub use self::trusted_proxies::TrustedProxies;
+pub use self::two_factor::TwoFactorConfig;
@@ -0,0 +1,55 @@
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct OidcConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_url: String,
+    pub scopes: String,
+    pub flow_ttl: Duration,
+    pub http_timeout: Duration,
+}
+
+impl OidcConfig {
+    pub fn new(issuer_url: &str, client_id: &str, redirect_url: &str) -> Self {
+        Self {
+            issuer_url: issuer_url.trim_end_matches('/').to_string(),
+            client_id: client_id.to_string(),
+            client_secret: None,
+            redirect_url: redirect_url.to_string(),
+            scopes: "openid email profile".to_string(),
+            flow_ttl: Duration::from_secs(10 * 60),
+            http_timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Returns `None` when no identity provider is configured, which leaves
+    /// OIDC login disabled. Setting only one of the issuer and client id is an
+    /// error rather than a silently disabled login.
+    pub fn from_env() -> Result<Option<Self>> {
+        let (issuer_url, client_id) = match (
+            std::env::var("OIDC_ISSUER_URL"),
+            std::env::var("OIDC_CLIENT_ID"),
+        ) {
+            (Ok(issuer_url), Ok(client_id)) => (issuer_url, client_id),
+            (Err(_), Err(_)) => return Ok(None),
+            _ => {
+                return Err(anyhow!(
+                    "OIDC_ISSUER_URL and OIDC_CLIENT_ID must be set together"
+                ));
+            }
+        };
+        let redirect_url = std::env::var("OIDC_REDIRECT_URL")
+            .unwrap_or_else(|_| "http://localhost:5000/api/auth/oidc/callback".to_string());
+
+        let default = Self::new(&issuer_url, &client_id, &redirect_url);
+
+        Ok(Some(Self {
+            client_secret: std::env::var("OIDC_CLIENT_SECRET").ok(),
+            scopes: std::env::var("OIDC_SCOPES").unwrap_or(default.scopes.clone()),
+            ..default
+        }))
+    }
+}
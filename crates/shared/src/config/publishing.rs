@@ -0,0 +1,41 @@
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct PublishingConfig {
+    /// How often the server looks for scheduled posts that are due.
+    pub check_interval: Duration,
+}
+
+impl Default for PublishingConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl PublishingConfig {
+    pub fn from_env() -> Result<Self> {
+        let default = Self::default();
+
+        let check_interval = match std::env::var("POST_PUBLISH_CHECK_INTERVAL_SECS") {
+            Ok(value) => match value.parse::<u64>() {
+                Ok(secs) if secs > 0 => Duration::from_secs(secs),
+                Ok(_) => {
+                    return Err(anyhow!(
+                        "Invalid value for POST_PUBLISH_CHECK_INTERVAL_SECS ('{value}'): must be greater than zero"
+                    ));
+                }
+                Err(e) => {
+                    return Err(anyhow!(
+                        "Invalid value for POST_PUBLISH_CHECK_INTERVAL_SECS ('{value}'): {e}"
+                    ));
+                }
+            },
+            Err(_) => default.check_interval,
+        };
+
+        Ok(Self { check_interval })
+    }
+}
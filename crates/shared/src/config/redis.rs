@@ -10,6 +10,17 @@ pub struct RedisConfig {
     pub password: Option<String>,
 }
 
+impl Default for RedisConfig {
+    fn default() -> Self {
+        Self {
+            host: "redis".into(),
+            port: 6379,
+            db: 1,
+            password: Some("dragon_knight".into()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct RedisClient {
     pub client: Client,
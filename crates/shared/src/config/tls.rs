@@ -0,0 +1,113 @@
+use anyhow::{Context, Result, anyhow};
+use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+
+#[derive(Clone, Debug, Default)]
+pub struct GrpcServerTlsConfig {
+    pub cert_path: Option<String>,
+    pub key_path: Option<String>,
+    pub client_ca_path: Option<String>,
+}
+
+impl GrpcServerTlsConfig {
+    pub fn from_env() -> Self {
+        Self {
+            cert_path: env_path("GRPC_TLS_CERT"),
+            key_path: env_path("GRPC_TLS_KEY"),
+            client_ca_path: env_path("GRPC_TLS_CLIENT_CA"),
+        }
+    }
+
+    pub fn load(&self) -> Result<Option<ServerTlsConfig>> {
+        let (cert_path, key_path) = match (&self.cert_path, &self.key_path) {
+            (Some(cert), Some(key)) => (cert, key),
+            (None, None) => {
+                if self.client_ca_path.is_some() {
+                    return Err(anyhow!(
+                        "GRPC_TLS_CLIENT_CA requires GRPC_TLS_CERT and GRPC_TLS_KEY"
+                    ));
+                }
+                return Ok(None);
+            }
+            _ => {
+                return Err(anyhow!(
+                    "GRPC_TLS_CERT and GRPC_TLS_KEY must be set together"
+                ));
+            }
+        };
+
+        let mut tls = ServerTlsConfig::new().identity(load_identity(cert_path, key_path)?);
+
+        if let Some(ca_path) = &self.client_ca_path {
+            tls = tls.client_ca_root(load_certificate(ca_path)?);
+        }
+
+        Ok(Some(tls))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GrpcClientTlsConfig {
+    pub ca_path: Option<String>,
+    pub domain_name: Option<String>,
+    pub cert_path: Option<String>,
+    pub key_path: Option<String>,
+}
+
+impl GrpcClientTlsConfig {
+    pub fn from_env() -> Self {
+        Self {
+            ca_path: env_path("GRPC_TLS_CA"),
+            domain_name: env_path("GRPC_TLS_DOMAIN"),
+            cert_path: env_path("GRPC_TLS_CLIENT_CERT"),
+            key_path: env_path("GRPC_TLS_CLIENT_KEY"),
+        }
+    }
+
+    pub fn load(&self) -> Result<Option<ClientTlsConfig>> {
+        let Some(ca_path) = &self.ca_path else {
+            if self.cert_path.is_some() || self.key_path.is_some() {
+                return Err(anyhow!(
+                    "GRPC_TLS_CLIENT_CERT and GRPC_TLS_CLIENT_KEY require GRPC_TLS_CA"
+                ));
+            }
+            return Ok(None);
+        };
+
+        let mut tls = ClientTlsConfig::new().ca_certificate(load_certificate(ca_path)?);
+
+        if let Some(domain_name) = &self.domain_name {
+            tls = tls.domain_name(domain_name.clone());
+        }
+
+        match (&self.cert_path, &self.key_path) {
+            (Some(cert), Some(key)) => tls = tls.identity(load_identity(cert, key)?),
+            (None, None) => (),
+            _ => {
+                return Err(anyhow!(
+                    "GRPC_TLS_CLIENT_CERT and GRPC_TLS_CLIENT_KEY must be set together"
+                ));
+            }
+        }
+
+        Ok(Some(tls))
+    }
+}
+
+fn env_path(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+fn load_certificate(path: &str) -> Result<Certificate> {
+    let pem = std::fs::read(path).with_context(|| format!("Failed to read certificate {path}"))?;
+
+    Ok(Certificate::from_pem(pem))
+}
+
+fn load_identity(cert_path: &str, key_path: &str) -> Result<Identity> {
+    let cert = std::fs::read(cert_path)
+        .with_context(|| format!("Failed to read certificate {cert_path}"))?;
+    let key = std::fs::read(key_path)
+        .with_context(|| format!("Failed to read private key {key_path}"))?;
+
+    Ok(Identity::from_pem(cert, key))
+}
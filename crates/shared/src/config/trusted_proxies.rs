@@ -0,0 +1,43 @@
+use anyhow::{Result, anyhow};
+use std::net::IpAddr;
+
+/// Peers allowed to tell the gRPC server who the real client is through
+/// `x-forwarded-for`. Anyone else could put any address there, so for them
+/// the socket's own address is used instead.
+#[derive(Clone, Debug, Default)]
+pub struct TrustedProxies {
+    /// Peer addresses whose forwarded-for header is believed.
+    pub addresses: Vec<IpAddr>,
+    /// Whether a peer that authenticated with a client certificate (the
+    /// gateway, when mutual TLS is on) is trusted regardless of address.
+    pub trust_client_certificates: bool,
+}
+
+impl TrustedProxies {
+    pub fn from_env() -> Result<Self> {
+        let addresses = std::env::var("GRPC_TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow!("Invalid value for GRPC_TRUSTED_PROXIES ('{value}'): {e}"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            addresses,
+            // The server only asks for client certificates when
+            // GRPC_TLS_CLIENT_CA is set, and then only the gateway has one.
+            trust_client_certificates: std::env::var("GRPC_TLS_CLIENT_CA")
+                .is_ok_and(|value| !value.is_empty()),
+        })
+    }
+
+    pub fn trusts(&self, peer: Option<IpAddr>, has_client_certificate: bool) -> bool {
+        (self.trust_client_certificates && has_client_certificate)
+            || peer.is_some_and(|peer| self.addresses.contains(&peer))
+    }
+}
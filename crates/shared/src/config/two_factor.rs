@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct TwoFactorConfig {
+    pub issuer: String,
+    pub challenge_ttl: Duration,
+    pub recovery_code_count: usize,
+}
+
+impl Default for TwoFactorConfig {
+    fn default() -> Self {
+        Self {
+            issuer: "SeaQuery Blog".to_string(),
+            challenge_ttl: Duration::from_secs(5 * 60),
+            recovery_code_count: 10,
+        }
+    }
+}
+
+impl TwoFactorConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let default = Self::default();
+
+        let challenge_ttl = match std::env::var("TWO_FACTOR_CHALLENGE_TTL_SECS") {
+            Ok(value) => Duration::from_secs(value.parse().map_err(|e| {
+                anyhow::anyhow!("Invalid value for TWO_FACTOR_CHALLENGE_TTL_SECS ('{value}'): {e}")
+            })?),
+            Err(_) => default.challenge_ttl,
+        };
+
+        Ok(Self {
+            issuer: std::env::var("TOTP_ISSUER").unwrap_or(default.issuer),
+            challenge_ttl,
+            ..default
+        })
+    }
+}
@@ -2,12 +2,21 @@ mod request;
 mod response;
 
 pub use self::request::{
-    CreateCategoryRequest, CreateCommentRequest, CreatePostRequest, CreateUserRequest,
-    FindAllCategoryRequest, FindAllPostRequest, FindAllUserRequest, LoginRequest, RegisterRequest,
-    UpdateCategoryRequest, UpdateCommentRequest, UpdatePostRequest, UpdateUserRequest,
+    AuditContext, ChangePasswordRequest, ClientInfo, ConfirmEmailChangeRequest,
+    CreateApiKeyRequest, CreateCategoryRequest, CreateCommentRequest, CreatePostRequest,
+    CreateTagRequest, CreateUserRequest, FindAllAuditEventRequest, FindAllCategoryRequest,
+    FindAllPostRequest, FindAllTagRequest, FindAllUserRequest, FindMyPostsRequest, LoginRequest,
+    LogoutRequest, OidcCallbackRequest, OidcLoginRequest, PasswordResetRequest,
+    RefreshTokenRequest, RegisterRequest, RequestEmailChangeRequest, ResendVerificationRequest,
+    ResetPasswordRequest, SearchPostsRequest, TagCloudRequest, TwoFactorCodeRequest,
+    TwoFactorLoginRequest, UpdateCategoryRequest, UpdateCommentRequest, UpdatePostRequest,
+    UpdateProfileRequest, UpdateTagRequest, UpdateUserRequest, VerifyEmailRequest,
 };
 
 pub use self::response::{
-    ApiResponse, ApiResponsePagination, CategoryResponse, CommentResponse, DeleteResponse,
-    ErrorResponse, Pagination, PostRelationResponse, PostResponse, UploadResponse, UserResponse,
+    ApiKeyResponse, ApiResponse, ApiResponsePagination, AuditEventResponse, CategoryResponse,
+    CommentResponse, CreatedApiKeyResponse, DeleteResponse, ErrorResponse, LoginResponse,
+    Pagination, PostRelationResponse, PostResponse, PostSearchResultResponse,
+    RecoveryCodesResponse, SessionResponse, TagCountResponse, TagResponse, TokenResponse,
+    TwoFactorChallengeResponse, TwoFactorEnrollmentResponse, UploadResponse, UserResponse,
 };
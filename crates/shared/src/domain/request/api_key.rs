@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1-100 characters"))]
+    pub name: String,
+
+    #[serde(default)]
+    pub scopes: Vec<String>,
+
+    #[validate(range(min = 1, max = 3650, message = "Expiry must be 1-3650 days"))]
+    pub expires_in_days: Option<i64>,
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use super::ClientInfo;
+use crate::config::Claims;
+
+/// Who is performing a mutating operation and from where.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor_id: Option<i32>,
+    pub client: ClientInfo,
+}
+
+impl AuditContext {
+    pub fn new(actor: &Claims, client: ClientInfo) -> Self {
+        Self {
+            actor_id: Some(actor.user_id as i32),
+            client,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+pub struct FindAllAuditEventRequest {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    pub actor_id: Option<i32>,
+
+    /// Exact action name, e.g. `user.deleted`
+    pub action: Option<String>,
+
+    pub target_type: Option<String>,
+
+    pub target_id: Option<String>,
+
+    /// RFC 3339 timestamp; only events at or after it are returned
+    pub from: Option<String>,
+
+    /// RFC 3339 timestamp; only events before it are returned
+    pub to: Option<String>,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_page_size() -> i32 {
+    10
+}
@@ -12,6 +12,11 @@ pub struct FindAllCategoryRequest {
 
     #[serde(default)]
     pub search: String,
+
+    /// Pages newest first by cursor instead of by `page`: empty for the first
+    /// page, then the `next_cursor` of the previous one.
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 fn default_page() -> i32 {
@@ -7,7 +7,12 @@ pub struct CreateCommentRequest {
     #[validate(range(min = 1, message = "Post ID must be greater than 0"))]
     pub id_post_comment: i32,
 
-    #[validate(length(min = 1, message = "User name must not be empty"))]
+    #[serde(default)]
+    #[schema(read_only)]
+    pub user_id: i32,
+
+    #[serde(default)]
+    #[schema(read_only)]
     pub user_name_comment: String,
 
     #[validate(length(min = 1, message = "Comment must not be empty"))]
@@ -19,9 +24,6 @@ pub struct UpdateCommentRequest {
     #[validate(range(min = 1, message = "Post ID must be greater than 0"))]
     pub id_post_comment: i32,
 
-    #[validate(length(min = 1, message = "User name must not be empty"))]
-    pub user_name_comment: String,
-
     #[validate(length(min = 1, message = "Comment must not be empty"))]
     pub comment: String,
 }
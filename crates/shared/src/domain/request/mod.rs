@@ -1,14 +1,34 @@
+mod api_key;
+mod audit;
 mod auth;
 mod category;
 mod comment;
 mod post;
+mod tag;
+mod two_factor;
 mod user;
 
+pub use self::api_key::CreateApiKeyRequest;
+pub use self::audit::{AuditContext, FindAllAuditEventRequest};
 pub use self::category::{CreateCategoryRequest, FindAllCategoryRequest, UpdateCategoryRequest};
-pub use self::post::{CreatePostRequest, FindAllPostRequest, UpdatePostRequest};
+pub use self::post::{
+    CreatePostRequest, FindAllPostRequest, FindMyPostsRequest, SearchPostsRequest,
+    UpdatePostRequest,
+};
+
+pub use self::tag::{CreateTagRequest, FindAllTagRequest, TagCloudRequest, UpdateTagRequest};
 
 pub use self::comment::{CreateCommentRequest, UpdateCommentRequest};
 
-pub use self::auth::{LoginRequest, RegisterRequest};
+pub use self::auth::{
+    ClientInfo, LoginRequest, LogoutRequest, OidcCallbackRequest, OidcLoginRequest,
+    PasswordResetRequest, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest,
+    ResetPasswordRequest, VerifyEmailRequest,
+};
+
+pub use self::two_factor::{TwoFactorCodeRequest, TwoFactorLoginRequest};
 
-pub use self::user::{CreateUserRequest, FindAllUserRequest, UpdateUserRequest};
+pub use self::user::{
+    ChangePasswordRequest, ConfirmEmailChangeRequest, CreateUserRequest, FindAllUserRequest,
+    RequestEmailChangeRequest, UpdateProfileRequest, UpdateUserRequest,
+};
@@ -10,8 +10,83 @@ pub struct FindAllPostRequest {
     #[serde(default = "default_page_size")]
     pub page_size: i32,
 
+    /// Full-text query over title and body; results are ordered by
+    /// relevance when set, unless paging by cursor.
     #[serde(default)]
     pub search: String,
+
+    /// Only posts carrying the tag with this slug.
+    #[serde(default)]
+    pub tag: String,
+
+    /// Pages newest first by cursor instead of by `page`: empty for the first
+    /// page, then the `next_cursor` of the previous one.
+    #[serde(default)]
+    pub cursor: Option<String>,
+
+    pub category_id: Option<i32>,
+
+    /// Only posts by this author.
+    pub user_id: Option<i32>,
+
+    /// RFC 3339 timestamp; only posts created at or after it are returned
+    pub from: Option<String>,
+
+    /// RFC 3339 timestamp; only posts created before it are returned
+    pub to: Option<String>,
+
+    /// Empty for published posts; `draft`, `scheduled` or `archived` need
+    /// the `posts:moderate` permission.
+    #[serde(default)]
+    pub status: String,
+
+    /// `created_at` (the default), `title` or `popularity` (comment count).
+    #[serde(default)]
+    pub sort: String,
+
+    /// `asc` or `desc`; titles default to `asc`, the rest to `desc`.
+    #[serde(default)]
+    pub order: String,
+}
+
+/// The caller's own posts, whatever their status.
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+pub struct FindMyPostsRequest {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    #[serde(default)]
+    pub search: String,
+
+    /// `draft`, `published`, `scheduled` or `archived`; empty for all.
+    #[serde(default)]
+    pub status: String,
+
+    /// Only posts carrying the tag with this slug.
+    #[serde(default)]
+    pub tag: String,
+}
+
+/// Full-text search over published posts, best matches first.
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams, Validate)]
+pub struct SearchPostsRequest {
+    /// Search terms. Quoted phrases, `or` and a leading `-` to exclude a word
+    /// work as in web search engines.
+    #[validate(length(min = 1, max = 200, message = "Query must be 1 to 200 characters"))]
+    pub q: String,
+
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    /// Only posts carrying the tag with this slug.
+    #[serde(default)]
+    pub tag: String,
 }
 
 fn default_page() -> i32 {
@@ -35,9 +110,25 @@ pub struct CreatePostRequest {
     pub file: String,
 
     pub category_id: i32,
+
+    /// `draft`, `published`, `scheduled` or `archived`; defaults to `published`.
+    #[serde(default)]
+    pub status: String,
+
+    /// RFC 3339 time a `scheduled` post goes public.
+    #[serde(default)]
+    pub published_at: String,
+
+    /// Tag names; new ones are created on the fly.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    #[schema(read_only)]
     pub user_id: i32,
 
-    #[validate(length(min = 1, message = "User name is required"))]
+    #[serde(default)]
+    #[schema(read_only)]
     pub user_name: String,
 }
 
@@ -56,8 +147,17 @@ pub struct UpdatePostRequest {
     pub file: String,
 
     pub category_id: i32,
-    pub user_id: i32,
 
-    #[validate(length(min = 1, message = "User name is required"))]
-    pub user_name: String,
+    /// `draft`, `published`, `scheduled` or `archived`; empty keeps the
+    /// current status.
+    #[serde(default)]
+    pub status: String,
+
+    /// RFC 3339 time a `scheduled` post goes public.
+    #[serde(default)]
+    pub published_at: String,
+
+    /// Replaces the post's tags when present; `None` leaves them alone.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
 }
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+pub struct FindAllTagRequest {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    #[serde(default)]
+    pub search: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
+pub struct TagCloudRequest {
+    /// How many of the most used tags to return.
+    #[serde(default = "default_cloud_limit")]
+    pub limit: i32,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_page_size() -> i32 {
+    10
+}
+
+fn default_cloud_limit() -> i32 {
+    50
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct CreateTagRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be 1 to 100 characters"))]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema, Validate)]
+pub struct UpdateTagRequest {
+    #[validate(range(min = 1, message = "ID must be greater than 0"))]
+    pub id: i32,
+
+    #[validate(length(min = 1, max = 100, message = "Name must be 1 to 100 characters"))]
+    pub name: String,
+}
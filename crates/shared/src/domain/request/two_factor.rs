@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct TwoFactorCodeRequest {
+    #[validate(length(min = 6, max = 32, message = "Code must be 6-32 characters"))]
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct TwoFactorLoginRequest {
+    #[validate(length(min = 1, message = "Challenge token is required"))]
+    pub challenge_token: String,
+
+    #[validate(length(min = 6, max = 32, message = "Code must be 6-32 characters"))]
+    pub code: String,
+}
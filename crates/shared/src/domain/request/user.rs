@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use utoipa::{IntoParams, ToSchema};
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::model::role::Role;
 
 #[derive(Serialize, Deserialize, Clone, Debug, IntoParams)]
 pub struct FindAllUserRequest {
@@ -12,6 +15,11 @@ pub struct FindAllUserRequest {
 
     #[serde(default)]
     pub search: String,
+
+    /// Pages newest first by cursor instead of by `page`: empty for the first
+    /// page, then the `next_cursor` of the previous one.
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 fn default_page() -> i32 {
@@ -52,4 +60,46 @@ pub struct UpdateUserRequest {
 
     #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
     pub password: Option<String>,
+
+    #[validate(custom(function = "validate_role"))]
+    pub role: Option<String>,
+}
+
+fn validate_role(role: &str) -> Result<(), ValidationError> {
+    Role::from_str(role)
+        .map(|_| ())
+        .map_err(|_| ValidationError::new("role").with_message("Unknown role".into()))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateProfileRequest {
+    #[validate(length(min = 1, message = "Firstname cannot be empty"))]
+    pub firstname: Option<String>,
+
+    #[validate(length(min = 1, message = "Lastname cannot be empty"))]
+    pub lastname: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 1, message = "Current password is required"))]
+    pub current_password: String,
+
+    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RequestEmailChangeRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub new_email: String,
+
+    #[validate(length(min = 1, message = "Current password is required"))]
+    pub current_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ConfirmEmailChangeRequest {
+    #[validate(length(min = 1, message = "Confirmation token is required"))]
+    pub token: String,
 }
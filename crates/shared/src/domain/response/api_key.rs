@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::model::api_key::ApiKey;
+use genproto::api_key::{
+    ApiKeyResponse as ProtoApiKeyResponse, CreatedApiKeyResponse as ProtoCreatedApiKeyResponse,
+};
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct ApiKeyResponse {
+    pub id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub revoked: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct CreatedApiKeyResponse {
+    pub key: String,
+    pub api_key: ApiKeyResponse,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(key: ApiKey) -> Self {
+        ApiKeyResponse {
+            id: key.id,
+            name: key.name,
+            prefix: key.prefix,
+            scopes: key.scopes,
+            last_used_at: key.last_used_at.map(|t| t.to_rfc3339()),
+            expires_at: key.expires_at.map(|t| t.to_rfc3339()),
+            revoked: key.revoked_at.is_some(),
+            created_at: key.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<ApiKeyResponse> for ProtoApiKeyResponse {
+    fn from(key: ApiKeyResponse) -> Self {
+        ProtoApiKeyResponse {
+            id: key.id,
+            name: key.name,
+            prefix: key.prefix,
+            scopes: key.scopes,
+            last_used_at: key.last_used_at,
+            expires_at: key.expires_at,
+            revoked: key.revoked,
+            created_at: key.created_at,
+        }
+    }
+}
+
+impl From<ProtoApiKeyResponse> for ApiKeyResponse {
+    fn from(key: ProtoApiKeyResponse) -> Self {
+        ApiKeyResponse {
+            id: key.id,
+            name: key.name,
+            prefix: key.prefix,
+            scopes: key.scopes,
+            last_used_at: key.last_used_at,
+            expires_at: key.expires_at,
+            revoked: key.revoked,
+            created_at: key.created_at,
+        }
+    }
+}
+
+impl From<CreatedApiKeyResponse> for ProtoCreatedApiKeyResponse {
+    fn from(created: CreatedApiKeyResponse) -> Self {
+        ProtoCreatedApiKeyResponse {
+            key: created.key,
+            api_key: Some(created.api_key.into()),
+        }
+    }
+}
+
+impl From<ProtoCreatedApiKeyResponse> for CreatedApiKeyResponse {
+    fn from(created: ProtoCreatedApiKeyResponse) -> Self {
+        CreatedApiKeyResponse {
+            key: created.key,
+            api_key: created
+                .api_key
+                .map(Into::into)
+                .unwrap_or_else(|| ApiKeyResponse {
+                    id: 0,
+                    name: "".to_string(),
+                    prefix: "".to_string(),
+                    scopes: vec![],
+                    last_used_at: None,
+                    expires_at: None,
+                    revoked: false,
+                    created_at: "".to_string(),
+                }),
+        }
+    }
+}
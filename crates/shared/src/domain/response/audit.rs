@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::model::audit_event::AuditEvent;
+use genproto::audit::AuditEventResponse as ProtoAuditEventResponse;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct AuditEventResponse {
+    pub id: i64,
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    pub created_at: String,
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+    fn from(event: AuditEvent) -> Self {
+        AuditEventResponse {
+            id: event.id,
+            actor_id: event.actor_id,
+            action: event.action,
+            target_type: event.target_type,
+            target_id: event.target_id,
+            ip_address: event.ip_address,
+            request_id: event.request_id,
+            before: event.before,
+            after: event.after,
+            created_at: event.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<AuditEventResponse> for ProtoAuditEventResponse {
+    fn from(event: AuditEventResponse) -> Self {
+        ProtoAuditEventResponse {
+            id: event.id,
+            actor_id: event.actor_id,
+            action: event.action,
+            target_type: event.target_type,
+            target_id: event.target_id,
+            ip_address: event.ip_address,
+            request_id: event.request_id,
+            before: event.before.map(|value| value.to_string()),
+            after: event.after.map(|value| value.to_string()),
+            created_at: event.created_at,
+        }
+    }
+}
+
+impl From<ProtoAuditEventResponse> for AuditEventResponse {
+    fn from(event: ProtoAuditEventResponse) -> Self {
+        AuditEventResponse {
+            id: event.id,
+            actor_id: event.actor_id,
+            action: event.action,
+            target_type: event.target_type,
+            target_id: event.target_id,
+            ip_address: event.ip_address,
+            request_id: event.request_id,
+            before: event
+                .before
+                .and_then(|json| serde_json::from_str(&json).ok()),
+            after: event
+                .after
+                .and_then(|json| serde_json::from_str(&json).ok()),
+            created_at: event.created_at,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use genproto::auth::TokenResponse as ProtoTokenResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::TwoFactorChallengeResponse;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(untagged)]
+pub enum LoginResponse {
+    Tokens(TokenResponse),
+    TwoFactorRequired(TwoFactorChallengeResponse),
+}
+
+impl From<TokenResponse> for ProtoTokenResponse {
+    fn from(token: TokenResponse) -> Self {
+        Self {
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            token_type: token.token_type,
+            expires_in: token.expires_in,
+        }
+    }
+}
+
+impl From<ProtoTokenResponse> for TokenResponse {
+    fn from(token: ProtoTokenResponse) -> Self {
+        Self {
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            token_type: token.token_type,
+            expires_in: token.expires_in,
+        }
+    }
+}
+
+impl From<Option<ProtoTokenResponse>> for TokenResponse {
+    fn from(token: Option<ProtoTokenResponse>) -> Self {
+        match token {
+            Some(token) => token.into(),
+            None => TokenResponse {
+                access_token: "".to_string(),
+                refresh_token: "".to_string(),
+                token_type: "".to_string(),
+                expires_in: 0,
+            },
+        }
+    }
+}
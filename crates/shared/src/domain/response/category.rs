@@ -9,6 +9,7 @@ use genproto::category::CategoryResponse as ProtoCategoryResponse;
 pub struct CategoryResponse {
     pub id: i32,
     pub name: String,
+    pub slug: String,
 }
 
 impl From<Category> for CategoryResponse {
@@ -16,6 +17,7 @@ impl From<Category> for CategoryResponse {
         CategoryResponse {
             id: category.id,
             name: category.name,
+            slug: category.slug,
         }
     }
 }
@@ -25,6 +27,7 @@ impl From<CategoryResponse> for ProtoCategoryResponse {
         ProtoCategoryResponse {
             id: category.id,
             name: category.name,
+            slug: category.slug,
         }
     }
 }
@@ -34,6 +37,7 @@ impl From<ProtoCategoryResponse> for CategoryResponse {
         CategoryResponse {
             id: category.id,
             name: category.name,
+            slug: category.slug,
         }
     }
 }
@@ -45,6 +49,7 @@ impl From<Option<ProtoCategoryResponse>> for CategoryResponse {
             None => CategoryResponse {
                 id: 0,
                 name: "".to_string(),
+                slug: "".to_string(),
             },
         }
     }
@@ -10,6 +10,7 @@ pub struct CommentResponse {
     pub id_post_comment: i32,
     pub user_name_comment: String,
     pub comment: String,
+    pub user_id: i32,
 }
 
 impl From<Comment> for CommentResponse {
@@ -19,6 +20,7 @@ impl From<Comment> for CommentResponse {
             id_post_comment: comment.id_post_comment,
             user_name_comment: comment.user_name_comment,
             comment: comment.comment,
+            user_id: comment.user_id.unwrap_or_default(),
         }
     }
 }
@@ -32,6 +34,7 @@ impl From<Option<Comment>> for CommentResponse {
                 id_post_comment: 0,
                 user_name_comment: "".to_string(),
                 comment: "".to_string(),
+                user_id: 0,
             },
         }
     }
@@ -44,6 +47,7 @@ impl From<CommentResponse> for ProtoCommentResponse {
             id_post_comment: comment.id_post_comment,
             user_name_comment: comment.user_name_comment,
             comment: comment.comment,
+            user_id: comment.user_id,
         }
     }
 }
@@ -55,6 +59,7 @@ impl From<ProtoCommentResponse> for CommentResponse {
             id_post_comment: comment.id_post_comment,
             user_name_comment: comment.user_name_comment,
             comment: comment.comment,
+            user_id: comment.user_id,
         }
     }
 }
@@ -68,6 +73,7 @@ impl From<Option<ProtoCommentResponse>> for CommentResponse {
                 id_post_comment: 0,
                 user_name_comment: "".to_string(),
                 comment: "".to_string(),
+                user_id: 0,
             },
         }
     }
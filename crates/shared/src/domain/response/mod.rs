@@ -3,20 +3,34 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 use utoipa::ToSchema;
 
+mod api_key;
+mod audit;
+mod auth;
 mod category;
 mod comment;
 mod file;
 mod pagination;
 mod post;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
 
 use crate::utils::AppError;
 
+pub use self::api_key::{ApiKeyResponse, CreatedApiKeyResponse};
+pub use self::audit::AuditEventResponse;
+pub use self::auth::{LoginResponse, TokenResponse};
 pub use self::category::CategoryResponse;
 pub use self::comment::CommentResponse;
 pub use self::file::{DeleteResponse, UploadResponse};
 pub use self::pagination::Pagination;
-pub use self::post::{PostRelationResponse, PostResponse};
+pub use self::post::{PostRelationResponse, PostResponse, PostSearchResultResponse};
+pub use self::session::SessionResponse;
+pub use self::tag::{TagCountResponse, TagResponse};
+pub use self::two_factor::{
+    RecoveryCodesResponse, TwoFactorChallengeResponse, TwoFactorEnrollmentResponse,
+};
 pub use self::user::UserResponse;
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
@@ -66,11 +80,29 @@ impl From<AppError> for ErrorResponse {
                 "error".to_string(),
                 "Error during password hashing".to_string(),
             ),
-            AppError::NotFound(ref msg) => ("error".to_string(), msg.clone()),
+            AppError::NotFound(ref msg) => ("not_found".to_string(), msg.clone()),
+            AppError::TooManyRequests(retry_after) => (
+                "too_many_requests".to_string(),
+                format!("Too many failed login attempts, try again in {retry_after} seconds"),
+            ),
+            AppError::Forbidden(ref msg) => ("forbidden".to_string(), msg.clone()),
             AppError::TokenExpiredError => ("error".to_string(), "Token has expired".to_string()),
             AppError::TokenValidationError => {
                 ("error".to_string(), "Token validation failed".to_string())
             }
+            AppError::TokenRevoked => ("error".to_string(), "Token has been revoked".to_string()),
+            AppError::InvalidTwoFactorCode => (
+                "unauthenticated".to_string(),
+                "Invalid two-factor code".to_string(),
+            ),
+            AppError::OidcError(ref msg) => (
+                "unauthenticated".to_string(),
+                format!("Identity provider login failed: {msg}"),
+            ),
+            AppError::RefreshTokenReused => (
+                "error".to_string(),
+                "Refresh token has already been used".to_string(),
+            ),
             AppError::TokenGenerationError(_) => {
                 ("error".to_string(), "Token generation failed".to_string())
             }
@@ -78,11 +110,18 @@ impl From<AppError> for ErrorResponse {
             AppError::InvalidCredentials => {
                 ("error".to_string(), "Invalid credentials".to_string())
             }
-            AppError::EmailAlreadyExists => {
-                ("error".to_string(), "Email already exists".to_string())
-            }
+            AppError::EmailAlreadyExists => (
+                "already_exists".to_string(),
+                "Email already exists".to_string(),
+            ),
+            AppError::AlreadyExists(ref msg) => ("already_exists".to_string(), msg.clone()),
             AppError::ValidationError(_) => ("error".to_string(), "Validation error".to_string()),
+            AppError::InvalidArgument(ref msg) => ("invalid_argument".to_string(), msg.clone()),
             AppError::InternalError(ref msg) => ("error".to_string(), msg.clone()),
+            AppError::CacheError(_) => (
+                "unavailable".to_string(),
+                "Cache backend is unavailable".to_string(),
+            ),
         };
         ErrorResponse { status, message }
     }
@@ -93,3 +132,18 @@ impl fmt::Display for ErrorResponse {
         write!(f, "Status: {}, Message: {}", self.status, self.message)
     }
 }
+
+impl From<ErrorResponse> for tonic::Status {
+    fn from(error: ErrorResponse) -> Self {
+        match error.status.as_str() {
+            "not_found" => tonic::Status::not_found(error.message),
+            "forbidden" => tonic::Status::permission_denied(error.message),
+            "too_many_requests" => tonic::Status::resource_exhausted(error.message),
+            "unauthenticated" => tonic::Status::unauthenticated(error.message),
+            "invalid_argument" => tonic::Status::invalid_argument(error.message),
+            "already_exists" => tonic::Status::already_exists(error.message),
+            "unavailable" => tonic::Status::unavailable(error.message),
+            _ => tonic::Status::internal(error.message),
+        }
+    }
+}
@@ -3,12 +3,31 @@ use utoipa::ToSchema;
 
 use genproto::api::Pagination as ProtoPagination;
 
+use crate::utils::PageCursor;
+
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct Pagination {
     pub page: i32,
     pub page_size: i32,
     pub total_items: i64,
     pub total_pages: i32,
+    /// Set when paging by cursor and more rows follow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl Pagination {
+    /// A cursor-paged listing counts nothing, so `page` and the totals stay
+    /// zero.
+    pub fn keyset(page_size: i32, next: Option<PageCursor>) -> Self {
+        Self {
+            page: 0,
+            page_size,
+            total_items: 0,
+            total_pages: 0,
+            next_cursor: next.map(|cursor| cursor.encode()),
+        }
+    }
 }
 
 impl From<Pagination> for ProtoPagination {
@@ -18,6 +37,7 @@ impl From<Pagination> for ProtoPagination {
             page_size: value.page_size,
             total_items: value.total_items,
             total_pages: value.total_pages,
+            next_cursor: value.next_cursor,
         }
     }
 }
@@ -29,6 +49,7 @@ impl From<ProtoPagination> for Pagination {
             page_size: value.page_size,
             total_items: value.total_items,
             total_pages: value.total_pages,
+            next_cursor: value.next_cursor,
         }
     }
 }
@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::model::posts::{Post, PostRelationModel};
+use super::TagResponse;
+use crate::model::posts::{Post, PostRelationModel, PostSearchHit};
+use crate::utils::render_highlight;
 use genproto::post::{
     PostRelationResponse as ProtoPostRelationResponse, PostResponse as ProtoPostResponse,
+    PostSearchResult as ProtoPostSearchResult,
 };
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -15,6 +18,10 @@ pub struct PostResponse {
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
+    pub slug: String,
+    pub status: String,
+    pub published_at: Option<String>,
+    pub tags: Vec<TagResponse>,
 }
 
 impl From<Post> for PostResponse {
@@ -27,6 +34,10 @@ impl From<Post> for PostResponse {
             category_id: post.category_id,
             user_id: post.user_id,
             user_name: post.user_name,
+            slug: post.slug,
+            status: post.status,
+            published_at: post.published_at.map(|at| at.to_rfc3339()),
+            tags: Vec::new(),
         }
     }
 }
@@ -41,6 +52,10 @@ impl From<PostResponse> for ProtoPostResponse {
             category_id: post.category_id,
             user_id: post.user_id,
             user_name: post.user_name,
+            slug: post.slug,
+            status: post.status,
+            published_at: post.published_at,
+            tags: post.tags.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -55,6 +70,10 @@ impl From<ProtoPostResponse> for PostResponse {
             category_id: post.category_id,
             user_id: post.user_id,
             user_name: post.user_name,
+            slug: post.slug,
+            status: post.status,
+            published_at: post.published_at,
+            tags: post.tags.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -71,11 +90,58 @@ impl From<Option<ProtoPostResponse>> for PostResponse {
                 category_id: 0,
                 user_id: 0,
                 user_name: "".to_string(),
+                slug: "".to_string(),
+                status: "".to_string(),
+                published_at: None,
+                tags: Vec::new(),
             },
         }
     }
 }
 
+/// One full-text search match. `title_highlight` and `snippet` are
+/// HTML-escaped, with the matched words wrapped in `<mark>`.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct PostSearchResultResponse {
+    pub post: PostResponse,
+    pub rank: f32,
+    pub title_highlight: String,
+    pub snippet: String,
+}
+
+impl From<PostSearchHit> for PostSearchResultResponse {
+    fn from(hit: PostSearchHit) -> Self {
+        PostSearchResultResponse {
+            post: PostResponse::from(hit.post),
+            rank: hit.rank,
+            title_highlight: render_highlight(&hit.title_highlight),
+            snippet: render_highlight(&hit.snippet),
+        }
+    }
+}
+
+impl From<PostSearchResultResponse> for ProtoPostSearchResult {
+    fn from(result: PostSearchResultResponse) -> Self {
+        ProtoPostSearchResult {
+            post: Some(result.post.into()),
+            rank: result.rank,
+            title_highlight: result.title_highlight,
+            snippet: result.snippet,
+        }
+    }
+}
+
+impl From<ProtoPostSearchResult> for PostSearchResultResponse {
+    fn from(result: ProtoPostSearchResult) -> Self {
+        PostSearchResultResponse {
+            post: result.post.into(),
+            rank: result.rank,
+            title_highlight: result.title_highlight,
+            snippet: result.snippet,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct PostRelationResponse {
     pub post_id: i32,
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::model::session::Session;
+use genproto::session::SessionResponse as ProtoSessionResponse;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct SessionResponse {
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: String,
+    pub last_seen_at: String,
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn new(session: Session, current_session_id: Option<&str>) -> Self {
+        SessionResponse {
+            current: current_session_id == Some(session.id.as_str()),
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at.to_rfc3339(),
+            last_seen_at: session.last_seen_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<SessionResponse> for ProtoSessionResponse {
+    fn from(session: SessionResponse) -> Self {
+        ProtoSessionResponse {
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            current: session.current,
+        }
+    }
+}
+
+impl From<ProtoSessionResponse> for SessionResponse {
+    fn from(session: ProtoSessionResponse) -> Self {
+        SessionResponse {
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            current: session.current,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::model::tag::{PostTag, Tag, TagCount};
+use genproto::tag::{TagCountResponse as ProtoTagCountResponse, TagResponse as ProtoTagResponse};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct TagResponse {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+}
+
+impl From<Tag> for TagResponse {
+    fn from(tag: Tag) -> Self {
+        TagResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+        }
+    }
+}
+
+impl From<PostTag> for TagResponse {
+    fn from(tag: PostTag) -> Self {
+        TagResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+        }
+    }
+}
+
+impl From<TagResponse> for ProtoTagResponse {
+    fn from(tag: TagResponse) -> Self {
+        ProtoTagResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+        }
+    }
+}
+
+impl From<ProtoTagResponse> for TagResponse {
+    fn from(tag: ProtoTagResponse) -> Self {
+        TagResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+        }
+    }
+}
+
+impl From<Option<ProtoTagResponse>> for TagResponse {
+    fn from(tag: Option<ProtoTagResponse>) -> Self {
+        match tag {
+            Some(tag) => tag.into(),
+            None => TagResponse {
+                id: 0,
+                name: "".to_string(),
+                slug: "".to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct TagCountResponse {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub post_count: i64,
+}
+
+impl From<TagCount> for TagCountResponse {
+    fn from(tag: TagCount) -> Self {
+        TagCountResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+            post_count: tag.post_count,
+        }
+    }
+}
+
+impl From<TagCountResponse> for ProtoTagCountResponse {
+    fn from(tag: TagCountResponse) -> Self {
+        ProtoTagCountResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+            post_count: tag.post_count,
+        }
+    }
+}
+
+impl From<ProtoTagCountResponse> for TagCountResponse {
+    fn from(tag: ProtoTagCountResponse) -> Self {
+        TagCountResponse {
+            id: tag.id,
+            name: tag.name,
+            slug: tag.slug,
+            post_count: tag.post_count,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use genproto::{
+    auth::TwoFactorChallenge as ProtoTwoFactorChallenge,
+    two_factor::{
+        RecoveryCodesResponse as ProtoRecoveryCodesResponse,
+        TwoFactorEnrollmentResponse as ProtoTwoFactorEnrollmentResponse,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct TwoFactorEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct TwoFactorChallengeResponse {
+    pub two_factor_required: bool,
+    pub challenge_token: String,
+    pub expires_in: i64,
+}
+
+impl From<TwoFactorEnrollmentResponse> for ProtoTwoFactorEnrollmentResponse {
+    fn from(enrollment: TwoFactorEnrollmentResponse) -> Self {
+        Self {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        }
+    }
+}
+
+impl From<Option<ProtoTwoFactorEnrollmentResponse>> for TwoFactorEnrollmentResponse {
+    fn from(enrollment: Option<ProtoTwoFactorEnrollmentResponse>) -> Self {
+        let enrollment = enrollment.unwrap_or_default();
+
+        Self {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        }
+    }
+}
+
+impl From<RecoveryCodesResponse> for ProtoRecoveryCodesResponse {
+    fn from(codes: RecoveryCodesResponse) -> Self {
+        Self {
+            recovery_codes: codes.recovery_codes,
+        }
+    }
+}
+
+impl From<Option<ProtoRecoveryCodesResponse>> for RecoveryCodesResponse {
+    fn from(codes: Option<ProtoRecoveryCodesResponse>) -> Self {
+        Self {
+            recovery_codes: codes.unwrap_or_default().recovery_codes,
+        }
+    }
+}
+
+impl From<TwoFactorChallengeResponse> for ProtoTwoFactorChallenge {
+    fn from(challenge: TwoFactorChallengeResponse) -> Self {
+        Self {
+            challenge_token: challenge.challenge_token,
+            expires_in: challenge.expires_in,
+        }
+    }
+}
+
+impl From<ProtoTwoFactorChallenge> for TwoFactorChallengeResponse {
+    fn from(challenge: ProtoTwoFactorChallenge) -> Self {
+        Self {
+            two_factor_required: true,
+            challenge_token: challenge.challenge_token,
+            expires_in: challenge.expires_in,
+        }
+    }
+}
@@ -9,6 +9,8 @@ pub struct UserResponse {
     pub firstname: String,
     pub lastname: String,
     pub email: String,
+    pub role: String,
+    pub email_verified: bool,
 }
 
 impl From<User> for UserResponse {
@@ -18,6 +20,8 @@ impl From<User> for UserResponse {
             firstname: user.firstname,
             lastname: user.lastname,
             email: user.email,
+            role: user.role,
+            email_verified: user.email_verified_at.is_some(),
         }
     }
 }
@@ -29,6 +33,8 @@ impl From<UserResponse> for ProtoUserResponse {
             firstname: user.firstname,
             lastname: user.lastname,
             email: user.email,
+            role: user.role,
+            email_verified: user.email_verified,
         }
     }
 }
@@ -40,6 +46,8 @@ impl From<ProtoUserResponse> for UserResponse {
             firstname: user.firstname,
             lastname: user.lastname,
             email: user.email,
+            role: user.role,
+            email_verified: user.email_verified,
         }
     }
 }
@@ -53,6 +61,8 @@ impl From<Option<ProtoUserResponse>> for UserResponse {
                 firstname: "".to_string(),
                 lastname: "".to_string(),
                 email: "".to_string(),
+                role: "".to_string(),
+                email_verified: false,
             },
         }
     }
@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::prelude::FromRow;
+use std::fmt;
+use tracing::warn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    Register,
+    Login,
+    LoginFailed,
+    OidcLogin,
+    TwoFactorLoginFailed,
+    Logout,
+    RefreshTokenReused,
+    PasswordResetRequested,
+    PasswordReset,
+    EmailVerified,
+    UserCreated,
+    UserUpdated,
+    UserDeleted,
+    ProfileUpdated,
+    PasswordChanged,
+    EmailChangeRequested,
+    EmailChanged,
+    CategoryCreated,
+    CategoryUpdated,
+    CategoryDeleted,
+    TagCreated,
+    TagUpdated,
+    TagDeleted,
+    PostCreated,
+    PostUpdated,
+    PostDeleted,
+    PostPublished,
+    CommentCreated,
+    CommentUpdated,
+    CommentDeleted,
+    ApiKeyCreated,
+    ApiKeyRevoked,
+    TwoFactorEnabled,
+    TwoFactorDisabled,
+    RecoveryCodesRegenerated,
+    SessionRevoked,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Register => "auth.register",
+            AuditAction::Login => "auth.login",
+            AuditAction::LoginFailed => "auth.login_failed",
+            AuditAction::OidcLogin => "auth.oidc_login",
+            AuditAction::TwoFactorLoginFailed => "auth.two_factor_failed",
+            AuditAction::Logout => "auth.logout",
+            AuditAction::RefreshTokenReused => "auth.refresh_token_reused",
+            AuditAction::PasswordResetRequested => "auth.password_reset_requested",
+            AuditAction::PasswordReset => "auth.password_reset",
+            AuditAction::EmailVerified => "auth.email_verified",
+            AuditAction::UserCreated => "user.created",
+            AuditAction::UserUpdated => "user.updated",
+            AuditAction::UserDeleted => "user.deleted",
+            AuditAction::ProfileUpdated => "user.profile_updated",
+            AuditAction::PasswordChanged => "user.password_changed",
+            AuditAction::EmailChangeRequested => "user.email_change_requested",
+            AuditAction::EmailChanged => "user.email_changed",
+            AuditAction::CategoryCreated => "category.created",
+            AuditAction::CategoryUpdated => "category.updated",
+            AuditAction::CategoryDeleted => "category.deleted",
+            AuditAction::TagCreated => "tag.created",
+            AuditAction::TagUpdated => "tag.updated",
+            AuditAction::TagDeleted => "tag.deleted",
+            AuditAction::PostCreated => "post.created",
+            AuditAction::PostUpdated => "post.updated",
+            AuditAction::PostDeleted => "post.deleted",
+            AuditAction::PostPublished => "post.published",
+            AuditAction::CommentCreated => "comment.created",
+            AuditAction::CommentUpdated => "comment.updated",
+            AuditAction::CommentDeleted => "comment.deleted",
+            AuditAction::ApiKeyCreated => "api_key.created",
+            AuditAction::ApiKeyRevoked => "api_key.revoked",
+            AuditAction::TwoFactorEnabled => "two_factor.enabled",
+            AuditAction::TwoFactorDisabled => "two_factor.disabled",
+            AuditAction::RecoveryCodesRegenerated => "two_factor.recovery_codes_regenerated",
+            AuditAction::SessionRevoked => "session.revoked",
+        }
+    }
+
+    /// The kind of record the action's `target_id` refers to.
+    pub fn target_type(&self) -> &'static str {
+        match self {
+            AuditAction::CategoryCreated
+            | AuditAction::CategoryUpdated
+            | AuditAction::CategoryDeleted => "category",
+            AuditAction::TagCreated | AuditAction::TagUpdated | AuditAction::TagDeleted => "tag",
+            AuditAction::PostCreated
+            | AuditAction::PostUpdated
+            | AuditAction::PostDeleted
+            | AuditAction::PostPublished => "post",
+            AuditAction::CommentCreated
+            | AuditAction::CommentUpdated
+            | AuditAction::CommentDeleted => "comment",
+            AuditAction::ApiKeyCreated | AuditAction::ApiKeyRevoked => "api_key",
+            AuditAction::Logout | AuditAction::SessionRevoked => "session",
+            _ => "user",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct AuditEvent {
+    pub id: i64,
+    pub actor_id: Option<i32>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAuditEvent {
+    pub actor_id: Option<i32>,
+    pub action: AuditAction,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// What happened to which record, as reported by the service that did it.
+/// Who did it and from where comes from the request's `AuditContext`.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub action: AuditAction,
+    pub target_id: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl AuditEntry {
+    pub fn new(action: AuditAction) -> Self {
+        Self {
+            action,
+            target_id: None,
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn target(mut self, id: impl ToString) -> Self {
+        self.target_id = Some(id.to_string());
+        self
+    }
+
+    pub fn before<T: Serialize>(mut self, snapshot: &T) -> Self {
+        self.before = snapshot_value(snapshot);
+        self
+    }
+
+    pub fn after<T: Serialize>(mut self, snapshot: &T) -> Self {
+        self.after = snapshot_value(snapshot);
+        self
+    }
+}
+
+fn snapshot_value<T: Serialize>(snapshot: &T) -> Option<Value> {
+    match serde_json::to_value(snapshot) {
+        Ok(Value::Null) => None,
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("Failed to serialize audit snapshot: {err}");
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventFilter {
+    pub actor_id: Option<i32>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
@@ -5,4 +5,5 @@ use sqlx::prelude::FromRow;
 pub struct Category {
     pub id: i32,
     pub name: String,
+    pub slug: String,
 }
@@ -7,4 +7,5 @@ pub struct Comment {
     pub id_post_comment: i32,
     pub user_name_comment: String,
     pub comment: String,
+    pub user_id: Option<i32>,
 }
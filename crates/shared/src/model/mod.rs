@@ -1,4 +1,14 @@
+pub mod api_key;
+pub mod audit_event;
 pub mod category;
 pub mod comment;
+pub mod jwt_key;
 pub mod posts;
+pub mod refresh_token;
+pub mod role;
+pub mod session;
+pub mod tag;
+pub mod two_factor;
 pub mod user;
+pub mod user_identity;
+pub mod user_token;
@@ -1,5 +1,256 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PostStatus {
+    Draft,
+    Published,
+    Scheduled,
+    Archived,
+}
+
+impl PostStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Published => "published",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Archived => "archived",
+        }
+    }
+}
+
+impl fmt::Display for PostStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PostStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(PostStatus::Draft),
+            "published" => Ok(PostStatus::Published),
+            "scheduled" => Ok(PostStatus::Scheduled),
+            "archived" => Ok(PostStatus::Archived),
+            other => Err(format!("Unknown post status: {other}")),
+        }
+    }
+}
+
+/// The status a post is saved with and when it went, or goes, public.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostPublication {
+    pub status: PostStatus,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl PostPublication {
+    /// Works out what a create (`current` is `None`) or update asks for.
+    /// An empty `status` publishes a new post and leaves an existing one as it
+    /// is; `published_at` is only read for scheduled posts, which need one in
+    /// the future.
+    pub fn resolve(
+        status: &str,
+        published_at: &str,
+        current: Option<&Post>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let current_status = current.and_then(|post| post.status.parse::<PostStatus>().ok());
+        let current_published_at = current.and_then(|post| post.published_at);
+
+        let status = match (status.trim(), current_status) {
+            ("", Some(status)) => status,
+            ("", None) => PostStatus::Published,
+            (status, _) => status.parse()?,
+        };
+
+        let published_at = match status {
+            PostStatus::Draft => None,
+            PostStatus::Published if current_status == Some(PostStatus::Published) => {
+                current_published_at.or(Some(now))
+            }
+            PostStatus::Published => Some(now),
+            PostStatus::Scheduled => {
+                let at = match published_at.trim() {
+                    "" if current_status == Some(PostStatus::Scheduled) => current_published_at,
+                    "" => None,
+                    value => Some(
+                        DateTime::parse_from_rfc3339(value)
+                            .map_err(|_| "published_at must be an RFC 3339 timestamp".to_string())?
+                            .with_timezone(&Utc),
+                    ),
+                };
+
+                match at {
+                    Some(at) if at > now => Some(at),
+                    Some(_) => return Err("published_at must be in the future".to_string()),
+                    None => return Err("Scheduled posts need a published_at".to_string()),
+                }
+            }
+            PostStatus::Archived => current_published_at,
+        };
+
+        Ok(Self {
+            status,
+            published_at,
+        })
+    }
+}
+
+/// Which posts a listing may include.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostScope {
+    /// Posts anyone can read.
+    Published,
+    /// One author's own posts in any state, optionally narrowed to one.
+    Author {
+        user_id: i32,
+        status: Option<PostStatus>,
+    },
+    /// Everyone's posts in any state, optionally narrowed to one. Only for
+    /// moderators.
+    All { status: Option<PostStatus> },
+}
+
+/// What a post listing can be sorted on. Requests pick from these names
+/// only, so nothing they send ends up in `ORDER BY` as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PostSortField {
+    #[default]
+    CreatedAt,
+    Title,
+    /// Number of comments.
+    Popularity,
+}
+
+impl PostSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostSortField::CreatedAt => "created_at",
+            PostSortField::Title => "title",
+            PostSortField::Popularity => "popularity",
+        }
+    }
+}
+
+impl FromStr for PostSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created_at" => Ok(PostSortField::CreatedAt),
+            "title" => Ok(PostSortField::Title),
+            "popularity" => Ok(PostSortField::Popularity),
+            other => Err(format!(
+                "Unknown sort field: {other}; use created_at, title or popularity"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+impl FromStr for SortDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(SortDirection::Asc),
+            "desc" => Ok(SortDirection::Desc),
+            other => Err(format!("Unknown sort order: {other}; use asc or desc")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostSort {
+    pub field: PostSortField,
+    pub direction: SortDirection,
+}
+
+impl PostSort {
+    /// Reads the `sort` and `order` of a request; `None` when both are empty.
+    /// Without an order, titles run A to Z and the rest largest first.
+    pub fn parse(sort: &str, order: &str) -> Result<Option<Self>, String> {
+        let (sort, order) = (sort.trim(), order.trim());
+
+        if sort.is_empty() && order.is_empty() {
+            return Ok(None);
+        }
+
+        let field = match sort {
+            "" => PostSortField::default(),
+            sort => sort.parse()?,
+        };
+
+        let direction = match (order, field) {
+            ("", PostSortField::Title) => SortDirection::Asc,
+            ("", _) => SortDirection::Desc,
+            (order, _) => order.parse()?,
+        };
+
+        Ok(Some(Self { field, direction }))
+    }
+
+    /// Cursor paging walks `(created_at, id)` newest first and cannot follow
+    /// any other order.
+    pub fn is_newest_first(&self) -> bool {
+        self.field == PostSortField::CreatedAt && self.direction == SortDirection::Desc
+    }
+}
+
+/// What a post listing is narrowed to, and how it is ordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostFilter {
+    /// Full-text query over title and body, in `websearch_to_tsquery` syntax.
+    pub search: Option<String>,
+    /// Slug of a tag the posts must carry.
+    pub tag: Option<String>,
+    pub scope: PostScope,
+    pub category_id: Option<i32>,
+    pub user_id: Option<i32>,
+    /// Only posts created at or after this.
+    pub created_from: Option<DateTime<Utc>>,
+    /// Only posts created before this.
+    pub created_to: Option<DateTime<Utc>>,
+    /// `None` orders by relevance when searching, newest first otherwise.
+    pub sort: Option<PostSort>,
+}
+
+impl PostFilter {
+    /// Everything in `scope`, in the default order.
+    pub fn new(scope: PostScope) -> Self {
+        Self {
+            search: None,
+            tag: None,
+            scope,
+            category_id: None,
+            user_id: None,
+            created_from: None,
+            created_to: None,
+            sort: None,
+        }
+    }
+}
 
 #[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
 pub struct Post {
@@ -10,6 +261,27 @@ pub struct Post {
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
+    pub slug: String,
+    pub status: String,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl Post {
+    pub fn is_published(&self) -> bool {
+        self.status == PostStatus::Published.as_str()
+    }
+}
+
+/// A post matching a full-text search. The highlights still carry the raw
+/// [`HIGHLIGHT_START`](crate::utils::HIGHLIGHT_START) and
+/// [`HIGHLIGHT_STOP`](crate::utils::HIGHLIGHT_STOP) markers.
+#[derive(Debug, FromRow, Clone)]
+pub struct PostSearchHit {
+    #[sqlx(flatten)]
+    pub post: Post,
+    pub rank: f32,
+    pub title_highlight: String,
+    pub snippet: String,
 }
 
 #[derive(Debug, sqlx::FromRow, Serialize, Deserialize, Clone)]
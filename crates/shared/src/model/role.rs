@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Editor,
+    Author,
+    Reader,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Editor => "editor",
+            Role::Author => "author",
+            Role::Reader => "reader",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "admin" => Ok(Role::Admin),
+            "editor" => Ok(Role::Editor),
+            "author" => Ok(Role::Author),
+            "reader" => Ok(Role::Reader),
+            other => Err(format!("Unknown role: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    UsersRead,
+    UsersWrite,
+    UsersDelete,
+    CategoriesWrite,
+    CategoriesDelete,
+    TagsWrite,
+    TagsDelete,
+    PostsWrite,
+    PostsDelete,
+    PostsModerate,
+    CommentsWrite,
+    CommentsModerate,
+    AuditRead,
+}
+
+impl Permission {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::UsersRead => "users:read",
+            Permission::UsersWrite => "users:write",
+            Permission::UsersDelete => "users:delete",
+            Permission::CategoriesWrite => "categories:write",
+            Permission::CategoriesDelete => "categories:delete",
+            Permission::TagsWrite => "tags:write",
+            Permission::TagsDelete => "tags:delete",
+            Permission::PostsWrite => "posts:write",
+            Permission::PostsDelete => "posts:delete",
+            Permission::PostsModerate => "posts:moderate",
+            Permission::CommentsWrite => "comments:write",
+            Permission::CommentsModerate => "comments:moderate",
+            Permission::AuditRead => "audit:read",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+use crate::utils::generate_slug;
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Tag {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+}
+
+/// A tag as it appears on one post.
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct PostTag {
+    pub post_id: i32,
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct TagCount {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub post_count: i64,
+}
+
+/// Most tags a single post can carry.
+pub const MAX_TAGS_PER_POST: usize = 20;
+
+/// Matches the `tags.name` column.
+pub const MAX_TAG_NAME_CHARS: usize = 100;
+
+/// Cleans up tag names as submitted with a post: whitespace is trimmed and
+/// collapsed, blanks are dropped and names that share a slug are kept once,
+/// in the order first given.
+pub fn normalize_tag_names(names: &[String]) -> Result<Vec<String>, String> {
+    let mut seen = Vec::new();
+    let mut normalized = Vec::new();
+
+    for name in names {
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if name.is_empty() {
+            continue;
+        }
+
+        if name.chars().count() > MAX_TAG_NAME_CHARS {
+            return Err(format!(
+                "Tag names must be at most {MAX_TAG_NAME_CHARS} characters"
+            ));
+        }
+
+        let slug = generate_slug(&name);
+
+        if slug.is_empty() {
+            return Err(format!("Tag \"{name}\" needs at least one letter or digit"));
+        }
+
+        if seen.contains(&slug) {
+            continue;
+        }
+
+        seen.push(slug);
+        normalized.push(name);
+    }
+
+    if normalized.len() > MAX_TAGS_PER_POST {
+        return Err(format!("A post can have at most {MAX_TAGS_PER_POST} tags"));
+    }
+
+    Ok(normalized)
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
@@ -8,4 +9,6 @@ pub struct User {
     pub lastname: String,
     pub email: String,
     pub password: String,
+    pub role: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
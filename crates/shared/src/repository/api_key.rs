@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::ApiKeyRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::api_key::{ApiKey, NewApiKey};
+use crate::schema::api_key::ApiKeys;
+use crate::utils::AppError;
+
+const API_KEY_COLUMNS: [ApiKeys; 10] = [
+    ApiKeys::Id,
+    ApiKeys::UserId,
+    ApiKeys::Name,
+    ApiKeys::Prefix,
+    ApiKeys::KeyHash,
+    ApiKeys::Scopes,
+    ApiKeys::LastUsedAt,
+    ApiKeys::ExpiresAt,
+    ApiKeys::RevokedAt,
+    ApiKeys::CreatedAt,
+];
+
+pub struct ApiKeyRepository {
+    db_pool: ConnectionPool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepositoryTrait for ApiKeyRepository {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<ApiKey>, AppError> {
+        let (sql, values) = Query::select()
+            .columns(API_KEY_COLUMNS)
+            .from(ApiKeys::Table)
+            .and_where(Expr::col(ApiKeys::UserId).eq(user_id))
+            .order_by(ApiKeys::CreatedAt, Order::Desc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let keys = sqlx::query_as_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(keys)
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, AppError> {
+        let (sql, values) = Query::select()
+            .columns(API_KEY_COLUMNS)
+            .from(ApiKeys::Table)
+            .and_where(Expr::col(ApiKeys::Prefix).eq(prefix))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let key = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn create(&self, key: &NewApiKey) -> Result<ApiKey, AppError> {
+        info!(
+            "Creating API key {} for user ID: {}",
+            key.prefix, key.user_id
+        );
+
+        let (sql, values) = Query::insert()
+            .into_table(ApiKeys::Table)
+            .columns([
+                ApiKeys::UserId,
+                ApiKeys::Name,
+                ApiKeys::Prefix,
+                ApiKeys::KeyHash,
+                ApiKeys::Scopes,
+                ApiKeys::ExpiresAt,
+            ])
+            .values([
+                key.user_id.into(),
+                key.name.clone().into(),
+                key.prefix.clone().into(),
+                key.key_hash.clone().into(),
+                key.scopes.clone().into(),
+                key.expires_at.into(),
+            ])
+            .unwrap()
+            .returning(Query::returning().columns(API_KEY_COLUMNS))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let key = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn revoke(&self, id: i32, user_id: i32) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(ApiKeys::Table)
+            .value(ApiKeys::RevokedAt, Utc::now())
+            .and_where(Expr::col(ApiKeys::Id).eq(id))
+            .and_where(Expr::col(ApiKeys::UserId).eq(user_id))
+            .and_where(Expr::col(ApiKeys::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            info!("Revoked API key {id} for user ID: {user_id}");
+        }
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn touch(&self, id: i32, used_at: DateTime<Utc>) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(ApiKeys::Table)
+            .value(ApiKeys::LastUsedAt, used_at)
+            .and_where(Expr::col(ApiKeys::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
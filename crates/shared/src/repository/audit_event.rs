@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use sea_query::{Expr, Func, Order, PostgresQueryBuilder, Query, SelectStatement};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::AuditRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::audit_event::{AuditEvent, AuditEventFilter, NewAuditEvent};
+use crate::schema::audit_event::AuditEvents;
+use crate::utils::AppError;
+
+const AUDIT_EVENT_COLUMNS: [AuditEvents; 10] = [
+    AuditEvents::Id,
+    AuditEvents::ActorId,
+    AuditEvents::Action,
+    AuditEvents::TargetType,
+    AuditEvents::TargetId,
+    AuditEvents::IpAddress,
+    AuditEvents::RequestId,
+    AuditEvents::Before,
+    AuditEvents::After,
+    AuditEvents::CreatedAt,
+];
+
+pub struct AuditRepository {
+    db_pool: ConnectionPool,
+}
+
+impl AuditRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+
+    fn apply_filter(query: &mut SelectStatement, filter: &AuditEventFilter) {
+        if let Some(actor_id) = filter.actor_id {
+            query.and_where(Expr::col(AuditEvents::ActorId).eq(actor_id));
+        }
+        if let Some(action) = &filter.action {
+            query.and_where(Expr::col(AuditEvents::Action).eq(action.as_str()));
+        }
+        if let Some(target_type) = &filter.target_type {
+            query.and_where(Expr::col(AuditEvents::TargetType).eq(target_type.as_str()));
+        }
+        if let Some(target_id) = &filter.target_id {
+            query.and_where(Expr::col(AuditEvents::TargetId).eq(target_id.as_str()));
+        }
+        if let Some(from) = filter.from {
+            query.and_where(Expr::col(AuditEvents::CreatedAt).gte(from));
+        }
+        if let Some(to) = filter.to {
+            query.and_where(Expr::col(AuditEvents::CreatedAt).lt(to));
+        }
+    }
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for AuditRepository {
+    async fn create(&self, event: &NewAuditEvent) -> Result<AuditEvent, AppError> {
+        info!(
+            "Recording audit event {} for actor {:?}",
+            event.action, event.actor_id
+        );
+
+        let (sql, values) = Query::insert()
+            .into_table(AuditEvents::Table)
+            .columns([
+                AuditEvents::ActorId,
+                AuditEvents::Action,
+                AuditEvents::TargetType,
+                AuditEvents::TargetId,
+                AuditEvents::IpAddress,
+                AuditEvents::RequestId,
+                AuditEvents::Before,
+                AuditEvents::After,
+            ])
+            .values([
+                event.actor_id.into(),
+                event.action.as_str().into(),
+                event.action.target_type().into(),
+                event.target_id.clone().into(),
+                event.ip_address.clone().into(),
+                event.request_id.clone().into(),
+                event.before.clone().into(),
+                event.after.clone().into(),
+            ])
+            .unwrap()
+            .returning(Query::returning().columns(AUDIT_EVENT_COLUMNS))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let audit_event = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(audit_event)
+    }
+
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &AuditEventFilter,
+    ) -> Result<(Vec<AuditEvent>, i64), AppError> {
+        info!("Getting audit events - page: {page}, page_size: {page_size}, filter: {filter:?}");
+
+        let page = if page > 0 { page } else { 1 };
+        let page_size = if page_size > 0 { page_size } else { 10 };
+
+        let offset = (page - 1) * page_size;
+
+        let mut select_query = Query::select();
+        select_query
+            .columns(AUDIT_EVENT_COLUMNS)
+            .from(AuditEvents::Table)
+            .order_by(AuditEvents::CreatedAt, Order::Desc)
+            .order_by(AuditEvents::Id, Order::Desc)
+            .limit(page_size as u64)
+            .offset(offset as u64);
+        Self::apply_filter(&mut select_query, filter);
+
+        let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
+
+        let events = sqlx::query_as_with::<_, AuditEvent, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        let mut count_query = Query::select();
+        count_query
+            .expr(Func::count(Expr::col(AuditEvents::Id)))
+            .from(AuditEvents::Table);
+        Self::apply_filter(&mut count_query, filter);
+
+        let (count_sql, count_values) = count_query.build_sqlx(PostgresQueryBuilder);
+
+        let (total,) = sqlx::query_as_with::<_, (i64,), _>(&count_sql, count_values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        info!("Found {} audit events out of total {total}", events.len());
+
+        Ok((events, total))
+    }
+}
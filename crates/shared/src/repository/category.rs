@@ -3,13 +3,15 @@ use crate::config::ConnectionPool;
 use crate::domain::{CreateCategoryRequest, UpdateCategoryRequest};
 use crate::model::category::Category;
 use crate::schema::category::Categories;
-use crate::utils::AppError;
+use crate::utils::{AppError, PageCursor, generate_slug, page_offset, slug_matches, unique_slug};
 use anyhow::Result;
 use async_trait::async_trait;
-use sea_query::{Expr, Func, Order, PostgresQueryBuilder, Query};
+use sea_query::{Cond, Expr, Func, Order, PostgresQueryBuilder, Query};
 use sea_query_binder::SqlxBinder;
 use tracing::{error, info};
 
+use super::keyset::{KeysetRow, into_page, page_after};
+
 pub struct CategoryRepository {
     db_pool: ConnectionPool,
 }
@@ -18,6 +20,36 @@ impl CategoryRepository {
     pub fn new(db_pool: ConnectionPool) -> Self {
         Self { db_pool }
     }
+
+    /// Picks a slug for `name` that no other category uses.
+    async fn free_slug(&self, name: &str, category_id: Option<i32>) -> Result<String, AppError> {
+        let base = match generate_slug(name) {
+            slug if slug.is_empty() => "category".to_string(),
+            slug => slug,
+        };
+
+        let mut query = Query::select();
+        query
+            .column(Categories::Slug)
+            .from(Categories::Table)
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col(Categories::Slug).eq(base.as_str()))
+                    .add(Expr::col(Categories::Slug).like(format!("{base}-%"))),
+            );
+
+        if let Some(id) = category_id {
+            query.and_where(Expr::col(Categories::Id).ne(id));
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let taken: Vec<String> = sqlx::query_scalar_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(unique_slug(&base, &taken))
+    }
 }
 
 #[async_trait]
@@ -36,15 +68,15 @@ impl CategoryRepositoryTrait for CategoryRepository {
         let page = if page > 0 { page } else { 1 };
         let page_size = if page_size > 0 { page_size } else { 10 };
 
-        let offset = (page - 1) * page_size;
+        let offset = page_offset(page, page_size);
 
         let mut select_query = Query::select();
         select_query
-            .columns([Categories::Id, Categories::Name])
+            .columns([Categories::Id, Categories::Name, Categories::Slug])
             .from(Categories::Table)
             .order_by(Categories::Id, Order::Asc)
             .limit(page_size as u64)
-            .offset(offset as u64);
+            .offset(offset);
 
         if let Some(term) = &search {
             select_query.and_where(Expr::col(Categories::Name).like(format!("{term}%")));
@@ -95,11 +127,65 @@ impl CategoryRepositoryTrait for CategoryRepository {
         Ok((categories, total))
     }
 
+    async fn find_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        search: Option<String>,
+    ) -> Result<(Vec<Category>, Option<PageCursor>), AppError> {
+        info!(
+            "Getting categories after {after:?} - page_size: {page_size}, search: {:?}",
+            search
+        );
+
+        let mut select_query = Query::select();
+        select_query
+            .columns([
+                Categories::Id,
+                Categories::Name,
+                Categories::Slug,
+                Categories::CreatedAt,
+            ])
+            .from(Categories::Table);
+
+        if let Some(term) = &search {
+            select_query.and_where(Expr::col(Categories::Name).like(format!("{term}%")));
+        }
+
+        page_after(
+            &mut select_query,
+            Categories::CreatedAt,
+            Categories::Id,
+            after,
+            page_size,
+        );
+
+        let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_as_with::<_, KeysetRow<Category>, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| {
+                error!("Error fetching categories: {e}");
+                AppError::SqlxError(e)
+            })?;
+
+        let (categories, next) = into_page(rows, page_size);
+
+        info!(
+            "Found {} categories, more to come: {}",
+            categories.len(),
+            next.is_some()
+        );
+
+        Ok((categories, next))
+    }
+
     async fn find_by_id(&self, id: i32) -> Result<Option<Category>, AppError> {
         info!("Finding category by id: {id}");
 
         let (sql, values) = Query::select()
-            .columns([Categories::Id, Categories::Name])
+            .columns([Categories::Id, Categories::Name, Categories::Slug])
             .from(Categories::Table)
             .and_where(Expr::col(Categories::Id).eq(id))
             .build_sqlx(PostgresQueryBuilder);
@@ -116,11 +202,14 @@ impl CategoryRepositoryTrait for CategoryRepository {
     async fn create(&self, input: &CreateCategoryRequest) -> Result<Category, AppError> {
         info!("Creating new category: {:?}", input.name);
 
+        let slug = self.free_slug(&input.name, None).await?;
+
         let insert = Query::insert()
             .into_table(Categories::Table)
-            .columns([Categories::Name])
-            .values([input.name.clone().into()])
+            .columns([Categories::Name, Categories::Slug])
+            .values([input.name.clone().into(), slug.into()])
             .unwrap()
+            .returning_all()
             .to_owned()
             .build_sqlx(PostgresQueryBuilder);
 
@@ -142,9 +231,22 @@ impl CategoryRepositoryTrait for CategoryRepository {
             input.id, input.name
         );
 
+        let existing = self.find_by_id(input.id).await?.ok_or_else(|| {
+            AppError::NotFound(format!("Category with ID {} not found", input.id))
+        })?;
+
+        let slug = if slug_matches(&existing.slug, &generate_slug(&input.name)) {
+            existing.slug
+        } else {
+            self.free_slug(&input.name, Some(input.id)).await?
+        };
+
         let (sql, values) = Query::update()
             .table(Categories::Table)
-            .values([(Categories::Name, Expr::val(input.name.clone()).into())])
+            .values([
+                (Categories::Name, Expr::val(input.name.clone()).into()),
+                (Categories::Slug, Expr::val(slug).into()),
+            ])
             .and_where(Expr::col(Categories::Id).eq(input.id))
             .build_sqlx(PostgresQueryBuilder);
 
@@ -30,6 +30,7 @@ impl CommentRepositoryTrait for CommentRepository {
                 Comments::IdPostComment,
                 Comments::UserNameComment,
                 Comments::Comment,
+                Comments::UserId,
             ])
             .from(Comments::Table)
             .build_sqlx(PostgresQueryBuilder);
@@ -53,6 +54,7 @@ impl CommentRepositoryTrait for CommentRepository {
                 Comments::IdPostComment,
                 Comments::UserNameComment,
                 Comments::Comment,
+                Comments::UserId,
             ])
             .from(Comments::Table)
             .and_where(Expr::col(Comments::Id).eq(id))
@@ -79,13 +81,16 @@ impl CommentRepositoryTrait for CommentRepository {
                 Comments::IdPostComment,
                 Comments::UserNameComment,
                 Comments::Comment,
+                Comments::UserId,
             ])
             .values([
                 input.id_post_comment.into(),
                 input.user_name_comment.clone().into(),
                 input.comment.clone().into(),
+                input.user_id.into(),
             ])
             .unwrap()
+            .returning_all()
             .to_owned()
             .build_sqlx(PostgresQueryBuilder);
 
@@ -102,20 +107,11 @@ impl CommentRepositoryTrait for CommentRepository {
     }
 
     async fn update(&self, input: &UpdateCommentRequest) -> Result<Comment, AppError> {
-        info!(
-            "Updating comment ID {} with new name '{}'",
-            input.id_post_comment, input.user_name_comment
-        );
+        info!("Updating comment ID {}", input.id_post_comment);
 
         let (sql, values) = Query::update()
             .table(Comments::Table)
-            .values(vec![
-                (
-                    Comments::UserNameComment,
-                    input.user_name_comment.clone().into(),
-                ),
-                (Comments::Comment, input.comment.clone().into()),
-            ])
+            .values(vec![(Comments::Comment, input.comment.clone().into())])
             .and_where(Expr::col(Comments::Id).eq(input.id_post_comment))
             .build_sqlx(PostgresQueryBuilder);
 
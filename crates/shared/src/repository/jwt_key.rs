@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Cond, Expr, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::JwtKeyRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::jwt_key::JwtKey;
+use crate::schema::jwt_key::JwtKeys;
+use crate::utils::AppError;
+
+pub struct JwtKeyRepository {
+    db_pool: ConnectionPool,
+}
+
+impl JwtKeyRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl JwtKeyRepositoryTrait for JwtKeyRepository {
+    async fn find_unretired(&self) -> Result<Vec<JwtKey>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                JwtKeys::Kid,
+                JwtKeys::Algorithm,
+                JwtKeys::PrivateKey,
+                JwtKeys::PublicJwk,
+                JwtKeys::ActivatesAt,
+                JwtKeys::RetiresAt,
+            ])
+            .from(JwtKeys::Table)
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col(JwtKeys::RetiresAt).is_null())
+                    .add(Expr::col(JwtKeys::RetiresAt).gt(Utc::now())),
+            )
+            .order_by(JwtKeys::ActivatesAt, Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let keys = sqlx::query_as_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(keys)
+    }
+
+    async fn create(&self, key: &JwtKey) -> Result<(), AppError> {
+        info!("Storing {} signing key {}", key.algorithm, key.kid);
+
+        let (sql, values) = Query::insert()
+            .into_table(JwtKeys::Table)
+            .columns([
+                JwtKeys::Kid,
+                JwtKeys::Algorithm,
+                JwtKeys::PrivateKey,
+                JwtKeys::PublicJwk,
+                JwtKeys::ActivatesAt,
+            ])
+            .values([
+                key.kid.clone().into(),
+                key.algorithm.clone().into(),
+                key.private_key.clone().into(),
+                key.public_jwk.clone().into(),
+                key.activates_at.into(),
+            ])
+            .unwrap()
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn retire_superseded(
+        &self,
+        current_kid: &str,
+        activated_before: DateTime<Utc>,
+        retires_at: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let (sql, values) = Query::update()
+            .table(JwtKeys::Table)
+            .value(JwtKeys::RetiresAt, retires_at)
+            .and_where(Expr::col(JwtKeys::Kid).ne(current_kid))
+            .and_where(Expr::col(JwtKeys::ActivatesAt).lte(activated_before))
+            .and_where(Expr::col(JwtKeys::RetiresAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_retired(&self) -> Result<u64, AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(JwtKeys::Table)
+            .and_where(Expr::col(JwtKeys::RetiresAt).lte(Utc::now()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
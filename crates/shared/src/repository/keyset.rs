@@ -0,0 +1,60 @@
+use crate::utils::PageCursor;
+use sea_query::{Expr, IntoColumnRef, Order, SelectStatement};
+use sqlx::prelude::FromRow;
+
+/// A listed row together with the `(created_at, id)` it is paged on.
+#[derive(FromRow)]
+pub(super) struct KeysetRow<T> {
+    #[sqlx(flatten)]
+    pub row: T,
+    #[sqlx(flatten)]
+    pub cursor: PageCursor,
+}
+
+/// Orders `query` newest first on `(created_at, id)`, starts it after
+/// `after` and fetches one row more than `page_size`, so that
+/// [`into_page`] can tell whether another page follows.
+pub(super) fn page_after(
+    query: &mut SelectStatement,
+    created_at: impl IntoColumnRef,
+    id: impl IntoColumnRef,
+    after: Option<PageCursor>,
+    page_size: i32,
+) {
+    let created_at = created_at.into_column_ref();
+    let id = id.into_column_ref();
+
+    if let Some(after) = after {
+        query.and_where(
+            Expr::tuple([
+                Expr::col(created_at.clone()).into(),
+                Expr::col(id.clone()).into(),
+            ])
+            .lt(Expr::tuple([
+                Expr::val(after.created_at).into(),
+                Expr::val(after.id).into(),
+            ])),
+        );
+    }
+
+    query
+        .order_by(created_at, Order::Desc)
+        .order_by(id, Order::Desc)
+        .limit(page_size as u64 + 1);
+}
+
+/// Trims the extra row [`page_after`] asked for and returns the cursor of the
+/// next page, if there is one.
+pub(super) fn into_page<T>(
+    mut rows: Vec<KeysetRow<T>>,
+    page_size: i32,
+) -> (Vec<T>, Option<PageCursor>) {
+    let has_more = rows.len() > page_size as usize;
+    rows.truncate(page_size as usize);
+
+    let next = has_more
+        .then(|| rows.last().map(|last| last.cursor))
+        .flatten();
+
+    (rows.into_iter().map(|keyed| keyed.row).collect(), next)
+}
@@ -1,9 +1,30 @@
+mod api_key;
+mod audit_event;
 mod category;
 mod comment;
+mod jwt_key;
+mod keyset;
 mod posts;
+mod refresh_token;
+mod role;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
+mod user_identity;
+mod user_token;
 
+pub use self::api_key::ApiKeyRepository;
+pub use self::audit_event::AuditRepository;
 pub use self::category::CategoryRepository;
 pub use self::comment::CommentRepository;
+pub use self::jwt_key::JwtKeyRepository;
 pub use self::posts::PostRepository;
+pub use self::refresh_token::RefreshTokenRepository;
+pub use self::role::RoleRepository;
+pub use self::session::SessionRepository;
+pub use self::tag::TagRepository;
+pub use self::two_factor::TwoFactorRepository;
 pub use self::user::UserRepository;
+pub use self::user_identity::UserIdentityRepository;
+pub use self::user_token::UserTokenRepository;
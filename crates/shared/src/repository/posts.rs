@@ -3,13 +3,30 @@ use crate::config::ConnectionPool;
 use crate::domain::{CreatePostRequest, PostRelationResponse, UpdatePostRequest};
 use crate::utils::AppError;
 
-use crate::model::posts::{Post, PostRelationModel};
+use crate::model::posts::{
+    Post, PostFilter, PostPublication, PostRelationModel, PostScope, PostSearchHit, PostSort,
+    PostSortField, PostStatus, SortDirection,
+};
 use crate::schema::comment::Comments;
+use crate::schema::post_slug_history::PostSlugHistory;
+use crate::schema::post_tags::PostTags;
 use crate::schema::posts::Posts;
+use crate::schema::tags::Tags;
+use crate::utils::{
+    HIGHLIGHT_START, HIGHLIGHT_STOP, PageCursor, generate_slug, page_offset, slug_matches,
+    unique_slug,
+};
+
+use super::TagRepository;
+use super::keyset::{KeysetRow, into_page, page_after};
 
 use async_trait::async_trait;
-use sea_query::{Expr, Func, JoinType, PostgresQueryBuilder, Query};
+use sea_query::{
+    Alias, Cond, Expr, Func, Iden, JoinType, Order, PostgresQueryBuilder, Query, SelectStatement,
+    SimpleExpr, UnionType,
+};
 use sea_query_binder::SqlxBinder;
+use sqlx::PgConnection;
 use tracing::{error, info};
 
 pub struct PostRepository {
@@ -20,6 +37,214 @@ impl PostRepository {
     pub fn new(db_pool: ConnectionPool) -> Self {
         Self { db_pool }
     }
+
+    /// Picks a slug for `title` that no other post uses or used to use.
+    /// Slugs `post_id` itself has had stay available to it.
+    async fn free_slug(
+        conn: &mut PgConnection,
+        base: &str,
+        post_id: Option<i32>,
+    ) -> Result<String, AppError> {
+        let like = |col: Expr| {
+            Cond::any()
+                .add(col.clone().eq(base))
+                .add(col.like(format!("{base}-%")))
+        };
+
+        let mut current = Query::select();
+        current
+            .column(Posts::Slug)
+            .from(Posts::Table)
+            .cond_where(like(Expr::col(Posts::Slug)));
+
+        let mut previous = Query::select();
+        previous
+            .column(PostSlugHistory::Slug)
+            .from(PostSlugHistory::Table)
+            .cond_where(like(Expr::col(PostSlugHistory::Slug)));
+
+        if let Some(id) = post_id {
+            current.and_where(Expr::col(Posts::Id).ne(id));
+            previous.and_where(Expr::col(PostSlugHistory::PostId).ne(id));
+        }
+
+        let (sql, values) = current
+            .union(UnionType::All, previous)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let taken: Vec<String> = sqlx::query_scalar_with(&sql, values)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        Ok(unique_slug(base, &taken))
+    }
+}
+
+fn post_columns() -> [(Posts, Posts); 10] {
+    [
+        (Posts::Table, Posts::Id),
+        (Posts::Table, Posts::Title),
+        (Posts::Table, Posts::Img),
+        (Posts::Table, Posts::Body),
+        (Posts::Table, Posts::CategoryId),
+        (Posts::Table, Posts::UserId),
+        (Posts::Table, Posts::UserName),
+        (Posts::Table, Posts::Slug),
+        (Posts::Table, Posts::Status),
+        (Posts::Table, Posts::PublishedAt),
+    ]
+}
+
+fn scope_condition(scope: PostScope) -> Cond {
+    match scope {
+        PostScope::Published => Cond::all()
+            .add(Expr::col((Posts::Table, Posts::Status)).eq(PostStatus::Published.as_str())),
+        PostScope::Author { user_id, status } => {
+            let mut cond = Cond::all().add(Expr::col((Posts::Table, Posts::UserId)).eq(user_id));
+
+            if let Some(status) = status {
+                cond = cond.add(Expr::col((Posts::Table, Posts::Status)).eq(status.as_str()));
+            }
+
+            cond
+        }
+        PostScope::All { status } => {
+            let mut cond = Cond::all();
+
+            if let Some(status) = status {
+                cond = cond.add(Expr::col((Posts::Table, Posts::Status)).eq(status.as_str()));
+            }
+
+            cond
+        }
+    }
+}
+
+fn filter_condition(filter: &PostFilter) -> Cond {
+    let mut cond = scope_condition(filter.scope);
+
+    if let Some(search) = &filter.search {
+        cond = cond.add(search_match(search));
+    }
+
+    if let Some(tag) = &filter.tag {
+        cond = cond.add(
+            Expr::col((Posts::Table, Posts::Id)).in_subquery(
+                Query::select()
+                    .column((PostTags::Table, PostTags::PostId))
+                    .from(PostTags::Table)
+                    .join(
+                        JoinType::InnerJoin,
+                        Tags::Table,
+                        Expr::col((Tags::Table, Tags::Id))
+                            .equals((PostTags::Table, PostTags::TagId)),
+                    )
+                    .and_where(Expr::col((Tags::Table, Tags::Slug)).eq(tag.as_str()))
+                    .to_owned(),
+            ),
+        );
+    }
+
+    if let Some(category_id) = filter.category_id {
+        cond = cond.add(Expr::col((Posts::Table, Posts::CategoryId)).eq(category_id));
+    }
+
+    if let Some(user_id) = filter.user_id {
+        cond = cond.add(Expr::col((Posts::Table, Posts::UserId)).eq(user_id));
+    }
+
+    if let Some(from) = filter.created_from {
+        cond = cond.add(Expr::col((Posts::Table, Posts::CreatedAt)).gte(from));
+    }
+
+    if let Some(to) = filter.created_to {
+        cond = cond.add(Expr::col((Posts::Table, Posts::CreatedAt)).lt(to));
+    }
+
+    cond
+}
+
+fn sort_expr(field: PostSortField) -> SimpleExpr {
+    match field {
+        PostSortField::CreatedAt => Expr::col((Posts::Table, Posts::CreatedAt)).into(),
+        PostSortField::Title => Expr::col((Posts::Table, Posts::Title)).into(),
+        PostSortField::Popularity => SimpleExpr::SubQuery(
+            None,
+            Box::new(
+                Query::select()
+                    .expr(Func::count(Expr::col((Comments::Table, Comments::Id))))
+                    .from(Comments::Table)
+                    .and_where(
+                        Expr::col((Comments::Table, Comments::IdPostComment))
+                            .equals((Posts::Table, Posts::Id)),
+                    )
+                    .to_owned()
+                    .into_sub_query_statement(),
+            ),
+        ),
+    }
+}
+
+/// Orders a listing by `filter.sort`, by relevance when searching without
+/// one, and newest first otherwise. Ties always fall back to the ID.
+fn order_listing(query: &mut SelectStatement, filter: &PostFilter) {
+    let sort = match (filter.sort, &filter.search) {
+        (Some(sort), _) => sort,
+        (None, Some(search)) => {
+            query
+                .order_by_expr(search_rank(search), Order::Desc)
+                .order_by((Posts::Table, Posts::Id), Order::Desc);
+            return;
+        }
+        (None, None) => PostSort {
+            field: PostSortField::CreatedAt,
+            direction: SortDirection::Desc,
+        },
+    };
+
+    let order = match sort.direction {
+        SortDirection::Asc => Order::Asc,
+        SortDirection::Desc => Order::Desc,
+    };
+
+    query
+        .order_by_expr(sort_expr(sort.field), order.clone())
+        .order_by((Posts::Table, Posts::Id), order);
+}
+
+// Must match the configuration `posts.search_vector` is generated with, or
+// the GIN index is not used.
+const TSQUERY: &str = "websearch_to_tsquery('english', $1)";
+
+fn search_match(query: &str) -> SimpleExpr {
+    Expr::cust_with_values(format!(r#""posts"."search_vector" @@ {TSQUERY}"#), [query])
+}
+
+fn search_rank(query: &str) -> SimpleExpr {
+    Expr::cust_with_values(
+        format!(r#"ts_rank("posts"."search_vector", {TSQUERY})"#),
+        [query],
+    )
+}
+
+/// `ts_headline` over one column; `options` go after the highlight markers.
+fn search_headline(column: Posts, query: &str, options: &str) -> SimpleExpr {
+    let options = format!("StartSel={HIGHLIGHT_START}, StopSel={HIGHLIGHT_STOP}, {options}");
+
+    Expr::cust_with_values(
+        format!(
+            r#"ts_headline('english', "posts"."{}", {TSQUERY}, $2)"#,
+            column.to_string()
+        ),
+        [query.to_string(), options],
+    )
+}
+
+fn base_slug(title: &str) -> String {
+    match generate_slug(title) {
+        slug if slug.is_empty() => "post".to_string(),
+        slug => slug,
+    }
 }
 
 #[async_trait]
@@ -28,33 +253,21 @@ impl PostsRepositoryTrait for PostRepository {
         &self,
         page: i32,
         page_size: i32,
-        search: Option<String>,
+        filter: &PostFilter,
     ) -> Result<(Vec<Post>, i64), AppError> {
-        info!(
-            "Getting all posts - page: {page}, page_size: {page_size}, search: {:?}",
-            search
-        );
+        info!("Getting all posts - page: {page}, page_size: {page_size}, filter: {filter:?}");
 
-        let offset = (page - 1) * page_size;
+        let offset = page_offset(page, page_size);
 
         let mut select_query = Query::select();
         select_query
-            .columns([
-                (Posts::Table, Posts::Id),
-                (Posts::Table, Posts::Title),
-                (Posts::Table, Posts::Img),
-                (Posts::Table, Posts::Body),
-                (Posts::Table, Posts::CategoryId),
-                (Posts::Table, Posts::UserId),
-                (Posts::Table, Posts::UserName),
-            ])
+            .columns(post_columns())
             .from(Posts::Table)
-            .offset(offset as u64)
+            .cond_where(filter_condition(filter))
+            .offset(offset)
             .limit(page_size as u64);
 
-        if let Some(ref s) = search {
-            select_query.and_where(Expr::col((Posts::Table, Posts::Title)).like(format!("%{s}%")));
-        }
+        order_listing(&mut select_query, filter);
 
         let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
 
@@ -62,16 +275,11 @@ impl PostsRepositoryTrait for PostRepository {
             .fetch_all(&self.db_pool)
             .await?;
 
-        let mut count_query = Query::select();
-        count_query
-            .expr(Func::count(Expr::col(Posts::Id)))
-            .from(Posts::Table);
-
-        if let Some(ref s) = search {
-            count_query.and_where(Expr::col((Posts::Table, Posts::Title)).like(format!("%{s}%")));
-        }
-
-        let (count_sql, count_values) = count_query.build_sqlx(PostgresQueryBuilder);
+        let (count_sql, count_values) = Query::select()
+            .expr(Func::count(Expr::col((Posts::Table, Posts::Id))))
+            .from(Posts::Table)
+            .cond_where(filter_condition(filter))
+            .build_sqlx(PostgresQueryBuilder);
 
         let total_result = sqlx::query_as_with::<_, (i64,), _>(&count_sql, count_values)
             .fetch_one(&self.db_pool)
@@ -90,19 +298,111 @@ impl PostsRepositoryTrait for PostRepository {
         Ok((posts, total))
     }
 
+    async fn get_posts_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        filter: &PostFilter,
+    ) -> Result<(Vec<Post>, Option<PageCursor>), AppError> {
+        info!("Getting posts after {after:?} - page_size: {page_size}, filter: {filter:?}");
+
+        let mut select_query = Query::select();
+        select_query
+            .columns(post_columns())
+            .column((Posts::Table, Posts::CreatedAt))
+            .from(Posts::Table)
+            .cond_where(filter_condition(filter));
+
+        page_after(
+            &mut select_query,
+            (Posts::Table, Posts::CreatedAt),
+            (Posts::Table, Posts::Id),
+            after,
+            page_size,
+        );
+
+        let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_as_with::<_, KeysetRow<Post>, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| {
+                error!("Error fetching posts: {e}");
+                AppError::SqlxError(e)
+            })?;
+
+        let (posts, next) = into_page(rows, page_size);
+
+        info!(
+            "Found {} posts, more to come: {}",
+            posts.len(),
+            next.is_some()
+        );
+
+        Ok((posts, next))
+    }
+
+    async fn search_posts(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &PostFilter,
+    ) -> Result<(Vec<PostSearchHit>, i64), AppError> {
+        info!("Searching posts - page: {page}, page_size: {page_size}, filter: {filter:?}");
+
+        let Some(search) = filter.search.as_deref() else {
+            return Ok((Vec::new(), 0));
+        };
+
+        let offset = page_offset(page, page_size);
+
+        let (sql, values) = Query::select()
+            .columns(post_columns())
+            .expr_as(search_rank(search), Alias::new("rank"))
+            .expr_as(
+                search_headline(Posts::Title, search, "HighlightAll=true"),
+                Alias::new("title_highlight"),
+            )
+            .expr_as(
+                search_headline(
+                    Posts::Body,
+                    search,
+                    "MaxWords=35, MinWords=15, MaxFragments=2",
+                ),
+                Alias::new("snippet"),
+            )
+            .from(Posts::Table)
+            .cond_where(filter_condition(filter))
+            .order_by_expr(search_rank(search), Order::Desc)
+            .order_by((Posts::Table, Posts::Id), Order::Desc)
+            .offset(offset)
+            .limit(page_size as u64)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let hits = sqlx::query_as_with::<_, PostSearchHit, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        let (count_sql, count_values) = Query::select()
+            .expr(Func::count(Expr::col((Posts::Table, Posts::Id))))
+            .from(Posts::Table)
+            .cond_where(filter_condition(filter))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let (total,) = sqlx::query_as_with::<_, (i64,), _>(&count_sql, count_values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        info!("Found {} matching posts out of total {total}", hits.len());
+
+        Ok((hits, total))
+    }
+
     async fn get_post(&self, post_id: i32) -> Result<Option<Post>, AppError> {
         info!("Getting post with ID: {post_id}");
 
         let (sql, values) = Query::select()
-            .columns([
-                Posts::Id,
-                Posts::Title,
-                Posts::Img,
-                Posts::Body,
-                Posts::CategoryId,
-                Posts::UserId,
-                Posts::UserName,
-            ])
+            .columns(post_columns())
             .from(Posts::Table)
             .and_where(Expr::col(Posts::Id).eq(post_id))
             .build_sqlx(PostgresQueryBuilder);
@@ -117,6 +417,37 @@ impl PostsRepositoryTrait for PostRepository {
         Ok(result)
     }
 
+    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<Post>, AppError> {
+        info!("Getting post with slug: {slug}");
+
+        // A slug the post was renamed away from still finds it; the caller
+        // can tell from the returned slug that it should redirect.
+        let (sql, values) = Query::select()
+            .columns(post_columns())
+            .from(Posts::Table)
+            .join(
+                JoinType::LeftJoin,
+                PostSlugHistory::Table,
+                Expr::col((PostSlugHistory::Table, PostSlugHistory::PostId))
+                    .equals((Posts::Table, Posts::Id))
+                    .and(Expr::col((PostSlugHistory::Table, PostSlugHistory::Slug)).eq(slug)),
+            )
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col((Posts::Table, Posts::Slug)).eq(slug))
+                    .add(Expr::col((PostSlugHistory::Table, PostSlugHistory::Slug)).eq(slug)),
+            )
+            .limit(1)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_as_with::<_, Post, _>(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result)
+    }
+
     async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, AppError> {
         info!("Getting post relation with ID: {post_id}");
 
@@ -149,9 +480,17 @@ impl PostsRepositoryTrait for PostRepository {
         Ok(responses)
     }
 
-    async fn create_post(&self, input: &CreatePostRequest) -> Result<Post, AppError> {
+    async fn create_post(
+        &self,
+        input: &CreatePostRequest,
+        publication: &PostPublication,
+        tags: &[String],
+    ) -> Result<Post, AppError> {
         info!("Creating new post: {}", input.title);
 
+        let mut tx = self.db_pool.begin().await?;
+        let slug = Self::free_slug(&mut tx, &base_slug(&input.title), None).await?;
+
         let (sql, values) = Query::insert()
             .into_table(Posts::Table)
             .columns([
@@ -161,6 +500,9 @@ impl PostsRepositoryTrait for PostRepository {
                 Posts::CategoryId,
                 Posts::UserId,
                 Posts::UserName,
+                Posts::Slug,
+                Posts::Status,
+                Posts::PublishedAt,
             ])
             .values([
                 input.title.clone().into(),
@@ -169,25 +511,65 @@ impl PostsRepositoryTrait for PostRepository {
                 input.category_id.into(),
                 input.user_id.into(),
                 input.user_name.clone().into(),
+                slug.into(),
+                publication.status.as_str().into(),
+                publication.published_at.into(),
             ])
             .unwrap()
+            .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
         let post: Post = sqlx::query_as_with(&sql, values)
-            .fetch_one(&self.db_pool)
+            .fetch_one(&mut *tx)
             .await
             .map_err(AppError::SqlxError)?;
 
-        info!("New post inserted with ID: {}", post.id);
+        if !tags.is_empty() {
+            TagRepository::replace_post_tags(&mut tx, post.id, tags).await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "New post inserted with ID: {} and slug: {}",
+            post.id, post.slug
+        );
 
         Ok(post)
     }
 
-    async fn update_post(&self, input: &UpdatePostRequest) -> Result<Post, AppError> {
+    async fn update_post(
+        &self,
+        input: &UpdatePostRequest,
+        publication: &PostPublication,
+        tags: Option<&[String]>,
+    ) -> Result<Post, AppError> {
         info!("Updating post ID {}", input.post_id);
 
         let id = input.post_id;
 
+        let mut tx = self.db_pool.begin().await?;
+
+        let (sql, values) = Query::select()
+            .column(Posts::Slug)
+            .from(Posts::Table)
+            .and_where(Expr::col(Posts::Id).eq(id))
+            .lock(sea_query::LockType::Update)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let current: String = sqlx::query_scalar_with(&sql, values)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Post with ID {id} not found")))?;
+
+        // Only a retitle that changes the slug's words moves the post.
+        let base = base_slug(&input.title);
+        let slug = if slug_matches(&current, &base) {
+            current.clone()
+        } else {
+            Self::free_slug(&mut tx, &base, Some(id)).await?
+        };
+
         let (sql, values) = Query::update()
             .table(Posts::Table)
             .values([
@@ -195,21 +577,70 @@ impl PostsRepositoryTrait for PostRepository {
                 (Posts::Body, input.body.clone().into()),
                 (Posts::Img, input.file.clone().into()),
                 (Posts::CategoryId, input.category_id.into()),
-                (Posts::UserId, input.user_id.into()),
-                (Posts::UserName, input.user_name.clone().into()),
+                (Posts::Slug, slug.clone().into()),
+                (Posts::Status, publication.status.as_str().into()),
+                (Posts::PublishedAt, publication.published_at.into()),
             ])
             .and_where(Expr::col(Posts::Id).eq(id))
+            .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
         let post: Post = sqlx::query_as_with(&sql, values)
-            .fetch_one(&self.db_pool)
+            .fetch_one(&mut *tx)
             .await
             .map_err(AppError::SqlxError)?;
 
+        if slug != current {
+            let (sql, values) = Query::delete()
+                .from_table(PostSlugHistory::Table)
+                .and_where(Expr::col(PostSlugHistory::Slug).eq(slug.as_str()))
+                .build_sqlx(PostgresQueryBuilder);
+
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+            let (sql, values) = Query::insert()
+                .into_table(PostSlugHistory::Table)
+                .columns([PostSlugHistory::Slug, PostSlugHistory::PostId])
+                .values([current.clone().into(), id.into()])
+                .unwrap()
+                .build_sqlx(PostgresQueryBuilder);
+
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+            info!("Post {id} slug changed from {current} to {slug}");
+        }
+
+        if let Some(tags) = tags {
+            TagRepository::replace_post_tags(&mut tx, id, tags).await?;
+        }
+
+        tx.commit().await?;
+
         info!("Post updated with ID: {}", post.id);
 
         Ok(post)
     }
+
+    async fn publish_due_posts(&self) -> Result<Vec<Post>, AppError> {
+        let (sql, values) = Query::update()
+            .table(Posts::Table)
+            .value(Posts::Status, PostStatus::Published.as_str())
+            .and_where(Expr::col(Posts::Status).eq(PostStatus::Scheduled.as_str()))
+            .and_where(Expr::col(Posts::PublishedAt).lte(Expr::current_timestamp()))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let posts = sqlx::query_as_with::<_, Post, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        if !posts.is_empty() {
+            info!("Published {} scheduled posts", posts.len());
+        }
+
+        Ok(posts)
+    }
+
     async fn delete_post(&self, post_id: i32) -> Result<(), AppError> {
         info!("Deleting post ID: {post_id}");
 
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::{info, warn};
+
+use crate::abstract_trait::RefreshTokenRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::refresh_token::RefreshToken;
+use crate::schema::refresh_token::RefreshTokens;
+use crate::utils::AppError;
+
+pub struct RefreshTokenRepository {
+    db_pool: ConnectionPool,
+}
+
+impl RefreshTokenRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepositoryTrait for RefreshTokenRepository {
+    async fn create(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        family_id: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<RefreshToken, AppError> {
+        info!("Creating refresh token for user ID: {user_id}");
+
+        let (sql, values) = Query::insert()
+            .into_table(RefreshTokens::Table)
+            .columns([
+                RefreshTokens::UserId,
+                RefreshTokens::TokenHash,
+                RefreshTokens::FamilyId,
+                RefreshTokens::ExpiresAt,
+            ])
+            .values([
+                user_id.into(),
+                token_hash.into(),
+                family_id.into(),
+                expires_at.into(),
+            ])
+            .unwrap()
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let token: RefreshToken = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(token)
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                RefreshTokens::Id,
+                RefreshTokens::UserId,
+                RefreshTokens::TokenHash,
+                RefreshTokens::FamilyId,
+                RefreshTokens::ExpiresAt,
+                RefreshTokens::RevokedAt,
+                RefreshTokens::ReplacedBy,
+            ])
+            .from(RefreshTokens::Table)
+            .and_where(Expr::col(RefreshTokens::TokenHash).eq(token_hash))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let token = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await?;
+
+        Ok(token)
+    }
+
+    async fn rotate(
+        &self,
+        current: &RefreshToken,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<RefreshToken>, AppError> {
+        info!("Rotating refresh token ID: {}", current.id);
+
+        let mut tx = self.db_pool.begin().await?;
+
+        let (insert_sql, insert_values) = Query::insert()
+            .into_table(RefreshTokens::Table)
+            .columns([
+                RefreshTokens::UserId,
+                RefreshTokens::TokenHash,
+                RefreshTokens::FamilyId,
+                RefreshTokens::ExpiresAt,
+            ])
+            .values([
+                current.user_id.into(),
+                token_hash.into(),
+                current.family_id.clone().into(),
+                expires_at.into(),
+            ])
+            .unwrap()
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let next: RefreshToken = sqlx::query_as_with(&insert_sql, insert_values)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let (update_sql, update_values) = Query::update()
+            .table(RefreshTokens::Table)
+            .values([
+                (RefreshTokens::RevokedAt, Utc::now().into()),
+                (RefreshTokens::ReplacedBy, next.id.into()),
+            ])
+            .and_where(Expr::col(RefreshTokens::Id).eq(current.id))
+            .and_where(Expr::col(RefreshTokens::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&update_sql, update_values)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            warn!(
+                "Refresh token ID: {} was rotated concurrently, discarding replacement",
+                current.id
+            );
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Refresh token ID: {} replaced by ID: {}",
+            current.id, next.id
+        );
+
+        Ok(Some(next))
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<(), AppError> {
+        info!("Revoking refresh token family: {family_id}");
+
+        let (sql, values) = Query::update()
+            .table(RefreshTokens::Table)
+            .value(RefreshTokens::RevokedAt, Utc::now())
+            .and_where(Expr::col(RefreshTokens::FamilyId).eq(family_id))
+            .and_where(Expr::col(RefreshTokens::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError> {
+        info!("Revoking all refresh tokens for user ID: {user_id}");
+
+        let (sql, values) = Query::update()
+            .table(RefreshTokens::Table)
+            .value(RefreshTokens::RevokedAt, Utc::now())
+            .and_where(Expr::col(RefreshTokens::UserId).eq(user_id))
+            .and_where(Expr::col(RefreshTokens::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
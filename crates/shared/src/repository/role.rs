@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use sea_query::{Expr, JoinType, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::RoleRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::schema::role::{Permissions, RolePermissions, Roles};
+use crate::utils::AppError;
+
+pub struct RoleRepository {
+    db_pool: ConnectionPool,
+}
+
+impl RoleRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RoleRepositoryTrait for RoleRepository {
+    async fn find_permissions(&self, role: &str) -> Result<Vec<String>, AppError> {
+        info!("Finding permissions for role: {role}");
+
+        let (sql, values) = Query::select()
+            .column((Permissions::Table, Permissions::Name))
+            .from(Roles::Table)
+            .join(
+                JoinType::InnerJoin,
+                RolePermissions::Table,
+                Expr::col((Roles::Table, Roles::Id))
+                    .equals((RolePermissions::Table, RolePermissions::RoleId)),
+            )
+            .join(
+                JoinType::InnerJoin,
+                Permissions::Table,
+                Expr::col((RolePermissions::Table, RolePermissions::PermissionId))
+                    .equals((Permissions::Table, Permissions::Id)),
+            )
+            .and_where(Expr::col((Roles::Table, Roles::Name)).eq(role))
+            .order_by((Permissions::Table, Permissions::Name), Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let permissions: Vec<String> = sqlx::query_scalar_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(permissions)
+    }
+}
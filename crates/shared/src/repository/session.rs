@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::SessionRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::session::Session;
+use crate::schema::session::Sessions;
+use crate::utils::AppError;
+
+const SESSION_COLUMNS: [Sessions; 7] = [
+    Sessions::Id,
+    Sessions::UserId,
+    Sessions::UserAgent,
+    Sessions::IpAddress,
+    Sessions::CreatedAt,
+    Sessions::LastSeenAt,
+    Sessions::RevokedAt,
+];
+
+pub struct SessionRepository {
+    db_pool: ConnectionPool,
+}
+
+impl SessionRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepositoryTrait for SessionRepository {
+    async fn create(
+        &self,
+        id: &str,
+        user_id: i32,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+    ) -> Result<Session, AppError> {
+        info!("Creating session {id} for user ID: {user_id}");
+
+        let (sql, values) = Query::insert()
+            .into_table(Sessions::Table)
+            .columns([
+                Sessions::Id,
+                Sessions::UserId,
+                Sessions::UserAgent,
+                Sessions::IpAddress,
+            ])
+            .values([
+                id.into(),
+                user_id.into(),
+                user_agent.map(str::to_string).into(),
+                ip_address.map(str::to_string).into(),
+            ])
+            .unwrap()
+            .returning(Query::returning().columns(SESSION_COLUMNS))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let session = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(session)
+    }
+
+    async fn find_active_by_user(
+        &self,
+        user_id: i32,
+        seen_after: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AppError> {
+        let (sql, values) = Query::select()
+            .columns(SESSION_COLUMNS)
+            .from(Sessions::Table)
+            .and_where(Expr::col(Sessions::UserId).eq(user_id))
+            .and_where(Expr::col(Sessions::RevokedAt).is_null())
+            .and_where(Expr::col(Sessions::LastSeenAt).gt(seen_after))
+            .order_by(Sessions::LastSeenAt, Order::Desc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let sessions = sqlx::query_as_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(sessions)
+    }
+
+    async fn touch(&self, id: &str) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(Sessions::Table)
+            .value(Sessions::LastSeenAt, Utc::now())
+            .and_where(Expr::col(Sessions::Id).eq(id))
+            .and_where(Expr::col(Sessions::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke(&self, id: &str, user_id: i32) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(Sessions::Table)
+            .value(Sessions::RevokedAt, Utc::now())
+            .and_where(Expr::col(Sessions::Id).eq(id))
+            .and_where(Expr::col(Sessions::UserId).eq(user_id))
+            .and_where(Expr::col(Sessions::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            info!("Revoked session {id} for user ID: {user_id}");
+        }
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError> {
+        info!("Revoking all sessions for user ID: {user_id}");
+
+        let (sql, values) = Query::update()
+            .table(Sessions::Table)
+            .value(Sessions::RevokedAt, Utc::now())
+            .and_where(Expr::col(Sessions::UserId).eq(user_id))
+            .and_where(Expr::col(Sessions::RevokedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_others(&self, user_id: i32, keep_id: &str) -> Result<Vec<String>, AppError> {
+        info!("Revoking sessions other than {keep_id} for user ID: {user_id}");
+
+        let (sql, values) = Query::update()
+            .table(Sessions::Table)
+            .value(Sessions::RevokedAt, Utc::now())
+            .and_where(Expr::col(Sessions::UserId).eq(user_id))
+            .and_where(Expr::col(Sessions::Id).ne(keep_id))
+            .and_where(Expr::col(Sessions::RevokedAt).is_null())
+            .returning_col(Sessions::Id)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let ids = sqlx::query_scalar_with(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(ids)
+    }
+}
@@ -0,0 +1,341 @@
+use crate::abstract_trait::TagRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::domain::{CreateTagRequest, UpdateTagRequest};
+use crate::model::posts::PostStatus;
+use crate::model::tag::{PostTag, Tag, TagCount};
+use crate::schema::post_tags::PostTags;
+use crate::schema::posts::Posts;
+use crate::schema::tags::Tags;
+use crate::utils::{AppError, generate_slug};
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_query::{Alias, Expr, Func, JoinType, OnConflict, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::PgConnection;
+use tracing::{error, info};
+
+pub struct TagRepository {
+    db_pool: ConnectionPool,
+}
+
+impl TagRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+
+    /// Sets the tags of `post_id` to exactly `names`, creating tags that do
+    /// not exist yet. Names are matched to existing tags by slug, so they
+    /// should already have gone through
+    /// [`normalize_tag_names`](crate::model::tag::normalize_tag_names).
+    pub(super) async fn replace_post_tags(
+        conn: &mut PgConnection,
+        post_id: i32,
+        names: &[String],
+    ) -> Result<(), AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(PostTags::Table)
+            .and_where(Expr::col(PostTags::PostId).eq(post_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        let slugs: Vec<String> = names.iter().map(|name| generate_slug(name)).collect();
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(Tags::Table)
+            .columns([Tags::Name, Tags::Slug]);
+
+        for (name, slug) in names.iter().zip(&slugs) {
+            insert
+                .values([name.clone().into(), slug.clone().into()])
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+        }
+
+        let (sql, values) = insert
+            .on_conflict(OnConflict::column(Tags::Slug).do_nothing().to_owned())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        let (sql, values) = Query::select()
+            .column(Tags::Id)
+            .from(Tags::Table)
+            .and_where(Expr::col(Tags::Slug).is_in(slugs))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let tag_ids: Vec<i32> = sqlx::query_scalar_with(&sql, values)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(PostTags::Table)
+            .columns([PostTags::PostId, PostTags::TagId]);
+
+        for tag_id in tag_ids {
+            insert
+                .values([post_id.into(), tag_id.into()])
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+        }
+
+        let (sql, values) = insert.build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        info!("Post {post_id} tagged with {} tags", names.len());
+
+        Ok(())
+    }
+}
+
+/// Maps a clash on `tags.slug` to a caller-facing error.
+fn map_slug_conflict(err: sqlx::Error, name: &str) -> AppError {
+    match err {
+        sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+            AppError::AlreadyExists(format!("A tag like \"{name}\" already exists"))
+        }
+        err => AppError::from(err),
+    }
+}
+
+fn tag_slug(name: &str) -> Result<String, AppError> {
+    match generate_slug(name) {
+        slug if slug.is_empty() => Err(AppError::InvalidArgument(
+            "Tag name needs at least one letter or digit".to_string(),
+        )),
+        slug => Ok(slug),
+    }
+}
+
+#[async_trait]
+impl TagRepositoryTrait for TagRepository {
+    async fn find_all(
+        &self,
+        page: i32,
+        page_size: i32,
+        search: Option<String>,
+    ) -> Result<(Vec<Tag>, i64), AppError> {
+        info!(
+            "Getting all tags - page: {page}, page_size: {page_size}, search: {:?}",
+            search
+        );
+
+        let page = if page > 0 { page } else { 1 };
+        let page_size = if page_size > 0 { page_size } else { 10 };
+
+        let offset = (page - 1) * page_size;
+
+        let mut select_query = Query::select();
+        select_query
+            .columns([Tags::Id, Tags::Name, Tags::Slug])
+            .from(Tags::Table)
+            .order_by(Tags::Name, Order::Asc)
+            .limit(page_size as u64)
+            .offset(offset as u64);
+
+        if let Some(term) = &search {
+            select_query.and_where(Expr::col(Tags::Name).like(format!("{term}%")));
+        }
+
+        let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
+
+        let tags = sqlx::query_as_with::<_, Tag, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| {
+                error!("Error fetching tags: {e}");
+                AppError::SqlxError(e)
+            })?;
+
+        let mut count_query = Query::select();
+        count_query
+            .expr(Func::count(Expr::col(Tags::Id)))
+            .from(Tags::Table);
+
+        if let Some(term) = &search {
+            count_query.and_where(Expr::col(Tags::Name).like(format!("{term}%")));
+        }
+
+        let (count_sql, count_values) = count_query.build_sqlx(PostgresQueryBuilder);
+
+        let (total,) = sqlx::query_as_with::<_, (i64,), _>(&count_sql, count_values)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|e| {
+                error!("Error counting tags: {e}");
+                AppError::SqlxError(e)
+            })?;
+
+        info!("Found {} tags out of total {total}", tags.len());
+
+        Ok((tags, total))
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Tag>, AppError> {
+        info!("Finding tag by id: {id}");
+
+        let (sql, values) = Query::select()
+            .columns([Tags::Id, Tags::Name, Tags::Slug])
+            .from(Tags::Table)
+            .and_where(Expr::col(Tags::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_as_with::<_, Tag, _>(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result)
+    }
+
+    async fn find_for_posts(&self, post_ids: &[i32]) -> Result<Vec<PostTag>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (sql, values) = Query::select()
+            .column((PostTags::Table, PostTags::PostId))
+            .columns([
+                (Tags::Table, Tags::Id),
+                (Tags::Table, Tags::Name),
+                (Tags::Table, Tags::Slug),
+            ])
+            .from(PostTags::Table)
+            .join(
+                JoinType::InnerJoin,
+                Tags::Table,
+                Expr::col((Tags::Table, Tags::Id)).equals((PostTags::Table, PostTags::TagId)),
+            )
+            .and_where(Expr::col((PostTags::Table, PostTags::PostId)).is_in(post_ids.to_vec()))
+            .order_by((Tags::Table, Tags::Name), Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let tags = sqlx::query_as_with::<_, PostTag, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(tags)
+    }
+
+    async fn tag_cloud(&self, limit: i32) -> Result<Vec<TagCount>, AppError> {
+        info!("Getting tag cloud - limit: {limit}");
+
+        let post_count = Func::count(Expr::col((Posts::Table, Posts::Id)));
+
+        // Only published posts count; a tag used solely on drafts is left out.
+        let (sql, values) = Query::select()
+            .columns([
+                (Tags::Table, Tags::Id),
+                (Tags::Table, Tags::Name),
+                (Tags::Table, Tags::Slug),
+            ])
+            .expr_as(post_count.clone(), Alias::new("post_count"))
+            .from(Tags::Table)
+            .join(
+                JoinType::InnerJoin,
+                PostTags::Table,
+                Expr::col((PostTags::Table, PostTags::TagId)).equals((Tags::Table, Tags::Id)),
+            )
+            .join(
+                JoinType::InnerJoin,
+                Posts::Table,
+                Expr::col((Posts::Table, Posts::Id))
+                    .equals((PostTags::Table, PostTags::PostId))
+                    .and(
+                        Expr::col((Posts::Table, Posts::Status)).eq(PostStatus::Published.as_str()),
+                    ),
+            )
+            .group_by_col((Tags::Table, Tags::Id))
+            .order_by_expr(post_count.into(), Order::Desc)
+            .order_by((Tags::Table, Tags::Name), Order::Asc)
+            .limit(limit.max(1) as u64)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let tags = sqlx::query_as_with::<_, TagCount, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await?;
+
+        Ok(tags)
+    }
+
+    async fn create(&self, input: &CreateTagRequest) -> Result<Tag, AppError> {
+        info!("Creating new tag: {:?}", input.name);
+
+        let slug = tag_slug(&input.name)?;
+
+        let (sql, values) = Query::insert()
+            .into_table(Tags::Table)
+            .columns([Tags::Name, Tags::Slug])
+            .values([input.name.clone().into(), slug.into()])
+            .unwrap()
+            .returning(Query::returning().columns([Tags::Id, Tags::Name, Tags::Slug]))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let tag = sqlx::query_as_with::<_, Tag, _>(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|e| map_slug_conflict(e, &input.name))?;
+
+        info!("New tag inserted with ID: {}", tag.id);
+
+        Ok(tag)
+    }
+
+    async fn update(&self, input: &UpdateTagRequest) -> Result<Tag, AppError> {
+        info!(
+            "Updating tag ID {} with new name '{}'",
+            input.id, input.name
+        );
+
+        let slug = tag_slug(&input.name)?;
+
+        let (sql, values) = Query::update()
+            .table(Tags::Table)
+            .values([
+                (Tags::Name, input.name.clone().into()),
+                (Tags::Slug, slug.into()),
+            ])
+            .and_where(Expr::col(Tags::Id).eq(input.id))
+            .returning(Query::returning().columns([Tags::Id, Tags::Name, Tags::Slug]))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let tag = sqlx::query_as_with::<_, Tag, _>(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(|e| map_slug_conflict(e, &input.name))?
+            .ok_or_else(|| AppError::NotFound(format!("Tag with ID {} not found", input.id)))?;
+
+        info!("Successfully updated tag ID {}", input.id);
+
+        Ok(tag)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), AppError> {
+        info!("Deleting tag with ID: {id}");
+
+        let (sql, values) = Query::delete()
+            .from_table(Tags::Table)
+            .and_where(Expr::col(Tags::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        match result.rows_affected() {
+            0 => {
+                error!("No tag found to delete with ID: {id}");
+                Err(AppError::NotFound(format!("Tag with ID {id} not found")))
+            }
+            _ => {
+                info!("Tag ID: {id} deleted successfully");
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_query::{Expr, OnConflict, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::TwoFactorRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::two_factor::TotpSecret;
+use crate::schema::two_factor::{RecoveryCodes, TotpSecrets};
+use crate::utils::AppError;
+
+pub struct TwoFactorRepository {
+    db_pool: ConnectionPool,
+}
+
+impl TwoFactorRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepositoryTrait for TwoFactorRepository {
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<TotpSecret>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                TotpSecrets::UserId,
+                TotpSecrets::Secret,
+                TotpSecrets::ConfirmedAt,
+                TotpSecrets::LastUsedStep,
+                TotpSecrets::CreatedAt,
+            ])
+            .from(TotpSecrets::Table)
+            .and_where(Expr::col(TotpSecrets::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let secret = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await?;
+
+        Ok(secret)
+    }
+
+    async fn save_pending(&self, user_id: i32, secret: &str) -> Result<TotpSecret, AppError> {
+        info!("Saving pending TOTP secret for user ID: {user_id}");
+
+        let (sql, values) = Query::insert()
+            .into_table(TotpSecrets::Table)
+            .columns([
+                TotpSecrets::UserId,
+                TotpSecrets::Secret,
+                TotpSecrets::ConfirmedAt,
+                TotpSecrets::LastUsedStep,
+            ])
+            .values([
+                user_id.into(),
+                secret.into(),
+                Option::<chrono::DateTime<Utc>>::None.into(),
+                Option::<i64>::None.into(),
+            ])
+            .unwrap()
+            .on_conflict(
+                OnConflict::column(TotpSecrets::UserId)
+                    .update_columns([
+                        TotpSecrets::Secret,
+                        TotpSecrets::ConfirmedAt,
+                        TotpSecrets::LastUsedStep,
+                    ])
+                    .to_owned(),
+            )
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let secret = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(secret)
+    }
+
+    async fn confirm(&self, user_id: i32, step: i64) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(TotpSecrets::Table)
+            .values([
+                (TotpSecrets::ConfirmedAt, Utc::now().into()),
+                (TotpSecrets::LastUsedStep, step.into()),
+            ])
+            .and_where(Expr::col(TotpSecrets::UserId).eq(user_id))
+            .and_where(Expr::col(TotpSecrets::ConfirmedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            info!("Enabled two-factor authentication for user ID: {user_id}");
+        }
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_step(&self, user_id: i32, step: i64) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(TotpSecrets::Table)
+            .value(TotpSecrets::LastUsedStep, step)
+            .and_where(Expr::col(TotpSecrets::UserId).eq(user_id))
+            .and_where(Expr::col(TotpSecrets::ConfirmedAt).is_not_null())
+            .and_where(
+                Expr::col(TotpSecrets::LastUsedStep)
+                    .is_null()
+                    .or(Expr::col(TotpSecrets::LastUsedStep).lt(step)),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete(&self, user_id: i32) -> Result<(), AppError> {
+        info!("Disabling two-factor authentication for user ID: {user_id}");
+
+        let mut tx = self.db_pool.begin().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(RecoveryCodes::Table)
+            .and_where(Expr::col(RecoveryCodes::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(TotpSecrets::Table)
+            .and_where(Expr::col(TotpSecrets::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: i32,
+        code_hashes: &[String],
+    ) -> Result<(), AppError> {
+        info!("Replacing recovery codes for user ID: {user_id}");
+
+        let mut tx = self.db_pool.begin().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(RecoveryCodes::Table)
+            .and_where(Expr::col(RecoveryCodes::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+
+        if !code_hashes.is_empty() {
+            let mut insert = Query::insert();
+            insert
+                .into_table(RecoveryCodes::Table)
+                .columns([RecoveryCodes::UserId, RecoveryCodes::CodeHash]);
+
+            for code_hash in code_hashes {
+                insert
+                    .values([user_id.into(), code_hash.clone().into()])
+                    .unwrap();
+            }
+
+            let (sql, values) = insert.build_sqlx(PostgresQueryBuilder);
+
+            sqlx::query_with(&sql, values).execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn consume_recovery_code(&self, user_id: i32, code_hash: &str) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(RecoveryCodes::Table)
+            .value(RecoveryCodes::UsedAt, Utc::now())
+            .and_where(Expr::col(RecoveryCodes::UserId).eq(user_id))
+            .and_where(Expr::col(RecoveryCodes::CodeHash).eq(code_hash))
+            .and_where(Expr::col(RecoveryCodes::UsedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() > 0 {
+            info!("Consumed recovery code for user ID: {user_id}");
+        }
+
+        Ok(result.rows_affected() > 0)
+    }
+}
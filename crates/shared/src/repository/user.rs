@@ -8,7 +8,9 @@ use crate::config::ConnectionPool;
 use crate::domain::{CreateUserRequest, UpdateUserRequest};
 use crate::model::user::User;
 use crate::schema::user::Users;
-use crate::utils::AppError;
+use crate::utils::{AppError, PageCursor, page_offset};
+
+use super::keyset::{KeysetRow, into_page, page_after};
 
 pub struct UserRepository {
     db_pool: ConnectionPool,
@@ -32,7 +34,7 @@ impl UserRepositoryTrait for UserRepository {
             "Getting all users - page: {page}, page_size: {page_size}, search: {:?}",
             search
         );
-        let offset = (page - 1) * page_size;
+        let offset = page_offset(page, page_size);
 
         let mut select_query = Query::select();
 
@@ -43,11 +45,13 @@ impl UserRepositoryTrait for UserRepository {
                 Users::Lastname,
                 Users::Email,
                 Users::Password,
+                Users::Role,
+                Users::EmailVerifiedAt,
             ])
             .from(Users::Table)
             .order_by(Users::Id, Order::Asc)
             .limit(page_size as u64)
-            .offset(offset as u64);
+            .offset(offset);
 
         if let Some(term) = &search {
             select_query.and_where(Expr::col(Users::Email).like(format!("{term}%")));
@@ -97,6 +101,65 @@ impl UserRepositoryTrait for UserRepository {
         Ok((users, total))
     }
 
+    async fn find_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        search: Option<String>,
+    ) -> Result<(Vec<User>, Option<PageCursor>), AppError> {
+        info!(
+            "Getting users after {after:?} - page_size: {page_size}, search: {:?}",
+            search
+        );
+
+        let mut select_query = Query::select();
+
+        select_query
+            .columns([
+                Users::Id,
+                Users::Firstname,
+                Users::Lastname,
+                Users::Email,
+                Users::Password,
+                Users::Role,
+                Users::EmailVerifiedAt,
+                Users::CreatedAt,
+            ])
+            .from(Users::Table);
+
+        if let Some(term) = &search {
+            select_query.and_where(Expr::col(Users::Email).like(format!("{term}%")));
+        }
+
+        page_after(
+            &mut select_query,
+            Users::CreatedAt,
+            Users::Id,
+            after,
+            page_size,
+        );
+
+        let (sql, values) = select_query.build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_as_with::<_, KeysetRow<User>, _>(&sql, values)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| {
+                error!("Error fetching users: {e}");
+                AppError::SqlxError(e)
+            })?;
+
+        let (users, next) = into_page(rows, page_size);
+
+        info!(
+            "Found {} users, more to come: {}",
+            users.len(),
+            next.is_some()
+        );
+
+        Ok((users, next))
+    }
+
     async fn find_by_email_exists(&self, email: &str) -> Result<bool, AppError> {
         let (sql, values) = Query::select()
             .expr(Expr::col(Users::Id).count())
@@ -127,6 +190,7 @@ impl UserRepositoryTrait for UserRepository {
                 input.password.clone().into(),
             ])
             .unwrap()
+            .returning_all()
             .to_owned()
             .build_sqlx(PostgresQueryBuilder);
 
@@ -145,6 +209,8 @@ impl UserRepositoryTrait for UserRepository {
                 Users::Lastname,
                 Users::Email,
                 Users::Password,
+                Users::Role,
+                Users::EmailVerifiedAt,
             ])
             .from(Users::Table)
             .and_where(Expr::col(Users::Email).eq(email))
@@ -168,6 +234,8 @@ impl UserRepositoryTrait for UserRepository {
                 Users::Lastname,
                 Users::Email,
                 Users::Password,
+                Users::Role,
+                Users::EmailVerifiedAt,
             ])
             .from(Users::Table)
             .and_where(Expr::col(Users::Id).eq(id))
@@ -205,6 +273,14 @@ impl UserRepositoryTrait for UserRepository {
             query = query.value(Users::Email, email.clone());
         }
 
+        if let Some(role) = &input.role {
+            query = query.value(Users::Role, role.clone());
+        }
+
+        if let Some(password) = &input.password {
+            query = query.value(Users::Password, password.clone());
+        }
+
         query = query.returning_all();
 
         let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
@@ -218,6 +294,89 @@ impl UserRepositoryTrait for UserRepository {
         Ok(user)
     }
 
+    async fn update_password(&self, id: i32, password: &str) -> Result<(), AppError> {
+        info!("Updating password for user ID {id}");
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::Password, password)
+            .and_where(Expr::col(Users::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("User with ID {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn mark_email_verified(&self, id: i32) -> Result<(), AppError> {
+        info!("Marking email as verified for user ID {id}");
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::EmailVerifiedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(id))
+            .and_where(Expr::col(Users::EmailVerifiedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_pending_email(&self, id: i32, email: &str) -> Result<(), AppError> {
+        info!("Storing pending email change for user ID {id}");
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::PendingEmail, email)
+            .and_where(Expr::col(Users::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("User with ID {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn confirm_email_change(&self, id: i32) -> Result<Option<User>, AppError> {
+        info!("Confirming email change for user ID {id}");
+
+        let (sql, values) = Query::update()
+            .table(Users::Table)
+            .value(Users::Email, Expr::col(Users::PendingEmail))
+            .value(Users::PendingEmail, Option::<String>::None)
+            .value(Users::EmailVerifiedAt, Expr::current_timestamp())
+            .and_where(Expr::col(Users::Id).eq(id))
+            .and_where(Expr::col(Users::PendingEmail).is_not_null())
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        // The address was free when the change was requested, but someone may
+        // have registered it since.
+        sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                    AppError::EmailAlreadyExists
+                }
+                err => AppError::from(err),
+            })
+    }
+
     async fn delete_user(&self, email: &str) -> Result<(), AppError> {
         info!("Deleting user with email: {}", email);
 
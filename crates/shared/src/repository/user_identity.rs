@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::UserIdentityRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::user_identity::UserIdentity;
+use crate::schema::user_identity::UserIdentities;
+use crate::utils::AppError;
+
+pub struct UserIdentityRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserIdentityRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl UserIdentityRepositoryTrait for UserIdentityRepository {
+    async fn find(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                UserIdentities::Id,
+                UserIdentities::UserId,
+                UserIdentities::Issuer,
+                UserIdentities::Subject,
+                UserIdentities::Email,
+                UserIdentities::CreatedAt,
+                UserIdentities::LastLoginAt,
+            ])
+            .from(UserIdentities::Table)
+            .and_where(Expr::col(UserIdentities::Issuer).eq(issuer))
+            .and_where(Expr::col(UserIdentities::Subject).eq(subject))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let identity = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await?;
+
+        Ok(identity)
+    }
+
+    async fn create(
+        &self,
+        user_id: i32,
+        issuer: &str,
+        subject: &str,
+        email: &str,
+    ) -> Result<UserIdentity, AppError> {
+        info!("Linking identity {subject} from {issuer} to user ID: {user_id}");
+
+        let (sql, values) = Query::insert()
+            .into_table(UserIdentities::Table)
+            .columns([
+                UserIdentities::UserId,
+                UserIdentities::Issuer,
+                UserIdentities::Subject,
+                UserIdentities::Email,
+                UserIdentities::LastLoginAt,
+            ])
+            .values([
+                user_id.into(),
+                issuer.into(),
+                subject.into(),
+                email.into(),
+                Utc::now().into(),
+            ])
+            .unwrap()
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let identity = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(identity)
+    }
+
+    async fn record_login(&self, id: i32, email: &str) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(UserIdentities::Table)
+            .values([
+                (UserIdentities::Email, email.into()),
+                (UserIdentities::LastLoginAt, Utc::now().into()),
+            ])
+            .and_where(Expr::col(UserIdentities::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use tracing::info;
+
+use crate::abstract_trait::UserTokenRepositoryTrait;
+use crate::config::ConnectionPool;
+use crate::model::user_token::{TokenPurpose, UserToken};
+use crate::schema::user_token::UserTokens;
+use crate::utils::AppError;
+
+pub struct UserTokenRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserTokenRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl UserTokenRepositoryTrait for UserTokenRepository {
+    async fn create(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<UserToken, AppError> {
+        info!("Creating {purpose} token for user ID: {user_id}");
+
+        let (sql, values) = Query::insert()
+            .into_table(UserTokens::Table)
+            .columns([
+                UserTokens::UserId,
+                UserTokens::Purpose,
+                UserTokens::TokenHash,
+                UserTokens::ExpiresAt,
+            ])
+            .values([
+                user_id.into(),
+                purpose.as_str().into(),
+                token_hash.into(),
+                expires_at.into(),
+            ])
+            .unwrap()
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let token: UserToken = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.db_pool)
+            .await?;
+
+        Ok(token)
+    }
+
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> Result<Option<UserToken>, AppError> {
+        let now = Utc::now();
+
+        let (sql, values) = Query::update()
+            .table(UserTokens::Table)
+            .value(UserTokens::UsedAt, now)
+            .and_where(Expr::col(UserTokens::TokenHash).eq(token_hash))
+            .and_where(Expr::col(UserTokens::Purpose).eq(purpose.as_str()))
+            .and_where(Expr::col(UserTokens::UsedAt).is_null())
+            .and_where(Expr::col(UserTokens::ExpiresAt).gt(now))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let token: Option<UserToken> = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.db_pool)
+            .await?;
+
+        if let Some(token) = &token {
+            info!("Consumed {purpose} token ID: {}", token.id);
+        }
+
+        Ok(token)
+    }
+
+    async fn invalidate_for_user(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+    ) -> Result<(), AppError> {
+        info!("Invalidating {purpose} tokens for user ID: {user_id}");
+
+        let (sql, values) = Query::update()
+            .table(UserTokens::Table)
+            .value(UserTokens::UsedAt, Utc::now())
+            .and_where(Expr::col(UserTokens::UserId).eq(user_id))
+            .and_where(Expr::col(UserTokens::Purpose).eq(purpose.as_str()))
+            .and_where(Expr::col(UserTokens::UsedAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
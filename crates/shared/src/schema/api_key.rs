@@ -0,0 +1,16 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum ApiKeys {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Prefix,
+    KeyHash,
+    Scopes,
+    LastUsedAt,
+    ExpiresAt,
+    RevokedAt,
+    CreatedAt,
+}
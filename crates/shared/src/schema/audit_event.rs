@@ -0,0 +1,16 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum AuditEvents {
+    Table,
+    Id,
+    ActorId,
+    Action,
+    TargetType,
+    TargetId,
+    IpAddress,
+    RequestId,
+    Before,
+    After,
+    CreatedAt,
+}
@@ -5,4 +5,6 @@ pub enum Categories {
     Table,
     Id,
     Name,
+    Slug,
+    CreatedAt,
 }
@@ -7,4 +7,5 @@ pub enum Comments {
     IdPostComment,
     UserNameComment,
     Comment,
+    UserId,
 }
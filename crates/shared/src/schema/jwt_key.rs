@@ -0,0 +1,12 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum JwtKeys {
+    Table,
+    Kid,
+    Algorithm,
+    PrivateKey,
+    PublicJwk,
+    ActivatesAt,
+    RetiresAt,
+}
@@ -1,4 +1,16 @@
+pub mod api_key;
+pub mod audit_event;
 pub mod category;
 pub mod comment;
+pub mod jwt_key;
+pub mod post_slug_history;
+pub mod post_tags;
 pub mod posts;
+pub mod refresh_token;
+pub mod role;
+pub mod session;
+pub mod tags;
+pub mod two_factor;
 pub mod user;
+pub mod user_identity;
+pub mod user_token;
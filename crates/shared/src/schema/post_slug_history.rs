@@ -0,0 +1,9 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum PostSlugHistory {
+    Table,
+    Slug,
+    PostId,
+    CreatedAt,
+}
@@ -0,0 +1,8 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum PostTags {
+    Table,
+    PostId,
+    TagId,
+}
@@ -10,4 +10,9 @@ pub enum Posts {
     CategoryId,
     UserId,
     UserName,
+    Slug,
+    Status,
+    PublishedAt,
+    SearchVector,
+    CreatedAt,
 }
@@ -0,0 +1,13 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum RefreshTokens {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    FamilyId,
+    ExpiresAt,
+    RevokedAt,
+    ReplacedBy,
+}
@@ -0,0 +1,22 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum Roles {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Debug, Iden)]
+pub enum Permissions {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Debug, Iden)]
+pub enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+}
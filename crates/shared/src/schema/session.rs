@@ -0,0 +1,13 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum Sessions {
+    Table,
+    Id,
+    UserId,
+    UserAgent,
+    IpAddress,
+    CreatedAt,
+    LastSeenAt,
+    RevokedAt,
+}
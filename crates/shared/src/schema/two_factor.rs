@@ -0,0 +1,20 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum TotpSecrets {
+    Table,
+    UserId,
+    Secret,
+    ConfirmedAt,
+    LastUsedStep,
+    CreatedAt,
+}
+
+#[derive(Debug, Iden)]
+pub enum RecoveryCodes {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+}
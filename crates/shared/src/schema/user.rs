@@ -8,4 +8,8 @@ pub enum Users {
     Lastname,
     Email,
     Password,
+    Role,
+    EmailVerifiedAt,
+    PendingEmail,
+    CreatedAt,
 }
@@ -0,0 +1,13 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum UserIdentities {
+    Table,
+    Id,
+    UserId,
+    Issuer,
+    Subject,
+    Email,
+    CreatedAt,
+    LastLoginAt,
+}
@@ -0,0 +1,12 @@
+use sea_query::Iden;
+
+#[derive(Debug, Iden)]
+pub enum UserTokens {
+    Table,
+    Id,
+    UserId,
+    Purpose,
+    TokenHash,
+    ExpiresAt,
+    UsedAt,
+}
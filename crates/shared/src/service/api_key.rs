@@ -0,0 +1,492 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::{
+        ApiKeyServiceTrait, DynApiKeyRepository, DynAuditService, DynRoleRepository,
+        DynUserRepository,
+    },
+    cache::TokenRevocationStore,
+    config::{Claims, JwtConfig},
+    domain::{
+        ApiKeyResponse, ApiResponse, AuditContext, CreateApiKeyRequest, CreatedApiKeyResponse,
+        ErrorResponse,
+    },
+    model::{
+        api_key::{ApiKey, NewApiKey},
+        audit_event::{AuditAction, AuditEntry},
+    },
+    utils::{
+        AppError, Method, Metrics, Status as StatusUtils, TracingContext, generate_random_token,
+        hash_token,
+    },
+};
+
+const API_KEY_PREFIX: &str = "bk";
+
+pub struct ApiKeyServiceDeps {
+    pub repository: DynApiKeyRepository,
+    pub user_repository: DynUserRepository,
+    pub role_repository: DynRoleRepository,
+    pub jwt_config: JwtConfig,
+    pub token_revocation: Arc<TokenRevocationStore>,
+    pub audit: DynAuditService,
+    pub metrics: Arc<Mutex<Metrics>>,
+}
+
+pub struct ApiKeyService {
+    repository: DynApiKeyRepository,
+    user_repository: DynUserRepository,
+    role_repository: DynRoleRepository,
+    jwt_config: JwtConfig,
+    token_revocation: Arc<TokenRevocationStore>,
+    audit: DynAuditService,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl std::fmt::Debug for ApiKeyService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeyService")
+            .field("repository", &"DynApiKeyRepository")
+            .field("user_repository", &"DynUserRepository")
+            .field("role_repository", &"DynRoleRepository")
+            .finish()
+    }
+}
+
+impl ApiKeyService {
+    pub async fn new(deps: ApiKeyServiceDeps, registry: &mut Registry) -> Self {
+        let ApiKeyServiceDeps {
+            repository,
+            user_repository,
+            role_repository,
+            jwt_config,
+            token_revocation,
+            audit,
+            metrics,
+        } = deps;
+
+        registry.register(
+            "api_key_service_request_counter",
+            "Total number of requests to the ApiKeyService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "api_key_service_request_duration",
+            "Histogram of request durations for the ApiKeyService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self {
+            repository,
+            user_repository,
+            role_repository,
+            jwt_config,
+            token_revocation,
+            audit,
+            metrics,
+        }
+    }
+
+    fn granted_scopes(actor: &Claims, requested: &[String]) -> Result<Vec<String>, AppError> {
+        if requested.is_empty() {
+            return Ok(actor.permissions.clone());
+        }
+
+        let mut scopes = Vec::with_capacity(requested.len());
+
+        for scope in requested {
+            if !actor.permissions.contains(scope) {
+                return Err(AppError::Forbidden(format!("Cannot grant scope: {scope}")));
+            }
+
+            if !scopes.contains(scope) {
+                scopes.push(scope.clone());
+            }
+        }
+
+        Ok(scopes)
+    }
+
+    async fn find_active_key(&self, key: &str) -> Result<ApiKey, AppError> {
+        let prefix = key
+            .strip_prefix(API_KEY_PREFIX)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .and_then(|rest| rest.split_once('_'))
+            .map(|(prefix, _)| prefix)
+            .ok_or(AppError::InvalidCredentials)?;
+
+        let api_key = self
+            .repository
+            .find_by_prefix(prefix)
+            .await?
+            .ok_or(AppError::InvalidCredentials)?;
+
+        if api_key.key_hash != hash_token(key) || !api_key.is_active(Utc::now()) {
+            return Err(AppError::InvalidCredentials);
+        }
+
+        Ok(api_key)
+    }
+
+    async fn exchange(&self, key: &str) -> Result<String, AppError> {
+        let api_key = self.find_active_key(key).await?;
+
+        let user = self
+            .user_repository
+            .find_by_id(api_key.user_id)
+            .await?
+            .ok_or(AppError::InvalidCredentials)?;
+
+        let permissions = self
+            .role_repository
+            .find_permissions(&user.role)
+            .await?
+            .into_iter()
+            .filter(|permission| api_key.scopes.contains(permission))
+            .collect();
+
+        let now = Utc::now();
+
+        if api_key
+            .last_used_at
+            .is_none_or(|last_used_at| now - last_used_at >= Duration::minutes(1))
+        {
+            if let Err(err) = self.repository.touch(api_key.id, now).await {
+                warn!("Failed to record use of API key {}: {err}", api_key.prefix);
+            }
+        }
+
+        self.jwt_config.generate_api_key_token(
+            user.id as i64,
+            &user.role,
+            permissions,
+            self.token_revocation
+                .current_generation(user.id as i64)
+                .await?,
+        )
+    }
+
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("api-key-service")
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl ApiKeyServiceTrait for ApiKeyService {
+    async fn create_api_key(
+        &self,
+        actor: &Claims,
+        input: &CreateApiKeyRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<CreatedApiKeyResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "CreateApiKey",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        let scopes = match Self::granted_scopes(actor, &input.scopes) {
+            Ok(scopes) => scopes,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to create API key: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let prefix = Uuid::new_v4().simple().to_string()[..12].to_string();
+        let key = format!("{API_KEY_PREFIX}_{prefix}_{}", generate_random_token());
+        let expires_at = input
+            .expires_in_days
+            .map(|days| Utc::now() + Duration::days(days));
+
+        match self
+            .repository
+            .create(&NewApiKey {
+                user_id: actor.user_id as i32,
+                name: input.name.clone(),
+                prefix: prefix.clone(),
+                key_hash: hash_token(&key),
+                scopes,
+                expires_at,
+            })
+            .await
+        {
+            Ok(api_key) => {
+                let api_key = ApiKeyResponse::from(api_key);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::ApiKeyCreated)
+                            .target(api_key.id)
+                            .after(&api_key),
+                    )
+                    .await;
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key {prefix} created"),
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "API key created successfully".to_string(),
+                    data: CreatedApiKeyResponse { key, api_key },
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to create API key: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn list_api_keys(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<Vec<ApiKeyResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "ListApiKeys",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        match self.repository.find_by_user(actor.user_id as i32).await {
+            Ok(keys) => {
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Retrieved {} API keys", keys.len()),
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "API keys retrieved successfully".to_string(),
+                    data: keys.into_iter().map(ApiKeyResponse::from).collect(),
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to list API keys: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn revoke_api_key(
+        &self,
+        actor: &Claims,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "RevokeApiKey",
+            vec![
+                KeyValue::new("component", "api_key"),
+                KeyValue::new("user.id", actor.user_id),
+                KeyValue::new("api_key.id", id as i64),
+            ],
+        );
+
+        let before = self
+            .repository
+            .find_by_user(actor.user_id as i32)
+            .await
+            .ok()
+            .and_then(|keys| keys.into_iter().find(|key| key.id == id))
+            .map(ApiKeyResponse::from);
+
+        match self.repository.revoke(id, actor.user_id as i32).await {
+            Ok(true) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::ApiKeyRevoked)
+                            .target(id)
+                            .before(&before),
+                    )
+                    .await;
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key {id} revoked"),
+                )
+                .await;
+
+                Ok(Some(ApiResponse {
+                    status: "success".to_string(),
+                    message: "API key revoked successfully".to_string(),
+                    data: (),
+                }))
+            }
+            Ok(false) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Active API key {id} not found"),
+                )
+                .await;
+
+                Ok(None)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke API key {id}: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn authenticate(&self, key: &str) -> Result<ApiResponse<String>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "AuthenticateApiKey",
+            vec![KeyValue::new("component", "api_key")],
+        );
+
+        match self.exchange(key).await {
+            Ok(access_token) => {
+                self.complete_tracing_success(&tracing_ctx, method, "API key authenticated")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "API key authenticated successfully".to_string(),
+                    data: access_token,
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("API key authentication failed: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+}
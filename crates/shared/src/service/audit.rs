@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::{AuditServiceTrait, DynAuditRepository},
+    domain::{
+        ApiResponsePagination, AuditContext, AuditEventResponse, ErrorResponse,
+        FindAllAuditEventRequest, Pagination,
+    },
+    model::audit_event::{AuditEntry, AuditEventFilter, NewAuditEvent},
+    utils::{AppError, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+
+pub struct AuditService {
+    repository: DynAuditRepository,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl std::fmt::Debug for AuditService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditService")
+            .field("repository", &"DynAuditRepository")
+            .finish()
+    }
+}
+
+impl AuditService {
+    pub async fn new(
+        repository: DynAuditRepository,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+    ) -> Self {
+        registry.register(
+            "audit_service_request_counter",
+            "Total number of requests to the AuditService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "audit_service_request_duration",
+            "Histogram of request durations for the AuditService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self {
+            repository,
+            metrics,
+        }
+    }
+
+    fn parse_filter(req: &FindAllAuditEventRequest) -> Result<AuditEventFilter, AppError> {
+        let timestamp = |field: &str, value: &Option<String>| {
+            value
+                .as_deref()
+                .filter(|value| !value.is_empty())
+                .map(|value| {
+                    DateTime::parse_from_rfc3339(value)
+                        .map(|parsed| parsed.with_timezone(&Utc))
+                        .map_err(|_| {
+                            AppError::InvalidArgument(format!(
+                                "{field} must be an RFC 3339 timestamp"
+                            ))
+                        })
+                })
+                .transpose()
+        };
+        let non_empty = |value: &Option<String>| value.clone().filter(|value| !value.is_empty());
+
+        Ok(AuditEventFilter {
+            actor_id: req.actor_id,
+            action: non_empty(&req.action),
+            target_type: non_empty(&req.target_type),
+            target_id: non_empty(&req.target_id),
+            from: timestamp("from", &req.from)?,
+            to: timestamp("to", &req.to)?,
+        })
+    }
+
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("audit-service")
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl AuditServiceTrait for AuditService {
+    async fn record(&self, ctx: &AuditContext, entry: AuditEntry) {
+        let event = NewAuditEvent {
+            actor_id: ctx.actor_id,
+            action: entry.action,
+            target_id: entry.target_id,
+            ip_address: ctx.client.ip_address.clone(),
+            request_id: ctx.client.request_id.clone(),
+            before: entry.before,
+            after: entry.after,
+        };
+
+        if let Err(err) = self.repository.create(&event).await {
+            error!(
+                "Failed to record audit event {} for actor {:?}: {err}",
+                event.action, event.actor_id
+            );
+        }
+    }
+
+    async fn get_audit_events(
+        &self,
+        req: FindAllAuditEventRequest,
+    ) -> Result<ApiResponsePagination<Vec<AuditEventResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let page = if req.page > 0 { req.page } else { 1 };
+        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+
+        let tracing_ctx = self.start_tracing(
+            "GetAuditEvents",
+            vec![
+                KeyValue::new("component", "audit"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+            ],
+        );
+
+        let events = async {
+            let filter = Self::parse_filter(&req)?;
+            self.repository.find_all(page, page_size, &filter).await
+        };
+
+        match events.await {
+            Ok((events, total_items)) => {
+                let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Audit events retrieved successfully",
+                )
+                .await;
+
+                Ok(ApiResponsePagination {
+                    status: "success".to_string(),
+                    message: "Audit events retrieved successfully".to_string(),
+                    data: events.into_iter().map(AuditEventResponse::from).collect(),
+                    pagination: Pagination {
+                        page,
+                        page_size,
+                        total_items,
+                        total_pages,
+                        next_cursor: None,
+                    },
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve audit events: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+}
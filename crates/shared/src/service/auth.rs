@@ -1,53 +1,124 @@
 use async_trait::async_trait;
+use chrono::Utc;
+use jsonwebtoken::jwk::JwkSet;
 use opentelemetry::{
     Context, KeyValue,
     global::{self, BoxedTracer},
     trace::{Span, SpanKind, TraceContextExt, Tracer},
 };
 use prometheus_client::registry::Registry;
+use serde_json::json;
 use std::{sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::Instant};
 use tonic::Request;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+use uuid::Uuid;
 
 use crate::{
-    abstract_trait::{AuthServiceTrait, DynUserRepository},
-    cache::CacheStore,
-    config::{Hashing, JwtConfig},
+    abstract_trait::{
+        AuthServiceTrait, DynAuditService, DynHashing, DynMailer, DynOidcProvider,
+        DynRefreshTokenRepository, DynRoleRepository, DynSessionRepository, DynTwoFactorService,
+        DynUserIdentityRepository, DynUserRepository, DynUserTokenRepository, MailMessage,
+        OidcIdentity,
+    },
+    cache::{CacheStore, LoginAttemptStore, TokenRevocationStore},
+    config::{Claims, EmailVerificationPolicy, JwtConfig, MailConfig, TwoFactorConfig},
     domain::{
-        ApiResponse, CreateUserRequest, ErrorResponse, LoginRequest, RegisterRequest, UserResponse,
+        ApiResponse, AuditContext, ClientInfo, CreateUserRequest, ErrorResponse, LoginRequest,
+        LoginResponse, LogoutRequest, OidcLoginRequest, PasswordResetRequest, RefreshTokenRequest,
+        RegisterRequest, ResendVerificationRequest, ResetPasswordRequest, TokenResponse,
+        TwoFactorChallengeResponse, TwoFactorLoginRequest, UserResponse, VerifyEmailRequest,
+    },
+    model::{
+        audit_event::{AuditAction, AuditEntry},
+        user::User,
+        user_token::TokenPurpose,
+    },
+    utils::{
+        AppError, MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext,
+        generate_random_token, hash_token,
     },
-    utils::{AppError, MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
 
 #[derive(Clone)]
 pub struct AuthService {
     repository: DynUserRepository,
-    hashing: Hashing,
+    refresh_token_repository: DynRefreshTokenRepository,
+    role_repository: DynRoleRepository,
+    user_token_repository: DynUserTokenRepository,
+    hashing: DynHashing,
     jwt_config: JwtConfig,
     metrics: Arc<Mutex<Metrics>>,
     cache_store: Arc<CacheStore>,
+    token_revocation: Arc<TokenRevocationStore>,
+    login_attempts: Arc<LoginAttemptStore>,
+    mailer: DynMailer,
+    mail_config: MailConfig,
+    two_factor: DynTwoFactorService,
+    two_factor_config: TwoFactorConfig,
+    identity_repository: DynUserIdentityRepository,
+    oidc: Option<DynOidcProvider>,
+    session_repository: DynSessionRepository,
+    audit: DynAuditService,
 }
 
 impl std::fmt::Debug for AuthService {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AuthService")
             .field("repository", &"DynUserRepository")
+            .field("refresh_token_repository", &"DynRefreshTokenRepository")
+            .field("role_repository", &"DynRoleRepository")
+            .field("user_token_repository", &"DynUserTokenRepository")
             .field("hashing", &"Hashing")
             .field("jwt_config", &"JwtConfig")
             .finish()
     }
 }
 
+pub struct AuthServiceDeps {
+    pub repository: DynUserRepository,
+    pub refresh_token_repository: DynRefreshTokenRepository,
+    pub role_repository: DynRoleRepository,
+    pub user_token_repository: DynUserTokenRepository,
+    pub hashing: DynHashing,
+    pub jwt_config: JwtConfig,
+    pub metrics: Arc<Mutex<Metrics>>,
+    pub cache_store: Arc<CacheStore>,
+    pub token_revocation: Arc<TokenRevocationStore>,
+    pub login_attempts: Arc<LoginAttemptStore>,
+    pub mailer: DynMailer,
+    pub mail_config: MailConfig,
+    pub two_factor: DynTwoFactorService,
+    pub two_factor_config: TwoFactorConfig,
+    pub identity_repository: DynUserIdentityRepository,
+    pub oidc: Option<DynOidcProvider>,
+    pub session_repository: DynSessionRepository,
+    pub audit: DynAuditService,
+}
+
 impl AuthService {
-    pub async fn new(
-        repository: DynUserRepository,
-        hashing: Hashing,
-        jwt_config: JwtConfig,
-        metrics: Arc<Mutex<Metrics>>,
-        registry: &mut Registry,
-        cache_store: Arc<CacheStore>,
-    ) -> Self {
+    pub async fn new(deps: AuthServiceDeps, registry: &mut Registry) -> Self {
+        let AuthServiceDeps {
+            repository,
+            refresh_token_repository,
+            role_repository,
+            user_token_repository,
+            hashing,
+            jwt_config,
+            metrics,
+            cache_store,
+            token_revocation,
+            login_attempts,
+            mailer,
+            mail_config,
+            two_factor,
+            two_factor_config,
+            identity_repository,
+            oidc,
+            session_repository,
+            audit,
+        } = deps;
+
         registry.register(
             "auth_service_request_counter",
             "Total number of requests to the AuthService",
@@ -58,13 +129,27 @@ impl AuthService {
             "Histogram of request durations for the AuthService",
             metrics.lock().await.request_duration.clone(),
         );
+        login_attempts.register(registry);
 
         Self {
             repository,
+            refresh_token_repository,
+            role_repository,
+            user_token_repository,
             hashing,
             jwt_config,
             metrics,
             cache_store,
+            token_revocation,
+            login_attempts,
+            mailer,
+            mail_config,
+            two_factor,
+            two_factor_config,
+            identity_repository,
+            oidc,
+            session_repository,
+            audit,
         }
     }
 
@@ -72,6 +157,306 @@ impl AuthService {
         global::tracer("auth-service")
     }
 
+    async fn record_audit(&self, client: &ClientInfo, actor_id: Option<i32>, entry: AuditEntry) {
+        let ctx = AuditContext {
+            actor_id,
+            client: client.clone(),
+        };
+
+        self.audit.record(&ctx, entry).await;
+    }
+
+    async fn rehash_password(&self, user: &User, password: &str) {
+        let hashed_password = match self.hashing.hash_password(password).await {
+            Ok(hashed) => hashed,
+            Err(err) => {
+                warn!("Failed to rehash password for user {}: {err}", user.id);
+                return;
+            }
+        };
+
+        match self
+            .repository
+            .update_password(user.id, &hashed_password)
+            .await
+        {
+            Ok(()) => info!("Upgraded password hash for user {}", user.id),
+            Err(err) => warn!(
+                "Failed to store rehashed password for user {}: {err}",
+                user.id
+            ),
+        }
+    }
+
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<(), AppError> {
+        self.token_revocation
+            .bump_generation(user_id as i64)
+            .await?;
+
+        self.refresh_token_repository
+            .revoke_all_for_user(user_id)
+            .await?;
+        self.session_repository.revoke_all_for_user(user_id).await
+    }
+
+    async fn end_session(&self, user_id: i32, session_id: &str) -> Result<(), AppError> {
+        if self.session_repository.revoke(session_id, user_id).await? {
+            self.refresh_token_repository
+                .revoke_family(session_id)
+                .await?;
+            self.token_revocation
+                .revoke_session(
+                    session_id,
+                    self.jwt_config
+                        .access_token_ttl
+                        .to_std()
+                        .map_err(|e| AppError::InternalError(e.to_string()))?,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn issue_user_token(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+        ttl: Duration,
+    ) -> Result<String, AppError> {
+        let token = generate_random_token();
+        let expires_at = Utc::now()
+            + chrono::Duration::from_std(ttl)
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        self.user_token_repository
+            .invalidate_for_user(user_id, purpose)
+            .await?;
+        self.user_token_repository
+            .create(user_id, purpose, &hash_token(&token), expires_at)
+            .await?;
+
+        Ok(token)
+    }
+
+    async fn send_verification_email(&self, user: &User) -> Result<(), AppError> {
+        let token = self
+            .issue_user_token(
+                user.id,
+                TokenPurpose::EmailVerification,
+                self.mail_config.email_verification_ttl,
+            )
+            .await?;
+
+        let message = MailMessage {
+            to: user.email.clone(),
+            subject: "Verify your email address".to_string(),
+            body: format!(
+                "Hello {},\n\nPlease confirm your email address using the link below. It expires in {} hours.\n\n{}/verify-email?token={}\n\nIf you did not create an account, you can ignore this email.",
+                user.firstname,
+                self.mail_config.email_verification_ttl.as_secs() / 3600,
+                self.mail_config.app_base_url,
+                token
+            ),
+        };
+
+        self.mailer.send(&message).await
+    }
+
+    async fn generate_access_token(
+        &self,
+        user: &User,
+        session_id: &str,
+    ) -> Result<String, AppError> {
+        let permissions = self.role_repository.find_permissions(&user.role).await?;
+
+        self.jwt_config.generate_token(
+            user.id as i64,
+            &user.role,
+            permissions,
+            Some(session_id),
+            self.token_revocation
+                .current_generation(user.id as i64)
+                .await?,
+        )
+    }
+
+    async fn issue_tokens(
+        &self,
+        user: &User,
+        client: &ClientInfo,
+    ) -> Result<TokenResponse, AppError> {
+        // Every login starts a session; its id is embedded in the access
+        // tokens and names the refresh token family.
+        let session = self
+            .session_repository
+            .create(
+                &Uuid::new_v4().to_string(),
+                user.id,
+                client.user_agent.as_deref(),
+                client.ip_address.as_deref(),
+            )
+            .await?;
+
+        let access_token = self.generate_access_token(user, &session.id).await?;
+        let refresh_token = generate_random_token();
+
+        self.refresh_token_repository
+            .create(
+                user.id,
+                &hash_token(&refresh_token),
+                &session.id,
+                Utc::now() + self.jwt_config.refresh_token_ttl,
+            )
+            .await?;
+
+        // Only a finished sign-in clears the failure count; a correct password
+        // still waiting on its second factor must not reset it.
+        self.login_attempts.record_success(&user.email).await;
+
+        Ok(self.token_response(access_token, refresh_token))
+    }
+
+    /// The last step of every sign-in: a challenge when the account has a
+    /// second factor, tokens otherwise.
+    async fn complete_login(
+        &self,
+        user: &User,
+        client: &ClientInfo,
+    ) -> Result<LoginResponse, AppError> {
+        if self.two_factor.is_enabled(user.id).await? {
+            let challenge = self.issue_two_factor_challenge(user).await?;
+            return Ok(LoginResponse::TwoFactorRequired(challenge));
+        }
+
+        Ok(LoginResponse::Tokens(
+            self.issue_tokens(user, client).await?,
+        ))
+    }
+
+    async fn issue_two_factor_challenge(
+        &self,
+        user: &User,
+    ) -> Result<TwoFactorChallengeResponse, AppError> {
+        let challenge_token = self
+            .issue_user_token(
+                user.id,
+                TokenPurpose::TwoFactorChallenge,
+                self.two_factor_config.challenge_ttl,
+            )
+            .await?;
+
+        Ok(TwoFactorChallengeResponse {
+            two_factor_required: true,
+            challenge_token,
+            expires_in: self.two_factor_config.challenge_ttl.as_secs() as i64,
+        })
+    }
+
+    async fn resolve_oidc_user(&self, identity: &OidcIdentity) -> Result<User, AppError> {
+        if let Some(linked) = self
+            .identity_repository
+            .find(&identity.issuer, &identity.subject)
+            .await?
+        {
+            self.identity_repository
+                .record_login(
+                    linked.id,
+                    identity.email.as_deref().unwrap_or(&linked.email),
+                )
+                .await?;
+
+            return self
+                .repository
+                .find_by_id(linked.user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("User not found".to_string()));
+        }
+
+        let email = match &identity.email {
+            Some(email) if identity.email_verified => email,
+            _ => {
+                return Err(AppError::Forbidden(
+                    "Identity provider did not supply a verified email address".to_string(),
+                ));
+            }
+        };
+
+        // An unverified local account may have been registered by someone else
+        // who still knows its password, so never link the provider user to it.
+        let user = match self.repository.find_by_email(email).await? {
+            Some(user) if user.email_verified_at.is_some() => user,
+            Some(_) => {
+                return Err(AppError::Forbidden(
+                    "Verify the existing account's email address before signing in with the identity provider"
+                        .to_string(),
+                ));
+            }
+            None => self.provision_oidc_user(identity, email).await?,
+        };
+
+        self.identity_repository
+            .create(user.id, &identity.issuer, &identity.subject, email)
+            .await?;
+
+        info!(
+            "Linked {} identity {} to user {}",
+            identity.issuer, identity.subject, user.id
+        );
+
+        Ok(user)
+    }
+
+    async fn provision_oidc_user(
+        &self,
+        identity: &OidcIdentity,
+        email: &str,
+    ) -> Result<User, AppError> {
+        let (firstname, lastname) = match (&identity.given_name, &identity.family_name) {
+            (Some(given), Some(family)) => (given.clone(), family.clone()),
+            _ => match identity.name.as_deref().map(str::trim) {
+                Some(name) if !name.is_empty() => match name.split_once(' ') {
+                    Some((first, last)) => (first.to_string(), last.trim().to_string()),
+                    None => (name.to_string(), String::new()),
+                },
+                _ => (
+                    email.split('@').next().unwrap_or(email).to_string(),
+                    String::new(),
+                ),
+            },
+        };
+
+        // Random, never disclosed: the account signs in through the provider
+        // until its owner sets a password with the reset flow.
+        let password = self.hashing.hash_password(&generate_random_token()).await?;
+
+        let user = self
+            .repository
+            .create_user(&CreateUserRequest {
+                firstname,
+                lastname,
+                email: email.to_string(),
+                password,
+            })
+            .await?;
+
+        self.repository.mark_email_verified(user.id).await?;
+
+        Ok(User {
+            email_verified_at: Some(Utc::now()),
+            ..user
+        })
+    }
+
+    fn token_response(&self, access_token: String, refresh_token: String) -> TokenResponse {
+        TokenResponse {
+            access_token,
+            refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.jwt_config.access_token_ttl.num_seconds(),
+        }
+    }
+
     fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
@@ -162,6 +547,7 @@ impl AuthServiceTrait for AuthService {
     async fn register_user(
         &self,
         input: &RegisterRequest,
+        client: &ClientInfo,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
         let method = Method::Post;
         let tracing_ctx = self.start_tracing(
@@ -175,25 +561,6 @@ impl AuthServiceTrait for AuthService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        let cache_key = format!("auth:registered:{}", input.email);
-
-        if let Some(cached_user) = self.cache_store.get_from_cache(&cache_key) {
-            info!("Found user in cache");
-
-            self.complete_tracing_success(
-                &tracing_ctx,
-                method,
-                "User already registered (from cache)",
-            )
-            .await;
-
-            return Ok(ApiResponse {
-                status: "success".to_string(),
-                message: "User already registered (from cache)".to_string(),
-                data: cached_user,
-            });
-        }
-
         match self.repository.find_by_email_exists(&input.email).await {
             Ok(true) => {
                 self.complete_tracing_error(&tracing_ctx, method, "Email already exists")
@@ -221,7 +588,7 @@ impl AuthServiceTrait for AuthService {
                     &format!("Password hashing failed: {e}"),
                 )
                 .await;
-                return Err(ErrorResponse::from(AppError::HashingError(e)));
+                return Err(ErrorResponse::from(e));
             }
         };
 
@@ -234,21 +601,28 @@ impl AuthServiceTrait for AuthService {
 
         match self.repository.create_user(&create_user_request).await {
             Ok(user) => {
+                if let Err(err) = self.send_verification_email(&user).await {
+                    warn!("Failed to send verification email to {}: {err}", user.email);
+                }
+
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "User registered successfully".to_string(),
                     data: UserResponse::from(user),
                 };
 
+                self.record_audit(
+                    client,
+                    Some(response.data.id),
+                    AuditEntry::new(AuditAction::Register)
+                        .target(response.data.id)
+                        .after(&response.data),
+                )
+                .await;
+
                 self.complete_tracing_success(&tracing_ctx, method, "User registered successfully")
                     .await;
 
-                self.cache_store.set_to_cache(
-                    &cache_key,
-                    &response.data.clone(),
-                    Duration::from_secs(60),
-                );
-
                 Ok(response)
             }
             Err(err) => {
@@ -264,8 +638,13 @@ impl AuthServiceTrait for AuthService {
         }
     }
 
-    async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse> {
+    async fn login_user(
+        &self,
+        input: &LoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse> {
         let method = Method::Post;
+        let client_ip = client.ip_address.as_deref();
 
         let tracing_ctx = self.start_tracing(
             "LoginUser",
@@ -278,28 +657,33 @@ impl AuthServiceTrait for AuthService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        let cache_key = format!("auth:login:{}", input.email);
-
-        if let Some(cached_token) = self.cache_store.get_from_cache(&cache_key) {
-            info!("Found token in cache");
-
-            self.complete_tracing_success(
-                &tracing_ctx,
-                method,
-                "User already logged in (from cache)",
-            )
-            .await;
-
-            return Ok(ApiResponse {
-                status: "success".to_string(),
-                message: "User already logged in (from cache)".to_string(),
-                data: cached_token,
-            });
+        if let Some(retry_after) = self
+            .login_attempts
+            .locked_for(&input.email, client_ip)
+            .await
+        {
+            self.complete_tracing_error(&tracing_ctx, method, "Login temporarily locked")
+                .await;
+            return Err(ErrorResponse::from(AppError::TooManyRequests(
+                retry_after.as_secs(),
+            )));
         }
 
         let user = match self.repository.find_by_email(&input.email).await {
             Ok(Some(user)) => user,
             Ok(None) => {
+                self.login_attempts
+                    .record_failure(&input.email, client_ip)
+                    .await;
+                self.record_audit(
+                    client,
+                    None,
+                    AuditEntry::new(AuditAction::LoginFailed).after(&json!({
+                        "email": input.email,
+                        "reason": "unknown_email",
+                    })),
+                )
+                .await;
                 self.complete_tracing_error(&tracing_ctx, method, "User not found")
                     .await;
                 return Err(ErrorResponse::from(AppError::NotFound(
@@ -323,12 +707,165 @@ impl AuthServiceTrait for AuthService {
             .await)
             .is_err()
         {
+            self.login_attempts
+                .record_failure(&input.email, client_ip)
+                .await;
+            self.record_audit(
+                client,
+                None,
+                AuditEntry::new(AuditAction::LoginFailed)
+                    .target(user.id)
+                    .after(&json!({ "reason": "invalid_password" })),
+            )
+            .await;
             self.complete_tracing_error(&tracing_ctx, method, "Invalid credentials")
                 .await;
             return Err(ErrorResponse::from(AppError::InvalidCredentials));
         }
 
-        let token = match self.jwt_config.generate_token(user.id as i64) {
+        if self.mail_config.verification_policy.blocks_login() && user.email_verified_at.is_none() {
+            self.complete_tracing_error(&tracing_ctx, method, "Email address not verified")
+                .await;
+            return Err(ErrorResponse::from(AppError::Forbidden(
+                "Email address must be verified before logging in".to_string(),
+            )));
+        }
+
+        if self.hashing.needs_rehash(&user.password) {
+            self.rehash_password(&user, &input.password).await;
+        }
+
+        let data = match self.complete_login(&user, client).await {
+            Ok(data) => data,
+            Err(err) => {
+                self.complete_tracing_error(&tracing_ctx, method, &format!("Login failed: {err}"))
+                    .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let message = match &data {
+            LoginResponse::Tokens(_) => {
+                self.record_audit(
+                    client,
+                    Some(user.id),
+                    AuditEntry::new(AuditAction::Login).target(user.id),
+                )
+                .await;
+                "Login successful"
+            }
+            LoginResponse::TwoFactorRequired(_) => "Two-factor authentication required",
+        };
+
+        self.complete_tracing_success(&tracing_ctx, method, message)
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: message.to_string(),
+            data,
+        })
+    }
+
+    async fn verify_two_factor_login(
+        &self,
+        input: &TwoFactorLoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let client_ip = client.ip_address.as_deref();
+
+        let tracing_ctx = self.start_tracing(
+            "VerifyTwoFactorLogin",
+            vec![KeyValue::new("component", "auth")],
+        );
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let challenge = match self
+            .user_token_repository
+            .consume(
+                TokenPurpose::TwoFactorChallenge,
+                &hash_token(&input.challenge_token),
+            )
+            .await
+        {
+            Ok(Some(challenge)) => challenge,
+            Ok(None) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    "Two-factor challenge is invalid, expired or already used",
+                )
+                .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error consuming two-factor challenge: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let user = match self.repository.find_by_id(challenge.user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                self.complete_tracing_error(&tracing_ctx, method, "User not found")
+                    .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error finding user: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if let Some(retry_after) = self.login_attempts.locked_for(&user.email, client_ip).await {
+            self.complete_tracing_error(&tracing_ctx, method, "Login temporarily locked")
+                .await;
+            return Err(ErrorResponse::from(AppError::TooManyRequests(
+                retry_after.as_secs(),
+            )));
+        }
+
+        match self.two_factor.verify_code(user.id, &input.code).await {
+            Ok(true) => (),
+            Ok(false) => {
+                self.login_attempts
+                    .record_failure(&user.email, client_ip)
+                    .await;
+                self.record_audit(
+                    client,
+                    None,
+                    AuditEntry::new(AuditAction::TwoFactorLoginFailed).target(user.id),
+                )
+                .await;
+                self.complete_tracing_error(&tracing_ctx, method, "Invalid two-factor code")
+                    .await;
+                return Err(ErrorResponse::from(AppError::InvalidTwoFactorCode));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error verifying two-factor code: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        }
+
+        let token = match self.issue_tokens(&user, client).await {
             Ok(token) => token,
             Err(err) => {
                 self.complete_tracing_error(
@@ -341,8 +878,14 @@ impl AuthServiceTrait for AuthService {
             }
         };
 
-        self.cache_store
-            .set_to_cache(&input.email, &token, Duration::from_secs(60));
+        self.record_audit(
+            client,
+            Some(user.id),
+            AuditEntry::new(AuditAction::Login)
+                .target(user.id)
+                .after(&json!({ "two_factor": true })),
+        )
+        .await;
 
         let response = ApiResponse {
             status: "success".to_string(),
@@ -350,13 +893,745 @@ impl AuthServiceTrait for AuthService {
             data: token,
         };
 
-        self.complete_tracing_success(&tracing_ctx, method, "Login successful")
+        self.complete_tracing_success(&tracing_ctx, method, "Two-factor login successful")
             .await;
 
         Ok(response)
     }
 
-    fn verify_token(&self, token: &str) -> Result<i64, AppError> {
-        self.jwt_config.verify_token(token)
+    async fn login_with_oidc(
+        &self,
+        input: &OidcLoginRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<LoginResponse>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx =
+            self.start_tracing("LoginWithOidc", vec![KeyValue::new("component", "auth")]);
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let Some(oidc) = &self.oidc else {
+            self.complete_tracing_error(&tracing_ctx, method, "OIDC login is not configured")
+                .await;
+            return Err(ErrorResponse::from(AppError::Forbidden(
+                "OpenID Connect login is not configured".to_string(),
+            )));
+        };
+
+        let login = async {
+            let identity = oidc.verify_id_token(&input.id_token, &input.nonce).await?;
+
+            // A valid ID token stays valid until it expires; whoever gets
+            // hold of one must not be able to sign in with it a second time.
+            if !self
+                .token_revocation
+                .consume_id_token(&identity.issuer, &identity.token_id, identity.expires_at)
+                .await?
+            {
+                return Err(AppError::OidcError(
+                    "ID token has already been used".to_string(),
+                ));
+            }
+
+            let user = self.resolve_oidc_user(&identity).await?;
+            let data = self.complete_login(&user, client).await?;
+            Ok::<_, AppError>((user, identity, data))
+        };
+
+        match login.await {
+            Ok((user, identity, data)) => {
+                let message = match data {
+                    LoginResponse::Tokens(_) => {
+                        self.record_audit(
+                            client,
+                            Some(user.id),
+                            AuditEntry::new(AuditAction::OidcLogin)
+                                .target(user.id)
+                                .after(&json!({
+                                    "issuer": identity.issuer,
+                                    "subject": identity.subject,
+                                })),
+                        )
+                        .await;
+
+                        "Login successful"
+                    }
+                    LoginResponse::TwoFactorRequired(_) => "Two-factor authentication required",
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, message)
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: message.to_string(),
+                    data,
+                })
+            }
+            Err(err) => {
+                self.record_audit(
+                    client,
+                    None,
+                    AuditEntry::new(AuditAction::LoginFailed).after(&json!({
+                        "method": "oidc",
+                        "reason": err.to_string(),
+                    })),
+                )
+                .await;
+
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("OIDC login failed: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn refresh_token(
+        &self,
+        input: &RefreshTokenRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<TokenResponse>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx =
+            self.start_tracing("RefreshToken", vec![KeyValue::new("component", "auth")]);
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let current = match self
+            .refresh_token_repository
+            .find_by_hash(&hash_token(&input.refresh_token))
+            .await
+        {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                self.complete_tracing_error(&tracing_ctx, method, "Refresh token not found")
+                    .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error finding refresh token: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if current.revoked_at.is_some() {
+            warn!(
+                "Refresh token reuse detected for user ID: {}, revoking family {}",
+                current.user_id, current.family_id
+            );
+
+            if let Err(err) = self
+                .refresh_token_repository
+                .revoke_family(&current.family_id)
+                .await
+            {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke refresh token family: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+
+            self.record_audit(
+                client,
+                None,
+                AuditEntry::new(AuditAction::RefreshTokenReused)
+                    .target(current.user_id)
+                    .after(&json!({ "session_id": current.family_id })),
+            )
+            .await;
+
+            self.complete_tracing_error(&tracing_ctx, method, "Refresh token reuse detected")
+                .await;
+            return Err(ErrorResponse::from(AppError::RefreshTokenReused));
+        }
+
+        if current.expires_at <= Utc::now() {
+            self.complete_tracing_error(&tracing_ctx, method, "Refresh token expired")
+                .await;
+            return Err(ErrorResponse::from(AppError::TokenExpiredError));
+        }
+
+        match self.session_repository.touch(&current.family_id).await {
+            Ok(true) => (),
+            Ok(false) => {
+                let _ = self
+                    .refresh_token_repository
+                    .revoke_family(&current.family_id)
+                    .await;
+
+                self.complete_tracing_error(&tracing_ctx, method, "Session has been revoked")
+                    .await;
+                return Err(ErrorResponse::from(AppError::TokenRevoked));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error updating session: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        }
+
+        let refresh_token = generate_random_token();
+
+        let rotated = match self
+            .refresh_token_repository
+            .rotate(
+                &current,
+                &hash_token(&refresh_token),
+                Utc::now() + self.jwt_config.refresh_token_ttl,
+            )
+            .await
+        {
+            Ok(rotated) => rotated,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Refresh token rotation failed: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if rotated.is_none() {
+            let _ = self
+                .refresh_token_repository
+                .revoke_family(&current.family_id)
+                .await;
+
+            self.record_audit(
+                client,
+                None,
+                AuditEntry::new(AuditAction::RefreshTokenReused)
+                    .target(current.user_id)
+                    .after(&json!({ "session_id": current.family_id })),
+            )
+            .await;
+
+            self.complete_tracing_error(&tracing_ctx, method, "Refresh token reuse detected")
+                .await;
+            return Err(ErrorResponse::from(AppError::RefreshTokenReused));
+        }
+
+        let user = match self.repository.find_by_id(current.user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                self.complete_tracing_error(&tracing_ctx, method, "User not found")
+                    .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error finding user: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let access_token = match self.generate_access_token(&user, &current.family_id).await {
+            Ok(token) => token,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Token generation failed: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Token refreshed successfully".to_string(),
+            data: self.token_response(access_token, refresh_token),
+        };
+
+        self.complete_tracing_success(&tracing_ctx, method, "Token refreshed successfully")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn logout(
+        &self,
+        access_token: &str,
+        input: &LogoutRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx = self.start_tracing(
+            "Logout",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("all_devices", input.all_devices),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let claims = match self.jwt_config.decode_token(access_token) {
+            Ok(claims) => claims,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Invalid access token: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if input.all_devices {
+            if let Err(err) = claims.require_interactive() {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    "API key tokens cannot sign out every device",
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        }
+
+        if let Err(err) = self.token_revocation.revoke(&claims).await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to revoke access token: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        if let Some(session_id) = &claims.sid {
+            if let Err(err) = self.end_session(claims.user_id as i32, session_id).await {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to end session: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        }
+
+        if let Some(refresh_token) = &input.refresh_token {
+            match self
+                .refresh_token_repository
+                .find_by_hash(&hash_token(refresh_token))
+                .await
+            {
+                Ok(Some(token)) if token.user_id as i64 == claims.user_id => {
+                    if let Err(err) = self
+                        .refresh_token_repository
+                        .revoke_family(&token.family_id)
+                        .await
+                    {
+                        self.complete_tracing_error(
+                            &tracing_ctx,
+                            method,
+                            &format!("Failed to revoke refresh token: {err}"),
+                        )
+                        .await;
+                        return Err(ErrorResponse::from(err));
+                    }
+                }
+                Ok(_) => warn!("Ignoring unknown refresh token on logout"),
+                Err(err) => {
+                    self.complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        &format!("Error finding refresh token: {err}"),
+                    )
+                    .await;
+                    return Err(ErrorResponse::from(err));
+                }
+            }
+        }
+
+        if input.all_devices {
+            if let Err(err) = self.revoke_all_sessions(claims.user_id as i32).await {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke sessions: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        }
+
+        let mut entry = AuditEntry::new(AuditAction::Logout)
+            .after(&json!({ "all_devices": input.all_devices }));
+        if let Some(session_id) = &claims.sid {
+            entry = entry.target(session_id);
+        }
+        self.record_audit(client, Some(claims.user_id as i32), entry)
+            .await;
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Logged out successfully".to_string(),
+            data: (),
+        };
+
+        self.complete_tracing_success(&tracing_ctx, method, "Logged out successfully")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn request_password_reset(
+        &self,
+        input: &PasswordResetRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx = self.start_tracing(
+            "RequestPasswordReset",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("user.email", input.email.clone()),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "If the account exists, a password reset link has been sent".to_string(),
+            data: (),
+        };
+
+        let user = match self.repository.find_by_email(&input.email).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                self.complete_tracing_success(&tracing_ctx, method, "Unknown email, nothing sent")
+                    .await;
+                return Ok(response);
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error finding user: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let token = match self
+            .issue_user_token(
+                user.id,
+                TokenPurpose::PasswordReset,
+                self.mail_config.password_reset_ttl,
+            )
+            .await
+        {
+            Ok(token) => token,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to store reset token: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let message = MailMessage {
+            to: user.email.clone(),
+            subject: "Reset your password".to_string(),
+            body: format!(
+                "Hello {},\n\nUse the link below to reset your password. It expires in {} minutes and can only be used once.\n\n{}/reset-password?token={}\n\nIf you did not request this, you can ignore this email.",
+                user.firstname,
+                self.mail_config.password_reset_ttl.as_secs() / 60,
+                self.mail_config.app_base_url,
+                token
+            ),
+        };
+
+        if let Err(err) = self.mailer.send(&message).await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to send reset email: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        self.record_audit(
+            client,
+            None,
+            AuditEntry::new(AuditAction::PasswordResetRequested).target(user.id),
+        )
+        .await;
+
+        self.complete_tracing_success(&tracing_ctx, method, "Password reset email sent")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn reset_password(
+        &self,
+        input: &ResetPasswordRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx =
+            self.start_tracing("ResetPassword", vec![KeyValue::new("component", "auth")]);
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let token = match self
+            .user_token_repository
+            .consume(TokenPurpose::PasswordReset, &hash_token(&input.token))
+            .await
+        {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    "Reset token is invalid, expired or already used",
+                )
+                .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error consuming reset token: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let updated = async {
+            let hashed_password = self.hashing.hash_password(&input.new_password).await?;
+            self.repository
+                .update_password(token.user_id, &hashed_password)
+                .await?;
+            self.revoke_all_sessions(token.user_id).await
+        };
+
+        if let Err(err) = updated.await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to reset password: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        self.record_audit(
+            client,
+            Some(token.user_id),
+            AuditEntry::new(AuditAction::PasswordReset).target(token.user_id),
+        )
+        .await;
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Password has been reset".to_string(),
+            data: (),
+        };
+
+        self.complete_tracing_success(&tracing_ctx, method, "Password reset successfully")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn verify_email(
+        &self,
+        input: &VerifyEmailRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx =
+            self.start_tracing("VerifyEmail", vec![KeyValue::new("component", "auth")]);
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let token = match self
+            .user_token_repository
+            .consume(TokenPurpose::EmailVerification, &hash_token(&input.token))
+            .await
+        {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    "Verification token is invalid, expired or already used",
+                )
+                .await;
+                return Err(ErrorResponse::from(AppError::TokenValidationError));
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error consuming verification token: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if let Err(err) = self.repository.mark_email_verified(token.user_id).await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to mark email as verified: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        // The content guard reads the cached user, which would otherwise keep
+        // reporting the address as unverified until it expires.
+        self.cache_store
+            .delete_from_cache(&format!("user:id={}", token.user_id));
+
+        self.record_audit(
+            client,
+            Some(token.user_id),
+            AuditEntry::new(AuditAction::EmailVerified).target(token.user_id),
+        )
+        .await;
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Email address verified".to_string(),
+            data: (),
+        };
+
+        self.complete_tracing_success(&tracing_ctx, method, "Email verified successfully")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn resend_verification(
+        &self,
+        input: &ResendVerificationRequest,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx = self.start_tracing(
+            "ResendVerification",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("user.email", input.email.clone()),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "If the account exists and is unverified, a verification link has been sent"
+                .to_string(),
+            data: (),
+        };
+
+        let user = match self.repository.find_by_email(&input.email).await {
+            Ok(Some(user)) if user.email_verified_at.is_none() => user,
+            Ok(_) => {
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Unknown or already verified email, nothing sent",
+                )
+                .await;
+                return Ok(response);
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error finding user: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        if let Err(err) = self.send_verification_email(&user).await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to send verification email: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        self.complete_tracing_success(&tracing_ctx, method, "Verification email sent")
+            .await;
+
+        Ok(response)
+    }
+
+    fn verification_policy(&self) -> EmailVerificationPolicy {
+        self.mail_config.verification_policy
+    }
+
+    fn jwks(&self) -> JwkSet {
+        self.jwt_config.jwks()
+    }
+
+    async fn verify_token(&self, token: &str) -> Result<Claims, AppError> {
+        let claims = self.jwt_config.decode_token(token)?;
+
+        if self.token_revocation.is_revoked(&claims).await? {
+            return Err(AppError::TokenRevoked);
+        }
+
+        Ok(claims)
     }
 }
@@ -1,11 +1,15 @@
 use crate::{
-    abstract_trait::{CategoryServiceTrait, DynCategoryRepository},
+    abstract_trait::{CategoryServiceTrait, DynAuditService, DynCategoryRepository},
     cache::CacheStore,
     domain::{
-        ApiResponse, ApiResponsePagination, CategoryResponse, CreateCategoryRequest, ErrorResponse,
-        FindAllCategoryRequest, Pagination, UpdateCategoryRequest,
+        ApiResponse, ApiResponsePagination, AuditContext, CategoryResponse, CreateCategoryRequest,
+        ErrorResponse, FindAllCategoryRequest, Pagination, UpdateCategoryRequest,
+    },
+    model::audit_event::{AuditAction, AuditEntry},
+    utils::{
+        MetadataInjector, Method, Metrics, Paging, Status as StatusUtils, TracingContext,
+        clamp_page_size,
     },
-    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
 use async_trait::async_trait;
 use opentelemetry::{
@@ -22,6 +26,7 @@ use tracing::{error, info};
 #[derive(Clone)]
 pub struct CategoryService {
     repository: DynCategoryRepository,
+    audit: DynAuditService,
     metrics: Arc<Mutex<Metrics>>,
     cache_store: Arc<CacheStore>,
 }
@@ -37,6 +42,7 @@ impl std::fmt::Debug for CategoryService {
 impl CategoryService {
     pub async fn new(
         repository: DynCategoryRepository,
+        audit: DynAuditService,
         metrics: Arc<Mutex<Metrics>>,
         registry: &mut Registry,
         cache_store: Arc<CacheStore>,
@@ -54,6 +60,7 @@ impl CategoryService {
 
         Self {
             repository,
+            audit,
             metrics,
             cache_store,
         }
@@ -157,7 +164,11 @@ impl CategoryServiceTrait for CategoryService {
         let method = Method::Get;
 
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = if req.page_size > 0 {
+            clamp_page_size(req.page_size)
+        } else {
+            10
+        };
         let search = if req.search.is_empty() {
             None
         } else {
@@ -171,6 +182,7 @@ impl CategoryServiceTrait for CategoryService {
                 KeyValue::new("page", page.to_string()),
                 KeyValue::new("page_size", page_size.to_string()),
                 KeyValue::new("search", search.clone().unwrap_or_default()),
+                KeyValue::new("cursor", req.cursor.clone().unwrap_or_default()),
             ],
         );
 
@@ -178,12 +190,24 @@ impl CategoryServiceTrait for CategoryService {
             page,
             page_size,
             search: search.clone().unwrap_or_default(),
+            cursor: req.cursor.clone(),
         });
 
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let paging = match Paging::from_request(page, req.cursor.as_deref()) {
+            Ok(paging) => paging,
+            Err(err) => {
+                self.complete_tracing_error(&tracing_ctx, method, &err.to_string())
+                    .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
         let cache_key = format!(
-            "categories:page={page}:size={page_size}:search={}",
+            "categories:page={}:size={page_size}:search={}",
+            paging.cache_key(),
             search.clone().unwrap_or_default()
         );
 
@@ -199,9 +223,31 @@ impl CategoryServiceTrait for CategoryService {
             return Ok(cached);
         }
 
-        match self.repository.find_all(page, page_size, search).await {
-            Ok((categories, total_items)) => {
-                let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+        let result = match paging {
+            Paging::Offset { page } => self.repository.find_all(page, page_size, search).await.map(
+                |(categories, total_items)| {
+                    let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+                    let pagination = Pagination {
+                        page,
+                        page_size,
+                        total_items,
+                        total_pages,
+                        next_cursor: None,
+                    };
+
+                    (categories, pagination)
+                },
+            ),
+            Paging::Keyset { after } => self
+                .repository
+                .find_after(page_size, after, search)
+                .await
+                .map(|(categories, next)| (categories, Pagination::keyset(page_size, next))),
+        };
+
+        match result {
+            Ok((categories, pagination)) => {
                 let category_responses = categories
                     .into_iter()
                     .map(CategoryResponse::from)
@@ -211,12 +257,7 @@ impl CategoryServiceTrait for CategoryService {
                     status: "success".to_string(),
                     message: "Categories retrieved successfully".to_string(),
                     data: category_responses.clone(),
-                    pagination: Pagination {
-                        page,
-                        page_size,
-                        total_items,
-                        total_pages,
-                    },
+                    pagination,
                 };
 
                 self.cache_store
@@ -318,6 +359,7 @@ impl CategoryServiceTrait for CategoryService {
     async fn create_category(
         &self,
         input: &CreateCategoryRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<CategoryResponse>, ErrorResponse> {
         let method = Method::Post;
 
@@ -340,6 +382,15 @@ impl CategoryServiceTrait for CategoryService {
                     data: CategoryResponse::from(category),
                 };
 
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CategoryCreated)
+                            .target(response.data.id)
+                            .after(&response.data),
+                    )
+                    .await;
+
                 self.complete_tracing_success(
                     &tracing_ctx,
                     method,
@@ -365,6 +416,7 @@ impl CategoryServiceTrait for CategoryService {
     async fn update_category(
         &self,
         input: &UpdateCategoryRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse> {
         let method = Method::Put;
 
@@ -379,12 +431,26 @@ impl CategoryServiceTrait for CategoryService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let before = self.repository.find_by_id(input.id).await.ok().flatten();
+
         match self.repository.update(input).await {
             Ok(category) => {
+                let data = CategoryResponse::from(category);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CategoryUpdated)
+                            .target(data.id)
+                            .before(&before.map(CategoryResponse::from))
+                            .after(&data),
+                    )
+                    .await;
+
                 let response = Some(ApiResponse {
                     status: "success".to_string(),
                     message: "Category updated successfully".to_string(),
-                    data: CategoryResponse::from(category),
+                    data,
                 });
 
                 let cache_key = format!("category:id={}", input.id);
@@ -414,7 +480,11 @@ impl CategoryServiceTrait for CategoryService {
         }
     }
 
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
+    async fn delete_category(
+        &self,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
         let method = Method::Delete;
         let tracing_ctx = self.start_tracing(
             "DeleteCategory",
@@ -427,8 +497,19 @@ impl CategoryServiceTrait for CategoryService {
         let mut request = Request::new(id);
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let before = self.repository.find_by_id(id).await.ok().flatten();
+
         match self.repository.delete(id).await {
             Ok(_) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CategoryDeleted)
+                            .target(id)
+                            .before(&before.map(CategoryResponse::from)),
+                    )
+                    .await;
+
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "Category deleted successfully".to_string(),
@@ -1,11 +1,22 @@
 use crate::{
-    abstract_trait::{CommentServiceTrait, DynCommentRepository},
+    abstract_trait::{
+        CommentServiceTrait, DynAuditService, DynCommentRepository, DynPostsRepository,
+    },
     cache::CacheStore,
+    config::Claims,
     domain::{
-        ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest,
+        ApiResponse, AuditContext, CommentResponse, CreateCommentRequest, ErrorResponse,
+        PostResponse, UpdateCommentRequest,
+    },
+    model::{
+        audit_event::{AuditAction, AuditEntry},
+        comment::Comment,
+        role::Permission,
     },
-    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+    utils::{AppError, MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
+
+use super::posts::can_view;
 use async_trait::async_trait;
 use opentelemetry::{
     Context, KeyValue,
@@ -21,6 +32,8 @@ use tracing::{error, info};
 #[derive(Clone)]
 pub struct CommentService {
     repository: DynCommentRepository,
+    post_repository: DynPostsRepository,
+    audit: DynAuditService,
     metrics: Arc<Mutex<Metrics>>,
     cache_store: Arc<CacheStore>,
 }
@@ -36,6 +49,8 @@ impl std::fmt::Debug for CommentService {
 impl CommentService {
     pub async fn new(
         repository: DynCommentRepository,
+        post_repository: DynPostsRepository,
+        audit: DynAuditService,
         metrics: Arc<Mutex<Metrics>>,
         registry: &mut Registry,
         cache_store: Arc<CacheStore>,
@@ -53,11 +68,43 @@ impl CommentService {
 
         Self {
             repository,
+            post_repository,
+            audit,
             metrics,
             cache_store,
         }
     }
 
+    async fn ensure_owner(&self, id: i32, actor: &Claims) -> Result<Comment, AppError> {
+        let comment = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Comment with ID {id} not found")))?;
+
+        if comment.user_id.map(i64::from) != Some(actor.user_id)
+            && !actor.has_permission(Permission::CommentsModerate)
+        {
+            return Err(AppError::Forbidden(
+                "You can only modify your own comments".to_string(),
+            ));
+        }
+
+        Ok(comment)
+    }
+
+    /// Only posts the actor can read take comments, so drafts stay private.
+    async fn ensure_commentable(&self, post_id: i32, actor: &Claims) -> Result<(), AppError> {
+        let post = self.post_repository.get_post(post_id).await?;
+
+        match post.map(PostResponse::from) {
+            Some(post) if can_view(&post, Some(actor)) => Ok(()),
+            _ => Err(AppError::NotFound(format!(
+                "Post with id {post_id} not found"
+            ))),
+        }
+    }
+
     fn get_tracer(&self) -> BoxedTracer {
         global::tracer("comment-service")
     }
@@ -266,6 +313,8 @@ impl CommentServiceTrait for CommentService {
     async fn create_comment(
         &self,
         input: &CreateCommentRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
         let method = Method::Post;
 
@@ -281,15 +330,33 @@ impl CommentServiceTrait for CommentService {
 
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        if let Err(err) = self.ensure_commentable(input.id_post_comment, actor).await {
+            self.complete_tracing_error(&tracing_ctx, method, "Failed to create comment")
+                .await;
+
+            return Err(ErrorResponse::from(err));
+        }
+
         match self.repository.create(input).await {
             Ok(comment) => {
+                let data = CommentResponse::from(comment);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CommentCreated)
+                            .target(data.id)
+                            .after(&data),
+                    )
+                    .await;
+
                 self.complete_tracing_success(&tracing_ctx, method, "Comment created successfully")
                     .await;
 
                 Ok(ApiResponse {
                     status: "success".to_string(),
                     message: "Comment created successfully".to_string(),
-                    data: CommentResponse::from(comment),
+                    data,
                 })
             }
             Err(err) => {
@@ -304,6 +371,8 @@ impl CommentServiceTrait for CommentService {
     async fn update_comment(
         &self,
         input: &UpdateCommentRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
         let method = Method::Put;
 
@@ -311,7 +380,7 @@ impl CommentServiceTrait for CommentService {
             "UpdateComment",
             vec![
                 KeyValue::new("component", "comment"),
-                KeyValue::new("comment.name", input.user_name_comment.clone()),
+                KeyValue::new("comment.id", input.id_post_comment.to_string()),
             ],
         );
 
@@ -319,6 +388,16 @@ impl CommentServiceTrait for CommentService {
 
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let before = match self.ensure_owner(input.id_post_comment, actor).await {
+            Ok(comment) => CommentResponse::from(comment),
+            Err(err) => {
+                self.complete_tracing_error(&tracing_ctx, method, "Failed to update comment")
+                    .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
         match self.repository.update(input).await {
             Ok(comment) => {
                 self.complete_tracing_success(&tracing_ctx, method, "Comment updated successfully")
@@ -329,10 +408,22 @@ impl CommentServiceTrait for CommentService {
                 self.cache_store
                     .set_to_cache(&cache_key, &comment, Duration::from_secs(60 * 5));
 
+                let data = CommentResponse::from(comment);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CommentUpdated)
+                            .target(input.id_post_comment)
+                            .before(&before)
+                            .after(&data),
+                    )
+                    .await;
+
                 Ok(Some(ApiResponse {
                     status: "success".to_string(),
                     message: "Comment updated successfully".to_string(),
-                    data: CommentResponse::from(comment),
+                    data,
                 }))
             }
             Err(err) => {
@@ -344,7 +435,12 @@ impl CommentServiceTrait for CommentService {
         }
     }
 
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
+    async fn delete_comment(
+        &self,
+        id: i32,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
         let tracing_ctx = self.start_tracing(
             "DeleteComment",
             vec![
@@ -356,8 +452,31 @@ impl CommentServiceTrait for CommentService {
         let mut request = Request::new(id);
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let before = match self.ensure_owner(id, actor).await {
+            Ok(comment) => CommentResponse::from(comment),
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    Method::Delete,
+                    &format!("Failed to delete comment: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
         match self.repository.delete(id).await {
             Ok(_) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::CommentDeleted)
+                            .target(id)
+                            .before(&before),
+                    )
+                    .await;
+
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "Comment deleted successfully".to_string(),
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{
+    EncodingKey,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use ring::{
+    rand::SystemRandom,
+    signature::{Ed25519KeyPair, KeyPair},
+};
+use rsa::{RsaPrivateKey, pkcs1::EncodeRsaPrivateKey, rand_core::OsRng, traits::PublicKeyParts};
+use tokio::task;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::{DynJwtKeyRepository, DynJwtKeyService, JwtKeyServiceTrait},
+    config::{JwtAlgorithm, JwtConfig, JwtKeyPolicy, SigningKey},
+    model::jwt_key::JwtKey,
+    utils::AppError,
+};
+
+pub struct JwtKeyService {
+    repository: DynJwtKeyRepository,
+    jwt_config: JwtConfig,
+    policy: JwtKeyPolicy,
+}
+
+impl std::fmt::Debug for JwtKeyService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeyService")
+            .field("repository", &"DynJwtKeyRepository")
+            .field("algorithm", &self.jwt_config.algorithm)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl JwtKeyService {
+    pub fn new(
+        repository: DynJwtKeyRepository,
+        jwt_config: JwtConfig,
+        policy: JwtKeyPolicy,
+    ) -> Self {
+        Self {
+            repository,
+            jwt_config,
+            policy,
+        }
+    }
+
+    async fn generate(&self, activates_at: DateTime<Utc>) -> Result<JwtKey, AppError> {
+        let algorithm = self.jwt_config.algorithm;
+        let kid = Uuid::new_v4().simple().to_string();
+
+        let (private_key, key_algorithm, parameters) = match algorithm {
+            JwtAlgorithm::EdDsa => generate_ed25519()?,
+            JwtAlgorithm::Rs256 => task::spawn_blocking(generate_rsa)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))??,
+            JwtAlgorithm::Hs256 => {
+                return Err(AppError::InternalError(
+                    "HS256 does not use signing key pairs".to_string(),
+                ));
+            }
+        };
+
+        let jwk = Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(key_algorithm),
+                key_id: Some(kid.clone()),
+                ..Default::default()
+            },
+            algorithm: parameters,
+        };
+
+        let key = JwtKey {
+            kid,
+            algorithm: algorithm.as_str().to_string(),
+            private_key,
+            public_jwk: serde_json::to_string(&jwk)
+                .map_err(|e| AppError::InternalError(e.to_string()))?,
+            activates_at,
+            retires_at: None,
+        };
+
+        self.repository.create(&key).await?;
+
+        Ok(key)
+    }
+}
+
+#[async_trait]
+impl JwtKeyServiceTrait for JwtKeyService {
+    async fn rotate(&self) -> Result<(), AppError> {
+        let algorithm = self.jwt_config.algorithm;
+
+        if !algorithm.is_asymmetric() {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+
+        let removed = self.repository.delete_retired().await?;
+        if removed > 0 {
+            info!("Removed {removed} retired JWT signing keys");
+        }
+
+        let mut keys = self.repository.find_unretired().await?;
+
+        let current = current_key(&keys, algorithm, now);
+        let pending = keys
+            .iter()
+            .any(|key| key.algorithm == algorithm.as_str() && key.activates_at > now);
+
+        let next_activation = match current {
+            None => Some(now),
+            Some(key) if !pending && key.activates_at + self.policy.rotation_interval <= now => {
+                Some(now + self.policy.publish_delay)
+            }
+            _ => None,
+        };
+
+        if let Some(activates_at) = next_activation {
+            let key = self.generate(activates_at).await?;
+            info!(
+                "Generated {} signing key {} active from {}",
+                key.algorithm, key.kid, key.activates_at
+            );
+            keys.push(key);
+        }
+
+        let current = current_key(&keys, algorithm, now)
+            .cloned()
+            .ok_or_else(|| AppError::InternalError("No active JWT signing key".to_string()))?;
+
+        let retired = self
+            .repository
+            .retire_superseded(
+                &current.kid,
+                current.activates_at,
+                now + self.jwt_config.access_token_ttl,
+            )
+            .await?;
+        if retired > 0 {
+            info!("Scheduled {retired} superseded JWT signing keys for retirement");
+        }
+
+        let jwks = keys
+            .iter()
+            .map(|key| serde_json::from_str::<Jwk>(&key.public_jwk))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        let encoding_key = match algorithm {
+            JwtAlgorithm::EdDsa => EncodingKey::from_ed_der(&current.private_key),
+            _ => EncodingKey::from_rsa_der(&current.private_key),
+        };
+
+        self.jwt_config.install_keys(
+            Some(SigningKey {
+                kid: current.kid,
+                algorithm,
+                key: encoding_key,
+            }),
+            jwks,
+        )
+    }
+}
+
+pub async fn run_key_rotation(service: DynJwtKeyService, interval: std::time::Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+
+        if let Err(err) = service.rotate().await {
+            error!("JWT key rotation failed: {err}");
+        }
+    }
+}
+
+fn current_key(keys: &[JwtKey], algorithm: JwtAlgorithm, now: DateTime<Utc>) -> Option<&JwtKey> {
+    keys.iter()
+        .filter(|key| key.algorithm == algorithm.as_str() && key.activates_at <= now)
+        .max_by_key(|key| key.activates_at)
+}
+
+fn generate_ed25519() -> Result<(Vec<u8>, KeyAlgorithm, AlgorithmParameters), AppError> {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+        .map_err(|_| AppError::InternalError("Failed to generate Ed25519 key".to_string()))?;
+    let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    let parameters = AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+        key_type: OctetKeyPairType::OctetKeyPair,
+        curve: EllipticCurve::Ed25519,
+        x: URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+    });
+
+    Ok((pkcs8.as_ref().to_vec(), KeyAlgorithm::EdDSA, parameters))
+}
+
+fn generate_rsa() -> Result<(Vec<u8>, KeyAlgorithm, AlgorithmParameters), AppError> {
+    let key =
+        RsaPrivateKey::new(&mut OsRng, 2048).map_err(|e| AppError::InternalError(e.to_string()))?;
+    let der = key
+        .to_pkcs1_der()
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    let parameters = AlgorithmParameters::RSA(RSAKeyParameters {
+        key_type: RSAKeyType::RSA,
+        n: URL_SAFE_NO_PAD.encode(key.n().to_bytes_be()),
+        e: URL_SAFE_NO_PAD.encode(key.e().to_bytes_be()),
+    });
+
+    Ok((der.as_bytes().to_vec(), KeyAlgorithm::RS256, parameters))
+}
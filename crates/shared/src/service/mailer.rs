@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::path::PathBuf;
+use tokio::fs;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::{MailMessage, MailerTrait},
+    utils::AppError,
+};
+
+#[derive(Debug, Clone)]
+pub struct FileMailer {
+    outbox_dir: PathBuf,
+    from_address: String,
+}
+
+impl FileMailer {
+    pub fn new(outbox_dir: impl Into<PathBuf>, from_address: impl Into<String>) -> Self {
+        Self {
+            outbox_dir: outbox_dir.into(),
+            from_address: from_address.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl MailerTrait for FileMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), AppError> {
+        fs::create_dir_all(&self.outbox_dir)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to create outbox: {e}")))?;
+
+        let now = Utc::now();
+        let file_name = format!("{}-{}.eml", now.format("%Y%m%dT%H%M%S"), Uuid::new_v4());
+        let path = self.outbox_dir.join(file_name);
+
+        let contents = format!(
+            "From: {}\r\nTo: {}\r\nDate: {}\r\nSubject: {}\r\n\r\n{}\r\n",
+            self.from_address,
+            message.to,
+            now.to_rfc2822(),
+            message.subject,
+            message.body
+        );
+
+        fs::write(&path, contents)
+            .await
+            .map_err(|e| AppError::InternalError(format!("Failed to write message: {e}")))?;
+
+        info!("Queued mail to {} at {}", message.to, path.display());
+
+        Ok(())
+    }
+}
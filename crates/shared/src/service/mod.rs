@@ -1,13 +1,29 @@
+mod api_key;
+mod audit;
 mod auth;
 mod category;
 mod comment;
 mod file;
+mod jwt_key;
+mod mailer;
+mod oidc;
 mod posts;
+mod session;
+mod tag;
+mod two_factor;
 mod user;
 
-pub use self::auth::AuthService;
+pub use self::api_key::{ApiKeyService, ApiKeyServiceDeps};
+pub use self::audit::AuditService;
+pub use self::auth::{AuthService, AuthServiceDeps};
 pub use self::category::CategoryService;
 pub use self::comment::CommentService;
 pub use self::file::FileService;
-pub use self::posts::PostService;
-pub use self::user::UserService;
+pub use self::jwt_key::{JwtKeyService, run_key_rotation};
+pub use self::mailer::FileMailer;
+pub use self::oidc::OidcProvider;
+pub use self::posts::{PostService, run_scheduled_publishing};
+pub use self::session::{SessionService, SessionServiceDeps};
+pub use self::tag::TagService;
+pub use self::two_factor::{TwoFactorService, TwoFactorServiceDeps};
+pub use self::user::{UserService, UserServiceDeps};
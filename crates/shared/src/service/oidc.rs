@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Validation, decode, decode_header,
+    jwk::{Jwk, JwkSet},
+};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use crate::{
+    abstract_trait::{OidcIdentity, OidcProviderTrait},
+    config::OidcConfig,
+    utils::AppError,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenEndpointResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenEndpointError {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    iss: String,
+    sub: String,
+    aud: Value,
+    exp: i64,
+    jti: Option<String>,
+    azp: Option<String>,
+    nonce: Option<String>,
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: Value,
+    given_name: Option<String>,
+    family_name: Option<String>,
+    name: Option<String>,
+}
+
+const ALLOWED_ALGORITHMS: [Algorithm; 9] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+pub struct OidcProvider {
+    config: OidcConfig,
+    http: Client,
+    metadata: RwLock<Option<ProviderMetadata>>,
+    jwks: RwLock<JwkSet>,
+}
+
+impl std::fmt::Debug for OidcProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProvider")
+            .field("issuer_url", &self.config.issuer_url)
+            .field("client_id", &self.config.client_id)
+            .finish()
+    }
+}
+
+impl OidcProvider {
+    pub fn new(config: OidcConfig) -> Result<Self, AppError> {
+        let http = Client::builder()
+            .timeout(config.http_timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| AppError::InternalError(format!("Failed to build HTTP client: {e}")))?;
+
+        Ok(Self {
+            config,
+            http,
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(JwkSet { keys: Vec::new() }),
+        })
+    }
+
+    async fn metadata(&self) -> Result<ProviderMetadata, AppError> {
+        if let Some(metadata) = self.metadata.read().await.as_ref() {
+            return Ok(metadata.clone());
+        }
+
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            self.config.issuer_url
+        );
+        let metadata: ProviderMetadata = self.get_json(&url).await?;
+
+        if metadata.issuer.trim_end_matches('/') != self.config.issuer_url {
+            return Err(AppError::OidcError(format!(
+                "Discovery document issuer {} does not match {}",
+                metadata.issuer, self.config.issuer_url
+            )));
+        }
+
+        info!("Loaded OpenID provider metadata from {url}");
+
+        *self.metadata.write().await = Some(metadata.clone());
+
+        Ok(metadata)
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, AppError> {
+        self.http
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AppError::OidcError(format!("Request to {url} failed: {e}")))?
+            .json()
+            .await
+            .map_err(|e| AppError::OidcError(format!("Invalid response from {url}: {e}")))
+    }
+
+    async fn signing_key(&self, kid: Option<&str>) -> Result<Jwk, AppError> {
+        let find = |jwks: &JwkSet| match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+            None => None,
+        };
+
+        if let Some(jwk) = find(&*self.jwks.read().await) {
+            return Ok(jwk);
+        }
+
+        // Unknown key id: the provider may have rotated its keys since the
+        // last fetch, so reload the key set once before giving up.
+        let metadata = self.metadata().await?;
+        let jwks: JwkSet = self.get_json(&metadata.jwks_uri).await?;
+        let jwk = find(&jwks);
+        *self.jwks.write().await = jwks;
+
+        jwk.ok_or_else(|| AppError::OidcError("ID token signed with an unknown key".to_string()))
+    }
+}
+
+#[async_trait]
+impl OidcProviderTrait for OidcProvider {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, AppError> {
+        let metadata = self.metadata().await?;
+
+        let mut url = Url::parse(&metadata.authorization_endpoint)
+            .map_err(|e| AppError::OidcError(format!("Invalid authorization endpoint: {e}")))?;
+
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_url)
+            .append_pair("scope", &self.config.scopes)
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url.into())
+    }
+
+    async fn exchange_code(&self, code: &str, code_verifier: &str) -> Result<String, AppError> {
+        let metadata = self.metadata().await?;
+
+        let mut request = self.http.post(&metadata.token_endpoint).form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.config.redirect_url.as_str()),
+            ("code_verifier", code_verifier),
+            ("client_id", self.config.client_id.as_str()),
+        ]);
+
+        if let Some(secret) = &self.config.client_secret {
+            request = request.basic_auth(&self.config.client_id, Some(secret));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AppError::OidcError(format!("Token request failed: {e}")))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = match response.json::<TokenEndpointError>().await {
+                Ok(err) => match err.error_description {
+                    Some(description) => format!("{}: {description}", err.error),
+                    None => err.error,
+                },
+                Err(_) => status.to_string(),
+            };
+
+            return Err(AppError::OidcError(format!(
+                "Token endpoint rejected the authorization code: {message}"
+            )));
+        }
+
+        response
+            .json::<TokenEndpointResponse>()
+            .await
+            .map_err(|e| AppError::OidcError(format!("Invalid token response: {e}")))?
+            .id_token
+            .ok_or_else(|| AppError::OidcError("Token response has no ID token".to_string()))
+    }
+
+    async fn verify_id_token(&self, id_token: &str, nonce: &str) -> Result<OidcIdentity, AppError> {
+        let header = decode_header(id_token)
+            .map_err(|e| AppError::OidcError(format!("Malformed ID token: {e}")))?;
+
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(AppError::OidcError(format!(
+                "ID token algorithm {:?} is not allowed",
+                header.alg
+            )));
+        }
+
+        let metadata = self.metadata().await?;
+        let jwk = self.signing_key(header.kid.as_deref()).await?;
+        let key = DecodingKey::from_jwk(&jwk)
+            .map_err(|e| AppError::OidcError(format!("Unusable provider key: {e}")))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| AppError::OidcError(format!("Invalid ID token: {e}")))?
+            .claims;
+
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(AppError::OidcError("ID token nonce mismatch".to_string()));
+        }
+
+        let multiple_audiences = claims.aud.as_array().is_some_and(|aud| aud.len() > 1);
+        if multiple_audiences && claims.azp.as_deref() != Some(self.config.client_id.as_str()) {
+            return Err(AppError::OidcError(
+                "ID token was issued to a different client".to_string(),
+            ));
+        }
+
+        // Some providers encode the flag as a string.
+        let email_verified = match &claims.email_verified {
+            Value::Bool(verified) => *verified,
+            Value::String(verified) => verified.eq_ignore_ascii_case("true"),
+            _ => false,
+        };
+
+        Ok(OidcIdentity {
+            issuer: claims.iss,
+            subject: claims.sub,
+            email: claims.email,
+            email_verified,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+            name: claims.name,
+            token_id: claims.jti.unwrap_or_else(|| nonce.to_string()),
+            expires_at: claims.exp,
+        })
+    }
+}
@@ -1,27 +1,44 @@
 use crate::{
-    abstract_trait::{DynPostsRepository, PostsServiceTrait},
+    abstract_trait::{
+        DynAuditService, DynPostsRepository, DynPostsService, DynTagRepository, PostsServiceTrait,
+    },
     cache::CacheStore,
+    config::Claims,
     domain::{
-        ApiResponse, ApiResponsePagination, CreatePostRequest, ErrorResponse, FindAllPostRequest,
-        Pagination, PostRelationResponse, PostResponse, UpdatePostRequest,
+        ApiResponse, ApiResponsePagination, AuditContext, CreatePostRequest, ErrorResponse,
+        FindAllPostRequest, FindMyPostsRequest, Pagination, PostRelationResponse, PostResponse,
+        PostSearchResultResponse, SearchPostsRequest, TagResponse, UpdatePostRequest,
+    },
+    model::{
+        audit_event::{AuditAction, AuditEntry},
+        posts::{Post, PostFilter, PostPublication, PostScope, PostSort, PostStatus},
+        role::Permission,
+        tag::normalize_tag_names,
+    },
+    utils::{
+        AppError, MetadataInjector, Method, Metrics, PageCursor, Paging, Status as StatusUtils,
+        TracingContext, clamp_page_size,
     },
-    utils::{AppError, MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use opentelemetry::{
     Context, KeyValue,
     global::{self, BoxedTracer},
     trace::{Span, SpanKind, TraceContextExt, Tracer},
 };
 use prometheus_client::registry::Registry;
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::Instant};
 use tonic::Request;
 use tracing::{error, info};
+use validator::Validate;
 
 #[derive(Clone)]
 pub struct PostService {
     repository: DynPostsRepository,
+    tags: DynTagRepository,
+    audit: DynAuditService,
     metrics: Arc<Mutex<Metrics>>,
     cache_store: Arc<CacheStore>,
 }
@@ -37,6 +54,8 @@ impl std::fmt::Debug for PostService {
 impl PostService {
     pub async fn new(
         repository: DynPostsRepository,
+        tags: DynTagRepository,
+        audit: DynAuditService,
         metrics: Arc<Mutex<Metrics>>,
         registry: &mut Registry,
         cache_store: Arc<CacheStore>,
@@ -54,11 +73,150 @@ impl PostService {
 
         Self {
             repository,
+            tags,
+            audit,
             metrics,
             cache_store,
         }
     }
 
+    async fn ensure_owner(&self, post_id: i32, actor: &Claims) -> Result<Post, AppError> {
+        let post = self
+            .repository
+            .get_post(post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Post with ID {post_id} not found")))?;
+
+        if i64::from(post.user_id) != actor.user_id
+            && !actor.has_permission(Permission::PostsModerate)
+        {
+            return Err(AppError::Forbidden(
+                "You can only modify your own posts".to_string(),
+            ));
+        }
+
+        Ok(post)
+    }
+
+    /// Drops every cached page of the public listing; which pages a post
+    /// appears on is not worth tracking. The tag cloud counts posts too.
+    fn invalidate_listings(&self) {
+        self.cache_store.delete_matching("posts:page=*");
+        self.cache_store.delete_matching("tags:cloud:*");
+    }
+
+    /// Turns posts into responses carrying their tags, with one query for
+    /// the whole batch.
+    async fn with_tags(&self, posts: Vec<Post>) -> Result<Vec<PostResponse>, AppError> {
+        let ids: Vec<i32> = posts.iter().map(|post| post.id).collect();
+
+        let mut tags: HashMap<i32, Vec<TagResponse>> = HashMap::new();
+        for tag in self.tags.find_for_posts(&ids).await? {
+            tags.entry(tag.post_id).or_default().push(tag.into());
+        }
+
+        Ok(posts
+            .into_iter()
+            .map(|post| {
+                let post_tags = tags.remove(&post.id).unwrap_or_default();
+                PostResponse {
+                    tags: post_tags,
+                    ..PostResponse::from(post)
+                }
+            })
+            .collect())
+    }
+
+    async fn post_response(&self, post: Post) -> Result<PostResponse, AppError> {
+        let mut responses = self.with_tags(vec![post]).await?;
+        Ok(responses.remove(0))
+    }
+
+    async fn find_posts(
+        &self,
+        page: i32,
+        page_size: i32,
+        filter: &PostFilter,
+    ) -> Result<(Vec<PostResponse>, i64), AppError> {
+        let (posts, total_items) = self
+            .repository
+            .get_all_posts(page, page_size, filter)
+            .await?;
+
+        Ok((self.with_tags(posts).await?, total_items))
+    }
+
+    /// Reads the filters of the public listing. Statuses other than
+    /// published are only listed for moderators.
+    fn listing_filter(
+        req: &FindAllPostRequest,
+        viewer: Option<&Claims>,
+    ) -> Result<PostFilter, AppError> {
+        let timestamp = |field: &str, value: &Option<String>| {
+            value
+                .as_deref()
+                .filter(|value| !value.is_empty())
+                .map(|value| {
+                    DateTime::parse_from_rfc3339(value)
+                        .map(|parsed| parsed.with_timezone(&Utc))
+                        .map_err(|_| {
+                            AppError::InvalidArgument(format!(
+                                "{field} must be an RFC 3339 timestamp"
+                            ))
+                        })
+                })
+                .transpose()
+        };
+        let non_empty = |value: &str| Some(value.to_string()).filter(|value| !value.is_empty());
+
+        let scope = match req.status.trim() {
+            "" => PostScope::Published,
+            status => match status.parse::<PostStatus>() {
+                Ok(PostStatus::Published) => PostScope::Published,
+                Ok(status)
+                    if viewer
+                        .is_some_and(|claims| claims.has_permission(Permission::PostsModerate)) =>
+                {
+                    PostScope::All {
+                        status: Some(status),
+                    }
+                }
+                Ok(status) => {
+                    return Err(AppError::Forbidden(format!(
+                        "Listing {status} posts needs the {} permission",
+                        Permission::PostsModerate
+                    )));
+                }
+                Err(message) => return Err(AppError::InvalidArgument(message)),
+            },
+        };
+
+        Ok(PostFilter {
+            search: non_empty(&req.search),
+            tag: non_empty(&req.tag),
+            category_id: req.category_id,
+            user_id: req.user_id,
+            created_from: timestamp("from", &req.from)?,
+            created_to: timestamp("to", &req.to)?,
+            sort: PostSort::parse(&req.sort, &req.order).map_err(AppError::InvalidArgument)?,
+            ..PostFilter::new(scope)
+        })
+    }
+
+    async fn find_posts_after(
+        &self,
+        page_size: i32,
+        after: Option<PageCursor>,
+        filter: &PostFilter,
+    ) -> Result<(Vec<PostResponse>, Option<PageCursor>), AppError> {
+        let (posts, next) = self
+            .repository
+            .get_posts_after(page_size, after, filter)
+            .await?;
+
+        Ok((self.with_tags(posts).await?, next))
+    }
+
     fn get_tracer(&self) -> BoxedTracer {
         global::tracer("post-service")
     }
@@ -148,21 +306,46 @@ impl PostService {
     }
 }
 
+/// Every filter goes in the key; they all start with `posts:page=` so that
+/// [`PostService::invalidate_listings`] clears them.
+fn listing_cache_key(paging: &Paging, page_size: i32, filter: &PostFilter) -> String {
+    let part = |value: Option<String>| value.unwrap_or_default();
+
+    let status = match filter.scope {
+        PostScope::All {
+            status: Some(status),
+        } => status.as_str(),
+        _ => PostStatus::Published.as_str(),
+    };
+
+    format!(
+        "posts:page={}:size={page_size}:search={}:tag={}:category={}:user={}:from={}:to={}:status={status}:sort={}",
+        paging.cache_key(),
+        part(filter.search.clone()),
+        part(filter.tag.clone()),
+        part(filter.category_id.map(|id| id.to_string())),
+        part(filter.user_id.map(|id| id.to_string())),
+        part(filter.created_from.map(|at| at.to_rfc3339())),
+        part(filter.created_to.map(|at| at.to_rfc3339())),
+        part(filter.sort.map(|sort| format!(
+            "{}-{}",
+            sort.field.as_str(),
+            sort.direction.as_str()
+        ))),
+    )
+}
+
 #[async_trait]
 impl PostsServiceTrait for PostService {
     async fn get_all_posts(
         &self,
         req: FindAllPostRequest,
+        viewer: Option<&Claims>,
     ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse> {
         let method = Method::Get;
 
         let page = req.page.max(1);
-        let page_size = req.page_size.max(1);
-        let search = if req.search.is_empty() {
-            None
-        } else {
-            Some(req.search.clone())
-        };
+        let page_size = clamp_page_size(req.page_size);
 
         let tracing_ctx = self.start_tracing(
             "GetAllPosts",
@@ -170,21 +353,45 @@ impl PostsServiceTrait for PostService {
                 KeyValue::new("component", "post"),
                 KeyValue::new("page", page.to_string()),
                 KeyValue::new("page_size", page_size.to_string()),
-                KeyValue::new("search", search.clone().unwrap_or_default()),
+                KeyValue::new("search", req.search.clone()),
+                KeyValue::new("tag", req.tag.clone()),
+                KeyValue::new("cursor", req.cursor.clone().unwrap_or_default()),
+                KeyValue::new("status", req.status.clone()),
+                KeyValue::new("sort", format!("{} {}", req.sort, req.order)),
             ],
         );
 
         let mut request = Request::new(FindAllPostRequest {
             page,
             page_size,
-            search: search.clone().unwrap_or_default(),
+            ..req.clone()
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        let cache_key = format!(
-            "posts:page={page}:size={page_size}:search={}",
-            search.clone().unwrap_or_default()
-        );
+        let parsed = Paging::from_request(page, req.cursor.as_deref()).and_then(|paging| {
+            let filter = Self::listing_filter(&req, viewer)?;
+
+            match (paging, filter.sort) {
+                (Paging::Keyset { .. }, Some(sort)) if !sort.is_newest_first() => {
+                    Err(AppError::InvalidArgument(
+                        "Cursor paging only supports sort=created_at&order=desc".to_string(),
+                    ))
+                }
+                _ => Ok((paging, filter)),
+            }
+        });
+
+        let (paging, filter) = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                self.complete_tracing_error(&tracing_ctx, method, &err.to_string())
+                    .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let cache_key = listing_cache_key(&paging, page_size, &filter);
 
         if let Some(cache) = self
             .cache_store
@@ -196,26 +403,122 @@ impl PostsServiceTrait for PostService {
             return Ok(cache);
         }
 
-        match self.repository.get_all_posts(page, page_size, search).await {
+        let result =
+            match paging {
+                Paging::Offset { page } => self.find_posts(page, page_size, &filter).await.map(
+                    |(responses, total_items)| {
+                        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+                        let pagination = Pagination {
+                            page,
+                            page_size,
+                            total_items,
+                            total_pages,
+                            next_cursor: None,
+                        };
+
+                        (responses, pagination)
+                    },
+                ),
+                Paging::Keyset { after } => self
+                    .find_posts_after(page_size, after, &filter)
+                    .await
+                    .map(|(responses, next)| (responses, Pagination::keyset(page_size, next))),
+            };
+
+        match result {
+            Ok((responses, pagination)) => {
+                let response = ApiResponsePagination {
+                    status: "success".to_string(),
+                    message: "Posts retrieved successfully".to_string(),
+                    data: responses,
+                    pagination,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
+
+                self.complete_tracing_success(&tracing_ctx, method, "Posts retrieved successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve posts: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+    async fn get_my_posts(
+        &self,
+        req: FindMyPostsRequest,
+        actor: &Claims,
+    ) -> Result<ApiResponsePagination<Vec<PostResponse>>, ErrorResponse> {
+        let method = Method::Get;
+
+        let page = req.page.max(1);
+        let page_size = clamp_page_size(req.page_size);
+        let search = if req.search.is_empty() {
+            None
+        } else {
+            Some(req.search.clone())
+        };
+
+        let tracing_ctx = self.start_tracing(
+            "GetMyPosts",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("user_id", actor.user_id.to_string()),
+                KeyValue::new("status", req.status.clone()),
+            ],
+        );
+
+        let status = match req.status.as_str() {
+            "" => None,
+            value => match value.parse::<PostStatus>() {
+                Ok(status) => Some(status),
+                Err(message) => {
+                    self.complete_tracing_error(&tracing_ctx, method, &message)
+                        .await;
+
+                    return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+                }
+            },
+        };
+
+        let filter = PostFilter {
+            search,
+            tag: Some(req.tag.clone()).filter(|tag| !tag.is_empty()),
+            ..PostFilter::new(PostScope::Author {
+                user_id: actor.user_id as i32,
+                status,
+            })
+        };
+
+        // Not cached: authors expect their own edits to show up at once.
+        match self.find_posts(page, page_size, &filter).await {
             Ok((posts, total_items)) => {
-                let responses = posts.into_iter().map(PostResponse::from).collect();
                 let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
 
                 let response = ApiResponsePagination {
                     status: "success".to_string(),
                     message: "Posts retrieved successfully".to_string(),
-                    data: responses,
+                    data: posts,
                     pagination: Pagination {
                         page,
                         page_size,
                         total_items,
                         total_pages,
+                        next_cursor: None,
                     },
                 };
 
-                self.cache_store
-                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
-
                 self.complete_tracing_success(&tracing_ctx, method, "Posts retrieved successfully")
                     .await;
 
@@ -233,9 +536,107 @@ impl PostsServiceTrait for PostService {
             }
         }
     }
+
+    async fn search_posts(
+        &self,
+        req: SearchPostsRequest,
+    ) -> Result<ApiResponsePagination<Vec<PostSearchResultResponse>>, ErrorResponse> {
+        let method = Method::Get;
+
+        let page = req.page.max(1);
+        let page_size = clamp_page_size(req.page_size);
+        let query = req.q.trim().to_string();
+
+        let tracing_ctx = self.start_tracing(
+            "SearchPosts",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("query", query.clone()),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+            ],
+        );
+
+        let invalid = match req.validate() {
+            Err(err) => Some(err.to_string()),
+            Ok(()) if query.is_empty() => Some("Search query must not be blank".to_string()),
+            Ok(()) => None,
+        };
+
+        if let Some(message) = invalid {
+            self.complete_tracing_error(&tracing_ctx, method, &message)
+                .await;
+
+            return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+        }
+
+        let filter = PostFilter {
+            search: Some(query),
+            tag: Some(req.tag.clone()).filter(|tag| !tag.is_empty()),
+            ..PostFilter::new(PostScope::Published)
+        };
+
+        // Not cached: queries are too varied for cached pages to be reused.
+        let result = match self.repository.search_posts(page, page_size, &filter).await {
+            Ok((hits, total_items)) => {
+                let (posts, hits): (Vec<_>, Vec<_>) =
+                    hits.into_iter().map(|hit| (hit.post.clone(), hit)).unzip();
+
+                self.with_tags(posts).await.map(|posts| {
+                    let results = hits
+                        .into_iter()
+                        .zip(posts)
+                        .map(|(hit, post)| PostSearchResultResponse {
+                            post,
+                            ..PostSearchResultResponse::from(hit)
+                        })
+                        .collect::<Vec<_>>();
+
+                    (results, total_items)
+                })
+            }
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok((results, total_items)) => {
+                let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+                let response = ApiResponsePagination {
+                    status: "success".to_string(),
+                    message: "Posts searched successfully".to_string(),
+                    data: results,
+                    pagination: Pagination {
+                        page,
+                        page_size,
+                        total_items,
+                        total_pages,
+                        next_cursor: None,
+                    },
+                };
+
+                self.complete_tracing_success(&tracing_ctx, method, "Posts searched successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to search posts: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
     async fn get_post(
         &self,
         post_id: i32,
+        viewer: Option<&Claims>,
     ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse> {
         let tracing_ctx = self.start_tracing(
             "GetPost",
@@ -253,15 +654,21 @@ impl PostsServiceTrait for PostService {
         {
             self.complete_tracing_success(&tracing_ctx, Method::Get, "Post retrieved from cache")
                 .await;
-            return Ok(Some(cache));
+            return Ok(Some(cache).filter(|post| can_view(&post.data, viewer)));
         }
 
-        match self.repository.get_post(post_id).await {
+        let result = match self.repository.get_post(post_id).await {
+            Ok(Some(post)) => self.post_response(post).await.map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        match result {
             Ok(Some(post)) => {
                 let response = Some(ApiResponse {
                     status: "success".to_string(),
                     message: "Post retrieved successfully".to_string(),
-                    data: PostResponse::from(post),
+                    data: post,
                 });
 
                 self.cache_store.set_to_cache(
@@ -277,7 +684,7 @@ impl PostsServiceTrait for PostService {
                 )
                 .await;
 
-                Ok(response)
+                Ok(response.filter(|post| can_view(&post.data, viewer)))
             }
             Ok(None) => {
                 self.complete_tracing_error(
@@ -304,9 +711,70 @@ impl PostsServiceTrait for PostService {
         }
     }
 
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        viewer: Option<&Claims>,
+    ) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse> {
+        let tracing_ctx = self.start_tracing(
+            "GetPostBySlug",
+            vec![
+                KeyValue::new("component", "post"),
+                KeyValue::new("slug", slug.to_string()),
+            ],
+        );
+
+        // Not cached: a rename has to start redirecting the old slug at once.
+        let result = match self.repository.get_post_by_slug(slug).await {
+            Ok(Some(post)) => self.post_response(post).await.map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(Some(post)) => {
+                let response = Some(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Post retrieved successfully".to_string(),
+                    data: post,
+                });
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    Method::Get,
+                    "Post retrieved successfully",
+                )
+                .await;
+
+                Ok(response.filter(|post| can_view(&post.data, viewer)))
+            }
+            Ok(None) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    Method::Get,
+                    &format!("Post with slug {slug} not found"),
+                )
+                .await;
+
+                Ok(None)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    Method::Get,
+                    &format!("Error retrieving post: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
     async fn get_post_relation(
         &self,
         post_id: i32,
+        viewer: Option<&Claims>,
     ) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse> {
         let method = Method::Get;
         let tracing_ctx = self.start_tracing(
@@ -336,6 +804,30 @@ impl PostsServiceTrait for PostService {
             return Ok(cache);
         }
 
+        let post = match self.repository.get_post(post_id).await {
+            Ok(post) => post.map(PostResponse::from),
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error retrieving post relation: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        // Hidden posts answer like missing ones, so their comments don't leak.
+        let Some(post) = post.filter(|post| can_view(post, viewer)) else {
+            self.complete_tracing_error(&tracing_ctx, method, "Post relation not found")
+                .await;
+
+            return Err(ErrorResponse::from(AppError::NotFound(
+                "Post relation not found".to_string(),
+            )));
+        };
+
         match self.repository.get_post_relation(post_id).await {
             Ok(relations) => match relations.into_iter().next() {
                 Some(first_relation) => {
@@ -345,11 +837,15 @@ impl PostsServiceTrait for PostService {
                         data: first_relation,
                     };
 
-                    self.cache_store.set_to_cache(
-                        &cache_key,
-                        &response.clone(),
-                        Duration::from_secs(60 * 5),
-                    );
+                    // Only public relations are cached: the cache hit above
+                    // is served without a visibility check.
+                    if post.status == PostStatus::Published.as_str() {
+                        self.cache_store.set_to_cache(
+                            &cache_key,
+                            &response.clone(),
+                            Duration::from_secs(60 * 5),
+                        );
+                    }
 
                     self.complete_tracing_success(
                         &tracing_ctx,
@@ -385,6 +881,7 @@ impl PostsServiceTrait for PostService {
     async fn create_post(
         &self,
         input: &CreatePostRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
         let method = Method::Post;
         let tracing_ctx = self.start_tracing(
@@ -398,14 +895,55 @@ impl PostsServiceTrait for PostService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.repository.create_post(input).await {
+        let publication =
+            match PostPublication::resolve(&input.status, &input.published_at, None, Utc::now()) {
+                Ok(publication) => publication,
+                Err(message) => {
+                    self.complete_tracing_error(&tracing_ctx, method, &message)
+                        .await;
+
+                    return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+                }
+            };
+
+        let tags = match normalize_tag_names(&input.tags) {
+            Ok(tags) => tags,
+            Err(message) => {
+                self.complete_tracing_error(&tracing_ctx, method, &message)
+                    .await;
+
+                return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+            }
+        };
+
+        let result = match self
+            .repository
+            .create_post(input, &publication, &tags)
+            .await
+        {
+            Ok(post) => self.post_response(post).await,
+            Err(err) => Err(err),
+        };
+
+        match result {
             Ok(post) => {
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "Post created successfully".to_string(),
-                    data: PostResponse::from(post),
+                    data: post,
                 };
 
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::PostCreated)
+                            .target(response.data.id)
+                            .after(&response.data),
+                    )
+                    .await;
+
+                self.invalidate_listings();
+
                 self.complete_tracing_success(&tracing_ctx, method, "Post created successfully")
                     .await;
 
@@ -427,6 +965,8 @@ impl PostsServiceTrait for PostService {
     async fn update_post(
         &self,
         input: &UpdatePostRequest,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
         let method = Method::Put;
         let tracing_ctx = self.start_tracing(
@@ -440,20 +980,96 @@ impl PostsServiceTrait for PostService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.repository.update_post(input).await {
+        let current = match self.ensure_owner(input.post_id, actor).await {
+            Ok(post) => post,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to update post: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let publication = match PostPublication::resolve(
+            &input.status,
+            &input.published_at,
+            Some(&current),
+            Utc::now(),
+        ) {
+            Ok(publication) => publication,
+            Err(message) => {
+                self.complete_tracing_error(&tracing_ctx, method, &message)
+                    .await;
+
+                return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+            }
+        };
+
+        let tags = match input.tags.as_deref().map(normalize_tag_names).transpose() {
+            Ok(tags) => tags,
+            Err(message) => {
+                self.complete_tracing_error(&tracing_ctx, method, &message)
+                    .await;
+
+                return Err(ErrorResponse::from(AppError::InvalidArgument(message)));
+            }
+        };
+
+        let before = match self.post_response(current).await {
+            Ok(post) => post,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to update post: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let result = match self
+            .repository
+            .update_post(input, &publication, tags.as_deref())
+            .await
+        {
+            Ok(post) => self.post_response(post).await,
+            Err(err) => Err(err),
+        };
+
+        match result {
             Ok(post) => {
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "Post updated successfully".to_string(),
-                    data: PostResponse::from(post),
+                    data: post,
                 };
 
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::PostUpdated)
+                            .target(input.post_id)
+                            .before(&before)
+                            .after(&response.data),
+                    )
+                    .await;
+
                 let cache_key = format!("post:id={}", input.post_id);
                 self.cache_store.set_to_cache(
                     &cache_key,
                     &response.clone(),
                     Duration::from_secs(60 * 5),
                 );
+                self.cache_store
+                    .delete_from_cache(&format!("post_relation:id={}", input.post_id));
+
+                self.invalidate_listings();
 
                 self.complete_tracing_success(&tracing_ctx, method, "Post updated successfully")
                     .await;
@@ -473,7 +1089,12 @@ impl PostsServiceTrait for PostService {
         }
     }
 
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
+    async fn delete_post(
+        &self,
+        post_id: i32,
+        actor: &Claims,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
         let method = Method::Delete;
         let tracing_ctx = self.start_tracing(
             "DeletePost",
@@ -486,8 +1107,31 @@ impl PostsServiceTrait for PostService {
         let mut request = Request::new(post_id);
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let before = match self.ensure_owner(post_id, actor).await {
+            Ok(post) => PostResponse::from(post),
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to delete post: {err}"),
+                )
+                .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
         match self.repository.delete_post(post_id).await {
             Ok(_) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::PostDeleted)
+                            .target(post_id)
+                            .before(&before),
+                    )
+                    .await;
+
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "Post deleted successfully".to_string(),
@@ -496,6 +1140,9 @@ impl PostsServiceTrait for PostService {
 
                 let cache_key = format!("post:id={post_id}");
                 self.cache_store.delete_from_cache(&cache_key);
+                self.cache_store
+                    .delete_from_cache(&format!("post_relation:id={post_id}"));
+                self.invalidate_listings();
 
                 self.complete_tracing_success(&tracing_ctx, method, "Post deleted successfully")
                     .await;
@@ -514,4 +1161,77 @@ impl PostsServiceTrait for PostService {
             }
         }
     }
+
+    async fn publish_due_posts(&self) -> Result<usize, ErrorResponse> {
+        let method = Method::Put;
+        let tracing_ctx =
+            self.start_tracing("PublishDuePosts", vec![KeyValue::new("component", "post")]);
+
+        match self.repository.publish_due_posts().await {
+            Ok(posts) => {
+                let audit_ctx = AuditContext::default();
+
+                for post in &posts {
+                    self.cache_store
+                        .delete_from_cache(&format!("post:id={}", post.id));
+                    self.cache_store
+                        .delete_from_cache(&format!("post_relation:id={}", post.id));
+
+                    self.audit
+                        .record(
+                            &audit_ctx,
+                            AuditEntry::new(AuditAction::PostPublished)
+                                .target(post.id)
+                                .after(&PostResponse::from(post.clone())),
+                        )
+                        .await;
+                }
+
+                if !posts.is_empty() {
+                    self.invalidate_listings();
+                }
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Published {} scheduled posts", posts.len()),
+                )
+                .await;
+
+                Ok(posts.len())
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to publish scheduled posts: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+}
+
+/// Drafts, scheduled and archived posts are only visible to their author and
+/// to moderators.
+pub(super) fn can_view(post: &PostResponse, viewer: Option<&Claims>) -> bool {
+    post.status == PostStatus::Published.as_str()
+        || viewer.is_some_and(|claims| {
+            i64::from(post.user_id) == claims.user_id
+                || claims.has_permission(Permission::PostsModerate)
+        })
+}
+
+pub async fn run_scheduled_publishing(service: DynPostsService, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+
+        if let Err(err) = service.publish_due_posts().await {
+            error!("Publishing scheduled posts failed: {}", err.message);
+        }
+    }
 }
@@ -0,0 +1,300 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::{
+        DynAuditService, DynRefreshTokenRepository, DynSessionRepository, SessionServiceTrait,
+    },
+    cache::TokenRevocationStore,
+    config::{Claims, JwtConfig},
+    domain::{ApiResponse, AuditContext, ErrorResponse, SessionResponse},
+    model::audit_event::{AuditAction, AuditEntry},
+    utils::{AppError, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+
+pub struct SessionServiceDeps {
+    pub repository: DynSessionRepository,
+    pub refresh_token_repository: DynRefreshTokenRepository,
+    pub jwt_config: JwtConfig,
+    pub token_revocation: Arc<TokenRevocationStore>,
+    pub audit: DynAuditService,
+    pub metrics: Arc<Mutex<Metrics>>,
+}
+
+pub struct SessionService {
+    repository: DynSessionRepository,
+    refresh_token_repository: DynRefreshTokenRepository,
+    jwt_config: JwtConfig,
+    token_revocation: Arc<TokenRevocationStore>,
+    audit: DynAuditService,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl std::fmt::Debug for SessionService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionService")
+            .field("repository", &"DynSessionRepository")
+            .field("refresh_token_repository", &"DynRefreshTokenRepository")
+            .finish()
+    }
+}
+
+impl SessionService {
+    pub async fn new(deps: SessionServiceDeps, registry: &mut Registry) -> Self {
+        let SessionServiceDeps {
+            repository,
+            refresh_token_repository,
+            jwt_config,
+            token_revocation,
+            audit,
+            metrics,
+        } = deps;
+
+        registry.register(
+            "session_service_request_counter",
+            "Total number of requests to the SessionService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "session_service_request_duration",
+            "Histogram of request durations for the SessionService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self {
+            repository,
+            refresh_token_repository,
+            jwt_config,
+            token_revocation,
+            audit,
+            metrics,
+        }
+    }
+
+    async fn end_session(&self, user_id: i32, id: &str) -> Result<bool, AppError> {
+        if !self.repository.revoke(id, user_id).await? {
+            return Ok(false);
+        }
+
+        // The session id doubles as the refresh token family id.
+        self.refresh_token_repository.revoke_family(id).await?;
+        self.token_revocation
+            .revoke_session(
+                id,
+                self.jwt_config
+                    .access_token_ttl
+                    .to_std()
+                    .map_err(|e| AppError::InternalError(e.to_string()))?,
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("session-service")
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl SessionServiceTrait for SessionService {
+    async fn list_sessions(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<Vec<SessionResponse>>, ErrorResponse> {
+        let method = Method::Get;
+        let tracing_ctx = self.start_tracing(
+            "ListSessions",
+            vec![
+                KeyValue::new("component", "session"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        // A session idle for longer than the refresh token lifetime can no
+        // longer be resumed, so it is not worth listing.
+        let seen_after = Utc::now() - self.jwt_config.refresh_token_ttl;
+
+        match self
+            .repository
+            .find_active_by_user(actor.user_id as i32, seen_after)
+            .await
+        {
+            Ok(sessions) => {
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Retrieved {} sessions", sessions.len()),
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Sessions retrieved successfully".to_string(),
+                    data: sessions
+                        .into_iter()
+                        .map(|session| SessionResponse::new(session, actor.sid.as_deref()))
+                        .collect(),
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to list sessions: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn revoke_session(
+        &self,
+        actor: &Claims,
+        id: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "RevokeSession",
+            vec![
+                KeyValue::new("component", "session"),
+                KeyValue::new("user.id", actor.user_id),
+                KeyValue::new("session.id", id.to_string()),
+            ],
+        );
+
+        match self.end_session(actor.user_id as i32, id).await {
+            Ok(true) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::SessionRevoked).target(id),
+                    )
+                    .await;
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    &format!("Session {id} revoked"),
+                )
+                .await;
+
+                Ok(Some(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Session revoked successfully".to_string(),
+                    data: (),
+                }))
+            }
+            Ok(false) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Active session {id} not found"),
+                )
+                .await;
+
+                Ok(None)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to revoke session {id}: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,560 @@
+use crate::{
+    abstract_trait::{DynAuditService, DynTagRepository, TagServiceTrait},
+    cache::CacheStore,
+    domain::{
+        ApiResponse, ApiResponsePagination, AuditContext, CreateTagRequest, ErrorResponse,
+        FindAllTagRequest, Pagination, TagCloudRequest, TagCountResponse, TagResponse,
+        UpdateTagRequest,
+    },
+    model::audit_event::{AuditAction, AuditEntry},
+    utils::{MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
+};
+use async_trait::async_trait;
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+use tonic::Request;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct TagService {
+    repository: DynTagRepository,
+    audit: DynAuditService,
+    metrics: Arc<Mutex<Metrics>>,
+    cache_store: Arc<CacheStore>,
+}
+
+impl std::fmt::Debug for TagService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TagService")
+            .field("repository", &"DynTagRepository")
+            .finish()
+    }
+}
+
+impl TagService {
+    pub async fn new(
+        repository: DynTagRepository,
+        audit: DynAuditService,
+        metrics: Arc<Mutex<Metrics>>,
+        registry: &mut Registry,
+        cache_store: Arc<CacheStore>,
+    ) -> Self {
+        registry.register(
+            "tag_service_request_counter",
+            "Total number of requests to the TagService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "tag_service_request_duration",
+            "Histogram of request durations for the TagService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self {
+            repository,
+            audit,
+            metrics,
+            cache_store,
+        }
+    }
+
+    /// Renaming or deleting a tag changes what every post carrying it
+    /// shows, so cached posts go along with the cached tag lists.
+    fn invalidate_tagged(&self) {
+        self.cache_store.delete_matching("tags:*");
+        self.cache_store.delete_matching("post:id=*");
+        self.cache_store.delete_matching("posts:page=*");
+    }
+
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("tag-service")
+    }
+
+    fn inject_trace_context<T>(&self, cx: &Context, request: &mut Request<T>) {
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
+        });
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl TagServiceTrait for TagService {
+    async fn get_tags(
+        &self,
+        req: FindAllTagRequest,
+    ) -> Result<ApiResponsePagination<Vec<TagResponse>>, ErrorResponse> {
+        let method = Method::Get;
+
+        let page = if req.page > 0 { req.page } else { 1 };
+        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let search = if req.search.is_empty() {
+            None
+        } else {
+            Some(req.search.clone())
+        };
+
+        let tracing_ctx = self.start_tracing(
+            "GetTags",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("search", search.clone().unwrap_or_default()),
+            ],
+        );
+
+        let mut request = Request::new(FindAllTagRequest {
+            page,
+            page_size,
+            search: search.clone().unwrap_or_default(),
+        });
+
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "tags:page={page}:size={page_size}:search={}",
+            search.clone().unwrap_or_default()
+        );
+
+        if let Some(cached) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<TagResponse>>>(&cache_key)
+        {
+            info!("Found tags in cache");
+
+            self.complete_tracing_success(&tracing_ctx, method, "Tags retrieved from cache")
+                .await;
+
+            return Ok(cached);
+        }
+
+        match self.repository.find_all(page, page_size, search).await {
+            Ok((tags, total_items)) => {
+                let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+                let tag_responses = tags.into_iter().map(TagResponse::from).collect::<Vec<_>>();
+
+                let response = ApiResponsePagination {
+                    status: "success".to_string(),
+                    message: "Tags retrieved successfully".to_string(),
+                    data: tag_responses.clone(),
+                    pagination: Pagination {
+                        page,
+                        page_size,
+                        total_items,
+                        total_pages,
+                        next_cursor: None,
+                    },
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
+
+                self.complete_tracing_success(&tracing_ctx, method, "Tags retrieved from database")
+                    .await;
+
+                Ok(response)
+            }
+
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve tags: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn get_tag(&self, id: i32) -> Result<Option<ApiResponse<TagResponse>>, ErrorResponse> {
+        let method = Method::Get;
+
+        let tracing_ctx = self.start_tracing(
+            "GetTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("id", id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(id);
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!("tag:id={id}");
+
+        if let Some(cached) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<TagResponse>>(&cache_key)
+        {
+            info!("Found tag in cache");
+
+            self.complete_tracing_success(&tracing_ctx, method, "Tag retrieved from cache")
+                .await;
+
+            return Ok(Some(cached));
+        }
+
+        match self.repository.find_by_id(id).await {
+            Ok(Some(tag)) => {
+                let response = ApiResponse {
+                    status: "success".to_string(),
+                    message: "Tag retrieved successfully".to_string(),
+                    data: TagResponse::from(tag),
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
+
+                self.complete_tracing_success(&tracing_ctx, method, "Tag retrieved successfully")
+                    .await;
+
+                Ok(Some(response))
+            }
+            Ok(None) => {
+                self.complete_tracing_error(&tracing_ctx, method, "Tag not found")
+                    .await;
+
+                Ok(None)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Error retrieving tag: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn get_tag_cloud(
+        &self,
+        req: TagCloudRequest,
+    ) -> Result<ApiResponse<Vec<TagCountResponse>>, ErrorResponse> {
+        let method = Method::Get;
+
+        let limit = req.limit.clamp(1, 200);
+
+        let tracing_ctx = self.start_tracing(
+            "GetTagCloud",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("limit", limit.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(TagCloudRequest { limit });
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!("tags:cloud:limit={limit}");
+
+        if let Some(cached) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<TagCountResponse>>>(&cache_key)
+        {
+            self.complete_tracing_success(&tracing_ctx, method, "Tag cloud retrieved from cache")
+                .await;
+
+            return Ok(cached);
+        }
+
+        match self.repository.tag_cloud(limit).await {
+            Ok(tags) => {
+                let response = ApiResponse {
+                    status: "success".to_string(),
+                    message: "Tag cloud retrieved successfully".to_string(),
+                    data: tags.into_iter().map(TagCountResponse::from).collect(),
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
+
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Tag cloud retrieved successfully",
+                )
+                .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to retrieve tag cloud: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn create_tag(
+        &self,
+        input: &CreateTagRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<TagResponse>, ErrorResponse> {
+        let method = Method::Post;
+
+        let tracing_ctx = self.start_tracing(
+            "CreateTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("tag.name", input.name.clone()),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.repository.create(input).await {
+            Ok(tag) => {
+                let response = ApiResponse {
+                    status: "success".to_string(),
+                    message: "Tag created successfully".to_string(),
+                    data: TagResponse::from(tag),
+                };
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::TagCreated)
+                            .target(response.data.id)
+                            .after(&response.data),
+                    )
+                    .await;
+
+                self.cache_store.delete_matching("tags:page=*");
+
+                self.complete_tracing_success(&tracing_ctx, method, "Tag created successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag creation failed: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn update_tag(
+        &self,
+        input: &UpdateTagRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<Option<ApiResponse<TagResponse>>, ErrorResponse> {
+        let method = Method::Put;
+
+        let tracing_ctx = self.start_tracing(
+            "UpdateTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("tag.name", input.name.clone()),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let before = self.repository.find_by_id(input.id).await.ok().flatten();
+
+        match self.repository.update(input).await {
+            Ok(tag) => {
+                let data = TagResponse::from(tag);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::TagUpdated)
+                            .target(data.id)
+                            .before(&before.map(TagResponse::from))
+                            .after(&data),
+                    )
+                    .await;
+
+                let response = Some(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Tag updated successfully".to_string(),
+                    data,
+                });
+
+                self.invalidate_tagged();
+
+                let cache_key = format!("tag:id={}", input.id);
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &response, Duration::from_secs(60 * 5));
+
+                self.complete_tracing_success(&tracing_ctx, method, "Tag updated successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Tag update failed: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn delete_tag(
+        &self,
+        id: i32,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "DeleteTag",
+            vec![
+                KeyValue::new("component", "tag"),
+                KeyValue::new("id", id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(id);
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let before = self.repository.find_by_id(id).await.ok().flatten();
+
+        match self.repository.delete(id).await {
+            Ok(_) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::TagDeleted)
+                            .target(id)
+                            .before(&before.map(TagResponse::from)),
+                    )
+                    .await;
+
+                let response = ApiResponse {
+                    status: "success".to_string(),
+                    message: "Tag deleted successfully".to_string(),
+                    data: (),
+                };
+
+                let cache_key = format!("tag:id={id}");
+
+                self.cache_store.delete_from_cache(&cache_key);
+                self.invalidate_tagged();
+
+                self.complete_tracing_success(&tracing_ctx, method, "Tag deleted successfully")
+                    .await;
+
+                Ok(response)
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to delete tag: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use opentelemetry::{
+    Context, KeyValue,
+    global::{self, BoxedTracer},
+    trace::{Span, SpanKind, TraceContextExt, Tracer},
+};
+use prometheus_client::registry::Registry;
+use std::sync::Arc;
+use tokio::{sync::Mutex, time::Instant};
+use tracing::{error, info, warn};
+
+use crate::{
+    abstract_trait::{
+        DynAuditService, DynTwoFactorRepository, DynUserRepository, TwoFactorServiceTrait,
+    },
+    cache::LoginAttemptStore,
+    config::{Claims, TwoFactorConfig},
+    domain::{
+        ApiResponse, AuditContext, ErrorResponse, RecoveryCodesResponse, TwoFactorCodeRequest,
+        TwoFactorEnrollmentResponse,
+    },
+    model::audit_event::{AuditAction, AuditEntry},
+    utils::{
+        AppError, Method, Metrics, Status as StatusUtils, TracingContext, generate_recovery_code,
+        generate_totp_secret, hash_token, normalize_recovery_code, totp_provisioning_uri,
+        verify_totp,
+    },
+};
+
+pub struct TwoFactorServiceDeps {
+    pub repository: DynTwoFactorRepository,
+    pub user_repository: DynUserRepository,
+    pub audit: DynAuditService,
+    pub login_attempts: Arc<LoginAttemptStore>,
+    pub config: TwoFactorConfig,
+    pub metrics: Arc<Mutex<Metrics>>,
+}
+
+pub struct TwoFactorService {
+    repository: DynTwoFactorRepository,
+    user_repository: DynUserRepository,
+    audit: DynAuditService,
+    login_attempts: Arc<LoginAttemptStore>,
+    config: TwoFactorConfig,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl std::fmt::Debug for TwoFactorService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TwoFactorService")
+            .field("repository", &"DynTwoFactorRepository")
+            .field("user_repository", &"DynUserRepository")
+            .field("audit", &"DynAuditService")
+            .field("login_attempts", &self.login_attempts)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl TwoFactorService {
+    pub async fn new(deps: TwoFactorServiceDeps, registry: &mut Registry) -> Self {
+        let TwoFactorServiceDeps {
+            repository,
+            user_repository,
+            audit,
+            login_attempts,
+            config,
+            metrics,
+        } = deps;
+
+        registry.register(
+            "two_factor_service_request_counter",
+            "Total number of requests to the TwoFactorService",
+            metrics.lock().await.request_counter.clone(),
+        );
+        registry.register(
+            "two_factor_service_request_duration",
+            "Histogram of request durations for the TwoFactorService",
+            metrics.lock().await.request_duration.clone(),
+        );
+
+        Self {
+            repository,
+            user_repository,
+            audit,
+            login_attempts,
+            config,
+            metrics,
+        }
+    }
+
+    async fn start_enrollment(
+        &self,
+        user_id: i32,
+    ) -> Result<TwoFactorEnrollmentResponse, AppError> {
+        if self
+            .repository
+            .find_by_user(user_id)
+            .await?
+            .is_some_and(|secret| secret.is_enabled())
+        {
+            return Err(AppError::Forbidden(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let secret = generate_totp_secret()?;
+        self.repository.save_pending(user_id, &secret).await?;
+
+        Ok(TwoFactorEnrollmentResponse {
+            provisioning_uri: totp_provisioning_uri(&self.config.issuer, &user.email, &secret),
+            secret,
+        })
+    }
+
+    async fn finish_enrollment(&self, user_id: i32, code: &str) -> Result<Vec<String>, AppError> {
+        let pending = self
+            .repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|secret| !secret.is_enabled())
+            .ok_or_else(|| AppError::Forbidden("No pending two-factor enrollment".to_string()))?;
+
+        let step = verify_totp(&pending.secret, code, Utc::now())?
+            .ok_or(AppError::InvalidTwoFactorCode)?;
+
+        if !self.repository.confirm(user_id, step).await? {
+            return Err(AppError::Forbidden(
+                "No pending two-factor enrollment".to_string(),
+            ));
+        }
+
+        self.issue_recovery_codes(user_id).await
+    }
+
+    /// Wrong codes count against the same per-account budget as failed
+    /// logins, so a stolen session cannot be used to guess the second factor
+    /// without tripping the lockout.
+    async fn require_valid_code(&self, user_id: i32, code: &str) -> Result<(), AppError> {
+        if !self.is_enabled(user_id).await? {
+            return Err(AppError::Forbidden(
+                "Two-factor authentication is not enabled".to_string(),
+            ));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if let Some(retry_after) = self.login_attempts.locked_for(&user.email, None).await {
+            return Err(AppError::TooManyRequests(retry_after.as_secs()));
+        }
+
+        if !self.verify_code(user_id, code).await? {
+            self.login_attempts.record_failure(&user.email, None).await;
+            return Err(AppError::InvalidTwoFactorCode);
+        }
+
+        Ok(())
+    }
+
+    async fn issue_recovery_codes(&self, user_id: i32) -> Result<Vec<String>, AppError> {
+        let codes = (0..self.config.recovery_code_count)
+            .map(|_| generate_recovery_code())
+            .collect::<Result<Vec<_>, _>>()?;
+        let hashes: Vec<String> = codes.iter().map(|code| hash_token(code)).collect();
+
+        self.repository
+            .replace_recovery_codes(user_id, &hashes)
+            .await?;
+
+        Ok(codes)
+    }
+
+    fn get_tracer(&self) -> BoxedTracer {
+        global::tracer("two-factor-service")
+    }
+
+    fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
+        let start_time = Instant::now();
+        let tracer = self.get_tracer();
+        let mut span = tracer
+            .span_builder(operation_name.to_string())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start(&tracer);
+
+        info!("Starting operation: {operation_name}");
+
+        span.add_event(
+            "Operation started",
+            vec![
+                KeyValue::new("operation", operation_name.to_string()),
+                KeyValue::new("timestamp", start_time.elapsed().as_secs_f64().to_string()),
+            ],
+        );
+
+        let cx = Context::current_with_span(span);
+        TracingContext { cx, start_time }
+    }
+
+    async fn complete_tracing_success(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, true, message)
+            .await;
+    }
+
+    async fn complete_tracing_error(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        error_message: &str,
+    ) {
+        self.complete_tracing_internal(tracing_ctx, method, false, error_message)
+            .await;
+    }
+
+    async fn complete_tracing_internal(
+        &self,
+        tracing_ctx: &TracingContext,
+        method: Method,
+        is_success: bool,
+        message: &str,
+    ) {
+        let status_str = if is_success { "SUCCESS" } else { "ERROR" };
+        let status = if is_success {
+            StatusUtils::Success
+        } else {
+            StatusUtils::Error
+        };
+        let elapsed = tracing_ctx.start_time.elapsed().as_secs_f64();
+
+        tracing_ctx.cx.span().add_event(
+            "Operation completed",
+            vec![
+                KeyValue::new("status", status_str),
+                KeyValue::new("duration_secs", elapsed.to_string()),
+                KeyValue::new("message", message.to_string()),
+            ],
+        );
+
+        if is_success {
+            info!("Operation completed successfully: {message}");
+        } else {
+            error!("Operation failed: {message}");
+        }
+
+        self.metrics.lock().await.record(method, status, elapsed);
+
+        tracing_ctx.cx.span().end();
+    }
+}
+
+#[async_trait]
+impl TwoFactorServiceTrait for TwoFactorService {
+    async fn enroll(
+        &self,
+        actor: &Claims,
+    ) -> Result<ApiResponse<TwoFactorEnrollmentResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "EnrollTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        match self.start_enrollment(actor.user_id as i32).await {
+            Ok(enrollment) => {
+                self.complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Two-factor enrollment started",
+                )
+                .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Scan the provisioning URI and confirm with a code".to_string(),
+                    data: enrollment,
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to start two-factor enrollment: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn confirm(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ConfirmTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        match self
+            .finish_enrollment(actor.user_id as i32, &input.code)
+            .await
+        {
+            Ok(recovery_codes) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::TwoFactorEnabled).target(actor.user_id),
+                    )
+                    .await;
+
+                self.complete_tracing_success(&tracing_ctx, method, "Two-factor enabled")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Two-factor authentication enabled; store these recovery codes safely"
+                        .to_string(),
+                    data: RecoveryCodesResponse { recovery_codes },
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to confirm two-factor enrollment: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn disable(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Delete;
+        let tracing_ctx = self.start_tracing(
+            "DisableTwoFactor",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        let user_id = actor.user_id as i32;
+        let disabled = async {
+            self.require_valid_code(user_id, &input.code).await?;
+            self.repository.delete(user_id).await
+        };
+
+        match disabled.await {
+            Ok(()) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::TwoFactorDisabled).target(user_id),
+                    )
+                    .await;
+
+                self.complete_tracing_success(&tracing_ctx, method, "Two-factor disabled")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Two-factor authentication disabled".to_string(),
+                    data: (),
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to disable two-factor authentication: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        actor: &Claims,
+        input: &TwoFactorCodeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "RegenerateRecoveryCodes",
+            vec![
+                KeyValue::new("component", "two_factor"),
+                KeyValue::new("user.id", actor.user_id),
+            ],
+        );
+
+        let user_id = actor.user_id as i32;
+        let regenerated = async {
+            self.require_valid_code(user_id, &input.code).await?;
+            self.issue_recovery_codes(user_id).await
+        };
+
+        match regenerated.await {
+            Ok(recovery_codes) => {
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::RecoveryCodesRegenerated).target(user_id),
+                    )
+                    .await;
+
+                self.complete_tracing_success(&tracing_ctx, method, "Recovery codes regenerated")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Recovery codes regenerated; previous codes no longer work"
+                        .to_string(),
+                    data: RecoveryCodesResponse { recovery_codes },
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to regenerate recovery codes: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn is_enabled(&self, user_id: i32) -> Result<bool, AppError> {
+        Ok(self
+            .repository
+            .find_by_user(user_id)
+            .await?
+            .is_some_and(|secret| secret.is_enabled()))
+    }
+
+    async fn verify_code(&self, user_id: i32, code: &str) -> Result<bool, AppError> {
+        let Some(secret) = self
+            .repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|secret| secret.is_enabled())
+        else {
+            return Ok(false);
+        };
+
+        if let Some(step) = verify_totp(&secret.secret, code, Utc::now())? {
+            if self.repository.record_step(user_id, step).await? {
+                return Ok(true);
+            }
+
+            warn!("Rejected replayed TOTP code for user ID: {user_id}");
+            return Ok(false);
+        }
+
+        self.repository
+            .consume_recovery_code(user_id, &hash_token(&normalize_recovery_code(code)))
+            .await
+    }
+}
@@ -1,38 +1,89 @@
 use crate::{
-    abstract_trait::{DynUserRepository, UserServiceTrait},
-    cache::CacheStore,
+    abstract_trait::{
+        DynAuditService, DynHashing, DynMailer, DynRefreshTokenRepository, DynSessionRepository,
+        DynUserRepository, DynUserTokenRepository, MailMessage, UserServiceTrait,
+    },
+    cache::{CacheStore, TokenRevocationStore},
+    config::{Claims, JwtConfig, MailConfig},
     domain::{
-        ApiResponse, ApiResponsePagination, CreateUserRequest, ErrorResponse, FindAllUserRequest,
-        Pagination, UpdateUserRequest, UserResponse,
+        ApiResponse, ApiResponsePagination, AuditContext, ChangePasswordRequest, ClientInfo,
+        ConfirmEmailChangeRequest, CreateUserRequest, ErrorResponse, FindAllUserRequest,
+        Pagination, RequestEmailChangeRequest, UpdateProfileRequest, UpdateUserRequest,
+        UserResponse,
+    },
+    model::{
+        audit_event::{AuditAction, AuditEntry},
+        role::Role,
+        user::User,
+        user_token::TokenPurpose,
+    },
+    utils::{
+        AppError, MetadataInjector, Method, Metrics, Paging, Status as StatusUtils, TracingContext,
+        clamp_page_size, generate_random_token, hash_token,
     },
-    utils::{AppError, MetadataInjector, Method, Metrics, Status as StatusUtils, TracingContext},
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use opentelemetry::{
     Context, KeyValue,
     global::{self, BoxedTracer},
     trace::{Span, SpanKind, TraceContextExt, Tracer},
 };
 use prometheus_client::registry::Registry;
+use serde_json::json;
 use std::{sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::Instant};
 use tonic::Request;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+pub struct UserServiceDeps {
+    pub repository: DynUserRepository,
+    pub user_token_repository: DynUserTokenRepository,
+    pub session_repository: DynSessionRepository,
+    pub refresh_token_repository: DynRefreshTokenRepository,
+    pub hashing: DynHashing,
+    pub mailer: DynMailer,
+    pub mail_config: MailConfig,
+    pub jwt_config: JwtConfig,
+    pub token_revocation: Arc<TokenRevocationStore>,
+    pub audit: DynAuditService,
+    pub metrics: Arc<Mutex<Metrics>>,
+    pub cache_store: Arc<CacheStore>,
+}
 
 #[derive(Clone)]
 pub struct UserService {
     repository: DynUserRepository,
+    user_token_repository: DynUserTokenRepository,
+    session_repository: DynSessionRepository,
+    refresh_token_repository: DynRefreshTokenRepository,
+    hashing: DynHashing,
+    mailer: DynMailer,
+    mail_config: MailConfig,
+    jwt_config: JwtConfig,
+    token_revocation: Arc<TokenRevocationStore>,
+    audit: DynAuditService,
     metrics: Arc<Mutex<Metrics>>,
     cache_store: Arc<CacheStore>,
 }
 
 impl UserService {
-    pub async fn new(
-        repository: DynUserRepository,
-        metrics: Arc<Mutex<Metrics>>,
-        registry: &mut Registry,
-        cache_store: Arc<CacheStore>,
-    ) -> Self {
+    pub async fn new(deps: UserServiceDeps, registry: &mut Registry) -> Self {
+        let UserServiceDeps {
+            repository,
+            user_token_repository,
+            session_repository,
+            refresh_token_repository,
+            hashing,
+            mailer,
+            mail_config,
+            jwt_config,
+            token_revocation,
+            audit,
+            metrics,
+            cache_store,
+        } = deps;
+
         registry.register(
             "user_service_request_counter",
             "Total number of requests to the UserService",
@@ -46,11 +97,165 @@ impl UserService {
 
         Self {
             repository,
+            user_token_repository,
+            session_repository,
+            refresh_token_repository,
+            hashing,
+            mailer,
+            mail_config,
+            jwt_config,
+            token_revocation,
+            audit,
             metrics,
             cache_store,
         }
     }
 
+    /// Promotes the account registered under `email` to admin, so a fresh
+    /// deployment has someone who can grant roles to everyone else.
+    pub async fn bootstrap_admin(&self, email: &str) -> Result<(), AppError> {
+        let Some(user) = self.repository.find_by_email(email).await? else {
+            warn!("ADMIN_EMAIL {email} does not belong to a registered user yet");
+            return Ok(());
+        };
+
+        if user.role == Role::Admin.as_str() {
+            return Ok(());
+        }
+
+        info!("Promoting {email} to admin");
+
+        self.repository
+            .update_user(&UpdateUserRequest {
+                id: user.id,
+                firstname: None,
+                lastname: None,
+                email: None,
+                password: None,
+                role: Some(Role::Admin.to_string()),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Ends every session and rejects every access token issued so far.
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<(), AppError> {
+        self.token_revocation
+            .bump_generation(user_id as i64)
+            .await?;
+
+        self.refresh_token_repository
+            .revoke_all_for_user(user_id)
+            .await?;
+        self.session_repository.revoke_all_for_user(user_id).await
+    }
+
+    /// Applies an admin edit. Tokens carry the role they were issued with, so
+    /// a role or password change signs the user out everywhere rather than
+    /// leaving old tokens with the old privileges.
+    async fn apply_user_update(
+        &self,
+        input: &UpdateUserRequest,
+        before: Option<&User>,
+    ) -> Result<User, AppError> {
+        let mut input = input.clone();
+
+        if let Some(password) = &input.password {
+            input.password = Some(self.hashing.hash_password(password).await?);
+        }
+
+        let user = self.repository.update_user(&input).await?;
+
+        let role_changed = before.is_some_and(|before| before.role != user.role);
+        if role_changed || input.password.is_some() {
+            self.revoke_all_sessions(user.id).await?;
+        }
+
+        Ok(user)
+    }
+
+    async fn find_user(&self, id: i32) -> Result<User, AppError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User with id {id} not found")))
+    }
+
+    async fn verify_current_password(&self, user: &User, password: &str) -> Result<(), AppError> {
+        self.hashing
+            .compare_password(&user.password, password)
+            .await
+            .map_err(|_| AppError::InvalidArgument("Current password is incorrect".to_string()))
+    }
+
+    /// Ends every session except the one the request was made from. Tokens
+    /// that are not tied to a session cannot be told apart from the others,
+    /// so they lose all of them.
+    async fn revoke_other_sessions(&self, actor: &Claims) -> Result<(), AppError> {
+        let user_id = actor.user_id as i32;
+
+        let Some(current) = actor.sid.as_deref() else {
+            return self.revoke_all_sessions(user_id).await;
+        };
+
+        let ttl = self
+            .jwt_config
+            .access_token_ttl
+            .to_std()
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        for id in self
+            .session_repository
+            .revoke_others(user_id, current)
+            .await?
+        {
+            // The session id doubles as the refresh token family id.
+            self.refresh_token_repository.revoke_family(&id).await?;
+            self.token_revocation.revoke_session(&id, ttl).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_email_change_confirmation(
+        &self,
+        user: &User,
+        new_email: &str,
+    ) -> Result<(), AppError> {
+        let token = generate_random_token();
+        let ttl = self.mail_config.email_verification_ttl;
+        let expires_at = Utc::now()
+            + chrono::Duration::from_std(ttl)
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        self.user_token_repository
+            .invalidate_for_user(user.id, TokenPurpose::EmailChange)
+            .await?;
+        self.user_token_repository
+            .create(
+                user.id,
+                TokenPurpose::EmailChange,
+                &hash_token(&token),
+                expires_at,
+            )
+            .await?;
+
+        let message = MailMessage {
+            to: new_email.to_string(),
+            subject: "Confirm your new email address".to_string(),
+            body: format!(
+                "Hello {},\n\nPlease confirm that you want to use this address for your account. The link below expires in {} hours.\n\n{}/confirm-email-change?token={}\n\nIf you did not request this change, you can ignore this email.",
+                user.firstname,
+                ttl.as_secs() / 3600,
+                self.mail_config.app_base_url,
+                token
+            ),
+        };
+
+        self.mailer.send(&message).await
+    }
+
     fn get_tracer(&self) -> BoxedTracer {
         global::tracer("user-service")
     }
@@ -148,7 +353,7 @@ impl UserServiceTrait for UserService {
     ) -> Result<ApiResponsePagination<Vec<UserResponse>>, ErrorResponse> {
         let method = Method::Get;
         let page = req.page.max(1);
-        let page_size = req.page_size.max(1);
+        let page_size = clamp_page_size(req.page_size);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -162,6 +367,7 @@ impl UserServiceTrait for UserService {
                 KeyValue::new("page", page.to_string()),
                 KeyValue::new("page_size", page_size.to_string()),
                 KeyValue::new("search", search.clone().unwrap_or_default()),
+                KeyValue::new("cursor", req.cursor.clone().unwrap_or_default()),
             ],
         );
 
@@ -169,11 +375,23 @@ impl UserServiceTrait for UserService {
             page,
             page_size,
             search: search.clone().unwrap_or_default(),
+            cursor: req.cursor.clone(),
         });
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        let paging = match Paging::from_request(page, req.cursor.as_deref()) {
+            Ok(paging) => paging,
+            Err(err) => {
+                self.complete_tracing_error(&tracing_ctx, method, &err.to_string())
+                    .await;
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
         let cache_key = format!(
-            "users:page={page}:size={page_size}:search={}",
+            "users:page={}:size={page_size}:search={}",
+            paging.cache_key(),
             search.clone().unwrap_or_default()
         );
 
@@ -187,22 +405,39 @@ impl UserServiceTrait for UserService {
             return Ok(cached);
         }
 
-        match self.repository.find_all(page, page_size, search).await {
-            Ok((users, total_items)) => {
+        let result = match paging {
+            Paging::Offset { page } => self.repository.find_all(page, page_size, search).await.map(
+                |(users, total_items)| {
+                    let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+                    let pagination = Pagination {
+                        page,
+                        page_size,
+                        total_items,
+                        total_pages,
+                        next_cursor: None,
+                    };
+
+                    (users, pagination)
+                },
+            ),
+            Paging::Keyset { after } => self
+                .repository
+                .find_after(page_size, after, search)
+                .await
+                .map(|(users, next)| (users, Pagination::keyset(page_size, next))),
+        };
+
+        match result {
+            Ok((users, pagination)) => {
                 info!("Found {} users", users.len());
-                let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
                 let user_responses = users.into_iter().map(UserResponse::from).collect();
 
                 let response = ApiResponsePagination {
                     status: "success".to_string(),
                     message: "Users retrieved successfully".to_string(),
                     data: user_responses,
-                    pagination: Pagination {
-                        page,
-                        page_size,
-                        total_items,
-                        total_pages,
-                    },
+                    pagination,
                 };
 
                 self.cache_store
@@ -228,6 +463,7 @@ impl UserServiceTrait for UserService {
     async fn create_user(
         &self,
         input: &CreateUserRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
         let method = Method::Post;
         let tracing_ctx = self.start_tracing(
@@ -267,6 +503,15 @@ impl UserServiceTrait for UserService {
                     data: UserResponse::from(user),
                 };
 
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::UserCreated)
+                            .target(response.data.id)
+                            .after(&response.data),
+                    )
+                    .await;
+
                 self.complete_tracing_success(&tracing_ctx, method, "User created successfully")
                     .await;
 
@@ -357,6 +602,7 @@ impl UserServiceTrait for UserService {
     async fn update_user(
         &self,
         input: &UpdateUserRequest,
+        audit_ctx: &AuditContext,
     ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse> {
         let method = Method::Put;
         let tracing_ctx = self.start_tracing(
@@ -370,16 +616,35 @@ impl UserServiceTrait for UserService {
         let mut request = Request::new(input.clone());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.repository.update_user(input).await {
+        let before = self.repository.find_by_id(input.id).await.ok().flatten();
+
+        match self.apply_user_update(input, before.as_ref()).await {
             Ok(user) => {
                 let user_email = user.email.clone();
+                let data = UserResponse::from(user);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::UserUpdated)
+                            .target(data.id)
+                            .before(&before.clone().map(UserResponse::from))
+                            .after(&data),
+                    )
+                    .await;
 
                 let response = Some(ApiResponse {
                     status: "success".to_string(),
                     message: "User updated successfully".to_string(),
-                    data: UserResponse::from(user),
+                    data,
                 });
 
+                self.cache_store
+                    .delete_from_cache(&format!("user:id={}", input.id));
+                if let Some(before) = before.filter(|before| before.email != user_email) {
+                    self.cache_store
+                        .delete_from_cache(&format!("user:email={}", before.email));
+                }
                 self.cache_store.set_to_cache(
                     &format!("user:email={user_email}"),
                     &response,
@@ -404,7 +669,11 @@ impl UserServiceTrait for UserService {
         }
     }
 
-    async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse> {
+    async fn delete_user(
+        &self,
+        email: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
         let method = Method::Delete;
         let tracing_ctx = self.start_tracing(
             "DeleteUser",
@@ -417,8 +686,27 @@ impl UserServiceTrait for UserService {
         let mut request = Request::new(email.to_string());
         self.inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.repository.delete_user(email).await {
+        let before = self.repository.find_by_email(email).await.ok().flatten();
+
+        // Sign the user out first so a failed revocation leaves the account in
+        // place rather than deleted with live tokens.
+        let deleted = async {
+            if let Some(user) = &before {
+                self.revoke_all_sessions(user.id).await?;
+            }
+            self.repository.delete_user(email).await
+        };
+
+        match deleted.await {
             Ok(_) => {
+                let entry = match &before {
+                    Some(user) => AuditEntry::new(AuditAction::UserDeleted)
+                        .target(user.id)
+                        .before(&UserResponse::from(user.clone())),
+                    None => AuditEntry::new(AuditAction::UserDeleted),
+                };
+                self.audit.record(audit_ctx, entry).await;
+
                 let response = ApiResponse {
                     status: "success".to_string(),
                     message: "User deleted successfully".to_string(),
@@ -427,6 +715,10 @@ impl UserServiceTrait for UserService {
 
                 self.cache_store
                     .delete_from_cache(&format!("user:email={email}"));
+                if let Some(user) = &before {
+                    self.cache_store
+                        .delete_from_cache(&format!("user:id={}", user.id));
+                }
 
                 self.complete_tracing_success(&tracing_ctx, method, "User deleted successfully")
                     .await;
@@ -445,4 +737,294 @@ impl UserServiceTrait for UserService {
             }
         }
     }
+
+    async fn update_profile(
+        &self,
+        actor: &Claims,
+        input: &UpdateProfileRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        let method = Method::Put;
+        let user_id = actor.user_id as i32;
+        let tracing_ctx = self.start_tracing(
+            "UpdateProfile",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("user.id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(input.clone());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let updated = async {
+            let before = self.find_user(user_id).await?;
+
+            if input.firstname.is_none() && input.lastname.is_none() {
+                return Ok((before.clone(), before));
+            }
+
+            let after = self
+                .repository
+                .update_user(&UpdateUserRequest {
+                    id: user_id,
+                    firstname: input.firstname.clone(),
+                    lastname: input.lastname.clone(),
+                    email: None,
+                    password: None,
+                    role: None,
+                })
+                .await?;
+
+            Ok::<_, AppError>((before, after))
+        };
+
+        match updated.await {
+            Ok((before, after)) => {
+                let data = UserResponse::from(after);
+
+                self.audit
+                    .record(
+                        audit_ctx,
+                        AuditEntry::new(AuditAction::ProfileUpdated)
+                            .target(user_id)
+                            .before(&UserResponse::from(before))
+                            .after(&data),
+                    )
+                    .await;
+
+                self.cache_store
+                    .delete_from_cache(&format!("user:id={user_id}"));
+
+                self.complete_tracing_success(&tracing_ctx, method, "Profile updated successfully")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Profile updated successfully".to_string(),
+                    data,
+                })
+            }
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to update profile: {err}"),
+                )
+                .await;
+
+                Err(ErrorResponse::from(err))
+            }
+        }
+    }
+
+    async fn change_password(
+        &self,
+        actor: &Claims,
+        input: &ChangePasswordRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let user_id = actor.user_id as i32;
+        let tracing_ctx = self.start_tracing(
+            "ChangePassword",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("user.id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let changed = async {
+            let user = self.find_user(user_id).await?;
+            self.verify_current_password(&user, &input.current_password)
+                .await?;
+
+            let hashed_password = self.hashing.hash_password(&input.new_password).await?;
+            self.repository
+                .update_password(user_id, &hashed_password)
+                .await?;
+            self.revoke_other_sessions(actor).await
+        };
+
+        if let Err(err) = changed.await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to change password: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        self.audit
+            .record(
+                audit_ctx,
+                AuditEntry::new(AuditAction::PasswordChanged).target(user_id),
+            )
+            .await;
+
+        self.complete_tracing_success(&tracing_ctx, method, "Password changed successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Password changed, other sessions have been signed out".to_string(),
+            data: (),
+        })
+    }
+
+    async fn request_email_change(
+        &self,
+        actor: &Claims,
+        input: &RequestEmailChangeRequest,
+        audit_ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let method = Method::Post;
+        let user_id = actor.user_id as i32;
+        let tracing_ctx = self.start_tracing(
+            "RequestEmailChange",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("user.id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let requested = async {
+            let user = self.find_user(user_id).await?;
+            self.verify_current_password(&user, &input.current_password)
+                .await?;
+
+            if user.email.eq_ignore_ascii_case(&input.new_email) {
+                return Err(AppError::InvalidArgument(
+                    "New email matches the current one".to_string(),
+                ));
+            }
+
+            if self
+                .repository
+                .find_by_email_exists(&input.new_email)
+                .await?
+            {
+                return Err(AppError::EmailAlreadyExists);
+            }
+
+            self.repository
+                .set_pending_email(user_id, &input.new_email)
+                .await?;
+            self.send_email_change_confirmation(&user, &input.new_email)
+                .await
+        };
+
+        if let Err(err) = requested.await {
+            self.complete_tracing_error(
+                &tracing_ctx,
+                method,
+                &format!("Failed to request email change: {err}"),
+            )
+            .await;
+            return Err(ErrorResponse::from(err));
+        }
+
+        self.audit
+            .record(
+                audit_ctx,
+                AuditEntry::new(AuditAction::EmailChangeRequested)
+                    .target(user_id)
+                    .after(&json!({ "email": input.new_email })),
+            )
+            .await;
+
+        self.complete_tracing_success(&tracing_ctx, method, "Email change requested")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Confirmation link sent to the new email address".to_string(),
+            data: (),
+        })
+    }
+
+    async fn confirm_email_change(
+        &self,
+        input: &ConfirmEmailChangeRequest,
+        client: &ClientInfo,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        let method = Method::Post;
+        let tracing_ctx = self.start_tracing(
+            "ConfirmEmailChange",
+            vec![KeyValue::new("component", "user")],
+        );
+
+        let mut request = Request::new(());
+        self.inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let confirmed = async {
+            let token = self
+                .user_token_repository
+                .consume(TokenPurpose::EmailChange, &hash_token(&input.token))
+                .await?
+                .ok_or_else(|| {
+                    AppError::InvalidArgument(
+                        "Confirmation token is invalid, expired or already used".to_string(),
+                    )
+                })?;
+
+            let before = self.find_user(token.user_id).await?;
+            let after = self
+                .repository
+                .confirm_email_change(token.user_id)
+                .await?
+                .ok_or_else(|| {
+                    AppError::InvalidArgument("No email change is pending".to_string())
+                })?;
+
+            Ok::<_, AppError>((before, after))
+        };
+
+        let (before, after) = match confirmed.await {
+            Ok(users) => users,
+            Err(err) => {
+                self.complete_tracing_error(
+                    &tracing_ctx,
+                    method,
+                    &format!("Failed to confirm email change: {err}"),
+                )
+                .await;
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        self.audit
+            .record(
+                &AuditContext {
+                    actor_id: Some(after.id),
+                    client: client.clone(),
+                },
+                AuditEntry::new(AuditAction::EmailChanged)
+                    .target(after.id)
+                    .before(&json!({ "email": before.email }))
+                    .after(&json!({ "email": after.email })),
+            )
+            .await;
+
+        self.cache_store
+            .delete_from_cache(&format!("user:id={}", after.id));
+        self.cache_store
+            .delete_from_cache(&format!("user:email={}", before.email));
+
+        self.complete_tracing_success(&tracing_ctx, method, "Email changed successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Email address changed successfully".to_string(),
+            data: UserResponse::from(after),
+        })
+    }
 }
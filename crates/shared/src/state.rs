@@ -3,7 +3,9 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::{
-    config::{ConnectionPool, Hashing, JwtConfig},
+    abstract_trait::DynHashing,
+    config::{ConnectionPool, Hashing, HashingConfig, JwtConfig, JwtKeyPolicy, TrustedProxies},
+    service::run_key_rotation,
     utils::{DependenciesInject, Metrics, SystemMetrics, run_metrics_collector},
 };
 
@@ -14,12 +16,15 @@ pub struct AppState {
     pub jwt_config: JwtConfig,
     pub metrics: Arc<Mutex<Metrics>>,
     pub system_metrics: Arc<SystemMetrics>,
+    pub trusted_proxies: TrustedProxies,
 }
 
 impl AppState {
     pub async fn new(pool: ConnectionPool, jwt_secret: &str) -> Self {
-        let jwt_config = JwtConfig::new(jwt_secret);
-        let hashing = Hashing;
+        let jwt_config = JwtConfig::from_env(jwt_secret).expect("Invalid JWT configuration");
+        let hashing = Arc::new(Hashing::new(
+            HashingConfig::from_env().expect("Invalid password hashing configuration"),
+        )) as DynHashing;
 
         let registry = Arc::new(Mutex::new(Registry::default()));
         let metrics = Arc::new(Mutex::new(Metrics::new()));
@@ -41,12 +46,27 @@ impl AppState {
             .await
         };
 
+        if jwt_config.algorithm.is_asymmetric() {
+            di_container
+                .jwt_key_service
+                .rotate()
+                .await
+                .expect("Failed to initialize JWT signing keys");
+
+            tokio::spawn(run_key_rotation(
+                di_container.jwt_key_service.clone(),
+                JwtKeyPolicy::default().check_interval,
+            ));
+        }
+
         Self {
             registry,
             di_container,
             jwt_config,
             metrics,
             system_metrics,
+            trusted_proxies: TrustedProxies::from_env()
+                .expect("Invalid trusted proxy configuration"),
         }
     }
 }
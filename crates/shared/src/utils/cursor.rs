@@ -0,0 +1,91 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use sqlx::prelude::FromRow;
+
+use super::AppError;
+
+/// The last row of a keyset page. Listings run newest first on
+/// `(created_at, id)`, so the next page holds the rows sorting below this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromRow)]
+pub struct PageCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: i32,
+}
+
+impl PageCursor {
+    /// Opaque to clients: they only ever hand back what a listing returned.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}.{}",
+            self.created_at.timestamp_micros(),
+            self.id
+        ))
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidArgument("Invalid pagination cursor".to_string());
+
+        let decoded = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+
+        let (micros, id) = decoded.split_once('.').ok_or_else(invalid)?;
+        let micros = micros.parse::<i64>().map_err(|_| invalid())?;
+        let id = id.parse::<i32>().map_err(|_| invalid())?;
+
+        let created_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+
+        Ok(Self { created_at, id })
+    }
+}
+
+/// The most rows a single listing page returns, whatever the client asks for.
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+/// Keeps a requested page size within `1..=MAX_PAGE_SIZE`.
+pub fn clamp_page_size(requested: i32) -> i32 {
+    requested.clamp(1, MAX_PAGE_SIZE)
+}
+
+/// Rows to skip before the 1-based `page`. Saturates rather than overflowing
+/// when a client asks for an absurdly distant page.
+pub fn page_offset(page: i32, page_size: i32) -> u64 {
+    let skipped_pages = u64::try_from(page.saturating_sub(1)).unwrap_or(0);
+    let page_size = u64::try_from(page_size).unwrap_or(0);
+
+    skipped_pages.saturating_mul(page_size)
+}
+
+/// How a list request is paged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Paging {
+    /// `OFFSET`/`LIMIT` plus a total count, for page numbers.
+    Offset { page: i32 },
+    /// Rows after `after`, or from the newest one when it is `None`. Nothing
+    /// is counted.
+    Keyset { after: Option<PageCursor> },
+}
+
+impl Paging {
+    /// Requests without a `cursor` keep offset paging; an empty one asks for
+    /// the first keyset page.
+    pub fn from_request(page: i32, cursor: Option<&str>) -> Result<Self, AppError> {
+        match cursor.map(str::trim) {
+            None => Ok(Paging::Offset { page: page.max(1) }),
+            Some("") => Ok(Paging::Keyset { after: None }),
+            Some(cursor) => Ok(Paging::Keyset {
+                after: Some(PageCursor::decode(cursor)?),
+            }),
+        }
+    }
+
+    /// Identifies the page within a listing's cache keys.
+    pub fn cache_key(&self) -> String {
+        match self {
+            Paging::Offset { page } => page.to_string(),
+            Paging::Keyset { after: None } => "first".to_string(),
+            Paging::Keyset {
+                after: Some(cursor),
+            } => format!("after-{}", cursor.encode()),
+        }
+    }
+}
@@ -5,15 +5,29 @@ use tokio::sync::Mutex;
 
 use crate::{
     abstract_trait::{
-        DynAuthService, DynCategoryRepository, DynCategoryService, DynCommentRepository,
-        DynCommentService, DynFileService, DynPostsRepository, DynPostsService, DynUserRepository,
-        DynUserService,
+        DynApiKeyRepository, DynApiKeyService, DynAuditRepository, DynAuditService, DynAuthService,
+        DynCategoryRepository, DynCategoryService, DynCommentRepository, DynCommentService,
+        DynFileService, DynHashing, DynJwtKeyRepository, DynJwtKeyService, DynMailer,
+        DynOidcProvider, DynPostsRepository, DynPostsService, DynRefreshTokenRepository,
+        DynRoleRepository, DynSessionRepository, DynSessionService, DynTagRepository,
+        DynTagService, DynTwoFactorRepository, DynTwoFactorService, DynUserIdentityRepository,
+        DynUserRepository, DynUserService, DynUserTokenRepository,
+    },
+    cache::{CacheStore, LoginAttemptPolicy, LoginAttemptStore, TokenRevocationStore},
+    config::{
+        ConnectionPool, JwtConfig, JwtKeyPolicy, MailConfig, OidcConfig, RedisClient, RedisConfig,
+        TwoFactorConfig,
+    },
+    repository::{
+        ApiKeyRepository, AuditRepository, CategoryRepository, CommentRepository, JwtKeyRepository,
+        PostRepository, RefreshTokenRepository, RoleRepository, SessionRepository, TagRepository,
+        TwoFactorRepository, UserIdentityRepository, UserRepository, UserTokenRepository,
     },
-    cache::CacheStore,
-    config::{ConnectionPool, Hashing, JwtConfig, RedisClient, RedisConfig},
-    repository::{CategoryRepository, CommentRepository, PostRepository, UserRepository},
     service::{
-        AuthService, CategoryService, CommentService, FileService, PostService, UserService,
+        ApiKeyService, ApiKeyServiceDeps, AuditService, AuthService, AuthServiceDeps,
+        CategoryService, CommentService, FileMailer, FileService, JwtKeyService, OidcProvider,
+        PostService, SessionService, SessionServiceDeps, TagService, TwoFactorService,
+        TwoFactorServiceDeps, UserService, UserServiceDeps,
     },
     utils::Metrics,
 };
@@ -22,10 +36,16 @@ use crate::{
 pub struct DependenciesInject {
     pub category_service: DynCategoryService,
     pub post_service: DynPostsService,
+    pub tag_service: DynTagService,
     pub comment_service: DynCommentService,
     pub user_service: DynUserService,
     pub auth_service: DynAuthService,
     pub file_service: DynFileService,
+    pub jwt_key_service: DynJwtKeyService,
+    pub api_key_service: DynApiKeyService,
+    pub two_factor_service: DynTwoFactorService,
+    pub session_service: DynSessionService,
+    pub audit_service: DynAuditService,
 }
 
 impl std::fmt::Debug for DependenciesInject {
@@ -33,10 +53,16 @@ impl std::fmt::Debug for DependenciesInject {
         f.debug_struct("DependenciesInject")
             .field("category_service", &"DynCategoryService")
             .field("post_service", &"DynPostsService")
+            .field("tag_service", &"DynTagService")
             .field("comment_service", &"DynCommentService")
             .field("user_service", &"DynUserService")
             .field("auth_service", &"DynAuthService")
             .field("file_service", &"DynFileService")
+            .field("jwt_key_service", &"DynJwtKeyService")
+            .field("api_key_service", &"DynApiKeyService")
+            .field("two_factor_service", &"DynTwoFactorService")
+            .field("session_service", &"DynSessionService")
+            .field("audit_service", &"DynAuditService")
             .finish()
     }
 }
@@ -44,17 +70,12 @@ impl std::fmt::Debug for DependenciesInject {
 impl DependenciesInject {
     pub async fn new(
         pool: ConnectionPool,
-        hashing: Hashing,
+        hashing: DynHashing,
         jwt_config: JwtConfig,
         metrics: Arc<Mutex<Metrics>>,
         registry: &mut Registry,
     ) -> Self {
-        let config = RedisConfig {
-            host: "redis".into(),
-            port: 6379,
-            db: 1,
-            password: Some("dragon_knight".into()),
-        };
+        let config = RedisConfig::default();
 
         let redis = RedisClient::new(&config)
             .await
@@ -64,17 +85,52 @@ impl DependenciesInject {
         redis.ping().context("Failed to ping Redis server").unwrap();
 
         let cache = Arc::new(CacheStore::new(redis.client.clone()));
+        let token_revocation = Arc::new(TokenRevocationStore::new(cache.clone()));
+        let login_attempts = Arc::new(LoginAttemptStore::new(
+            cache.clone(),
+            LoginAttemptPolicy::default(),
+        ));
 
         let category_repository =
             Arc::new(CategoryRepository::new(pool.clone())) as DynCategoryRepository;
         let post_repository = Arc::new(PostRepository::new(pool.clone())) as DynPostsRepository;
+        let tag_repository = Arc::new(TagRepository::new(pool.clone())) as DynTagRepository;
         let comment_repository =
             Arc::new(CommentRepository::new(pool.clone())) as DynCommentRepository;
+        let refresh_token_repository =
+            Arc::new(RefreshTokenRepository::new(pool.clone())) as DynRefreshTokenRepository;
+        let role_repository = Arc::new(RoleRepository::new(pool.clone())) as DynRoleRepository;
+        let user_token_repository =
+            Arc::new(UserTokenRepository::new(pool.clone())) as DynUserTokenRepository;
+        let api_key_repository =
+            Arc::new(ApiKeyRepository::new(pool.clone())) as DynApiKeyRepository;
+        let two_factor_repository =
+            Arc::new(TwoFactorRepository::new(pool.clone())) as DynTwoFactorRepository;
+        let identity_repository =
+            Arc::new(UserIdentityRepository::new(pool.clone())) as DynUserIdentityRepository;
+        let session_repository =
+            Arc::new(SessionRepository::new(pool.clone())) as DynSessionRepository;
+        let audit_repository = Arc::new(AuditRepository::new(pool.clone())) as DynAuditRepository;
+        let jwt_key_repository =
+            Arc::new(JwtKeyRepository::new(pool.clone())) as DynJwtKeyRepository;
         let user_repository = Arc::new(UserRepository::new(pool)) as DynUserRepository;
 
+        let mail_config = MailConfig::from_env()
+            .context("Invalid mail configuration")
+            .unwrap();
+        let mailer = Arc::new(FileMailer::new(
+            mail_config.outbox_dir.clone(),
+            mail_config.from_address.clone(),
+        )) as DynMailer;
+
+        let audit_service =
+            Arc::new(AuditService::new(audit_repository, metrics.clone(), registry).await)
+                as DynAuditService;
+
         let category_service = Arc::new(
             CategoryService::new(
                 category_repository,
+                audit_service.clone(),
                 metrics.clone(),
                 registry,
                 cache.clone(),
@@ -83,44 +139,180 @@ impl DependenciesInject {
         ) as DynCategoryService;
 
         let post_service = Arc::new(
-            PostService::new(post_repository, metrics.clone(), registry, cache.clone()).await,
+            PostService::new(
+                post_repository.clone(),
+                tag_repository.clone(),
+                audit_service.clone(),
+                metrics.clone(),
+                registry,
+                cache.clone(),
+            )
+            .await,
         ) as DynPostsService;
 
-        let comment_service = Arc::new(
-            CommentService::new(comment_repository, metrics.clone(), registry, cache.clone()).await,
-        ) as DynCommentService;
+        let tag_service = Arc::new(
+            TagService::new(
+                tag_repository,
+                audit_service.clone(),
+                metrics.clone(),
+                registry,
+                cache.clone(),
+            )
+            .await,
+        ) as DynTagService;
 
-        let user_service = Arc::new(
-            UserService::new(
-                user_repository.clone(),
+        let comment_service = Arc::new(
+            CommentService::new(
+                comment_repository,
+                post_repository.clone(),
+                audit_service.clone(),
                 metrics.clone(),
                 registry,
                 cache.clone(),
             )
             .await,
-        ) as DynUserService;
+        ) as DynCommentService;
+
+        let user_service = UserService::new(
+            UserServiceDeps {
+                repository: user_repository.clone(),
+                user_token_repository: user_token_repository.clone(),
+                session_repository: session_repository.clone(),
+                refresh_token_repository: refresh_token_repository.clone(),
+                hashing: hashing.clone(),
+                mailer: mailer.clone(),
+                mail_config: mail_config.clone(),
+                jwt_config: jwt_config.clone(),
+                token_revocation: token_revocation.clone(),
+                audit: audit_service.clone(),
+                metrics: metrics.clone(),
+                cache_store: cache.clone(),
+            },
+            registry,
+        )
+        .await;
+
+        if let Ok(email) = std::env::var("ADMIN_EMAIL") {
+            user_service
+                .bootstrap_admin(&email)
+                .await
+                .context("Failed to bootstrap the admin account")
+                .unwrap();
+        }
+
+        let user_service = Arc::new(user_service) as DynUserService;
+
+        let api_key_service = Arc::new(
+            ApiKeyService::new(
+                ApiKeyServiceDeps {
+                    repository: api_key_repository,
+                    user_repository: user_repository.clone(),
+                    role_repository: role_repository.clone(),
+                    jwt_config: jwt_config.clone(),
+                    token_revocation: token_revocation.clone(),
+                    audit: audit_service.clone(),
+                    metrics: metrics.clone(),
+                },
+                registry,
+            )
+            .await,
+        ) as DynApiKeyService;
+
+        let session_service = Arc::new(
+            SessionService::new(
+                SessionServiceDeps {
+                    repository: session_repository.clone(),
+                    refresh_token_repository: refresh_token_repository.clone(),
+                    jwt_config: jwt_config.clone(),
+                    token_revocation: token_revocation.clone(),
+                    audit: audit_service.clone(),
+                    metrics: metrics.clone(),
+                },
+                registry,
+            )
+            .await,
+        ) as DynSessionService;
+
+        let two_factor_config = TwoFactorConfig::from_env()
+            .context("Invalid two-factor configuration")
+            .unwrap();
+
+        let two_factor_service = Arc::new(
+            TwoFactorService::new(
+                TwoFactorServiceDeps {
+                    repository: two_factor_repository,
+                    user_repository: user_repository.clone(),
+                    audit: audit_service.clone(),
+                    login_attempts: login_attempts.clone(),
+                    config: two_factor_config.clone(),
+                    metrics: metrics.clone(),
+                },
+                registry,
+            )
+            .await,
+        ) as DynTwoFactorService;
+
+        let oidc = OidcConfig::from_env()
+            .context("Invalid OIDC configuration")
+            .unwrap()
+            .map(|config| {
+                Arc::new(
+                    OidcProvider::new(config)
+                        .context("Failed to initialize OIDC provider")
+                        .unwrap(),
+                ) as DynOidcProvider
+            });
 
         let auth_service = Arc::new(
             AuthService::new(
-                user_repository,
-                hashing,
-                jwt_config,
-                metrics.clone(),
+                AuthServiceDeps {
+                    repository: user_repository,
+                    refresh_token_repository,
+                    role_repository,
+                    user_token_repository,
+                    hashing,
+                    jwt_config: jwt_config.clone(),
+                    metrics: metrics.clone(),
+                    cache_store: cache.clone(),
+                    token_revocation,
+                    login_attempts,
+                    mailer,
+                    mail_config,
+                    two_factor: two_factor_service.clone(),
+                    two_factor_config,
+                    identity_repository,
+                    oidc,
+                    session_repository,
+                    audit: audit_service.clone(),
+                },
                 registry,
-                cache.clone(),
             )
             .await,
         ) as DynAuthService;
 
         let file_service = Arc::new(FileService::default()) as DynFileService;
 
+        let jwt_key_service = Arc::new(JwtKeyService::new(
+            jwt_key_repository,
+            jwt_config,
+            JwtKeyPolicy::from_env()
+                .context("Invalid JWT key rotation configuration")
+                .unwrap(),
+        )) as DynJwtKeyService;
+
         Self {
             category_service,
             post_service,
+            tag_service,
             comment_service,
             user_service,
             auth_service,
             file_service,
+            jwt_key_service,
+            api_key_service,
+            two_factor_service,
+            session_service,
+            audit_service,
         }
     }
 }
@@ -1,5 +1,4 @@
 use anyhow::Error as AnyhowError;
-use bcrypt::BcryptError;
 use jsonwebtoken::errors::Error as JwtError;
 use serde::Serialize;
 use sqlx::Error as SqlxError;
@@ -12,7 +11,7 @@ pub enum AppError {
     SqlxError(#[from] SqlxError),
 
     #[error("Hashing error: {0}")]
-    HashingError(#[from] BcryptError),
+    HashingError(String),
 
     #[error("Invalid credentials")]
     InvalidCredentials,
@@ -23,23 +22,50 @@ pub enum AppError {
     #[error("Token validation error")]
     TokenValidationError,
 
+    #[error("Token has been revoked")]
+    TokenRevoked,
+
+    #[error("Invalid two-factor code")]
+    InvalidTwoFactorCode,
+
+    #[error("OpenID Connect error: {0}")]
+    OidcError(String),
+
+    #[error("Refresh token reuse detected")]
+    RefreshTokenReused,
+
     #[error("Token generation error: {0}")]
     TokenGenerationError(#[from] JwtError),
 
     #[error("Bcrypt error: {0}")]
     BcryptError(String),
 
+    #[error("Too many failed login attempts, retry after {0} seconds")]
+    TooManyRequests(u64),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
     #[error("Email already exists")]
     EmailAlreadyExists,
 
+    #[error("Already exists: {0}")]
+    AlreadyExists(String),
+
     #[error("Validation error: {0}")]
     ValidationError(ValidationErrors),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
+
+    #[error("Cache error: {0}")]
+    CacheError(String),
 }
 
 impl From<AnyhowError> for AppError {
@@ -0,0 +1,25 @@
+/// Markers `ts_headline` is told to put around matches. Private-use code
+/// points, so they cannot clash with anything in a post.
+pub const HIGHLIGHT_START: char = '\u{E000}';
+pub const HIGHLIGHT_STOP: char = '\u{E001}';
+
+/// HTML-escapes a `ts_headline` result and turns its markers into `<mark>`
+/// tags, so the snippet can be shown as is without trusting the post text.
+pub fn render_highlight(raw: &str) -> String {
+    let mut html = String::with_capacity(raw.len() + 16);
+
+    for c in raw.chars() {
+        match c {
+            HIGHLIGHT_START => html.push_str("<mark>"),
+            HIGHLIGHT_STOP => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+
+    html
+}
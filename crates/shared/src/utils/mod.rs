@@ -1,15 +1,26 @@
+mod cursor;
 mod di;
 mod errors;
+mod highlight;
 mod log;
 mod metadata;
 mod metrics;
 mod otel;
 mod slug;
+mod token;
+mod totp;
 
+pub use self::cursor::{MAX_PAGE_SIZE, PageCursor, Paging, clamp_page_size, page_offset};
 pub use self::di::DependenciesInject;
 pub use self::errors::AppError;
+pub use self::highlight::{HIGHLIGHT_START, HIGHLIGHT_STOP, render_highlight};
 pub use self::log::init_logger;
 pub use self::metadata::MetadataInjector;
 pub use self::metrics::{Method, Metrics, Status, SystemMetrics, run_metrics_collector};
 pub use self::otel::{Telemetry, TracingContext};
-pub use self::slug::generate_slug;
+pub use self::slug::{generate_slug, slug_matches, unique_slug};
+pub use self::token::{generate_random_token, hash_token, pkce_challenge};
+pub use self::totp::{
+    TOTP_DIGITS, TOTP_PERIOD_SECS, generate_recovery_code, generate_totp_secret,
+    normalize_recovery_code, totp_code, totp_provisioning_uri, totp_step, verify_totp,
+};
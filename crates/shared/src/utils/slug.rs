@@ -1,14 +1,128 @@
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// Slugs are stored in `VARCHAR(255)` columns; this leaves room for a
+/// collision suffix.
+const MAX_SLUG_CHARS: usize = 200;
+
+/// Turns a title into a lowercase, hyphen-separated slug. Accents are
+/// stripped and Cyrillic and Greek are transliterated to ASCII; letters from
+/// other scripts are kept as they are.
 pub fn generate_slug(title: &str) -> String {
-    let title = title.to_lowercase();
+    let mut slug = String::with_capacity(title.len());
+
+    for c in title.nfkd().filter(|c| !is_combining_mark(*c)) {
+        for c in c.to_lowercase() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c);
+            } else if let Some(latin) = transliterate(c) {
+                slug.push_str(latin);
+            } else if c.is_alphanumeric() {
+                slug.push(c);
+            } else if matches!(c, '\'' | '’') {
+                // "don't" reads better as "dont" than "don-t".
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+    }
 
-    let mut slug = title.replace(' ', "-");
+    let slug: String = slug.chars().take(MAX_SLUG_CHARS).collect();
 
-    slug = slug
-        .chars()
-        .filter(|c| c.is_alphanumeric() || *c == '-')
-        .collect();
+    slug.trim_matches('-').to_string()
+}
+
+/// Returns `base`, or `base-2`, `base-3`, … for the first one not in `taken`.
+pub fn unique_slug(base: &str, taken: &[String]) -> String {
+    if !taken.iter().any(|slug| slug == base) {
+        return base.to_string();
+    }
 
-    slug = slug.trim_matches('-').to_string();
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("an unused suffix always exists")
+}
+
+/// Letters that survive NFKD decomposition but have a conventional Latin
+/// spelling. `Some("")` drops the letter.
+fn transliterate(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'ø' => "o",
+        'đ' | 'ð' => "d",
+        'þ' => "th",
+        'ł' => "l",
+        'ı' => "i",
+
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'э' => "e",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' | 'й' => "i",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ы' => "y",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' | 'ι' => "i",
+        'θ' => "th",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ω' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        _ => return None,
+    };
+
+    Some(latin)
+}
 
-    slug
+/// Whether `slug` is `base` itself or `base` with a collision suffix, i.e.
+/// whether a record that already has `slug` can keep it for `base`.
+pub fn slug_matches(slug: &str, base: &str) -> bool {
+    match slug.strip_prefix(base) {
+        Some("") => true,
+        Some(rest) => rest
+            .strip_prefix('-')
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit())),
+        None => false,
+    }
 }
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use ring::{
+    hmac,
+    rand::{SecureRandom, SystemRandom},
+};
+
+use crate::utils::AppError;
+
+pub const TOTP_DIGITS: u32 = 6;
+pub const TOTP_PERIOD_SECS: i64 = 30;
+const TOTP_SECRET_BYTES: usize = 20;
+const TOTP_ALLOWED_SKEW: i64 = 1;
+const RECOVERY_CODE_BYTES: usize = 10;
+const URI_LABEL: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_');
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], AppError> {
+    let mut bytes = [0u8; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| AppError::InternalError("Failed to generate random bytes".to_string()))?;
+
+    Ok(bytes)
+}
+
+pub fn generate_totp_secret() -> Result<String, AppError> {
+    Ok(BASE32_NOPAD.encode(&random_bytes::<TOTP_SECRET_BYTES>()?))
+}
+
+pub fn generate_recovery_code() -> Result<String, AppError> {
+    let code = BASE32_NOPAD
+        .encode(&random_bytes::<RECOVERY_CODE_BYTES>()?)
+        .to_ascii_lowercase();
+    let (head, tail) = code.split_at(code.len() / 2);
+
+    Ok(format!("{head}-{tail}"))
+}
+
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.trim().to_ascii_lowercase()
+}
+
+pub fn totp_provisioning_uri(issuer: &str, account: &str, secret: &str) -> String {
+    let issuer = utf8_percent_encode(issuer, URI_LABEL);
+    let account = utf8_percent_encode(account, URI_LABEL);
+
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={TOTP_DIGITS}&period={TOTP_PERIOD_SECS}"
+    )
+}
+
+/// Compares every byte regardless of where the first mismatch is, so the
+/// response time does not reveal how many leading digits were right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+pub fn totp_step(at: DateTime<Utc>) -> i64 {
+    at.timestamp().div_euclid(TOTP_PERIOD_SECS)
+}
+
+pub fn totp_code(secret: &str, step: i64) -> Result<String, AppError> {
+    let key_bytes = BASE32_NOPAD
+        .decode(secret.as_bytes())
+        .map_err(|e| AppError::InternalError(format!("Invalid TOTP secret: {e}")))?;
+
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, &key_bytes);
+    let digest = hmac::sign(&key, &(step as u64).to_be_bytes());
+    let digest = digest.as_ref();
+
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    Ok(format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    ))
+}
+
+/// Returns the time step the code was generated for, allowing one step of
+/// clock drift either way, so callers can reject replays of the same step.
+pub fn verify_totp(secret: &str, code: &str, at: DateTime<Utc>) -> Result<Option<i64>, AppError> {
+    let code = code.trim();
+
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let current = totp_step(at);
+
+    for step in (current - TOTP_ALLOWED_SKEW)..=(current + TOTP_ALLOWED_SKEW) {
+        if constant_time_eq(totp_code(secret, step)?.as_bytes(), code.as_bytes()) {
+            return Ok(Some(step));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,42 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde_json::Value;
+use shared::{
+    config::{JwtConfig, TokenKind},
+    utils::AppError,
+};
+
+fn payload(token: &str) -> Value {
+    let payload = token.split('.').nth(1).unwrap();
+    serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap()
+}
+
+#[test]
+fn login_tokens_are_interactive() {
+    let config = JwtConfig::new("test-secret");
+    let token = config
+        .generate_token(7, "reader", vec![], Some("3f0c1a9e-session"), 0)
+        .unwrap();
+
+    let claims = config.decode_token(&token).unwrap();
+
+    assert_eq!(claims.kind, TokenKind::Session);
+    assert!(payload(&token).get("typ").is_none());
+    assert!(claims.require_interactive().is_ok());
+}
+
+#[test]
+fn api_key_tokens_cannot_manage_the_account() {
+    let config = JwtConfig::new("test-secret");
+    let token = config
+        .generate_api_key_token(7, "reader", vec![], 0)
+        .unwrap();
+
+    let claims = config.decode_token(&token).unwrap();
+
+    assert_eq!(payload(&token)["typ"], "api_key");
+    assert_eq!(claims.kind, TokenKind::ApiKey);
+    assert!(matches!(
+        claims.require_interactive(),
+        Err(AppError::Forbidden(_))
+    ));
+}
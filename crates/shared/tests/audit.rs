@@ -0,0 +1,56 @@
+use genproto::audit::AuditEventResponse as ProtoAuditEventResponse;
+use serde_json::json;
+use shared::{
+    domain::AuditEventResponse,
+    model::audit_event::{AuditAction, AuditEntry},
+};
+
+#[test]
+fn entry_records_target_and_snapshots() {
+    let entry = AuditEntry::new(AuditAction::CategoryUpdated)
+        .target(42)
+        .before(&json!({ "name": "old" }))
+        .after(&json!({ "name": "new" }));
+
+    assert_eq!(entry.target_id.as_deref(), Some("42"));
+    assert_eq!(entry.before, Some(json!({ "name": "old" })));
+    assert_eq!(entry.after, Some(json!({ "name": "new" })));
+}
+
+#[test]
+fn null_snapshots_are_omitted() {
+    let entry = AuditEntry::new(AuditAction::UserDeleted).before(&None::<String>);
+
+    assert!(entry.before.is_none());
+}
+
+#[test]
+fn actions_name_their_target_type() {
+    assert_eq!(AuditAction::PostDeleted.target_type(), "post");
+    assert_eq!(AuditAction::SessionRevoked.target_type(), "session");
+    assert_eq!(AuditAction::LoginFailed.target_type(), "user");
+    assert_eq!(AuditAction::ApiKeyRevoked.as_str(), "api_key.revoked");
+}
+
+#[test]
+fn snapshots_survive_the_grpc_round_trip() {
+    let event = AuditEventResponse {
+        id: 1,
+        actor_id: Some(7),
+        action: "category.updated".into(),
+        target_type: "category".into(),
+        target_id: Some("42".into()),
+        ip_address: Some("127.0.0.1".into()),
+        request_id: Some("req-1".into()),
+        before: Some(json!({ "name": "old" })),
+        after: None,
+        created_at: "2026-10-16T00:00:00+00:00".into(),
+    };
+
+    let proto: ProtoAuditEventResponse = event.into();
+    assert_eq!(proto.before.as_deref(), Some(r#"{"name":"old"}"#));
+
+    let back: AuditEventResponse = proto.into();
+    assert_eq!(back.before, Some(json!({ "name": "old" })));
+    assert!(back.after.is_none());
+}
@@ -0,0 +1,1048 @@
+mod common {
+    pub mod audit;
+    pub mod cache;
+    pub mod hashing;
+    pub mod mailer;
+    pub mod memory_store;
+    pub mod refresh_tokens;
+    pub mod sessions;
+    pub mod user_tokens;
+    pub mod users;
+}
+
+use async_trait::async_trait;
+use chrono::Utc;
+use common::{
+    audit::RecordingAudit,
+    cache::unreachable_cache,
+    hashing::{cheap, cheap_argon2id},
+    mailer::CapturingMailer,
+    memory_store::MemoryStore,
+    refresh_tokens::InMemoryRefreshTokens,
+    sessions::InMemorySessions,
+    user_tokens::InMemoryUserTokens,
+    users::InMemoryUsers,
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    abstract_trait::{
+        AuthServiceTrait, HashingTrait, OidcIdentity, OidcProviderTrait, RoleRepositoryTrait,
+        TwoFactorServiceTrait, UserIdentityRepositoryTrait,
+    },
+    cache::{LoginAttemptPolicy, LoginAttemptStore, TokenRevocationStore},
+    config::{
+        Claims, EmailVerificationPolicy, HashAlgorithm, Hashing, JwtConfig, MailConfig,
+        TwoFactorConfig,
+    },
+    domain::{
+        ApiResponse, AuditContext, ClientInfo, ErrorResponse, LoginRequest, LoginResponse,
+        LogoutRequest, OidcLoginRequest, PasswordResetRequest, RecoveryCodesResponse,
+        RefreshTokenRequest, RegisterRequest, ResendVerificationRequest, ResetPasswordRequest,
+        TokenResponse, TwoFactorCodeRequest, TwoFactorEnrollmentResponse, TwoFactorLoginRequest,
+        VerifyEmailRequest,
+    },
+    model::{audit_event::AuditAction, user::User, user_identity::UserIdentity},
+    service::{AuthService, AuthServiceDeps},
+    utils::{AppError, Metrics},
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The permissions the roles migration seeds for authors.
+struct SeededRoles;
+
+#[async_trait]
+impl RoleRepositoryTrait for SeededRoles {
+    async fn find_permissions(&self, role: &str) -> Result<Vec<String>, AppError> {
+        Ok(match role {
+            "author" => vec!["comments:write", "posts:delete", "posts:write"],
+            _ => vec![],
+        }
+        .into_iter()
+        .map(str::to_string)
+        .collect())
+    }
+}
+
+/// Two-factor is off when `.0` is `None`; otherwise it is on for every user
+/// and only that code is accepted.
+struct TwoFactor(Option<&'static str>);
+
+#[async_trait]
+impl TwoFactorServiceTrait for TwoFactor {
+    async fn enroll(
+        &self,
+        _actor: &Claims,
+    ) -> Result<ApiResponse<TwoFactorEnrollmentResponse>, ErrorResponse> {
+        unimplemented!("enrollment is covered by the two-factor service tests")
+    }
+
+    async fn confirm(
+        &self,
+        _actor: &Claims,
+        _input: &TwoFactorCodeRequest,
+        _ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        unimplemented!("enrollment is covered by the two-factor service tests")
+    }
+
+    async fn disable(
+        &self,
+        _actor: &Claims,
+        _input: &TwoFactorCodeRequest,
+        _ctx: &AuditContext,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        unimplemented!("disabling is covered by the two-factor service tests")
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        _actor: &Claims,
+        _input: &TwoFactorCodeRequest,
+        _ctx: &AuditContext,
+    ) -> Result<ApiResponse<RecoveryCodesResponse>, ErrorResponse> {
+        unimplemented!("recovery codes are covered by the two-factor service tests")
+    }
+
+    async fn is_enabled(&self, _user_id: i32) -> Result<bool, AppError> {
+        Ok(self.0.is_some())
+    }
+
+    async fn verify_code(&self, _user_id: i32, code: &str) -> Result<bool, AppError> {
+        Ok(self.0 == Some(code))
+    }
+}
+
+/// Links every provider identity to user `.0`, or to nobody when `None`.
+struct Identities(Option<i32>);
+
+#[async_trait]
+impl UserIdentityRepositoryTrait for Identities {
+    async fn find(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>, AppError> {
+        Ok(self.0.map(|user_id| UserIdentity {
+            id: 1,
+            user_id,
+            issuer: issuer.to_string(),
+            subject: subject.to_string(),
+            email: String::new(),
+            created_at: Utc::now(),
+            last_login_at: None,
+        }))
+    }
+
+    async fn create(
+        &self,
+        _user_id: i32,
+        _issuer: &str,
+        _subject: &str,
+        _email: &str,
+    ) -> Result<UserIdentity, AppError> {
+        unimplemented!("linking is covered by the OIDC tests")
+    }
+
+    async fn record_login(&self, _id: i32, _email: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+}
+
+/// Accepts any ID token as the identity it was built with, keyed by the
+/// nonce; signature checks are covered against a real provider in `oidc.rs`.
+struct StaticOidcProvider(OidcIdentity);
+
+#[async_trait]
+impl OidcProviderTrait for StaticOidcProvider {
+    async fn authorization_url(
+        &self,
+        _state: &str,
+        _nonce: &str,
+        _code_challenge: &str,
+    ) -> Result<String, AppError> {
+        unimplemented!("the redirect flow is covered by the OIDC tests")
+    }
+
+    async fn exchange_code(&self, _code: &str, _code_verifier: &str) -> Result<String, AppError> {
+        unimplemented!("the redirect flow is covered by the OIDC tests")
+    }
+
+    async fn verify_id_token(
+        &self,
+        _id_token: &str,
+        nonce: &str,
+    ) -> Result<OidcIdentity, AppError> {
+        Ok(OidcIdentity {
+            token_id: nonce.to_string(),
+            ..self.0.clone()
+        })
+    }
+}
+
+fn auth_deps(users: Arc<InMemoryUsers>) -> AuthServiceDeps {
+    AuthServiceDeps {
+        repository: users,
+        refresh_token_repository: Arc::new(InMemoryRefreshTokens::default()),
+        role_repository: Arc::new(SeededRoles),
+        hashing: Arc::new(cheap_argon2id()),
+        jwt_config: JwtConfig::new("test-secret"),
+        metrics: Arc::new(tokio::sync::Mutex::new(Metrics::new())),
+        cache_store: unreachable_cache(),
+        token_revocation: Arc::new(TokenRevocationStore::new(Arc::new(MemoryStore::default()))),
+        login_attempts: Arc::new(LoginAttemptStore::new(
+            Arc::new(MemoryStore::default()),
+            LoginAttemptPolicy::default(),
+        )),
+        user_token_repository: Arc::new(InMemoryUserTokens::default()),
+        mailer: Arc::new(CapturingMailer::default()),
+        mail_config: MailConfig::default(),
+        two_factor: Arc::new(TwoFactor(None)),
+        two_factor_config: TwoFactorConfig::default(),
+        identity_repository: Arc::new(Identities(None)),
+        oidc: None,
+        session_repository: Arc::new(InMemorySessions::default()),
+        audit: Arc::new(RecordingAudit::default()),
+    }
+}
+
+async fn auth_service() -> AuthService {
+    AuthService::new(
+        auth_deps(Arc::new(InMemoryUsers::default())),
+        &mut Registry::default(),
+    )
+    .await
+}
+
+/// Registers Ada and signs her in once.
+async fn signed_in(service: &AuthService) -> TokenResponse {
+    service
+        .register_user(
+            &RegisterRequest {
+                firstname: "Ada".to_string(),
+                lastname: "Lovelace".to_string(),
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    log_in(service).await
+}
+
+/// Signs Ada in again, expecting tokens rather than a two-factor challenge.
+async fn log_in(service: &AuthService) -> TokenResponse {
+    let response = service
+        .login_user(
+            &LoginRequest {
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    match response.data {
+        LoginResponse::Tokens(tokens) => tokens,
+        LoginResponse::TwoFactorRequired(_) => panic!("expected tokens"),
+    }
+}
+
+#[tokio::test]
+async fn refreshing_rotates_the_refresh_token() {
+    let service = auth_service().await;
+    let issued = signed_in(&service).await;
+
+    let refreshed = service
+        .refresh_token(
+            &RefreshTokenRequest {
+                refresh_token: issued.refresh_token.clone(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+    assert_ne!(refreshed.refresh_token, issued.refresh_token);
+    assert_eq!(
+        service
+            .verify_token(&refreshed.access_token)
+            .await
+            .unwrap()
+            .user_id,
+        service
+            .verify_token(&issued.access_token)
+            .await
+            .unwrap()
+            .user_id
+    );
+
+    let again = service
+        .refresh_token(
+            &RefreshTokenRequest {
+                refresh_token: refreshed.refresh_token.clone(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+    assert_ne!(again.refresh_token, refreshed.refresh_token);
+}
+
+#[tokio::test]
+async fn reusing_a_refresh_token_revokes_its_whole_family() {
+    let service = auth_service().await;
+    let stolen = RefreshTokenRequest {
+        refresh_token: signed_in(&service).await.refresh_token,
+    };
+
+    let legitimate = service
+        .refresh_token(&stolen, &ClientInfo::default())
+        .await
+        .unwrap()
+        .data;
+
+    let err = service
+        .refresh_token(&stolen, &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Refresh token has already been used");
+
+    // The replay ends the session for the legitimate holder too.
+    let err = service
+        .refresh_token(
+            &RefreshTokenRequest {
+                refresh_token: legitimate.refresh_token,
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Refresh token has already been used");
+}
+
+#[tokio::test]
+async fn unknown_refresh_tokens_are_rejected() {
+    let service = auth_service().await;
+    signed_in(&service).await;
+
+    let err = service
+        .refresh_token(
+            &RefreshTokenRequest {
+                refresh_token: "not-a-token".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Token validation failed");
+}
+
+#[tokio::test]
+async fn new_accounts_are_authors_who_can_write_posts() {
+    let service = auth_service().await;
+    let tokens = signed_in(&service).await;
+
+    let claims = service.verify_token(&tokens.access_token).await.unwrap();
+
+    assert_eq!(claims.role, "author");
+    assert!(claims.permissions.contains(&"posts:write".to_string()));
+}
+
+#[tokio::test]
+async fn login_verifies_the_password_after_a_successful_login() {
+    let service = auth_service().await;
+    signed_in(&service).await;
+
+    let err = service
+        .login_user(
+            &LoginRequest {
+                email: "ada@example.com".to_string(),
+                password: "wrong-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Invalid credentials");
+}
+
+#[tokio::test]
+async fn registering_a_taken_email_fails_every_time() {
+    let users = Arc::new(InMemoryUsers::default());
+    let service = AuthService::new(auth_deps(users.clone()), &mut Registry::default()).await;
+    let register = RegisterRequest {
+        firstname: "Ada".to_string(),
+        lastname: "Lovelace".to_string(),
+        email: "ada@example.com".to_string(),
+        password: "correct-horse".to_string(),
+    };
+
+    service
+        .register_user(&register, &ClientInfo::default())
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        let err = service
+            .register_user(&register, &ClientInfo::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Email already exists");
+    }
+
+    assert_eq!(users.users.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn logout_revokes_the_access_token() {
+    let service = auth_service().await;
+    let tokens = signed_in(&service).await;
+
+    service.verify_token(&tokens.access_token).await.unwrap();
+
+    service
+        .logout(
+            &tokens.access_token,
+            &LogoutRequest::default(),
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    let err = service
+        .verify_token(&tokens.access_token)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::TokenRevoked));
+}
+
+#[tokio::test]
+async fn logging_out_everywhere_rejects_every_token_and_refresh() {
+    let service = auth_service().await;
+    let first = signed_in(&service).await;
+    let second = log_in(&service).await;
+
+    service
+        .logout(
+            &first.access_token,
+            &LogoutRequest {
+                refresh_token: None,
+                all_devices: true,
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    for tokens in [&first, &second] {
+        let err = service
+            .verify_token(&tokens.access_token)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::TokenRevoked));
+
+        service
+            .refresh_token(
+                &RefreshTokenRequest {
+                    refresh_token: tokens.refresh_token.clone(),
+                },
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap_err();
+    }
+}
+
+#[tokio::test]
+async fn each_login_issues_its_own_session() {
+    let sessions = Arc::new(InMemorySessions::default());
+    let service = AuthService::new(
+        AuthServiceDeps {
+            session_repository: sessions.clone(),
+            ..auth_deps(Arc::new(InMemoryUsers::default()))
+        },
+        &mut Registry::default(),
+    )
+    .await;
+
+    let first = signed_in(&service).await;
+    let second = log_in(&service).await;
+
+    let sid = |tokens: &TokenResponse| {
+        JwtConfig::new("test-secret")
+            .decode_token(&tokens.access_token)
+            .unwrap()
+            .sid
+            .unwrap()
+    };
+    assert_ne!(sid(&first), sid(&second));
+    assert_eq!(sessions.active(), vec![sid(&first), sid(&second)]);
+}
+
+#[tokio::test]
+async fn failed_and_successful_logins_are_audited() {
+    let audit = Arc::new(RecordingAudit::default());
+    let service = AuthService::new(
+        AuthServiceDeps {
+            audit: audit.clone(),
+            ..auth_deps(Arc::new(InMemoryUsers::default()))
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    signed_in(&service).await;
+    service
+        .login_user(
+            &LoginRequest {
+                email: "ada@example.com".to_string(),
+                password: "wrong-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+
+    let actions: Vec<_> = audit
+        .entries
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, entry)| entry.action)
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::Register,
+            AuditAction::Login,
+            AuditAction::LoginFailed
+        ]
+    );
+}
+
+#[tokio::test]
+async fn logging_in_upgrades_a_legacy_bcrypt_hash() {
+    let users = Arc::new(InMemoryUsers::default());
+    let service = AuthService::new(auth_deps(users.clone()), &mut Registry::default()).await;
+    signed_in(&service).await;
+    let legacy = Hashing::new(cheap(HashAlgorithm::Bcrypt))
+        .hash_password("correct-horse")
+        .await
+        .unwrap();
+    users.users.lock().unwrap()[0].password = legacy;
+    let password = || users.users.lock().unwrap()[0].password.clone();
+    let login = LoginRequest {
+        email: "ada@example.com".to_string(),
+        password: "correct-horse".to_string(),
+    };
+
+    service
+        .login_user(&login, &ClientInfo::default())
+        .await
+        .unwrap();
+    let upgraded = password();
+    assert!(upgraded.starts_with("$argon2id$"));
+
+    service
+        .login_user(&login, &ClientInfo::default())
+        .await
+        .unwrap();
+    assert_eq!(password(), upgraded);
+}
+
+#[tokio::test]
+async fn repeated_wrong_passwords_lock_the_account_even_for_the_right_one() {
+    let service = auth_service().await;
+    signed_in(&service).await;
+    let attempt = |password: &str| LoginRequest {
+        email: "ada@example.com".to_string(),
+        password: password.to_string(),
+    };
+
+    for _ in 0..LoginAttemptPolicy::default().max_failures_per_email {
+        let err = service
+            .login_user(&attempt("wrong-horse"), &ClientInfo::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Invalid credentials");
+    }
+
+    let err = service
+        .login_user(&attempt("correct-horse"), &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "too_many_requests");
+}
+
+async fn request_reset(service: &AuthService) {
+    service
+        .request_password_reset(
+            &PasswordResetRequest {
+                email: "ada@example.com".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn a_reset_link_works_once_and_signs_out_every_session() {
+    let users = Arc::new(InMemoryUsers::default());
+    let mailer = Arc::new(CapturingMailer::default());
+    let sessions = Arc::new(InMemorySessions::default());
+    let service = AuthService::new(
+        AuthServiceDeps {
+            mailer: mailer.clone(),
+            session_repository: sessions.clone(),
+            ..auth_deps(users.clone())
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    let tokens = signed_in(&service).await;
+
+    request_reset(&service).await;
+    let reset = ResetPasswordRequest {
+        token: mailer.last_token(),
+        new_password: "battery-staple".to_string(),
+    };
+    service
+        .reset_password(&reset, &ClientInfo::default())
+        .await
+        .unwrap();
+
+    let password = users.users.lock().unwrap()[0].password.clone();
+    cheap_argon2id()
+        .compare_password(&password, "battery-staple")
+        .await
+        .unwrap();
+    let err = service
+        .verify_token(&tokens.access_token)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::TokenRevoked));
+    assert!(sessions.active().is_empty());
+    service
+        .refresh_token(
+            &RefreshTokenRequest {
+                refresh_token: tokens.refresh_token,
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+
+    let err = service
+        .reset_password(&reset, &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Token validation failed");
+}
+
+#[tokio::test]
+async fn an_expired_reset_link_is_refused() {
+    let users = Arc::new(InMemoryUsers::default());
+    let mailer = Arc::new(CapturingMailer::default());
+    let service = AuthService::new(
+        AuthServiceDeps {
+            mailer: mailer.clone(),
+            mail_config: MailConfig {
+                password_reset_ttl: Duration::ZERO,
+                ..MailConfig::default()
+            },
+            ..auth_deps(users.clone())
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    signed_in(&service).await;
+    let before = users.users.lock().unwrap()[0].password.clone();
+
+    request_reset(&service).await;
+    let err = service
+        .reset_password(
+            &ResetPasswordRequest {
+                token: mailer.last_token(),
+                new_password: "battery-staple".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.message, "Token validation failed");
+    assert_eq!(users.users.lock().unwrap()[0].password, before);
+}
+
+struct VerificationFixture {
+    service: AuthService,
+    users: Arc<InMemoryUsers>,
+    mailer: Arc<CapturingMailer>,
+}
+
+impl VerificationFixture {
+    fn verified(&self) -> bool {
+        self.users.users.lock().unwrap()[0]
+            .email_verified_at
+            .is_some()
+    }
+
+    async fn verify(&self) {
+        self.service
+            .verify_email(
+                &VerifyEmailRequest {
+                    token: self.mailer.last_token(),
+                },
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn verification_fixture(policy: EmailVerificationPolicy) -> VerificationFixture {
+    let users = Arc::new(InMemoryUsers::default());
+    let mailer = Arc::new(CapturingMailer::default());
+    let service = AuthService::new(
+        AuthServiceDeps {
+            mailer: mailer.clone(),
+            mail_config: MailConfig {
+                verification_policy: policy,
+                ..MailConfig::default()
+            },
+            ..auth_deps(users.clone())
+        },
+        &mut Registry::default(),
+    )
+    .await;
+
+    service
+        .register_user(
+            &RegisterRequest {
+                firstname: "Ada".to_string(),
+                lastname: "Lovelace".to_string(),
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    VerificationFixture {
+        service,
+        users,
+        mailer,
+    }
+}
+
+#[tokio::test]
+async fn a_verification_link_works_once() {
+    let f = verification_fixture(EmailVerificationPolicy::BlockContent).await;
+    let token = f.mailer.last_token();
+    assert!(!f.verified());
+
+    f.verify().await;
+    assert!(f.verified());
+
+    let err = f
+        .service
+        .verify_email(&VerifyEmailRequest { token }, &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Token validation failed");
+}
+
+#[tokio::test]
+async fn resending_verification_only_mails_unverified_accounts() {
+    let f = verification_fixture(EmailVerificationPolicy::BlockContent).await;
+    let resend = ResendVerificationRequest {
+        email: "ada@example.com".to_string(),
+    };
+
+    f.service.resend_verification(&resend).await.unwrap();
+    assert_eq!(f.mailer.sent.lock().unwrap().len(), 2);
+
+    f.verify().await;
+
+    // Known, verified and unknown addresses all get the same answer.
+    let verified = f.service.resend_verification(&resend).await.unwrap();
+    let unknown = f
+        .service
+        .resend_verification(&ResendVerificationRequest {
+            email: "nobody@example.com".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(verified.message, unknown.message);
+    assert_eq!(f.mailer.sent.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn the_login_policy_requires_a_verified_address() {
+    let f = verification_fixture(EmailVerificationPolicy::BlockLogin).await;
+    let login = LoginRequest {
+        email: "ada@example.com".to_string(),
+        password: "correct-horse".to_string(),
+    };
+
+    let err = f
+        .service
+        .login_user(&login, &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "forbidden");
+
+    f.verify().await;
+
+    f.service
+        .login_user(&login, &ClientInfo::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn bearer_tokens_must_be_ours_and_unexpired() {
+    let service = auth_service().await;
+
+    let forged = JwtConfig::new("other-secret")
+        .generate_token(1, "admin", vec![], None, 0)
+        .unwrap();
+    assert!(matches!(
+        service.verify_token(&forged).await,
+        Err(AppError::TokenValidationError)
+    ));
+    assert!(matches!(
+        service.verify_token("not-a-token").await,
+        Err(AppError::TokenValidationError)
+    ));
+
+    let mut expired = JwtConfig::new("test-secret");
+    expired.access_token_ttl = chrono::Duration::minutes(-5);
+    let token = expired
+        .generate_token(1, "author", vec![], None, 0)
+        .unwrap();
+    assert!(matches!(
+        service.verify_token(&token).await,
+        Err(AppError::TokenExpiredError)
+    ));
+}
+
+#[tokio::test]
+async fn bearer_tokens_are_refused_when_revocation_cannot_be_checked() {
+    let service = AuthService::new(
+        AuthServiceDeps {
+            token_revocation: Arc::new(TokenRevocationStore::new(unreachable_cache())),
+            ..auth_deps(Arc::new(InMemoryUsers::default()))
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    let token = JwtConfig::new("test-secret")
+        .generate_token(1, "author", vec![], None, 0)
+        .unwrap();
+
+    let err = service.verify_token(&token).await.unwrap_err();
+
+    assert!(matches!(err, AppError::CacheError(_)));
+    assert_eq!(
+        tonic::Status::from(ErrorResponse::from(err)).code(),
+        tonic::Code::Unavailable
+    );
+}
+
+#[tokio::test]
+async fn a_second_factor_is_required_before_tokens_are_issued() {
+    let service = AuthService::new(
+        AuthServiceDeps {
+            two_factor: Arc::new(TwoFactor(Some("123456"))),
+            ..auth_deps(Arc::new(InMemoryUsers::default()))
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    service
+        .register_user(
+            &RegisterRequest {
+                firstname: "Ada".to_string(),
+                lastname: "Lovelace".to_string(),
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    let challenge = two_factor_challenge(&service).await;
+    let tokens = service
+        .verify_two_factor_login(
+            &TwoFactorLoginRequest {
+                challenge_token: challenge.clone(),
+                code: "123456".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+    service.verify_token(&tokens.access_token).await.unwrap();
+
+    let err = service
+        .verify_two_factor_login(
+            &TwoFactorLoginRequest {
+                challenge_token: challenge,
+                code: "123456".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "Token validation failed");
+}
+
+async fn two_factor_challenge(service: &AuthService) -> String {
+    let response = service
+        .login_user(
+            &LoginRequest {
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    match response.data {
+        LoginResponse::TwoFactorRequired(challenge) => challenge.challenge_token,
+        LoginResponse::Tokens(_) => panic!("expected a two-factor challenge"),
+    }
+}
+
+#[tokio::test]
+async fn a_correct_password_does_not_reset_failed_two_factor_codes() {
+    let service = AuthService::new(
+        AuthServiceDeps {
+            two_factor: Arc::new(TwoFactor(Some("123456"))),
+            ..auth_deps(Arc::new(InMemoryUsers::default()))
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    service
+        .register_user(
+            &RegisterRequest {
+                firstname: "Ada".to_string(),
+                lastname: "Lovelace".to_string(),
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    for _ in 0..LoginAttemptPolicy::default().max_failures_per_email {
+        let err = service
+            .verify_two_factor_login(
+                &TwoFactorLoginRequest {
+                    challenge_token: two_factor_challenge(&service).await,
+                    code: "000000".to_string(),
+                },
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Invalid two-factor code");
+    }
+
+    let err = service
+        .login_user(
+            &LoginRequest {
+                email: "ada@example.com".to_string(),
+                password: "correct-horse".to_string(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "too_many_requests");
+}
+
+#[tokio::test]
+async fn api_key_tokens_cannot_sign_out_every_device() {
+    let service = auth_service().await;
+    signed_in(&service).await;
+    let token = JwtConfig::new("test-secret")
+        .generate_api_key_token(1, "author", vec![], 0)
+        .unwrap();
+
+    let request = LogoutRequest {
+        refresh_token: None,
+        all_devices: true,
+    };
+    let err = service
+        .logout(&token, &request, &ClientInfo::default())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "forbidden");
+    service.verify_token(&token).await.unwrap();
+}
+
+#[tokio::test]
+async fn an_oidc_id_token_signs_in_only_once() {
+    let identity = OidcIdentity {
+        issuer: "https://idp.example".to_string(),
+        subject: "employee-42".to_string(),
+        email: Some("jane@corp.example".to_string()),
+        email_verified: true,
+        given_name: None,
+        family_name: None,
+        name: None,
+        // Filled in from the nonce by `StaticOidcProvider`, as the real
+        // provider does for ID tokens without a `jti`.
+        token_id: String::new(),
+        expires_at: Utc::now().timestamp() + 300,
+    };
+    let users = Arc::new(InMemoryUsers {
+        users: Mutex::new(vec![User {
+            id: 1,
+            firstname: "Jane".to_string(),
+            lastname: "Doe".to_string(),
+            email: "jane@corp.example".to_string(),
+            password: "unused".to_string(),
+            role: "author".to_string(),
+            email_verified_at: Some(Utc::now()),
+        }]),
+        ..Default::default()
+    });
+    let service = AuthService::new(
+        AuthServiceDeps {
+            identity_repository: Arc::new(Identities(Some(1))),
+            oidc: Some(Arc::new(StaticOidcProvider(identity))),
+            ..auth_deps(users)
+        },
+        &mut Registry::default(),
+    )
+    .await;
+    let request = OidcLoginRequest {
+        id_token: "captured.id.token".to_string(),
+        nonce: "flow-nonce".to_string(),
+    };
+
+    service
+        .login_with_oidc(&request, &ClientInfo::default())
+        .await
+        .unwrap();
+    let err = service
+        .login_with_oidc(&request, &ClientInfo::default())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "unauthenticated");
+    assert!(err.message.contains("already been used"));
+}
@@ -0,0 +1,231 @@
+mod common {
+    pub mod audit;
+    pub mod cache;
+    pub mod posts;
+}
+
+use async_trait::async_trait;
+use common::{
+    audit::RecordingAudit,
+    cache::unreachable_cache,
+    posts::{InMemoryPosts, post},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    abstract_trait::{CommentRepositoryTrait, CommentServiceTrait},
+    config::Claims,
+    domain::{AuditContext, ClientInfo, CreateCommentRequest, UpdateCommentRequest},
+    model::{audit_event::AuditAction, comment::Comment, posts::PostStatus, role::Permission},
+    service::CommentService,
+    utils::{AppError, Metrics},
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct InMemoryComments {
+    comments: Mutex<Vec<Comment>>,
+}
+
+#[async_trait]
+impl CommentRepositoryTrait for InMemoryComments {
+    async fn find_all(&self) -> Result<Vec<Comment>, AppError> {
+        Ok(self.comments.lock().unwrap().clone())
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<Comment>, AppError> {
+        let comments = self.comments.lock().unwrap();
+        Ok(comments.iter().find(|comment| comment.id == id).cloned())
+    }
+
+    async fn create(&self, input: &CreateCommentRequest) -> Result<Comment, AppError> {
+        let mut comments = self.comments.lock().unwrap();
+        let comment = Comment {
+            id: comments.len() as i32 + 1,
+            id_post_comment: input.id_post_comment,
+            user_name_comment: input.user_name_comment.clone(),
+            comment: input.comment.clone(),
+            user_id: Some(input.user_id),
+        };
+        comments.push(comment.clone());
+        Ok(comment)
+    }
+
+    async fn update(&self, input: &UpdateCommentRequest) -> Result<Comment, AppError> {
+        let mut comments = self.comments.lock().unwrap();
+        let comment = comments
+            .iter_mut()
+            .find(|comment| comment.id == input.id_post_comment)
+            .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+        comment.comment = input.comment.clone();
+        Ok(comment.clone())
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), AppError> {
+        self.comments
+            .lock()
+            .unwrap()
+            .retain(|comment| comment.id != id);
+        Ok(())
+    }
+}
+
+struct Fixture {
+    service: CommentService,
+    comments: Arc<InMemoryComments>,
+    audit: Arc<RecordingAudit>,
+}
+
+/// One comment, id 1, written by user 1, on published post 1. Post 2 is a
+/// draft; user 3 wrote both posts.
+async fn fixture() -> Fixture {
+    let comments = Arc::new(InMemoryComments::default());
+    comments.comments.lock().unwrap().push(Comment {
+        id: 1,
+        id_post_comment: 1,
+        user_name_comment: "Ada Lovelace".to_string(),
+        comment: "First!".to_string(),
+        user_id: Some(1),
+    });
+
+    let audit = Arc::new(RecordingAudit::default());
+    let service = CommentService::new(
+        comments.clone(),
+        Arc::new(InMemoryPosts {
+            posts: Mutex::new(vec![
+                post(1, 3, PostStatus::Published),
+                post(2, 3, PostStatus::Draft),
+            ]),
+        }),
+        audit.clone(),
+        Arc::new(tokio::sync::Mutex::new(Metrics::new())),
+        &mut Registry::default(),
+        unreachable_cache(),
+    )
+    .await;
+
+    Fixture {
+        service,
+        comments,
+        audit,
+    }
+}
+
+fn actor(user_id: i64, role: &str, permissions: &[Permission]) -> Claims {
+    Claims::new(
+        user_id,
+        role.to_string(),
+        permissions.iter().map(|p| p.as_str().to_string()).collect(),
+        0,
+        usize::MAX,
+        0,
+    )
+}
+
+fn ctx(actor: &Claims) -> AuditContext {
+    AuditContext::new(actor, ClientInfo::default())
+}
+
+fn edit(comment: &str) -> UpdateCommentRequest {
+    UpdateCommentRequest {
+        id_post_comment: 1,
+        comment: comment.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn only_the_author_can_edit_or_delete_a_comment() {
+    let f = fixture().await;
+    let stranger = actor(2, "author", &[Permission::CommentsWrite]);
+
+    let err = f
+        .service
+        .update_comment(&edit("Hijacked"), &stranger, &ctx(&stranger))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "forbidden");
+
+    let err = f
+        .service
+        .delete_comment(1, &stranger, &ctx(&stranger))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "forbidden");
+
+    assert_eq!(f.comments.comments.lock().unwrap()[0].comment, "First!");
+
+    let author = actor(1, "reader", &[Permission::CommentsWrite]);
+    f.service
+        .update_comment(&edit("First, edited"), &author, &ctx(&author))
+        .await
+        .unwrap();
+    assert_eq!(
+        f.comments.comments.lock().unwrap()[0].comment,
+        "First, edited"
+    );
+}
+
+#[tokio::test]
+async fn moderators_can_remove_any_comment() {
+    let f = fixture().await;
+    let admin = actor(
+        9,
+        "admin",
+        &[Permission::CommentsWrite, Permission::CommentsModerate],
+    );
+
+    f.service
+        .delete_comment(1, &admin, &ctx(&admin))
+        .await
+        .unwrap();
+
+    assert!(f.comments.comments.lock().unwrap().is_empty());
+    let entries = f.audit.entries.lock().unwrap();
+    let (actor_id, entry) = entries.last().unwrap();
+    assert_eq!(*actor_id, Some(9));
+    assert_eq!(entry.action, AuditAction::CommentDeleted);
+    assert_eq!(entry.target_id.as_deref(), Some("1"));
+}
+
+fn reply(post_id: i32, actor: &Claims) -> CreateCommentRequest {
+    CreateCommentRequest {
+        id_post_comment: post_id,
+        user_id: actor.user_id as i32,
+        user_name_comment: "Commenter".to_string(),
+        comment: "Nice post".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn drafts_only_take_comments_from_their_author_or_moderators() {
+    let f = fixture().await;
+    let reader = actor(2, "author", &[Permission::CommentsWrite]);
+
+    f.service
+        .create_comment(&reply(1, &reader), &reader, &ctx(&reader))
+        .await
+        .unwrap();
+
+    for post_id in [2, 99] {
+        let err = f
+            .service
+            .create_comment(&reply(post_id, &reader), &reader, &ctx(&reader))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, "not_found");
+    }
+    assert_eq!(f.comments.comments.lock().unwrap().len(), 2);
+
+    let owner = actor(3, "author", &[Permission::CommentsWrite]);
+    let moderator = actor(
+        9,
+        "admin",
+        &[Permission::CommentsWrite, Permission::PostsModerate],
+    );
+    for actor in [owner, moderator] {
+        f.service
+            .create_comment(&reply(2, &actor), &actor, &ctx(&actor))
+            .await
+            .unwrap();
+    }
+    assert_eq!(f.comments.comments.lock().unwrap().len(), 4);
+}
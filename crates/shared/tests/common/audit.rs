@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use shared::{
+    abstract_trait::AuditServiceTrait,
+    domain::{
+        ApiResponsePagination, AuditContext, AuditEventResponse, ErrorResponse,
+        FindAllAuditEventRequest,
+    },
+    model::audit_event::AuditEntry,
+    utils::AppError,
+};
+use std::sync::Mutex;
+
+/// Keeps every recorded entry, along with who it was recorded for.
+#[derive(Default)]
+pub struct RecordingAudit {
+    pub entries: Mutex<Vec<(Option<i32>, AuditEntry)>>,
+}
+
+#[async_trait]
+impl AuditServiceTrait for RecordingAudit {
+    async fn record(&self, ctx: &AuditContext, entry: AuditEntry) {
+        self.entries.lock().unwrap().push((ctx.actor_id, entry));
+    }
+
+    async fn get_audit_events(
+        &self,
+        _req: FindAllAuditEventRequest,
+    ) -> Result<ApiResponsePagination<Vec<AuditEventResponse>>, ErrorResponse> {
+        Err(AppError::InternalError("Reading the audit log is not supported".to_string()).into())
+    }
+}
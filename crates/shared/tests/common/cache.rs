@@ -0,0 +1,9 @@
+use shared::cache::CacheStore;
+use std::sync::Arc;
+
+/// Nothing listens on port 1, so every cache call fails.
+pub fn unreachable_cache() -> Arc<CacheStore> {
+    Arc::new(CacheStore::new(
+        redis::Client::open("redis://127.0.0.1:1/").unwrap(),
+    ))
+}
@@ -0,0 +1,16 @@
+use shared::config::{HashAlgorithm, Hashing, HashingConfig};
+
+/// Real algorithms at the cheapest settings they accept, to keep tests fast.
+pub fn cheap(algorithm: HashAlgorithm) -> HashingConfig {
+    HashingConfig {
+        algorithm,
+        bcrypt_cost: 4,
+        argon2_memory_kib: 1024,
+        argon2_iterations: 1,
+        argon2_parallelism: 1,
+    }
+}
+
+pub fn cheap_argon2id() -> Hashing {
+    Hashing::new(cheap(HashAlgorithm::Argon2id))
+}
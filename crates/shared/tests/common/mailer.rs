@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use shared::{
+    abstract_trait::{MailMessage, MailerTrait},
+    utils::AppError,
+};
+use std::sync::Mutex;
+
+/// Keeps every message instead of delivering it.
+#[derive(Default)]
+pub struct CapturingMailer {
+    pub sent: Mutex<Vec<MailMessage>>,
+}
+
+impl CapturingMailer {
+    /// The `token=` query parameter of the most recent message's link.
+    pub fn last_token(&self) -> String {
+        let mail = self.sent.lock().unwrap().last().cloned().expect("a mail");
+        mail.body
+            .split("token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .expect("a token link")
+            .to_string()
+    }
+}
+
+#[async_trait]
+impl MailerTrait for CapturingMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), AppError> {
+        self.sent.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
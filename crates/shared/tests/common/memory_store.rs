@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use shared::{abstract_trait::KeyValueStoreTrait, utils::AppError};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Keeps flags and counters in process, expiring them like Redis would.
+#[derive(Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, (i64, Option<Instant>)>>,
+}
+
+impl MemoryStore {
+    fn live(&self) -> std::sync::MutexGuard<'_, HashMap<String, (i64, Option<Instant>)>> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (_, expires_at)| expires_at.is_none_or(|at| at > Instant::now()));
+        entries
+    }
+}
+
+#[async_trait]
+impl KeyValueStoreTrait for MemoryStore {
+    async fn set_flag(&self, key: &str, expiration: Duration) -> Result<(), AppError> {
+        self.live()
+            .insert(key.to_string(), (1, Some(Instant::now() + expiration)));
+        Ok(())
+    }
+
+    async fn set_if_absent(&self, key: &str, expiration: Duration) -> Result<bool, AppError> {
+        let mut entries = self.live();
+        if entries.contains_key(key) {
+            return Ok(false);
+        }
+        entries.insert(key.to_string(), (1, Some(Instant::now() + expiration)));
+        Ok(true)
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, AppError> {
+        Ok(self.live().contains_key(key))
+    }
+
+    async fn get_counter(&self, key: &str) -> Result<i64, AppError> {
+        Ok(self.live().get(key).map_or(0, |(value, _)| *value))
+    }
+
+    async fn increment(&self, key: &str) -> Result<i64, AppError> {
+        let mut entries = self.live();
+        let entry = entries.entry(key.to_string()).or_insert((0, None));
+        entry.0 += 1;
+        Ok(entry.0)
+    }
+
+    async fn increment_with_expiry(
+        &self,
+        key: &str,
+        expiration: Duration,
+    ) -> Result<i64, AppError> {
+        let mut entries = self.live();
+        let entry = entries
+            .entry(key.to_string())
+            .or_insert((0, Some(Instant::now() + expiration)));
+        entry.0 += 1;
+        Ok(entry.0)
+    }
+
+    async fn time_to_live(&self, key: &str) -> Result<Option<Duration>, AppError> {
+        Ok(self
+            .live()
+            .get(key)
+            .and_then(|(_, expires_at)| *expires_at)
+            .map(|at| at.saturating_duration_since(Instant::now())))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        self.live().remove(key);
+        Ok(())
+    }
+}
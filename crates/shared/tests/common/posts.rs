@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use shared::{
+    abstract_trait::PostsRepositoryTrait,
+    domain::{CreatePostRequest, PostRelationResponse, UpdatePostRequest},
+    model::posts::{Post, PostFilter, PostPublication, PostSearchHit, PostStatus},
+    utils::{AppError, PageCursor},
+};
+use std::sync::Mutex;
+
+pub fn post(id: i32, user_id: i32, status: PostStatus) -> Post {
+    Post {
+        id,
+        title: "Hello".to_string(),
+        img: "hello.png".to_string(),
+        body: "Hello, world".to_string(),
+        category_id: 1,
+        user_id,
+        user_name: "Grace Hopper".to_string(),
+        slug: format!("hello-{id}"),
+        status: status.to_string(),
+        published_at: None,
+    }
+}
+
+/// Finds posts by id or slug; every post carries one comment.
+#[derive(Default)]
+pub struct InMemoryPosts {
+    pub posts: Mutex<Vec<Post>>,
+}
+
+#[async_trait]
+impl PostsRepositoryTrait for InMemoryPosts {
+    async fn get_all_posts(
+        &self,
+        _page: i32,
+        _page_size: i32,
+        _filter: &PostFilter,
+    ) -> Result<(Vec<Post>, i64), AppError> {
+        unimplemented!("listings are covered by the repository")
+    }
+
+    async fn get_posts_after(
+        &self,
+        _page_size: i32,
+        _after: Option<PageCursor>,
+        _filter: &PostFilter,
+    ) -> Result<(Vec<Post>, Option<PageCursor>), AppError> {
+        unimplemented!("listings are covered by the repository")
+    }
+
+    async fn search_posts(
+        &self,
+        _page: i32,
+        _page_size: i32,
+        _filter: &PostFilter,
+    ) -> Result<(Vec<PostSearchHit>, i64), AppError> {
+        unimplemented!("search is covered by the repository")
+    }
+
+    async fn get_post(&self, post_id: i32) -> Result<Option<Post>, AppError> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts.iter().find(|post| post.id == post_id).cloned())
+    }
+
+    async fn get_post_by_slug(&self, slug: &str) -> Result<Option<Post>, AppError> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts.iter().find(|post| post.slug == slug).cloned())
+    }
+
+    async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, AppError> {
+        let posts = self.posts.lock().unwrap();
+        Ok(posts
+            .iter()
+            .filter(|post| post.id == post_id)
+            .map(|post| PostRelationResponse {
+                post_id: post.id,
+                title: post.title.clone(),
+                comment_id: 1,
+                id_post_comment: post.id,
+                user_name_comment: "Commenter".to_string(),
+                comment: "Nice post".to_string(),
+            })
+            .collect())
+    }
+
+    async fn create_post(
+        &self,
+        _input: &CreatePostRequest,
+        _publication: &PostPublication,
+        _tags: &[String],
+    ) -> Result<Post, AppError> {
+        unimplemented!("writes are not needed by the visibility tests")
+    }
+
+    async fn update_post(
+        &self,
+        _input: &UpdatePostRequest,
+        _publication: &PostPublication,
+        _tags: Option<&[String]>,
+    ) -> Result<Post, AppError> {
+        unimplemented!("writes are not needed by the visibility tests")
+    }
+
+    async fn publish_due_posts(&self) -> Result<Vec<Post>, AppError> {
+        unimplemented!("writes are not needed by the visibility tests")
+    }
+
+    async fn delete_post(&self, _post_id: i32) -> Result<(), AppError> {
+        unimplemented!("writes are not needed by the visibility tests")
+    }
+}
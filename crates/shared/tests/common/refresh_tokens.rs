@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::{
+    abstract_trait::RefreshTokenRepositoryTrait, model::refresh_token::RefreshToken,
+    utils::AppError,
+};
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct InMemoryRefreshTokens {
+    tokens: Mutex<Vec<RefreshToken>>,
+}
+
+#[async_trait]
+impl RefreshTokenRepositoryTrait for InMemoryRefreshTokens {
+    async fn create(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        family_id: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<RefreshToken, AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let token = RefreshToken {
+            id: tokens.len() as i32 + 1,
+            user_id,
+            token_hash: token_hash.to_string(),
+            family_id: family_id.to_string(),
+            expires_at,
+            revoked_at: None,
+            replaced_by: None,
+        };
+        tokens.push(token.clone());
+        Ok(token)
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, AppError> {
+        let tokens = self.tokens.lock().unwrap();
+        Ok(tokens.iter().find(|t| t.token_hash == token_hash).cloned())
+    }
+
+    async fn rotate(
+        &self,
+        current: &RefreshToken,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<RefreshToken>, AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let next_id = tokens.len() as i32 + 1;
+
+        let Some(old) = tokens
+            .iter_mut()
+            .find(|t| t.id == current.id && t.revoked_at.is_none())
+        else {
+            return Ok(None);
+        };
+        old.revoked_at = Some(Utc::now());
+        old.replaced_by = Some(next_id);
+
+        let token = RefreshToken {
+            id: next_id,
+            user_id: current.user_id,
+            token_hash: token_hash.to_string(),
+            family_id: current.family_id.clone(),
+            expires_at,
+            revoked_at: None,
+            replaced_by: None,
+        };
+        tokens.push(token.clone());
+        Ok(Some(token))
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<(), AppError> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.family_id == family_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.user_id == user_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::{abstract_trait::SessionRepositoryTrait, model::session::Session, utils::AppError};
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct InMemorySessions {
+    sessions: Mutex<Vec<Session>>,
+}
+
+impl InMemorySessions {
+    pub fn active(&self) -> Vec<String> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|session| session.revoked_at.is_none())
+            .map(|session| session.id.clone())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SessionRepositoryTrait for InMemorySessions {
+    async fn create(
+        &self,
+        id: &str,
+        user_id: i32,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+    ) -> Result<Session, AppError> {
+        let session = Session {
+            id: id.to_string(),
+            user_id,
+            user_agent: user_agent.map(str::to_string),
+            ip_address: ip_address.map(str::to_string),
+            created_at: Utc::now(),
+            last_seen_at: Utc::now(),
+            revoked_at: None,
+        };
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(session)
+    }
+
+    async fn find_active_by_user(
+        &self,
+        user_id: i32,
+        seen_after: DateTime<Utc>,
+    ) -> Result<Vec<Session>, AppError> {
+        let sessions = self.sessions.lock().unwrap();
+        Ok(sessions
+            .iter()
+            .filter(|s| s.user_id == user_id && s.revoked_at.is_none())
+            .filter(|s| s.last_seen_at > seen_after)
+            .cloned()
+            .collect())
+    }
+
+    async fn touch(&self, id: &str) -> Result<bool, AppError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions
+            .iter_mut()
+            .find(|s| s.id == id && s.revoked_at.is_none());
+
+        Ok(session
+            .map(|session| session.last_seen_at = Utc::now())
+            .is_some())
+    }
+
+    async fn revoke(&self, id: &str, user_id: i32) -> Result<bool, AppError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions
+            .iter_mut()
+            .find(|s| s.id == id && s.user_id == user_id && s.revoked_at.is_none());
+
+        Ok(session
+            .map(|session| session.revoked_at = Some(Utc::now()))
+            .is_some())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: i32) -> Result<(), AppError> {
+        for session in self.sessions.lock().unwrap().iter_mut() {
+            if session.user_id == user_id {
+                session.revoked_at.get_or_insert_with(Utc::now);
+            }
+        }
+        Ok(())
+    }
+
+    async fn revoke_others(&self, user_id: i32, keep_id: &str) -> Result<Vec<String>, AppError> {
+        let mut revoked = Vec::new();
+
+        for session in self.sessions.lock().unwrap().iter_mut() {
+            if session.user_id == user_id && session.id != keep_id && session.revoked_at.is_none() {
+                session.revoked_at = Some(Utc::now());
+                revoked.push(session.id.clone());
+            }
+        }
+
+        Ok(revoked)
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::{
+    abstract_trait::UserTokenRepositoryTrait,
+    model::user_token::{TokenPurpose, UserToken},
+    utils::AppError,
+};
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct InMemoryUserTokens {
+    tokens: Mutex<Vec<UserToken>>,
+}
+
+#[async_trait]
+impl UserTokenRepositoryTrait for InMemoryUserTokens {
+    async fn create(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<UserToken, AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let token = UserToken {
+            id: tokens.len() as i32 + 1,
+            user_id,
+            purpose: purpose.as_str().to_string(),
+            token_hash: token_hash.to_string(),
+            expires_at,
+            used_at: None,
+        };
+        tokens.push(token.clone());
+        Ok(token)
+    }
+
+    /// Marks the token used, like the real repository, so replays miss.
+    async fn consume(
+        &self,
+        purpose: TokenPurpose,
+        token_hash: &str,
+    ) -> Result<Option<UserToken>, AppError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let token = tokens.iter_mut().find(|t| {
+            t.purpose == purpose.as_str()
+                && t.token_hash == token_hash
+                && t.used_at.is_none()
+                && t.expires_at > Utc::now()
+        });
+
+        Ok(token.map(|token| {
+            token.used_at = Some(Utc::now());
+            token.clone()
+        }))
+    }
+
+    async fn invalidate_for_user(
+        &self,
+        user_id: i32,
+        purpose: TokenPurpose,
+    ) -> Result<(), AppError> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.user_id == user_id && token.purpose == purpose.as_str() {
+                token.used_at.get_or_insert_with(Utc::now);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use shared::{
+    abstract_trait::UserRepositoryTrait,
+    domain::{CreateUserRequest, UpdateUserRequest},
+    model::user::User,
+    utils::{AppError, PageCursor},
+};
+use std::{collections::HashMap, sync::Mutex};
+
+#[derive(Default)]
+pub struct InMemoryUsers {
+    pub users: Mutex<Vec<User>>,
+    pub pending_emails: Mutex<HashMap<i32, String>>,
+}
+
+#[async_trait]
+impl UserRepositoryTrait for InMemoryUsers {
+    async fn find_all(
+        &self,
+        _page: i32,
+        _page_size: i32,
+        _search: Option<String>,
+    ) -> Result<(Vec<User>, i64), AppError> {
+        let users = self.users.lock().unwrap().clone();
+        let total = users.len() as i64;
+        Ok((users, total))
+    }
+
+    async fn find_after(
+        &self,
+        _page_size: i32,
+        _after: Option<PageCursor>,
+        _search: Option<String>,
+    ) -> Result<(Vec<User>, Option<PageCursor>), AppError> {
+        let users = self.users.lock().unwrap().clone();
+        Ok((users, None))
+    }
+
+    async fn find_by_email_exists(&self, email: &str) -> Result<bool, AppError> {
+        Ok(self.find_by_email(email).await?.is_some())
+    }
+
+    async fn create_user(&self, input: &CreateUserRequest) -> Result<User, AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = User {
+            id: users.iter().map(|user| user.id).max().unwrap_or(0) + 1,
+            firstname: input.firstname.clone(),
+            lastname: input.lastname.clone(),
+            email: input.email.clone(),
+            password: input.password.clone(),
+            role: "author".to_string(),
+            email_verified_at: None,
+        };
+        users.push(user.clone());
+        Ok(user)
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().find(|user| user.email == email).cloned())
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<User>, AppError> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().find(|user| user.id == id).cloned())
+    }
+
+    async fn update_user(&self, input: &UpdateUserRequest) -> Result<User, AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == input.id)
+            .ok_or_else(|| AppError::NotFound(format!("User {} not found", input.id)))?;
+
+        if let Some(firstname) = &input.firstname {
+            user.firstname = firstname.clone();
+        }
+        if let Some(lastname) = &input.lastname {
+            user.lastname = lastname.clone();
+        }
+        if let Some(email) = &input.email {
+            user.email = email.clone();
+        }
+        if let Some(password) = &input.password {
+            user.password = password.clone();
+        }
+        if let Some(role) = &input.role {
+            user.role = role.clone();
+        }
+
+        Ok(user.clone())
+    }
+
+    async fn update_password(&self, id: i32, password: &str) -> Result<(), AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == id)
+            .ok_or_else(|| AppError::NotFound(format!("User {id} not found")))?;
+        user.password = password.to_string();
+        Ok(())
+    }
+
+    async fn mark_email_verified(&self, id: i32) -> Result<(), AppError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == id)
+            .ok_or_else(|| AppError::NotFound(format!("User {id} not found")))?;
+        user.email_verified_at.get_or_insert_with(Utc::now);
+        Ok(())
+    }
+
+    async fn set_pending_email(&self, id: i32, email: &str) -> Result<(), AppError> {
+        self.pending_emails
+            .lock()
+            .unwrap()
+            .insert(id, email.to_string());
+        Ok(())
+    }
+
+    async fn confirm_email_change(&self, id: i32) -> Result<Option<User>, AppError> {
+        let Some(email) = self.pending_emails.lock().unwrap().remove(&id) else {
+            return Ok(None);
+        };
+
+        let mut users = self.users.lock().unwrap();
+        let user = users.iter_mut().find(|user| user.id == id);
+
+        Ok(user.map(|user| {
+            user.email = email;
+            user.clone()
+        }))
+    }
+
+    async fn delete_user(&self, email: &str) -> Result<(), AppError> {
+        self.users
+            .lock()
+            .unwrap()
+            .retain(|user| user.email != email);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use shared::utils::{MAX_PAGE_SIZE, PageCursor, Paging, clamp_page_size, page_offset};
+
+fn cursor() -> PageCursor {
+    PageCursor {
+        created_at: DateTime::parse_from_rfc3339("2026-10-17T08:30:12.345678Z")
+            .unwrap()
+            .with_timezone(&Utc),
+        id: 42,
+    }
+}
+
+#[test]
+fn cursor_round_trips_with_microseconds() {
+    let encoded = cursor().encode();
+
+    assert!(
+        !encoded.contains('='),
+        "cursors go in query strings unpadded"
+    );
+    assert_eq!(PageCursor::decode(&encoded).unwrap(), cursor());
+}
+
+#[test]
+fn rejects_tampered_cursors() {
+    for bad in ["", "not base64!", "MTIz", "YWJjLjQy"] {
+        assert!(
+            PageCursor::decode(bad).is_err(),
+            "{bad:?} should not decode"
+        );
+    }
+}
+
+#[test]
+fn no_cursor_keeps_offset_paging() {
+    assert_eq!(
+        Paging::from_request(3, None).unwrap(),
+        Paging::Offset { page: 3 }
+    );
+    assert_eq!(
+        Paging::from_request(0, None).unwrap(),
+        Paging::Offset { page: 1 }
+    );
+}
+
+#[test]
+fn empty_cursor_starts_keyset_paging() {
+    assert_eq!(
+        Paging::from_request(5, Some("")).unwrap(),
+        Paging::Keyset { after: None }
+    );
+
+    let encoded = cursor().encode();
+    assert_eq!(
+        Paging::from_request(5, Some(&encoded)).unwrap(),
+        Paging::Keyset {
+            after: Some(cursor())
+        }
+    );
+    assert!(Paging::from_request(1, Some("garbage")).is_err());
+}
+
+#[test]
+fn page_sizes_are_clamped() {
+    assert_eq!(clamp_page_size(-5), 1);
+    assert_eq!(clamp_page_size(0), 1);
+    assert_eq!(clamp_page_size(25), 25);
+    assert_eq!(clamp_page_size(i32::MAX), MAX_PAGE_SIZE);
+}
+
+#[test]
+fn offsets_saturate_instead_of_overflowing() {
+    assert_eq!(page_offset(1, 20), 0);
+    assert_eq!(page_offset(3, 20), 40);
+    assert_eq!(page_offset(i32::MIN, 20), 0);
+    assert_eq!(
+        page_offset(i32::MAX, MAX_PAGE_SIZE),
+        (i32::MAX as u64 - 1) * MAX_PAGE_SIZE as u64
+    );
+}
@@ -0,0 +1,223 @@
+use genproto::category::{FindAllCategoryRequest, category_service_client::CategoryServiceClient};
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, DnType, ExtendedKeyUsagePurpose, IsCa,
+    KeyPair, KeyUsagePurpose,
+};
+use shared::config::{GrpcClientTlsConfig, GrpcServerTlsConfig};
+use std::{
+    net::{SocketAddr, TcpListener},
+    path::PathBuf,
+    time::Duration,
+};
+use tonic::{
+    Code,
+    service::Routes,
+    transport::{Channel, Server},
+};
+
+struct TestPki {
+    dir: PathBuf,
+}
+
+impl TestPki {
+    fn generate() -> Self {
+        let dir = std::env::temp_dir().join(format!("grpc-tls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let ca_key = KeyPair::generate().unwrap();
+        let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        ca_params
+            .distinguished_name
+            .push(DnType::CommonName, "test ca");
+        ca_params.key_usages = vec![
+            KeyUsagePurpose::KeyCertSign,
+            KeyUsagePurpose::CrlSign,
+            KeyUsagePurpose::DigitalSignature,
+        ];
+        let ca_cert = ca_params.self_signed(&ca_key).unwrap();
+
+        let pki = Self { dir };
+        pki.write("ca.pem", &ca_cert.pem());
+        pki.issue(
+            "server",
+            "localhost",
+            ExtendedKeyUsagePurpose::ServerAuth,
+            &ca_cert,
+            &ca_key,
+        );
+        pki.issue(
+            "client",
+            "blog-client",
+            ExtendedKeyUsagePurpose::ClientAuth,
+            &ca_cert,
+            &ca_key,
+        );
+        pki
+    }
+
+    fn issue(
+        &self,
+        name: &str,
+        subject: &str,
+        usage: ExtendedKeyUsagePurpose,
+        ca_cert: &Certificate,
+        ca_key: &KeyPair,
+    ) {
+        let key = KeyPair::generate().unwrap();
+        let mut params = CertificateParams::new(vec![subject.to_string()]).unwrap();
+        params.distinguished_name.push(DnType::CommonName, subject);
+        params.extended_key_usages = vec![usage];
+        let cert = params.signed_by(&key, ca_cert, ca_key).unwrap();
+
+        self.write(&format!("{name}.pem"), &cert.pem());
+        self.write(&format!("{name}.key"), &key.serialize_pem());
+    }
+
+    fn write(&self, name: &str, contents: &str) {
+        std::fs::write(self.dir.join(name), contents).unwrap();
+    }
+
+    fn path(&self, name: &str) -> Option<String> {
+        Some(self.dir.join(name).to_string_lossy().into_owned())
+    }
+
+    fn server_config(&self, require_client_cert: bool) -> GrpcServerTlsConfig {
+        GrpcServerTlsConfig {
+            cert_path: self.path("server.pem"),
+            key_path: self.path("server.key"),
+            client_ca_path: if require_client_cert {
+                self.path("ca.pem")
+            } else {
+                None
+            },
+        }
+    }
+
+    fn client_config(&self, with_identity: bool) -> GrpcClientTlsConfig {
+        GrpcClientTlsConfig {
+            ca_path: self.path("ca.pem"),
+            domain_name: Some("localhost".to_string()),
+            cert_path: with_identity.then(|| self.path("client.pem")).flatten(),
+            key_path: with_identity.then(|| self.path("client.key")).flatten(),
+        }
+    }
+}
+
+impl Drop for TestPki {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+async fn spawn_server(config: GrpcServerTlsConfig) -> SocketAddr {
+    let addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let tls = config.load().unwrap().expect("TLS should be enabled");
+
+    tokio::spawn(async move {
+        Server::builder()
+            .tls_config(tls)
+            .unwrap()
+            .add_routes(Routes::default())
+            .serve(addr)
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    addr
+}
+
+async fn call(addr: SocketAddr, config: GrpcClientTlsConfig) -> Code {
+    let tls = config.load().unwrap().expect("TLS should be enabled");
+
+    let channel = match Channel::from_shared(format!("https://{addr}"))
+        .unwrap()
+        .tls_config(tls)
+        .unwrap()
+        .connect()
+        .await
+    {
+        Ok(channel) => channel,
+        Err(_) => return Code::Unavailable,
+    };
+
+    match CategoryServiceClient::new(channel)
+        .get_categories(FindAllCategoryRequest::default())
+        .await
+    {
+        Ok(_) => Code::Ok,
+        Err(status) => status.code(),
+    }
+}
+
+#[tokio::test]
+async fn tls_channel_reaches_server() {
+    let pki = TestPki::generate();
+    let addr = spawn_server(pki.server_config(false)).await;
+
+    assert_eq!(
+        call(addr, pki.client_config(false)).await,
+        Code::Unimplemented
+    );
+}
+
+#[tokio::test]
+async fn mtls_accepts_client_signed_by_trusted_ca() {
+    let pki = TestPki::generate();
+    let addr = spawn_server(pki.server_config(true)).await;
+
+    assert_eq!(
+        call(addr, pki.client_config(true)).await,
+        Code::Unimplemented
+    );
+}
+
+#[tokio::test]
+async fn mtls_rejects_client_without_certificate() {
+    let pki = TestPki::generate();
+    let addr = spawn_server(pki.server_config(true)).await;
+
+    assert_ne!(
+        call(addr, pki.client_config(false)).await,
+        Code::Unimplemented
+    );
+}
+
+#[tokio::test]
+async fn client_rejects_server_from_untrusted_ca() {
+    let pki = TestPki::generate();
+    let other = TestPki::generate();
+    let addr = spawn_server(pki.server_config(false)).await;
+
+    assert_ne!(
+        call(addr, other.client_config(false)).await,
+        Code::Unimplemented
+    );
+}
+
+#[test]
+fn tls_is_disabled_without_paths() {
+    assert!(GrpcServerTlsConfig::default().load().unwrap().is_none());
+    assert!(GrpcClientTlsConfig::default().load().unwrap().is_none());
+}
+
+#[test]
+fn partial_tls_configuration_is_rejected() {
+    let pki = TestPki::generate();
+
+    let server = GrpcServerTlsConfig {
+        key_path: None,
+        ..pki.server_config(false)
+    };
+    assert!(server.load().is_err());
+
+    let client = GrpcClientTlsConfig {
+        key_path: None,
+        ..pki.client_config(true)
+    };
+    assert!(client.load().is_err());
+}
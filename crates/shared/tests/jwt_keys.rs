@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{
+    decode_header,
+    jwk::{AlgorithmParameters, KeyAlgorithm},
+};
+use shared::{
+    abstract_trait::{JwtKeyRepositoryTrait, JwtKeyServiceTrait},
+    config::{JwtAlgorithm, JwtConfig, JwtKeyPolicy},
+    model::jwt_key::JwtKey,
+    service::JwtKeyService,
+    utils::AppError,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct InMemoryKeys {
+    keys: Mutex<Vec<JwtKey>>,
+}
+
+#[async_trait]
+impl JwtKeyRepositoryTrait for InMemoryKeys {
+    async fn find_unretired(&self) -> Result<Vec<JwtKey>, AppError> {
+        let now = Utc::now();
+        let keys = self.keys.lock().unwrap();
+        Ok(keys
+            .iter()
+            .filter(|key| key.retires_at.is_none_or(|retires_at| retires_at > now))
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, key: &JwtKey) -> Result<(), AppError> {
+        self.keys.lock().unwrap().push(key.clone());
+        Ok(())
+    }
+
+    async fn retire_superseded(
+        &self,
+        current_kid: &str,
+        activated_before: DateTime<Utc>,
+        retires_at: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let mut retired = 0;
+        for key in self.keys.lock().unwrap().iter_mut() {
+            if key.kid != current_kid
+                && key.activates_at <= activated_before
+                && key.retires_at.is_none()
+            {
+                key.retires_at = Some(retires_at);
+                retired += 1;
+            }
+        }
+        Ok(retired)
+    }
+
+    async fn delete_retired(&self) -> Result<u64, AppError> {
+        let now = Utc::now();
+        let mut keys = self.keys.lock().unwrap();
+        let before = keys.len();
+        keys.retain(|key| key.retires_at.is_none_or(|retires_at| retires_at > now));
+        Ok((before - keys.len()) as u64)
+    }
+}
+
+/// An EdDSA config whose key service rotates on every call.
+fn rotating_config() -> (JwtConfig, JwtKeyService) {
+    let mut config = JwtConfig::new("unused");
+    config.algorithm = JwtAlgorithm::EdDsa;
+    let service = JwtKeyService::new(
+        Arc::new(InMemoryKeys::default()),
+        config.clone(),
+        JwtKeyPolicy {
+            rotation_interval: Duration::zero(),
+            publish_delay: Duration::zero(),
+            ..JwtKeyPolicy::default()
+        },
+    );
+
+    (config, service)
+}
+
+fn sign(config: &JwtConfig) -> String {
+    config
+        .generate_token(7, "author", vec![], Some("3f0c1a9e-session"), 0)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn tokens_name_the_key_that_signed_them() {
+    let (config, service) = rotating_config();
+    service.rotate().await.unwrap();
+
+    let token = sign(&config);
+    let kid = decode_header(&token).unwrap().kid.unwrap();
+
+    let jwks = config.jwks();
+    assert_eq!(jwks.keys.len(), 1);
+    assert_eq!(jwks.keys[0].common.key_id.as_deref(), Some(kid.as_str()));
+    assert_eq!(config.decode_token(&token).unwrap().user_id, 7);
+}
+
+#[tokio::test]
+async fn tokens_from_the_previous_key_verify_after_rotation() {
+    let (config, service) = rotating_config();
+    service.rotate().await.unwrap();
+    let old = sign(&config);
+
+    service.rotate().await.unwrap();
+    let new = sign(&config);
+
+    let old_kid = decode_header(&old).unwrap().kid.unwrap();
+    let new_kid = decode_header(&new).unwrap().kid.unwrap();
+    assert_ne!(old_kid, new_kid);
+
+    assert_eq!(config.decode_token(&old).unwrap().user_id, 7);
+    assert_eq!(config.decode_token(&new).unwrap().user_id, 7);
+}
+
+#[tokio::test]
+async fn the_jwks_publishes_only_public_keys() {
+    let (config, service) = rotating_config();
+    service.rotate().await.unwrap();
+    service.rotate().await.unwrap();
+
+    let jwks = config.jwks();
+    assert_eq!(jwks.keys.len(), 2);
+
+    for jwk in &jwks.keys {
+        assert!(jwk.common.key_id.is_some());
+        assert_eq!(jwk.common.key_algorithm, Some(KeyAlgorithm::EdDSA));
+        assert!(matches!(
+            jwk.algorithm,
+            AlgorithmParameters::OctetKeyPair(_)
+        ));
+    }
+
+    let published = serde_json::to_value(&jwks).unwrap();
+    for jwk in published["keys"].as_array().unwrap() {
+        assert!(jwk.get("d").is_none());
+    }
+}
+
+#[tokio::test]
+async fn tokens_signed_by_an_unknown_key_are_rejected() {
+    let (config, service) = rotating_config();
+    service.rotate().await.unwrap();
+    let (other, other_service) = rotating_config();
+    other_service.rotate().await.unwrap();
+
+    assert!(matches!(
+        config.decode_token(&sign(&other)),
+        Err(AppError::TokenValidationError)
+    ));
+}
+
+/// Environment variables are process-wide, so every case lives in one test.
+#[test]
+fn invalid_jwt_settings_are_configuration_errors() {
+    // SAFETY: no other test in this binary reads or writes the environment.
+    unsafe { std::env::set_var("JWT_ALGORITHM", "HS512") };
+    assert!(JwtConfig::from_env("secret").is_err());
+
+    unsafe { std::env::set_var("JWT_ALGORITHM", "eddsa") };
+    assert_eq!(
+        JwtConfig::from_env("secret").unwrap().algorithm,
+        JwtAlgorithm::EdDsa
+    );
+
+    unsafe { std::env::set_var("JWT_KEY_ROTATION_DAYS", "monthly") };
+    assert!(JwtKeyPolicy::from_env().is_err());
+
+    unsafe { std::env::set_var("JWT_KEY_ROTATION_DAYS", "7") };
+    assert_eq!(
+        JwtKeyPolicy::from_env().unwrap().rotation_interval,
+        Duration::days(7)
+    );
+}
@@ -0,0 +1,85 @@
+mod common {
+    pub mod memory_store;
+}
+
+use common::memory_store::MemoryStore;
+use shared::cache::{LoginAttemptPolicy, LoginAttemptStore};
+use std::{sync::Arc, time::Duration};
+
+fn store() -> LoginAttemptStore {
+    LoginAttemptStore::new(
+        Arc::new(MemoryStore::default()),
+        LoginAttemptPolicy {
+            max_failures_per_email: 3,
+            max_failures_per_ip: 5,
+            ..LoginAttemptPolicy::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn locks_out_once_the_threshold_is_reached() {
+    let attempts = store();
+
+    for _ in 0..2 {
+        attempts.record_failure("ada@example.com", None).await;
+    }
+    assert_eq!(attempts.locked_for("ada@example.com", None).await, None);
+
+    attempts.record_failure("ada@example.com", None).await;
+
+    let locked_for = attempts.locked_for("ada@example.com", None).await.unwrap();
+    assert!(locked_for > Duration::ZERO);
+    assert!(locked_for <= LoginAttemptPolicy::default().base_lockout);
+}
+
+#[tokio::test]
+async fn a_successful_login_resets_the_count() {
+    let attempts = store();
+
+    for _ in 0..2 {
+        attempts.record_failure("ada@example.com", None).await;
+    }
+    attempts.record_success("ada@example.com").await;
+    for _ in 0..2 {
+        attempts.record_failure("ada@example.com", None).await;
+    }
+
+    assert_eq!(attempts.locked_for("ada@example.com", None).await, None);
+}
+
+#[tokio::test]
+async fn email_case_does_not_split_the_count() {
+    let attempts = store();
+
+    attempts.record_failure("Ada@Example.com", None).await;
+    attempts.record_failure(" ada@example.com", None).await;
+    attempts.record_failure("ADA@EXAMPLE.COM", None).await;
+
+    assert!(attempts.locked_for("ada@example.com", None).await.is_some());
+}
+
+#[tokio::test]
+async fn failures_across_accounts_lock_out_the_address() {
+    let attempts = store();
+    let ip = Some("203.0.113.7");
+
+    for n in 0..5 {
+        attempts
+            .record_failure(&format!("user{n}@example.com"), ip)
+            .await;
+    }
+
+    assert!(
+        attempts
+            .locked_for("someone@example.com", ip)
+            .await
+            .is_some()
+    );
+    assert_eq!(
+        attempts
+            .locked_for("someone@example.com", Some("198.51.100.1"))
+            .await,
+        None
+    );
+}
@@ -0,0 +1,341 @@
+use axum::{
+    Form, Json, Router,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Redirect},
+    routing::{get, post},
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::Utc;
+use jsonwebtoken::{
+    Algorithm, EncodingKey, Header, encode,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, OctetKeyPairParameters,
+        OctetKeyPairType,
+    },
+};
+use ring::{
+    rand::SystemRandom,
+    signature::{Ed25519KeyPair, KeyPair},
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use shared::{
+    abstract_trait::OidcProviderTrait,
+    config::OidcConfig,
+    service::OidcProvider,
+    utils::{AppError, generate_random_token, pkce_challenge},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::net::TcpListener;
+
+const CLIENT_ID: &str = "blog-gateway";
+const REDIRECT_URL: &str = "http://localhost:5000/api/auth/oidc/callback";
+
+struct SigningKey {
+    kid: String,
+    key: EncodingKey,
+    jwk: Jwk,
+}
+
+impl SigningKey {
+    fn generate() -> Self {
+        let kid = generate_random_token();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+
+        let jwk = Jwk {
+            common: CommonParameters {
+                key_id: Some(kid.clone()),
+                ..Default::default()
+            },
+            algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+            }),
+        };
+
+        Self {
+            kid,
+            key: EncodingKey::from_ed_der(pkcs8.as_ref()),
+            jwk,
+        }
+    }
+
+    fn sign(&self, claims: &Value) -> String {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(self.kid.clone());
+        encode(&header, claims, &self.key).unwrap()
+    }
+}
+
+struct PendingCode {
+    code_challenge: String,
+    nonce: String,
+    redirect_uri: String,
+}
+
+/// Minimal OpenID provider: discovery, JWKS, an authorize endpoint that
+/// approves immediately, and a token endpoint that enforces PKCE.
+struct MockProvider {
+    issuer: String,
+    key: SigningKey,
+    codes: Mutex<HashMap<String, PendingCode>>,
+}
+
+impl MockProvider {
+    fn id_token(&self, nonce: &str) -> String {
+        let now = Utc::now().timestamp();
+
+        self.key.sign(&json!({
+            "iss": self.issuer,
+            "sub": "employee-42",
+            "aud": CLIENT_ID,
+            "exp": now + 300,
+            "iat": now,
+            "nonce": nonce,
+            "email": "jane@corp.example",
+            "email_verified": true,
+            "given_name": "Jane",
+            "family_name": "Doe",
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthorizeParams {
+    response_type: String,
+    client_id: String,
+    redirect_uri: String,
+    state: String,
+    nonce: String,
+    code_challenge: String,
+    code_challenge_method: String,
+}
+
+#[derive(Deserialize)]
+struct TokenForm {
+    grant_type: String,
+    code: String,
+    redirect_uri: String,
+    code_verifier: String,
+}
+
+async fn discovery(State(idp): State<Arc<MockProvider>>) -> Json<Value> {
+    Json(json!({
+        "issuer": idp.issuer,
+        "authorization_endpoint": format!("{}/authorize", idp.issuer),
+        "token_endpoint": format!("{}/token", idp.issuer),
+        "jwks_uri": format!("{}/jwks", idp.issuer),
+    }))
+}
+
+async fn jwks(State(idp): State<Arc<MockProvider>>) -> Json<JwkSet> {
+    Json(JwkSet {
+        keys: vec![idp.key.jwk.clone()],
+    })
+}
+
+async fn authorize(
+    State(idp): State<Arc<MockProvider>>,
+    Query(params): Query<AuthorizeParams>,
+) -> impl IntoResponse {
+    assert_eq!(params.response_type, "code");
+    assert_eq!(params.client_id, CLIENT_ID);
+    assert_eq!(params.code_challenge_method, "S256");
+
+    let code = generate_random_token();
+    let location = format!("{}?code={code}&state={}", params.redirect_uri, params.state);
+
+    idp.codes.lock().unwrap().insert(
+        code,
+        PendingCode {
+            code_challenge: params.code_challenge,
+            nonce: params.nonce,
+            redirect_uri: params.redirect_uri,
+        },
+    );
+
+    Redirect::to(&location)
+}
+
+async fn token(
+    State(idp): State<Arc<MockProvider>>,
+    Form(form): Form<TokenForm>,
+) -> impl IntoResponse {
+    let pending = idp.codes.lock().unwrap().remove(&form.code);
+
+    match pending {
+        Some(pending)
+            if form.grant_type == "authorization_code"
+                && form.redirect_uri == pending.redirect_uri
+                && pkce_challenge(&form.code_verifier) == pending.code_challenge =>
+        {
+            (
+                StatusCode::OK,
+                Json(json!({
+                    "access_token": generate_random_token(),
+                    "token_type": "Bearer",
+                    "id_token": idp.id_token(&pending.nonce),
+                })),
+            )
+        }
+        _ => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "error": "invalid_grant",
+                "error_description": "Code or verifier is invalid",
+            })),
+        ),
+    }
+}
+
+async fn start_mock_provider() -> Arc<MockProvider> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let issuer = format!("http://{}", listener.local_addr().unwrap());
+
+    let idp = Arc::new(MockProvider {
+        issuer,
+        key: SigningKey::generate(),
+        codes: Mutex::new(HashMap::new()),
+    });
+
+    let app = Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/jwks", get(jwks))
+        .route("/authorize", get(authorize))
+        .route("/token", post(token))
+        .with_state(idp.clone());
+
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    idp
+}
+
+fn provider(idp: &MockProvider) -> OidcProvider {
+    OidcProvider::new(OidcConfig::new(&idp.issuer, CLIENT_ID, REDIRECT_URL)).unwrap()
+}
+
+/// Follows the authorization URL like a browser would and returns the code
+/// handed back to the redirect URI.
+async fn authorize_code(provider: &OidcProvider, nonce: &str, code_verifier: &str) -> String {
+    let state = generate_random_token();
+    let url = provider
+        .authorization_url(&state, nonce, &pkce_challenge(code_verifier))
+        .await
+        .unwrap();
+
+    let response = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap()
+        .get(url)
+        .send()
+        .await
+        .unwrap();
+
+    let location = reqwest::Url::parse(
+        response
+            .headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+    )
+    .unwrap();
+    let params: HashMap<_, _> = location.query_pairs().into_owned().collect();
+
+    assert!(location.as_str().starts_with(REDIRECT_URL));
+    assert_eq!(params["state"], state);
+
+    params["code"].clone()
+}
+
+#[tokio::test]
+async fn authorization_code_flow_with_pkce_yields_verified_identity() {
+    let idp = start_mock_provider().await;
+    let provider = provider(&idp);
+    let nonce = generate_random_token();
+    let code_verifier = generate_random_token();
+
+    let code = authorize_code(&provider, &nonce, &code_verifier).await;
+    let id_token = provider.exchange_code(&code, &code_verifier).await.unwrap();
+    let identity = provider.verify_id_token(&id_token, &nonce).await.unwrap();
+
+    assert_eq!(identity.issuer, idp.issuer);
+    assert_eq!(identity.subject, "employee-42");
+    assert_eq!(identity.email.as_deref(), Some("jane@corp.example"));
+    assert!(identity.email_verified);
+    assert_eq!(identity.given_name.as_deref(), Some("Jane"));
+    // The mock provider sets no `jti`, so the nonce identifies the token.
+    assert_eq!(identity.token_id, nonce);
+    assert!(identity.expires_at > Utc::now().timestamp());
+}
+
+#[tokio::test]
+async fn token_endpoint_rejects_wrong_code_verifier() {
+    let idp = start_mock_provider().await;
+    let provider = provider(&idp);
+    let nonce = generate_random_token();
+
+    let code = authorize_code(&provider, &nonce, &generate_random_token()).await;
+    let result = provider
+        .exchange_code(&code, &generate_random_token())
+        .await;
+
+    assert!(matches!(result, Err(AppError::OidcError(msg)) if msg.contains("invalid_grant")));
+}
+
+#[tokio::test]
+async fn rejects_id_token_with_wrong_nonce() {
+    let idp = start_mock_provider().await;
+    let provider = provider(&idp);
+    let code_verifier = generate_random_token();
+
+    let code = authorize_code(&provider, &generate_random_token(), &code_verifier).await;
+    let id_token = provider.exchange_code(&code, &code_verifier).await.unwrap();
+
+    assert!(matches!(
+        provider
+            .verify_id_token(&id_token, &generate_random_token())
+            .await,
+        Err(AppError::OidcError(_))
+    ));
+}
+
+#[tokio::test]
+async fn rejects_id_tokens_for_other_audiences_issuers_or_keys() {
+    let idp = start_mock_provider().await;
+    let provider = provider(&idp);
+    let now = Utc::now().timestamp();
+    let claims = |iss: &str, aud: &str| {
+        json!({
+            "iss": iss,
+            "sub": "employee-42",
+            "aud": aud,
+            "exp": now + 300,
+            "iat": now,
+            "nonce": "n-0",
+            "email": "jane@corp.example",
+            "email_verified": true,
+        })
+    };
+
+    let valid = idp.key.sign(&claims(&idp.issuer, CLIENT_ID));
+    let other_audience = idp.key.sign(&claims(&idp.issuer, "another-app"));
+    let other_issuer = idp.key.sign(&claims("http://evil.example", CLIENT_ID));
+    let unknown_key = SigningKey::generate().sign(&claims(&idp.issuer, CLIENT_ID));
+
+    assert!(provider.verify_id_token(&valid, "n-0").await.is_ok());
+
+    for token in [other_audience, other_issuer, unknown_key] {
+        assert!(matches!(
+            provider.verify_id_token(&token, "n-0").await,
+            Err(AppError::OidcError(_))
+        ));
+    }
+}
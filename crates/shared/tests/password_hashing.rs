@@ -0,0 +1,87 @@
+mod common {
+    pub mod hashing;
+}
+
+use common::hashing::{cheap, cheap_argon2id};
+use shared::{
+    abstract_trait::HashingTrait,
+    config::{HashAlgorithm, Hashing, HashingConfig},
+    utils::AppError,
+};
+
+#[tokio::test]
+async fn argon2id_hashes_verify_and_reject_the_wrong_password() {
+    let hashing = cheap_argon2id();
+
+    let hash = hashing.hash_password("correct-horse").await.unwrap();
+
+    assert!(hash.starts_with("$argon2id$"));
+    assert!(
+        hashing
+            .compare_password(&hash, "correct-horse")
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        hashing.compare_password(&hash, "wrong-horse").await,
+        Err(AppError::InvalidCredentials)
+    ));
+    assert!(!hashing.needs_rehash(&hash));
+}
+
+#[tokio::test]
+async fn legacy_bcrypt_hashes_still_verify_but_need_a_rehash() {
+    let legacy = Hashing::new(cheap(HashAlgorithm::Bcrypt))
+        .hash_password("correct-horse")
+        .await
+        .unwrap();
+    let hashing = cheap_argon2id();
+
+    assert!(legacy.starts_with("$2"));
+    assert!(
+        hashing
+            .compare_password(&legacy, "correct-horse")
+            .await
+            .is_ok()
+    );
+    assert!(hashing.needs_rehash(&legacy));
+}
+
+#[tokio::test]
+async fn changed_argon2_parameters_trigger_a_rehash() {
+    let old = cheap_argon2id()
+        .hash_password("correct-horse")
+        .await
+        .unwrap();
+    let hashing = Hashing::new(HashingConfig {
+        argon2_iterations: 2,
+        ..cheap(HashAlgorithm::Argon2id)
+    });
+
+    assert!(hashing.needs_rehash(&old));
+}
+
+/// Environment variables are process-wide, so every case lives in one test.
+#[test]
+fn invalid_hashing_settings_fail_at_startup() {
+    let load = |key: &str, value: &str| {
+        // SAFETY: no other test in this binary reads or writes the environment.
+        unsafe { std::env::set_var(key, value) };
+        let config = HashingConfig::from_env();
+        unsafe { std::env::remove_var(key) };
+        config
+    };
+
+    assert_eq!(
+        HashingConfig::from_env().unwrap().algorithm,
+        HashAlgorithm::Argon2id
+    );
+    assert_eq!(
+        load("PASSWORD_HASH_ALGORITHM", "bcrypt").unwrap().algorithm,
+        HashAlgorithm::Bcrypt
+    );
+    assert!(load("PASSWORD_HASH_ALGORITHM", "argon2").is_err());
+    assert!(load("BCRYPT_COST", "twelve").is_err());
+    assert!(load("BCRYPT_COST", "40").is_err());
+    assert!(load("ARGON2_MEMORY_KIB", "1").is_err());
+}
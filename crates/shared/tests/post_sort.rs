@@ -0,0 +1,57 @@
+use shared::model::posts::{PostSort, PostSortField, SortDirection};
+
+fn sort(field: PostSortField, direction: SortDirection) -> Option<PostSort> {
+    Some(PostSort { field, direction })
+}
+
+#[test]
+fn empty_sort_keeps_the_default_order() {
+    assert_eq!(PostSort::parse("", "").unwrap(), None);
+    assert_eq!(PostSort::parse("  ", "").unwrap(), None);
+}
+
+#[test]
+fn direction_defaults_per_field() {
+    assert_eq!(
+        PostSort::parse("title", "").unwrap(),
+        sort(PostSortField::Title, SortDirection::Asc)
+    );
+    assert_eq!(
+        PostSort::parse("popularity", "").unwrap(),
+        sort(PostSortField::Popularity, SortDirection::Desc)
+    );
+    assert_eq!(
+        PostSort::parse("", "asc").unwrap(),
+        sort(PostSortField::CreatedAt, SortDirection::Asc)
+    );
+}
+
+#[test]
+fn only_whitelisted_fields_and_orders_are_accepted() {
+    assert!(PostSort::parse("body", "").is_err());
+    assert!(PostSort::parse("id; DROP TABLE posts", "").is_err());
+    assert!(PostSort::parse("title", "sideways").is_err());
+    assert!(PostSort::parse("Title", "").is_err());
+}
+
+#[test]
+fn only_newest_first_suits_cursor_paging() {
+    assert!(
+        PostSort::parse("created_at", "desc")
+            .unwrap()
+            .unwrap()
+            .is_newest_first()
+    );
+    assert!(
+        !PostSort::parse("created_at", "asc")
+            .unwrap()
+            .unwrap()
+            .is_newest_first()
+    );
+    assert!(
+        !PostSort::parse("title", "desc")
+            .unwrap()
+            .unwrap()
+            .is_newest_first()
+    );
+}
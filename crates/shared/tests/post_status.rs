@@ -0,0 +1,75 @@
+use chrono::{DateTime, Duration, Utc};
+use shared::model::posts::{Post, PostPublication, PostStatus};
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-10-17T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn post(status: PostStatus, published_at: Option<DateTime<Utc>>) -> Post {
+    Post {
+        id: 1,
+        title: "Hello".into(),
+        img: "hello.png".into(),
+        body: "Hello, world".into(),
+        category_id: 1,
+        user_id: 1,
+        user_name: "author".into(),
+        slug: "hello".into(),
+        status: status.to_string(),
+        published_at,
+    }
+}
+
+#[test]
+fn new_posts_are_published_unless_asked_otherwise() {
+    let publication = PostPublication::resolve("", "", None, now()).unwrap();
+    assert_eq!(publication.status, PostStatus::Published);
+    assert_eq!(publication.published_at, Some(now()));
+
+    let draft = PostPublication::resolve("draft", "", None, now()).unwrap();
+    assert_eq!(draft.status, PostStatus::Draft);
+    assert!(draft.published_at.is_none());
+}
+
+#[test]
+fn updates_keep_status_and_original_publication_time() {
+    let earlier = now() - Duration::days(3);
+    let current = post(PostStatus::Published, Some(earlier));
+
+    let unchanged = PostPublication::resolve("", "", Some(&current), now()).unwrap();
+    assert_eq!(unchanged.status, PostStatus::Published);
+    assert_eq!(unchanged.published_at, Some(earlier));
+
+    let archived = PostPublication::resolve("archived", "", Some(&current), now()).unwrap();
+    assert_eq!(archived.status, PostStatus::Archived);
+    assert_eq!(archived.published_at, Some(earlier));
+}
+
+#[test]
+fn scheduling_needs_a_future_time() {
+    let at =
+        PostPublication::resolve("scheduled", "2026-10-18T09:00:00+02:00", None, now()).unwrap();
+    assert_eq!(at.status, PostStatus::Scheduled);
+    assert_eq!(at.published_at, Some(now() + Duration::hours(19)));
+
+    assert!(PostPublication::resolve("scheduled", "", None, now()).is_err());
+    assert!(PostPublication::resolve("scheduled", "2026-10-16T00:00:00Z", None, now()).is_err());
+    assert!(PostPublication::resolve("scheduled", "tomorrow", None, now()).is_err());
+}
+
+#[test]
+fn rescheduling_without_a_time_keeps_the_old_one() {
+    let later = now() + Duration::hours(1);
+    let current = post(PostStatus::Scheduled, Some(later));
+
+    let publication = PostPublication::resolve("scheduled", "", Some(&current), now()).unwrap();
+    assert_eq!(publication.published_at, Some(later));
+}
+
+#[test]
+fn unknown_statuses_are_rejected() {
+    assert!(PostPublication::resolve("hidden", "", None, now()).is_err());
+    assert_eq!("archived".parse::<PostStatus>(), Ok(PostStatus::Archived));
+}
@@ -0,0 +1,138 @@
+mod common {
+    pub mod audit;
+    pub mod cache;
+    pub mod posts;
+}
+
+use async_trait::async_trait;
+use common::{
+    audit::RecordingAudit,
+    cache::unreachable_cache,
+    posts::{InMemoryPosts, post},
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    abstract_trait::{PostsServiceTrait, TagRepositoryTrait},
+    config::Claims,
+    domain::{CreateTagRequest, UpdateTagRequest},
+    model::{
+        posts::PostStatus,
+        role::Permission,
+        tag::{PostTag, Tag, TagCount},
+    },
+    service::PostService,
+    utils::{AppError, Metrics},
+};
+use std::sync::{Arc, Mutex};
+
+/// Posts without tags; tagging is covered by the repository.
+struct NoTags;
+
+#[async_trait]
+impl TagRepositoryTrait for NoTags {
+    async fn find_all(
+        &self,
+        _page: i32,
+        _page_size: i32,
+        _search: Option<String>,
+    ) -> Result<(Vec<Tag>, i64), AppError> {
+        unimplemented!("posts only look up their own tags")
+    }
+
+    async fn find_by_id(&self, _id: i32) -> Result<Option<Tag>, AppError> {
+        unimplemented!("posts only look up their own tags")
+    }
+
+    async fn find_for_posts(&self, _post_ids: &[i32]) -> Result<Vec<PostTag>, AppError> {
+        Ok(Vec::new())
+    }
+
+    async fn tag_cloud(&self, _limit: i32) -> Result<Vec<TagCount>, AppError> {
+        unimplemented!("posts only look up their own tags")
+    }
+
+    async fn create(&self, _input: &CreateTagRequest) -> Result<Tag, AppError> {
+        unimplemented!("posts never write tags")
+    }
+
+    async fn update(&self, _input: &UpdateTagRequest) -> Result<Tag, AppError> {
+        unimplemented!("posts never write tags")
+    }
+
+    async fn delete(&self, _id: i32) -> Result<(), AppError> {
+        unimplemented!("posts never write tags")
+    }
+}
+
+/// Post 1 is published and post 2 is a draft, both written by user 3.
+async fn service() -> PostService {
+    let posts = InMemoryPosts {
+        posts: Mutex::new(vec![
+            post(1, 3, PostStatus::Published),
+            post(2, 3, PostStatus::Draft),
+        ]),
+    };
+
+    PostService::new(
+        Arc::new(posts),
+        Arc::new(NoTags),
+        Arc::new(RecordingAudit::default()),
+        Arc::new(tokio::sync::Mutex::new(Metrics::new())),
+        &mut Registry::default(),
+        unreachable_cache(),
+    )
+    .await
+}
+
+fn viewer(user_id: i64, permissions: &[Permission]) -> Claims {
+    Claims::new(
+        user_id,
+        "author".to_string(),
+        permissions.iter().map(|p| p.as_str().to_string()).collect(),
+        0,
+        usize::MAX,
+        0,
+    )
+}
+
+#[tokio::test]
+async fn drafts_are_found_by_slug_only_by_their_author_and_moderators() {
+    let service = service().await;
+    let stranger = viewer(2, &[]);
+    let author = viewer(3, &[]);
+    let moderator = viewer(9, &[Permission::PostsModerate]);
+
+    let published = service.get_post_by_slug("hello-1", None).await.unwrap();
+    assert_eq!(published.unwrap().data.id, 1);
+
+    for anyone in [None, Some(&stranger)] {
+        let draft = service.get_post_by_slug("hello-2", anyone).await.unwrap();
+        assert!(draft.is_none());
+    }
+
+    for allowed in [&author, &moderator] {
+        let draft = service
+            .get_post_by_slug("hello-2", Some(allowed))
+            .await
+            .unwrap();
+        assert_eq!(draft.unwrap().data.id, 2);
+    }
+}
+
+#[tokio::test]
+async fn comments_on_drafts_stay_hidden_from_other_readers() {
+    let service = service().await;
+    let stranger = viewer(2, &[]);
+    let author = viewer(3, &[]);
+
+    let relation = service.get_post_relation(1, None).await.unwrap();
+    assert_eq!(relation.data.post_id, 1);
+
+    for anyone in [None, Some(&stranger)] {
+        let err = service.get_post_relation(2, anyone).await.unwrap_err();
+        assert_eq!(err.status, "not_found");
+    }
+
+    let relation = service.get_post_relation(2, Some(&author)).await.unwrap();
+    assert_eq!(relation.data.post_id, 2);
+}
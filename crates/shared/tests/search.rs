@@ -0,0 +1,31 @@
+use shared::utils::{HIGHLIGHT_START, HIGHLIGHT_STOP, render_highlight};
+
+fn marked(word: &str) -> String {
+    format!("{HIGHLIGHT_START}{word}{HIGHLIGHT_STOP}")
+}
+
+#[test]
+fn markers_become_mark_tags() {
+    let raw = format!("Searching {} with {}", marked("Postgres"), marked("Rust"));
+
+    assert_eq!(
+        render_highlight(&raw),
+        "Searching <mark>Postgres</mark> with <mark>Rust</mark>"
+    );
+}
+
+#[test]
+fn post_text_is_escaped() {
+    let raw = format!("<script>alert(\"x\")</script> & {} 'quoted'", marked("<b>"));
+
+    assert_eq!(
+        render_highlight(&raw),
+        "&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt; &amp; <mark>&lt;b&gt;</mark> &#39;quoted&#39;"
+    );
+}
+
+#[test]
+fn text_without_matches_is_left_alone() {
+    assert_eq!(render_highlight("plain snippet"), "plain snippet");
+    assert_eq!(render_highlight(""), "");
+}
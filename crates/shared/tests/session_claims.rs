@@ -0,0 +1,30 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde_json::Value;
+use shared::config::JwtConfig;
+
+fn payload(token: &str) -> Value {
+    let payload = token.split('.').nth(1).unwrap();
+    serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap()
+}
+
+#[test]
+fn session_id_round_trips_through_access_token() {
+    let config = JwtConfig::new("test-secret");
+    let token = config
+        .generate_token(7, "user", vec![], Some("3f0c1a9e-session"), 0)
+        .unwrap();
+
+    let claims = config.decode_token(&token).unwrap();
+
+    assert_eq!(claims.user_id, 7);
+    assert_eq!(claims.sid.as_deref(), Some("3f0c1a9e-session"));
+}
+
+#[test]
+fn tokens_without_session_omit_the_claim() {
+    let config = JwtConfig::new("test-secret");
+    let token = config.generate_token(7, "user", vec![], None, 0).unwrap();
+
+    assert!(payload(&token).get("sid").is_none());
+    assert!(config.decode_token(&token).unwrap().sid.is_none());
+}
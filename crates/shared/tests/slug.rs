@@ -0,0 +1,46 @@
+use shared::utils::{generate_slug, slug_matches, unique_slug};
+
+#[test]
+fn titles_become_hyphenated_lowercase() {
+    assert_eq!(generate_slug("Hello, World!"), "hello-world");
+    assert_eq!(
+        generate_slug("  Rust -- 2024 edition  "),
+        "rust-2024-edition"
+    );
+    assert_eq!(generate_slug("Don't panic"), "dont-panic");
+}
+
+#[test]
+fn non_ascii_titles_are_transliterated() {
+    assert_eq!(
+        generate_slug("Crème brûlée à la française"),
+        "creme-brulee-a-la-francaise"
+    );
+    assert_eq!(generate_slug("Straße über Øresund"), "strasse-uber-oresund");
+    assert_eq!(generate_slug("Привет, мир"), "privet-mir");
+    assert_eq!(generate_slug("Καλημέρα"), "kalimera");
+}
+
+#[test]
+fn untransliterated_scripts_are_kept() {
+    assert_eq!(generate_slug("東京 2024"), "東京-2024");
+    assert_eq!(generate_slug("!!!"), "");
+}
+
+#[test]
+fn collisions_get_a_numeric_suffix() {
+    let taken = vec!["hello".to_string(), "hello-2".to_string()];
+
+    assert_eq!(unique_slug("fresh", &taken), "fresh");
+    assert_eq!(unique_slug("hello", &taken), "hello-3");
+    assert_eq!(unique_slug("hello", &["hello-2".to_string()]), "hello");
+}
+
+#[test]
+fn suffixed_slugs_match_their_base() {
+    assert!(slug_matches("hello", "hello"));
+    assert!(slug_matches("hello-12", "hello"));
+    assert!(!slug_matches("hello-world", "hello"));
+    assert!(!slug_matches("hello-", "hello"));
+    assert!(!slug_matches("help", "hello"));
+}
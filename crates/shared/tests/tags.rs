@@ -0,0 +1,41 @@
+use shared::model::tag::{MAX_TAG_NAME_CHARS, MAX_TAGS_PER_POST, normalize_tag_names};
+
+fn names(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn trims_collapses_and_drops_blank_names() {
+    let tags = normalize_tag_names(&names(&["  Rust  ", "", "   ", "web   dev"])).unwrap();
+    assert_eq!(tags, names(&["Rust", "web dev"]));
+}
+
+#[test]
+fn names_sharing_a_slug_are_kept_once() {
+    let tags = normalize_tag_names(&names(&["Rust", "rust", "RUST!", "Café", "cafe"])).unwrap();
+    assert_eq!(tags, names(&["Rust", "Café"]));
+}
+
+#[test]
+fn rejects_names_without_letters_or_digits() {
+    assert!(normalize_tag_names(&names(&["rust", "!!!"])).is_err());
+}
+
+#[test]
+fn enforces_length_and_count_limits() {
+    let long = "a".repeat(MAX_TAG_NAME_CHARS + 1);
+    assert!(normalize_tag_names(&[long]).is_err());
+
+    let many: Vec<String> = (0..=MAX_TAGS_PER_POST)
+        .map(|n| format!("tag {n}"))
+        .collect();
+    assert!(normalize_tag_names(&many).is_err());
+
+    // Duplicates do not count against the limit.
+    let mut repeated = many[..MAX_TAGS_PER_POST].to_vec();
+    repeated.push("Tag 0".to_string());
+    assert_eq!(
+        normalize_tag_names(&repeated).unwrap().len(),
+        MAX_TAGS_PER_POST
+    );
+}
@@ -0,0 +1,96 @@
+mod common {
+    pub mod cache;
+    pub mod memory_store;
+}
+
+use chrono::Utc;
+use common::{cache::unreachable_cache, memory_store::MemoryStore};
+use shared::{cache::TokenRevocationStore, config::Claims, utils::AppError};
+use std::{sync::Arc, time::Duration};
+
+fn claims(sid: Option<&str>, generation: i64) -> Claims {
+    let exp = Utc::now().timestamp() as usize + 3600;
+    let mut claims = Claims::new(1, "reader".to_string(), vec![], generation, exp, 0);
+    claims.sid = sid.map(str::to_string);
+    claims
+}
+
+#[tokio::test]
+async fn revoked_tokens_are_rejected_and_others_are_not() {
+    let store = TokenRevocationStore::new(Arc::new(MemoryStore::default()));
+    let revoked = claims(None, 0);
+    let other = claims(None, 0);
+
+    store.revoke(&revoked).await.unwrap();
+
+    assert!(store.is_revoked(&revoked).await.unwrap());
+    assert!(!store.is_revoked(&other).await.unwrap());
+}
+
+#[tokio::test]
+async fn revoking_a_session_rejects_every_token_issued_for_it() {
+    let store = TokenRevocationStore::new(Arc::new(MemoryStore::default()));
+
+    store
+        .revoke_session("session-a", Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    assert!(
+        store
+            .is_revoked(&claims(Some("session-a"), 0))
+            .await
+            .unwrap()
+    );
+    assert!(
+        !store
+            .is_revoked(&claims(Some("session-b"), 0))
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn bumping_the_generation_rejects_older_tokens() {
+    let store = TokenRevocationStore::new(Arc::new(MemoryStore::default()));
+    let before = claims(None, store.current_generation(1).await.unwrap());
+
+    store.bump_generation(1).await.unwrap();
+    let after = claims(None, store.current_generation(1).await.unwrap());
+
+    assert!(store.is_revoked(&before).await.unwrap());
+    assert!(!store.is_revoked(&after).await.unwrap());
+}
+
+#[tokio::test]
+async fn an_id_token_signs_in_only_once() {
+    let store = TokenRevocationStore::new(Arc::new(MemoryStore::default()));
+    let issuer = "https://idp.example";
+    let expires_at = Utc::now().timestamp() + 300;
+
+    let first = store
+        .consume_id_token(issuer, "nonce-1", expires_at)
+        .await
+        .unwrap();
+    let replay = store
+        .consume_id_token(issuer, "nonce-1", expires_at)
+        .await
+        .unwrap();
+    let other = store
+        .consume_id_token(issuer, "nonce-2", expires_at)
+        .await
+        .unwrap();
+
+    assert!(first);
+    assert!(!replay);
+    assert!(other);
+}
+
+#[tokio::test]
+async fn an_unreachable_cache_fails_closed() {
+    let store = TokenRevocationStore::new(unreachable_cache());
+
+    let err = store.is_revoked(&claims(Some("session-a"), 0)).await;
+
+    assert!(matches!(err, Err(AppError::CacheError(_))));
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use shared::utils::{
+    generate_recovery_code, generate_totp_secret, totp_code, totp_provisioning_uri, totp_step,
+    verify_totp,
+};
+
+// RFC 6238 appendix B seed "12345678901234567890", base32 encoded.
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+fn at(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+#[test]
+fn matches_rfc6238_sha1_vectors() {
+    let vectors = [
+        (59, "287082"),
+        (1_111_111_109, "081804"),
+        (1_111_111_111, "050471"),
+        (1_234_567_890, "005924"),
+        (2_000_000_000, "279037"),
+    ];
+
+    for (secs, expected) in vectors {
+        assert_eq!(
+            totp_code(RFC_SECRET, totp_step(at(secs))).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn accepts_one_step_of_drift_and_reports_matched_step() {
+    let now = at(1_234_567_890);
+    let step = totp_step(now);
+    let previous = totp_code(RFC_SECRET, step - 1).unwrap();
+    let stale = totp_code(RFC_SECRET, step - 2).unwrap();
+
+    assert_eq!(
+        verify_totp(RFC_SECRET, &previous, now).unwrap(),
+        Some(step - 1)
+    );
+    assert_eq!(verify_totp(RFC_SECRET, &stale, now).unwrap(), None);
+    assert_eq!(verify_totp(RFC_SECRET, "12345", now).unwrap(), None);
+    assert_eq!(verify_totp(RFC_SECRET, "abcdef", now).unwrap(), None);
+}
+
+#[test]
+fn generated_secrets_round_trip() {
+    let secret = generate_totp_secret().unwrap();
+    let now = Utc::now();
+    let code = totp_code(&secret, totp_step(now)).unwrap();
+
+    assert_eq!(secret.len(), 32);
+    assert!(verify_totp(&secret, &code, now).unwrap().is_some());
+    assert_ne!(
+        generate_recovery_code().unwrap(),
+        generate_recovery_code().unwrap()
+    );
+}
+
+#[test]
+fn provisioning_uri_escapes_label() {
+    let uri = totp_provisioning_uri("Example Blog", "jane@example.com", RFC_SECRET);
+
+    assert_eq!(
+        uri,
+        format!(
+            "otpauth://totp/Example%20Blog:jane%40example.com?secret={RFC_SECRET}&issuer=Example%20Blog&algorithm=SHA1&digits=6&period=30"
+        )
+    );
+}
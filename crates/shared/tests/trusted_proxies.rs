@@ -0,0 +1,25 @@
+use shared::config::TrustedProxies;
+
+#[test]
+fn only_listed_peers_may_forward_the_client_address() {
+    let proxies = TrustedProxies {
+        addresses: vec!["10.0.0.5".parse().unwrap()],
+        trust_client_certificates: false,
+    };
+
+    assert!(proxies.trusts(Some("10.0.0.5".parse().unwrap()), false));
+    assert!(!proxies.trusts(Some("10.0.0.6".parse().unwrap()), false));
+    assert!(!proxies.trusts(None, false));
+}
+
+#[test]
+fn client_certificates_are_trusted_only_under_mutual_tls() {
+    let mtls = TrustedProxies {
+        addresses: vec![],
+        trust_client_certificates: true,
+    };
+
+    assert!(mtls.trusts(None, true));
+    assert!(!mtls.trusts(None, false));
+    assert!(!TrustedProxies::default().trusts(None, true));
+}
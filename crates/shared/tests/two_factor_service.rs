@@ -0,0 +1,146 @@
+mod common {
+    pub mod audit;
+    pub mod memory_store;
+    pub mod users;
+}
+
+use async_trait::async_trait;
+use chrono::Utc;
+use common::{audit::RecordingAudit, memory_store::MemoryStore, users::InMemoryUsers};
+use prometheus_client::registry::Registry;
+use shared::{
+    abstract_trait::{TwoFactorRepositoryTrait, TwoFactorServiceTrait},
+    cache::{LoginAttemptPolicy, LoginAttemptStore},
+    config::{Claims, TwoFactorConfig},
+    domain::{AuditContext, ClientInfo, TwoFactorCodeRequest},
+    model::{two_factor::TotpSecret, user::User},
+    service::{TwoFactorService, TwoFactorServiceDeps},
+    utils::{AppError, Metrics, generate_totp_secret, totp_code, totp_step},
+};
+use std::sync::{Arc, Mutex};
+
+/// One user whose two-factor enrollment is already confirmed.
+struct EnabledTwoFactor {
+    secret: Mutex<Option<TotpSecret>>,
+}
+
+impl EnabledTwoFactor {
+    fn new(user_id: i32, secret: &str) -> Self {
+        Self {
+            secret: Mutex::new(Some(TotpSecret {
+                user_id,
+                secret: secret.to_string(),
+                confirmed_at: Some(Utc::now()),
+                last_used_step: None,
+                created_at: Utc::now(),
+            })),
+        }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepositoryTrait for EnabledTwoFactor {
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<TotpSecret>, AppError> {
+        let secret = self.secret.lock().unwrap();
+        Ok(secret.clone().filter(|secret| secret.user_id == user_id))
+    }
+
+    async fn save_pending(&self, _user_id: i32, _secret: &str) -> Result<TotpSecret, AppError> {
+        unimplemented!("enrollment is already confirmed")
+    }
+
+    async fn confirm(&self, _user_id: i32, _step: i64) -> Result<bool, AppError> {
+        unimplemented!("enrollment is already confirmed")
+    }
+
+    async fn record_step(&self, user_id: i32, step: i64) -> Result<bool, AppError> {
+        let mut secret = self.secret.lock().unwrap();
+        let Some(secret) = secret.as_mut().filter(|secret| secret.user_id == user_id) else {
+            return Ok(false);
+        };
+        if secret.last_used_step.is_some_and(|last| last >= step) {
+            return Ok(false);
+        }
+        secret.last_used_step = Some(step);
+        Ok(true)
+    }
+
+    async fn delete(&self, _user_id: i32) -> Result<(), AppError> {
+        self.secret.lock().unwrap().take();
+        Ok(())
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        _user_id: i32,
+        _code_hashes: &[String],
+    ) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    async fn consume_recovery_code(
+        &self,
+        _user_id: i32,
+        _code_hash: &str,
+    ) -> Result<bool, AppError> {
+        Ok(false)
+    }
+}
+
+#[tokio::test]
+async fn guessing_the_code_to_disable_two_factor_locks_the_account() {
+    let secret = generate_totp_secret().unwrap();
+    let users = Arc::new(InMemoryUsers {
+        users: Mutex::new(vec![User {
+            id: 7,
+            firstname: "Ada".to_string(),
+            lastname: "Lovelace".to_string(),
+            email: "ada@example.com".to_string(),
+            password: "unused".to_string(),
+            role: "author".to_string(),
+            email_verified_at: Some(Utc::now()),
+        }]),
+        ..Default::default()
+    });
+    let audit = Arc::new(RecordingAudit::default());
+    let service = TwoFactorService::new(
+        TwoFactorServiceDeps {
+            repository: Arc::new(EnabledTwoFactor::new(7, &secret)),
+            user_repository: users,
+            audit: audit.clone(),
+            login_attempts: Arc::new(LoginAttemptStore::new(
+                Arc::new(MemoryStore::default()),
+                LoginAttemptPolicy::default(),
+            )),
+            config: TwoFactorConfig::default(),
+            metrics: Arc::new(tokio::sync::Mutex::new(Metrics::new())),
+        },
+        &mut Registry::default(),
+    )
+    .await;
+
+    let actor = Claims::new(7, "author".to_string(), vec![], 0, 0, 0);
+    let ctx = AuditContext::new(&actor, ClientInfo::default());
+    let current = totp_code(&secret, totp_step(Utc::now())).unwrap();
+    let wrong = TwoFactorCodeRequest {
+        code: if current == "000000" {
+            "111111"
+        } else {
+            "000000"
+        }
+        .to_string(),
+    };
+
+    for _ in 0..LoginAttemptPolicy::default().max_failures_per_email {
+        let err = service.disable(&actor, &wrong, &ctx).await.unwrap_err();
+        assert_eq!(err.message, "Invalid two-factor code");
+    }
+
+    // Once locked, even the right code is refused.
+    let err = service
+        .disable(&actor, &TwoFactorCodeRequest { code: current }, &ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "too_many_requests");
+    assert!(audit.entries.lock().unwrap().is_empty());
+}
@@ -0,0 +1,367 @@
+mod common {
+    pub mod audit;
+    pub mod cache;
+    pub mod hashing;
+    pub mod mailer;
+    pub mod memory_store;
+    pub mod refresh_tokens;
+    pub mod sessions;
+    pub mod user_tokens;
+    pub mod users;
+}
+
+use chrono::{Duration, Utc};
+use common::{
+    audit::RecordingAudit, cache::unreachable_cache, hashing::cheap_argon2id,
+    mailer::CapturingMailer, memory_store::MemoryStore, refresh_tokens::InMemoryRefreshTokens,
+    sessions::InMemorySessions, user_tokens::InMemoryUserTokens, users::InMemoryUsers,
+};
+use prometheus_client::registry::Registry;
+use shared::{
+    abstract_trait::{
+        HashingTrait, RefreshTokenRepositoryTrait, SessionRepositoryTrait, UserServiceTrait,
+    },
+    cache::TokenRevocationStore,
+    config::{Claims, JwtConfig, MailConfig},
+    domain::{
+        AuditContext, ChangePasswordRequest, ClientInfo, ConfirmEmailChangeRequest,
+        RequestEmailChangeRequest, UpdateUserRequest,
+    },
+    model::user::User,
+    service::{UserService, UserServiceDeps},
+    utils::Metrics,
+};
+use std::sync::{Arc, Mutex};
+
+struct Fixture {
+    service: UserService,
+    users: Arc<InMemoryUsers>,
+    sessions: Arc<InMemorySessions>,
+    refresh_tokens: Arc<InMemoryRefreshTokens>,
+    revocation: Arc<TokenRevocationStore>,
+    mailer: Arc<CapturingMailer>,
+    audit: Arc<RecordingAudit>,
+}
+
+fn user(id: i32, email: &str, password: String) -> User {
+    User {
+        id,
+        firstname: "Ada".to_string(),
+        lastname: "Lovelace".to_string(),
+        email: email.to_string(),
+        password,
+        role: "author".to_string(),
+        email_verified_at: Some(Utc::now()),
+    }
+}
+
+async fn fixture() -> Fixture {
+    let password = cheap_argon2id()
+        .hash_password("correct-horse")
+        .await
+        .unwrap();
+    let users = Arc::new(InMemoryUsers {
+        users: Mutex::new(vec![
+            user(1, "ada@example.com", password),
+            user(2, "taken@example.com", "unused".to_string()),
+        ]),
+        ..Default::default()
+    });
+    let sessions = Arc::new(InMemorySessions::default());
+    let refresh_tokens = Arc::new(InMemoryRefreshTokens::default());
+    let revocation = Arc::new(TokenRevocationStore::new(Arc::new(MemoryStore::default())));
+    let mailer = Arc::new(CapturingMailer::default());
+    let audit = Arc::new(RecordingAudit::default());
+
+    for id in ["current-session", "other-session"] {
+        sessions.create(id, 1, None, None).await.unwrap();
+    }
+    refresh_tokens
+        .create(1, "refresh-hash", "family", Utc::now() + Duration::days(1))
+        .await
+        .unwrap();
+
+    let service = UserService::new(
+        UserServiceDeps {
+            repository: users.clone(),
+            user_token_repository: Arc::new(InMemoryUserTokens::default()),
+            session_repository: sessions.clone(),
+            refresh_token_repository: refresh_tokens.clone(),
+            hashing: Arc::new(cheap_argon2id()),
+            mailer: mailer.clone(),
+            mail_config: MailConfig::default(),
+            jwt_config: JwtConfig::new("test-secret"),
+            token_revocation: revocation.clone(),
+            audit: audit.clone(),
+            metrics: Arc::new(tokio::sync::Mutex::new(Metrics::new())),
+            cache_store: unreachable_cache(),
+        },
+        &mut Registry::default(),
+    )
+    .await;
+
+    Fixture {
+        service,
+        users,
+        sessions,
+        refresh_tokens,
+        revocation,
+        mailer,
+        audit,
+    }
+}
+
+impl Fixture {
+    fn user(&self) -> User {
+        self.users.users.lock().unwrap()[0].clone()
+    }
+
+    /// A token Ada was issued before the change under test.
+    fn issued_token(&self) -> Claims {
+        let user = self.user();
+        Claims::new(1, user.role, vec![], 0, usize::MAX, 0)
+    }
+
+    async fn signed_out(&self) -> bool {
+        let refresh = self
+            .refresh_tokens
+            .find_by_hash("refresh-hash")
+            .await
+            .unwrap()
+            .unwrap();
+
+        self.revocation
+            .is_revoked(&self.issued_token())
+            .await
+            .unwrap()
+            && refresh.revoked_at.is_some()
+    }
+
+    async fn has_password(&self, password: &str) -> bool {
+        cheap_argon2id()
+            .compare_password(&self.user().password, password)
+            .await
+            .is_ok()
+    }
+}
+
+fn actor() -> Claims {
+    let mut claims = Claims::new(1, "author".to_string(), vec![], 0, usize::MAX, 0);
+    claims.sid = Some("current-session".to_string());
+    claims
+}
+
+fn no_changes(id: i32) -> UpdateUserRequest {
+    UpdateUserRequest {
+        id,
+        firstname: None,
+        lastname: None,
+        email: None,
+        password: None,
+        role: None,
+    }
+}
+
+#[tokio::test]
+async fn demoting_a_user_rejects_their_existing_tokens() {
+    let f = fixture().await;
+
+    f.service
+        .update_user(
+            &UpdateUserRequest {
+                role: Some("reader".to_string()),
+                ..no_changes(1)
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(f.user().role, "reader");
+    assert!(f.signed_out().await);
+}
+
+#[tokio::test]
+async fn renaming_a_user_keeps_their_sessions() {
+    let f = fixture().await;
+
+    f.service
+        .update_user(
+            &UpdateUserRequest {
+                firstname: Some("Augusta".to_string()),
+                ..no_changes(1)
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(f.user().firstname, "Augusta");
+    assert!(!f.revocation.is_revoked(&f.issued_token()).await.unwrap());
+}
+
+#[tokio::test]
+async fn resetting_a_password_as_admin_stores_it_hashed_and_signs_the_user_out() {
+    let f = fixture().await;
+
+    f.service
+        .update_user(
+            &UpdateUserRequest {
+                password: Some("battery-staple".to_string()),
+                ..no_changes(1)
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(f.has_password("battery-staple").await);
+    assert!(f.signed_out().await);
+}
+
+#[tokio::test]
+async fn deleting_a_user_revokes_their_tokens_and_audits_the_account() {
+    let f = fixture().await;
+    let issued = f.issued_token();
+
+    f.service
+        .delete_user("ada@example.com", &AuditContext::default())
+        .await
+        .unwrap();
+
+    assert!(
+        f.users
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|user| user.email != "ada@example.com")
+    );
+    assert!(f.revocation.is_revoked(&issued).await.unwrap());
+
+    // The audit snapshot describes the account without its password hash.
+    let entries = f.audit.entries.lock().unwrap();
+    let before = entries[0].1.before.as_ref().unwrap();
+    assert_eq!(before["email"], "ada@example.com");
+    assert!(before.get("password").is_none());
+}
+
+#[tokio::test]
+async fn the_configured_admin_email_is_promoted() {
+    let f = fixture().await;
+
+    f.service
+        .bootstrap_admin("nobody@example.com")
+        .await
+        .unwrap();
+    assert_eq!(f.user().role, "author");
+
+    f.service.bootstrap_admin("ada@example.com").await.unwrap();
+    assert_eq!(f.user().role, "admin");
+}
+
+#[tokio::test]
+async fn change_password_requires_the_current_password() {
+    let f = fixture().await;
+
+    let err = f
+        .service
+        .change_password(
+            &actor(),
+            &ChangePasswordRequest {
+                current_password: "wrong".to_string(),
+                new_password: "battery-staple".to_string(),
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "invalid_argument");
+    assert!(f.has_password("correct-horse").await);
+    assert_eq!(f.sessions.active(), ["current-session", "other-session"]);
+}
+
+#[tokio::test]
+async fn change_password_rehashes_and_keeps_the_current_session() {
+    let f = fixture().await;
+
+    f.service
+        .change_password(
+            &actor(),
+            &ChangePasswordRequest {
+                current_password: "correct-horse".to_string(),
+                new_password: "battery-staple".to_string(),
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(f.has_password("battery-staple").await);
+    assert_eq!(f.sessions.active(), ["current-session"]);
+}
+
+#[tokio::test]
+async fn email_changes_only_after_the_new_address_confirms() {
+    let f = fixture().await;
+
+    f.service
+        .request_email_change(
+            &actor(),
+            &RequestEmailChangeRequest {
+                new_email: "ada@new.example.com".to_string(),
+                current_password: "correct-horse".to_string(),
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(f.user().email, "ada@example.com");
+
+    let mail = f.mailer.sent.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(mail.to, "ada@new.example.com");
+    let token = f.mailer.last_token();
+
+    let confirmed = f
+        .service
+        .confirm_email_change(
+            &ConfirmEmailChangeRequest {
+                token: token.clone(),
+            },
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(confirmed.data.email, "ada@new.example.com");
+    assert_eq!(f.user().email, "ada@new.example.com");
+
+    let err = f
+        .service
+        .confirm_email_change(&ConfirmEmailChangeRequest { token }, &ClientInfo::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, "invalid_argument");
+}
+
+#[tokio::test]
+async fn email_change_rejects_an_address_in_use() {
+    let f = fixture().await;
+
+    let err = f
+        .service
+        .request_email_change(
+            &actor(),
+            &RequestEmailChangeRequest {
+                new_email: "taken@example.com".to_string(),
+                current_password: "correct-horse".to_string(),
+            },
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "already_exists");
+    assert!(f.mailer.sent.lock().unwrap().is_empty());
+}
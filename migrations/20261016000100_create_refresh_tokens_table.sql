@@ -0,0 +1,21 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "refresh_tokens" (
+        "id" SERIAL PRIMARY KEY,
+        "user_id" INT NOT NULL,
+        "token_hash" VARCHAR(64) NOT NULL UNIQUE,
+        "family_id" VARCHAR(36) NOT NULL,
+        "expires_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL,
+            "revoked_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "replaced_by" INT,
+            "created_at" TIMESTAMP
+        WITH
+            TIME ZONE DEFAULT NOW(),
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS refresh_tokens_family_id_idx ON refresh_tokens (family_id);
@@ -0,0 +1,85 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "roles" (
+        "id" SERIAL PRIMARY KEY,
+        "name" VARCHAR(50) NOT NULL UNIQUE,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE DEFAULT NOW()
+    );
+
+CREATE TABLE
+    IF NOT EXISTS "permissions" (
+        "id" SERIAL PRIMARY KEY,
+        "name" VARCHAR(100) NOT NULL UNIQUE,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE DEFAULT NOW()
+    );
+
+CREATE TABLE
+    IF NOT EXISTS "role_permissions" (
+        "role_id" INT NOT NULL,
+        "permission_id" INT NOT NULL,
+        PRIMARY KEY (role_id, permission_id),
+        FOREIGN KEY (role_id) REFERENCES roles(id) ON UPDATE CASCADE ON DELETE CASCADE,
+        FOREIGN KEY (permission_id) REFERENCES permissions(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+INSERT INTO
+    roles (name)
+VALUES
+    ('admin'),
+    ('editor'),
+    ('author'),
+    ('reader') ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO
+    permissions (name)
+VALUES
+    ('users:read'),
+    ('users:write'),
+    ('users:delete'),
+    ('categories:write'),
+    ('categories:delete'),
+    ('posts:write'),
+    ('posts:delete'),
+    ('posts:moderate'),
+    ('comments:write'),
+    ('comments:moderate') ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO
+    role_permissions (role_id, permission_id)
+SELECT
+    r.id,
+    p.id
+FROM
+    roles r
+    JOIN permissions p ON (
+        r.name = 'admin'
+        OR (
+            r.name = 'editor'
+            AND p.name IN (
+                'categories:write',
+                'posts:write',
+                'posts:delete',
+                'posts:moderate',
+                'comments:write',
+                'comments:moderate'
+            )
+        )
+        OR (
+            r.name = 'author'
+            AND p.name IN ('posts:write', 'posts:delete', 'comments:write')
+        )
+        OR (
+            r.name = 'reader'
+            AND p.name IN ('comments:write')
+        )
+    ) ON CONFLICT DO NOTHING;
+
+-- Every signed-in user could write posts before roles existed, so existing
+-- and newly registered accounts start as authors. Admins are bootstrapped
+-- from ADMIN_EMAIL at startup.
+ALTER TABLE "users"
+ADD COLUMN IF NOT EXISTS "role" VARCHAR(50) NOT NULL DEFAULT 'author' REFERENCES roles(name) ON UPDATE CASCADE;
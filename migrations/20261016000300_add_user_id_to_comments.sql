@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE "comments"
+ADD COLUMN IF NOT EXISTS "user_id" INT REFERENCES users (id) ON UPDATE CASCADE ON DELETE CASCADE;
@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE "users"
+ADD COLUMN IF NOT EXISTS "email_verified_at" TIMESTAMPTZ;
+
+UPDATE "users" SET "email_verified_at" = NOW() WHERE "email_verified_at" IS NULL;
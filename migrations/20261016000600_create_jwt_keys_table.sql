@@ -0,0 +1,17 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "jwt_keys" (
+        "kid" VARCHAR(64) PRIMARY KEY,
+        "algorithm" VARCHAR(16) NOT NULL,
+        "private_key" BYTEA NOT NULL,
+        "public_jwk" TEXT NOT NULL,
+        "activates_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL,
+            "retires_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "created_at" TIMESTAMP
+        WITH
+            TIME ZONE DEFAULT NOW()
+    );
@@ -0,0 +1,25 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "api_keys" (
+        "id" SERIAL PRIMARY KEY,
+        "user_id" INT NOT NULL,
+        "name" VARCHAR(100) NOT NULL,
+        "prefix" VARCHAR(16) NOT NULL UNIQUE,
+        "key_hash" VARCHAR(64) NOT NULL,
+        "scopes" TEXT[] NOT NULL DEFAULT '{}',
+        "last_used_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "expires_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "revoked_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS api_keys_user_id_idx ON api_keys (user_id);
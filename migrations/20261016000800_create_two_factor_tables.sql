@@ -0,0 +1,30 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "totp_secrets" (
+        "user_id" INT PRIMARY KEY,
+        "secret" VARCHAR(64) NOT NULL,
+        "confirmed_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "last_used_step" BIGINT,
+            "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE TABLE
+    IF NOT EXISTS "recovery_codes" (
+        "id" SERIAL PRIMARY KEY,
+        "user_id" INT NOT NULL,
+        "code_hash" VARCHAR(64) NOT NULL,
+        "used_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS recovery_codes_user_id_idx ON recovery_codes (user_id);
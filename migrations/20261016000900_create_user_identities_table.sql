@@ -0,0 +1,19 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "user_identities" (
+        "id" SERIAL PRIMARY KEY,
+        "user_id" INT NOT NULL,
+        "issuer" VARCHAR(255) NOT NULL,
+        "subject" VARCHAR(255) NOT NULL,
+        "email" VARCHAR(255) NOT NULL,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            "last_login_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            UNIQUE (issuer, subject),
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS user_identities_user_id_idx ON user_identities (user_id);
@@ -0,0 +1,37 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "sessions" (
+        "id" VARCHAR(36) PRIMARY KEY,
+        "user_id" INT NOT NULL,
+        "user_agent" TEXT,
+        "ip_address" VARCHAR(45),
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            "last_seen_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            "revoked_at" TIMESTAMP
+        WITH
+            TIME ZONE,
+            FOREIGN KEY (user_id) REFERENCES users(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS sessions_user_id_idx ON sessions (user_id);
+
+-- Refresh token families issued before sessions existed become sessions of
+-- their own so those logins keep working and show up in the session list.
+INSERT INTO
+    sessions (id, user_id, created_at, last_seen_at)
+SELECT
+    family_id,
+    user_id,
+    MIN(COALESCE(created_at, NOW())),
+    MAX(COALESCE(created_at, NOW()))
+FROM
+    refresh_tokens
+WHERE
+    revoked_at IS NULL
+GROUP BY
+    family_id,
+    user_id ON CONFLICT (id) DO NOTHING;
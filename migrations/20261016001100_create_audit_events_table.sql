@@ -0,0 +1,53 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "audit_events" (
+        "id" BIGSERIAL PRIMARY KEY,
+        -- No foreign key: the trail must outlive the users it mentions.
+        "actor_id" INT,
+        "action" VARCHAR(64) NOT NULL,
+        "target_type" VARCHAR(32) NOT NULL,
+        "target_id" VARCHAR(64),
+        "ip_address" VARCHAR(45),
+        "request_id" VARCHAR(64),
+        "before" JSONB,
+        "after" JSONB,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW()
+    );
+
+CREATE INDEX IF NOT EXISTS audit_events_created_at_idx ON audit_events (created_at DESC, id DESC);
+
+CREATE INDEX IF NOT EXISTS audit_events_actor_id_idx ON audit_events (actor_id);
+
+CREATE INDEX IF NOT EXISTS audit_events_target_idx ON audit_events (target_type, target_id);
+
+CREATE INDEX IF NOT EXISTS audit_events_action_idx ON audit_events (action);
+
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS audit_events_append_only ON audit_events;
+
+CREATE TRIGGER audit_events_append_only BEFORE
+UPDATE
+OR DELETE ON audit_events FOR EACH ROW
+EXECUTE FUNCTION audit_events_append_only();
+
+INSERT INTO
+    permissions (name)
+VALUES
+    ('audit:read') ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO
+    role_permissions (role_id, permission_id)
+SELECT
+    r.id,
+    p.id
+FROM
+    roles r
+    JOIN permissions p ON r.name = 'admin'
+    AND p.name = 'audit:read' ON CONFLICT DO NOTHING;
@@ -0,0 +1,51 @@
+-- Add migration script here
+ALTER TABLE "posts"
+ADD COLUMN IF NOT EXISTS "slug" VARCHAR(255);
+
+ALTER TABLE "categories"
+ADD COLUMN IF NOT EXISTS "slug" VARCHAR(255);
+
+-- Existing rows get an ASCII-only slug made unique by their id; new rows are
+-- slugged by the application.
+UPDATE "posts"
+SET
+    "slug" = TRIM(
+        BOTH '-'
+        FROM
+            REGEXP_REPLACE(LOWER("title"), '[^a-z0-9]+', '-', 'g') || '-' || "id"
+    )
+WHERE
+    "slug" IS NULL;
+
+UPDATE "categories"
+SET
+    "slug" = TRIM(
+        BOTH '-'
+        FROM
+            REGEXP_REPLACE(LOWER("name"), '[^a-z0-9]+', '-', 'g') || '-' || "id"
+    )
+WHERE
+    "slug" IS NULL;
+
+ALTER TABLE "posts"
+ALTER COLUMN "slug" SET NOT NULL;
+
+ALTER TABLE "categories"
+ALTER COLUMN "slug" SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS posts_slug_key ON posts (slug);
+
+CREATE UNIQUE INDEX IF NOT EXISTS categories_slug_key ON categories (slug);
+
+-- Slugs a post has been renamed away from, so old links can redirect.
+CREATE TABLE
+    IF NOT EXISTS "post_slug_history" (
+        "slug" VARCHAR(255) PRIMARY KEY,
+        "post_id" INT NOT NULL,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE NOT NULL DEFAULT NOW(),
+            FOREIGN KEY (post_id) REFERENCES posts(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS post_slug_history_post_id_idx ON post_slug_history (post_id);
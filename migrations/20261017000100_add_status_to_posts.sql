@@ -0,0 +1,26 @@
+-- Add migration script here
+ALTER TABLE "posts"
+ADD COLUMN IF NOT EXISTS "status" VARCHAR(20) NOT NULL DEFAULT 'published' CHECK (
+    "status" IN ('draft', 'published', 'scheduled', 'archived')
+);
+
+ALTER TABLE "posts"
+ADD COLUMN IF NOT EXISTS "published_at" TIMESTAMP
+WITH
+    TIME ZONE;
+
+-- Everything already in the table was public, so it counts as published from
+-- the day it was written. New rows state their status explicitly.
+UPDATE "posts"
+SET
+    "published_at" = COALESCE("created_at", NOW())
+WHERE
+    "status" = 'published'
+    AND "published_at" IS NULL;
+
+ALTER TABLE "posts"
+ALTER COLUMN "status" SET DEFAULT 'draft';
+
+CREATE INDEX IF NOT EXISTS posts_status_published_at_idx ON posts (status, published_at);
+
+CREATE INDEX IF NOT EXISTS posts_user_id_idx ON posts (user_id);
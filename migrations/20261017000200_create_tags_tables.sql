@@ -0,0 +1,45 @@
+-- Add migration script here
+CREATE TABLE
+    IF NOT EXISTS "tags" (
+        "id" SERIAL PRIMARY KEY,
+        "name" VARCHAR(100) NOT NULL,
+        "slug" VARCHAR(255) NOT NULL UNIQUE,
+        "created_at" TIMESTAMP
+        WITH
+            TIME ZONE DEFAULT NOW()
+    );
+
+CREATE TABLE
+    IF NOT EXISTS "post_tags" (
+        "post_id" INT NOT NULL,
+        "tag_id" INT NOT NULL,
+        PRIMARY KEY (post_id, tag_id),
+        FOREIGN KEY (post_id) REFERENCES posts(id) ON UPDATE CASCADE ON DELETE CASCADE,
+        FOREIGN KEY (tag_id) REFERENCES tags(id) ON UPDATE CASCADE ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS post_tags_tag_id_idx ON post_tags (tag_id);
+
+INSERT INTO
+    permissions (name)
+VALUES
+    ('tags:write'),
+    ('tags:delete') ON CONFLICT (name) DO NOTHING;
+
+-- Authors tag their own posts through `posts:write`; these cover managing the
+-- tag list itself, mirroring categories.
+INSERT INTO
+    role_permissions (role_id, permission_id)
+SELECT
+    r.id,
+    p.id
+FROM
+    roles r
+    JOIN permissions p ON (
+        r.name = 'admin'
+        AND p.name IN ('tags:write', 'tags:delete')
+    )
+    OR (
+        r.name = 'editor'
+        AND p.name = 'tags:write'
+    ) ON CONFLICT DO NOTHING;
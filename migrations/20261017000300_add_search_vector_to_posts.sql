@@ -0,0 +1,10 @@
+-- Add migration script here
+-- Title matches outrank body matches. The configuration has to be spelled
+-- out for the expression to be immutable, and queries must use the same one
+-- for the index to apply.
+ALTER TABLE "posts"
+ADD COLUMN IF NOT EXISTS "search_vector" TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', coalesce("title", '')), 'A') || setweight(to_tsvector('english', coalesce("body", '')), 'B')
+) STORED;
+
+CREATE INDEX IF NOT EXISTS posts_search_vector_idx ON posts USING GIN (search_vector);
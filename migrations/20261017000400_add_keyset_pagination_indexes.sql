@@ -0,0 +1,13 @@
+-- Keyset pagination orders listings on (created_at, id), which needs both
+-- to be set on every row.
+UPDATE "posts" SET "created_at" = NOW() WHERE "created_at" IS NULL;
+UPDATE "users" SET "created_at" = NOW() WHERE "created_at" IS NULL;
+UPDATE "categories" SET "created_at" = NOW() WHERE "created_at" IS NULL;
+
+ALTER TABLE "posts" ALTER COLUMN "created_at" SET NOT NULL;
+ALTER TABLE "users" ALTER COLUMN "created_at" SET NOT NULL;
+ALTER TABLE "categories" ALTER COLUMN "created_at" SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS posts_created_at_id_idx ON posts (created_at, id);
+CREATE INDEX IF NOT EXISTS users_created_at_id_idx ON users (created_at, id);
+CREATE INDEX IF NOT EXISTS categories_created_at_id_idx ON categories (created_at, id);